@@ -0,0 +1,376 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Polled account and position updates.
+//!
+//! MT5 has no push feed for account changes, so the execution client polls
+//! `account_info` and `positions_get` and runs each snapshot through
+//! [`AccountSnapshotDiffer`], which only yields an update for values that
+//! changed since the previous poll.
+
+use std::collections::HashMap;
+
+use dashmap::DashMap;
+use nautilus_core::{nanos::UnixNanos, UUID4};
+use nautilus_model::{
+    enums::{AccountType, PositionSideSpecified},
+    events::AccountState,
+    identifiers::{AccountId, InstrumentId, PositionId, Symbol, Venue},
+    instruments::{Instrument, InstrumentAny},
+    reports::PositionStatusReport,
+    types::{AccountBalance, Quantity},
+};
+
+use crate::{
+    common::{
+        consts::MT5_NAME,
+        models::Mt5AccountInfo,
+        parse::{parse_account_balance, ParseError},
+        symbol::SymbolMapper,
+    },
+    http::models::Mt5TradePosition,
+};
+
+/// Size precision used for positions in instruments no provider has loaded.
+const DEFAULT_SIZE_PRECISION: u8 = 2;
+/// Tolerance when comparing lot volumes.
+const VOLUME_EPSILON: f64 = 1e-9;
+
+/// Update emitted by [`crate::execution_client::Mt5ExecutionClient::subscribe_account_state`].
+#[derive(Debug, Clone)]
+pub enum AccountUpdate {
+    Account(AccountState),
+    Position(PositionStatusReport),
+}
+
+/// The position fields that change a `PositionStatusReport`.
+#[derive(Debug, Clone, PartialEq)]
+struct PositionSnapshot {
+    symbol: String,
+    side: PositionSideSpecified,
+    volume: f64,
+}
+
+impl From<&Mt5TradePosition> for PositionSnapshot {
+    fn from(position: &Mt5TradePosition) -> Self {
+        Self {
+            symbol: position.symbol.clone(),
+            side: if position.is_long() {
+                PositionSideSpecified::Long
+            } else {
+                PositionSideSpecified::Short
+            },
+            volume: position.volume,
+        }
+    }
+}
+
+/// Builds the margin account state reporting `balance`.
+pub fn account_state(account_id: AccountId, balance: AccountBalance, ts_init: UnixNanos) -> AccountState {
+    AccountState::new(
+        account_id,
+        AccountType::Margin,
+        vec![balance],
+        Vec::new(),
+        true,
+        UUID4::new(),
+        ts_init,
+        ts_init,
+        Some(balance.currency),
+    )
+}
+
+/// Diffs successive account and position snapshots.
+///
+/// The first snapshot reports the account and every open position. After that
+/// the account is reported when its balance changes, a position when it opens
+/// or its side or volume changes, and a closed position as a flat report.
+/// Floating P&L alone does not produce position updates.
+#[derive(Debug)]
+pub struct AccountSnapshotDiffer {
+    account_id: AccountId,
+    instruments: std::sync::Arc<DashMap<InstrumentId, InstrumentAny>>,
+    symbol_mapper: SymbolMapper,
+    balance: Option<AccountBalance>,
+    positions: HashMap<u64, PositionSnapshot>,
+}
+
+impl AccountSnapshotDiffer {
+    /// Creates a differ; `instruments` supplies the size precision of position
+    /// quantities and `symbol_mapper` the instrument ids of MT5 symbols.
+    pub fn new(
+        account_id: AccountId,
+        instruments: std::sync::Arc<DashMap<InstrumentId, InstrumentAny>>,
+        symbol_mapper: SymbolMapper,
+    ) -> Self {
+        Self {
+            account_id,
+            instruments,
+            symbol_mapper,
+            balance: None,
+            positions: HashMap::new(),
+        }
+    }
+
+    /// Returns the updates between the previous snapshot and this one.
+    pub fn diff(
+        &mut self,
+        info: &Mt5AccountInfo,
+        positions: &[Mt5TradePosition],
+        ts_init: UnixNanos,
+    ) -> Vec<AccountUpdate> {
+        let mut updates = Vec::new();
+
+        match parse_account_balance(info) {
+            Ok(balance) if self.balance != Some(balance) => {
+                self.balance = Some(balance);
+                updates.push(AccountUpdate::Account(account_state(self.account_id, balance, ts_init)));
+            }
+            Ok(_) => {}
+            Err(e) => tracing::warn!("Skipping account update: {e}"),
+        }
+
+        let mut current = HashMap::with_capacity(positions.len());
+        for position in positions {
+            let snapshot = PositionSnapshot::from(position);
+            if self.positions.get(&position.ticket) != Some(&snapshot) {
+                match self.position_report(position.ticket, &snapshot, ts_init) {
+                    Ok(report) => updates.push(AccountUpdate::Position(report)),
+                    Err(e) => tracing::warn!("Skipping update for position {}: {e}", position.ticket),
+                }
+            }
+            current.insert(position.ticket, snapshot);
+        }
+
+        let mut closed: Vec<_> = self
+            .positions
+            .iter()
+            .filter(|(ticket, _)| !current.contains_key(ticket))
+            .collect();
+        closed.sort_by_key(|(ticket, _)| **ticket);
+        for (ticket, snapshot) in closed {
+            let flat = PositionSnapshot {
+                side: PositionSideSpecified::Flat,
+                volume: 0.0,
+                ..snapshot.clone()
+            };
+            match self.position_report(*ticket, &flat, ts_init) {
+                Ok(report) => updates.push(AccountUpdate::Position(report)),
+                Err(e) => tracing::warn!("Skipping update for position {ticket}: {e}"),
+            }
+        }
+
+        self.positions = current;
+        updates
+    }
+
+    /// Returns a report for every open position, regardless of earlier snapshots.
+    ///
+    /// Positions whose volume cannot be represented are skipped with a warning.
+    pub fn position_reports(
+        &self,
+        positions: &[Mt5TradePosition],
+        ts_init: UnixNanos,
+    ) -> Vec<PositionStatusReport> {
+        positions
+            .iter()
+            .filter_map(|position| {
+                match self.position_report(position.ticket, &PositionSnapshot::from(position), ts_init) {
+                    Ok(report) => Some(report),
+                    Err(e) => {
+                        tracing::warn!("Skipping position {}: {e}", position.ticket);
+                        None
+                    }
+                }
+            })
+            .collect()
+    }
+
+    fn position_report(
+        &self,
+        ticket: u64,
+        snapshot: &PositionSnapshot,
+        ts_init: UnixNanos,
+    ) -> Result<PositionStatusReport, ParseError> {
+        position_report(
+            self.account_id,
+            &self.instruments,
+            &self.symbol_mapper,
+            ticket,
+            snapshot,
+            ts_init,
+        )
+    }
+}
+
+/// Builds the report for `position` after `closed_volume` of it was closed.
+///
+/// The report is flat once nothing remains, matching the report
+/// [`AccountSnapshotDiffer`] emits when a closed position drops out of a poll.
+///
+/// # Errors
+///
+/// Returns `ParseError::InvalidValue` if the remaining volume cannot be
+/// represented as a `Quantity`.
+pub fn closed_position_report(
+    account_id: AccountId,
+    instruments: &DashMap<InstrumentId, InstrumentAny>,
+    symbol_mapper: &SymbolMapper,
+    position: &Mt5TradePosition,
+    closed_volume: f64,
+    ts_init: UnixNanos,
+) -> Result<PositionStatusReport, ParseError> {
+    let mut snapshot = PositionSnapshot::from(position);
+    snapshot.volume -= closed_volume;
+    if snapshot.volume <= VOLUME_EPSILON {
+        snapshot.side = PositionSideSpecified::Flat;
+        snapshot.volume = 0.0;
+    }
+    position_report(account_id, instruments, symbol_mapper, position.ticket, &snapshot, ts_init)
+}
+
+fn position_report(
+    account_id: AccountId,
+    instruments: &DashMap<InstrumentId, InstrumentAny>,
+    symbol_mapper: &SymbolMapper,
+    ticket: u64,
+    snapshot: &PositionSnapshot,
+    ts_init: UnixNanos,
+) -> Result<PositionStatusReport, ParseError> {
+    let instrument_id = InstrumentId::new(
+        Symbol::new(symbol_mapper.mt5_to_nautilus(&snapshot.symbol)),
+        Venue::new(MT5_NAME),
+    );
+    let size_precision = instruments
+        .get(&instrument_id)
+        .map_or(DEFAULT_SIZE_PRECISION, |instrument| instrument.size_precision());
+    let quantity = Quantity::new_checked(snapshot.volume, size_precision).map_err(|e| {
+        ParseError::InvalidValue(format!("position {ticket} volume {}: {e}", snapshot.volume))
+    })?;
+    Ok(PositionStatusReport::new(
+        account_id,
+        instrument_id,
+        snapshot.side,
+        quantity,
+        ts_init,
+        ts_init,
+        None,
+        Some(PositionId::new(ticket.to_string())),
+        None,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::testing::{load_account_info_fixture, load_positions_fixture};
+
+    fn differ() -> AccountSnapshotDiffer {
+        AccountSnapshotDiffer::new(AccountId::from("MT5-001"), Default::default(), Default::default())
+    }
+
+    #[test]
+    fn test_unchanged_snapshot_emits_nothing() {
+        let mut differ = differ();
+        let info = load_account_info_fixture();
+        let mut positions = load_positions_fixture();
+
+        assert_eq!(differ.diff(&info, &positions, UnixNanos::from(1)).len(), 3);
+        positions[0].profit += 5.0;
+        assert!(differ.diff(&info, &positions, UnixNanos::from(2)).is_empty());
+    }
+
+    #[test]
+    fn test_closed_position_reported_flat() {
+        let mut differ = differ();
+        let info = load_account_info_fixture();
+        let positions = load_positions_fixture();
+        differ.diff(&info, &positions, UnixNanos::from(1));
+
+        let updates = differ.diff(&info, &positions[1..], UnixNanos::from(2));
+
+        assert_eq!(updates.len(), 1);
+        let AccountUpdate::Position(report) = &updates[0] else {
+            panic!("expected PositionStatusReport, was {:?}", updates[0]);
+        };
+        assert_eq!(report.position_side, PositionSideSpecified::Flat);
+        assert_eq!(
+            report.venue_position_id,
+            Some(PositionId::new(positions[0].ticket.to_string()))
+        );
+    }
+
+    #[test]
+    fn test_partially_closed_position_keeps_remaining_volume() {
+        let instruments = DashMap::new();
+        let position = &load_positions_fixture()[1];
+
+        let mapper = SymbolMapper::default();
+        let partial = closed_position_report(AccountId::from("MT5-001"), &instruments, &mapper, position, 0.4, UnixNanos::from(1)).unwrap();
+        let full = closed_position_report(AccountId::from("MT5-001"), &instruments, &mapper, position, 1.0, UnixNanos::from(1)).unwrap();
+
+        assert_eq!(partial.position_side, PositionSideSpecified::Short);
+        assert_eq!(partial.quantity, Quantity::from("0.60"));
+        assert_eq!(full.position_side, PositionSideSpecified::Flat);
+        assert_eq!(full.quantity, Quantity::from("0.00"));
+    }
+
+    #[test]
+    fn test_reports_map_mt5_symbols_back() {
+        let positions = load_positions_fixture();
+        let symbol = &positions[0].symbol;
+        let mapper = SymbolMapper::new(
+            &HashMap::from([(format!("X-{symbol}"), symbol.clone())]),
+            None,
+        )
+        .unwrap();
+        let differ = AccountSnapshotDiffer::new(AccountId::from("MT5-001"), Default::default(), mapper.clone());
+
+        let reports = differ.position_reports(&positions[..1], UnixNanos::from(1));
+        let closed = closed_position_report(
+            AccountId::from("MT5-001"),
+            &DashMap::new(),
+            &mapper,
+            &positions[0],
+            0.0,
+            UnixNanos::from(1),
+        )
+        .unwrap();
+
+        let expected = InstrumentId::from(format!("X-{symbol}.{MT5_NAME}").as_str());
+        assert_eq!(reports[0].instrument_id, expected);
+        assert_eq!(closed.instrument_id, expected);
+    }
+
+    #[test]
+    fn test_corrupt_values_are_skipped() {
+        let mut differ = differ();
+        let mut info = load_account_info_fixture();
+        let mut positions = load_positions_fixture();
+        info.margin = f64::NAN;
+        positions[0].volume = f64::INFINITY;
+
+        let updates = differ.diff(&info, &positions, UnixNanos::from(1));
+
+        // Only the intact position is reported
+        assert_eq!(updates.len(), positions.len() - 1);
+        assert!(updates.iter().all(|update| matches!(
+            update,
+            AccountUpdate::Position(report)
+                if report.venue_position_id != Some(PositionId::new(positions[0].ticket.to_string()))
+        )));
+        assert_eq!(differ.position_reports(&positions, UnixNanos::from(2)).len(), positions.len() - 1);
+    }
+}
@@ -0,0 +1,207 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Caching of historical bar requests.
+//!
+//! Backtests and notebooks often request the same or overlapping bar ranges.
+//! [`Mt5DataClient::request_bars`](crate::data_client::Mt5DataClient::request_bars)
+//! consults a [`BarCache`] first: an exact hit skips the bridge, and a cached
+//! range that starts at the same bar only has its missing tail fetched.
+
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, PoisonError,
+    },
+    time::{Duration, Instant},
+};
+
+use nautilus_model::data::{Bar, BarType};
+
+/// Identifies a cached bar range.
+///
+/// `start` and `end` are bar open times in Unix seconds, aligned down to the
+/// bar interval, so requests that differ only within one bar share an entry.
+/// Both edges are included. The precisions are part of the key since they are
+/// baked into the cached bars.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BarCacheKey {
+    pub bar_type: BarType,
+    pub price_precision: u8,
+    pub size_precision: u8,
+    pub start: i64,
+    pub end: i64,
+}
+
+impl BarCacheKey {
+    /// Returns whether `other` is the same bar series, regardless of range.
+    pub fn same_series(&self, other: &Self) -> bool {
+        self.bar_type == other.bar_type
+            && self.price_precision == other.price_precision
+            && self.size_precision == other.size_precision
+    }
+}
+
+/// Storage for bars fetched by `request_bars`.
+pub trait BarCache: Debug + Send + Sync {
+    /// Returns the bars cached under exactly `key`.
+    fn get(&self, key: &BarCacheKey) -> Option<Vec<Bar>>;
+
+    /// Returns the entry for the same series and start as `key` that reaches
+    /// furthest without passing `key.end`.
+    fn get_prefix(&self, key: &BarCacheKey) -> Option<(BarCacheKey, Vec<Bar>)>;
+
+    /// Stores `bars` under `key`.
+    fn insert(&self, key: BarCacheKey, bars: Vec<Bar>);
+
+    /// Drops the entry under `key`, if any.
+    fn remove(&self, key: &BarCacheKey);
+}
+
+#[derive(Debug)]
+struct CachedBars {
+    inserted: Instant,
+    /// Insertion order, which decides eviction when timestamps tie.
+    sequence: u64,
+    bars: Vec<Bar>,
+}
+
+/// In-memory [`BarCache`] bounded by entry count and optionally by age.
+///
+/// When full, the least recently inserted entry is evicted.
+#[derive(Debug)]
+pub struct InMemoryBarCache {
+    max_entries: usize,
+    ttl: Option<Duration>,
+    sequence: AtomicU64,
+    entries: Mutex<HashMap<BarCacheKey, CachedBars>>,
+}
+
+impl InMemoryBarCache {
+    pub fn new(max_entries: usize, ttl: Option<Duration>) -> Self {
+        Self {
+            max_entries,
+            ttl,
+            sequence: AtomicU64::new(0),
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn is_fresh(&self, inserted: Instant) -> bool {
+        self.ttl.map_or(true, |ttl| inserted.elapsed() < ttl)
+    }
+}
+
+impl BarCache for InMemoryBarCache {
+    fn get(&self, key: &BarCacheKey) -> Option<Vec<Bar>> {
+        let entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        let entry = entries.get(key)?;
+        self.is_fresh(entry.inserted).then(|| entry.bars.clone())
+    }
+
+    fn get_prefix(&self, key: &BarCacheKey) -> Option<(BarCacheKey, Vec<Bar>)> {
+        let entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        entries
+            .iter()
+            .filter(|(cached, entry)| {
+                cached.same_series(key)
+                    && cached.start == key.start
+                    && cached.end <= key.end
+                    && self.is_fresh(entry.inserted)
+            })
+            .max_by_key(|(cached, _)| cached.end)
+            .map(|(cached, entry)| (cached.clone(), entry.bars.clone()))
+    }
+
+    fn insert(&self, key: BarCacheKey, bars: Vec<Bar>) {
+        if self.max_entries == 0 {
+            return;
+        }
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        entries.retain(|_, entry| self.is_fresh(entry.inserted));
+        while entries.len() >= self.max_entries && !entries.contains_key(&key) {
+            let Some(oldest) = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.sequence)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            entries.remove(&oldest);
+        }
+        let entry = CachedBars {
+            inserted: Instant::now(),
+            sequence: self.sequence.fetch_add(1, Ordering::Relaxed),
+            bars,
+        };
+        entries.insert(key, entry);
+    }
+
+    fn remove(&self, key: &BarCacheKey) {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner).remove(key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(start: i64, end: i64) -> BarCacheKey {
+        BarCacheKey {
+            bar_type: BarType::from("EURUSD.MT5-1-MINUTE-LAST-EXTERNAL"),
+            price_precision: 5,
+            size_precision: 0,
+            start,
+            end,
+        }
+    }
+
+    #[test]
+    fn test_prefix_is_longest_range_within_request() {
+        let cache = InMemoryBarCache::new(8, None);
+        cache.insert(key(0, 60), Vec::new());
+        cache.insert(key(0, 120), Vec::new());
+        cache.insert(key(0, 600), Vec::new());
+        cache.insert(key(60, 240), Vec::new());
+
+        let (prefix, _) = cache.get_prefix(&key(0, 300)).unwrap();
+
+        assert_eq!(prefix, key(0, 120));
+        assert!(cache.get_prefix(&key(120, 300)).is_none());
+    }
+
+    #[test]
+    fn test_oldest_entry_evicted_when_full() {
+        let cache = InMemoryBarCache::new(2, None);
+        cache.insert(key(0, 60), Vec::new());
+        cache.insert(key(0, 120), Vec::new());
+        cache.insert(key(0, 180), Vec::new());
+
+        assert!(cache.get(&key(0, 60)).is_none());
+        assert!(cache.get(&key(0, 120)).is_some());
+        assert!(cache.get(&key(0, 180)).is_some());
+    }
+
+    #[test]
+    fn test_expired_entries_are_misses() {
+        let cache = InMemoryBarCache::new(8, Some(Duration::ZERO));
+        cache.insert(key(0, 60), Vec::new());
+
+        assert!(cache.get(&key(0, 60)).is_none());
+        assert!(cache.get_prefix(&key(0, 120)).is_none());
+    }
+}
@@ -0,0 +1,182 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Bounded channel with a configurable backpressure policy.
+//!
+//! Used for streaming output (e.g. polled ticks) so a slow consumer cannot grow
+//! memory without limit.
+
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+};
+
+use tokio::sync::Notify;
+
+use crate::common::enums::BackpressurePolicy;
+
+#[derive(Debug)]
+struct Shared<T> {
+    queue: Mutex<VecDeque<T>>,
+    capacity: usize,
+    policy: BackpressurePolicy,
+    dropped: AtomicU64,
+    warned: AtomicBool,
+    item_available: Notify,
+    slot_available: Notify,
+}
+
+/// Cloneable handle to a bounded multi-producer channel.
+#[derive(Debug)]
+pub struct BoundedChannel<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Clone for BoundedChannel<T> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> BoundedChannel<T> {
+    /// Creates a channel holding at most `capacity` items (minimum 1).
+    pub fn new(capacity: usize, policy: BackpressurePolicy) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            shared: Arc::new(Shared {
+                queue: Mutex::new(VecDeque::with_capacity(capacity.min(1024))),
+                capacity,
+                policy,
+                dropped: AtomicU64::new(0),
+                warned: AtomicBool::new(false),
+                item_available: Notify::new(),
+                slot_available: Notify::new(),
+            }),
+        }
+    }
+
+    /// Sends `item`, applying the backpressure policy when the channel is full.
+    pub async fn send(&self, item: T) {
+        let mut item = Some(item);
+        loop {
+            let slot_available = self.shared.slot_available.notified();
+            {
+                let mut queue = self.shared.queue.lock().unwrap_or_else(PoisonError::into_inner);
+                if queue.len() < self.shared.capacity {
+                    queue.push_back(item.take().expect("item sent once"));
+                } else if self.shared.policy == BackpressurePolicy::DropOldest {
+                    queue.pop_front();
+                    queue.push_back(item.take().expect("item sent once"));
+                    self.record_drop();
+                }
+            }
+            if item.is_none() {
+                self.shared.item_available.notify_one();
+                return;
+            }
+            slot_available.await;
+        }
+    }
+
+    /// Receives the oldest buffered item, waiting until one is available.
+    pub async fn recv(&self) -> T {
+        loop {
+            let item_available = self.shared.item_available.notified();
+            if let Some(item) = self.try_recv() {
+                return item;
+            }
+            item_available.await;
+        }
+    }
+
+    /// Receives the oldest buffered item if there is one.
+    pub fn try_recv(&self) -> Option<T> {
+        let item = self.shared.queue.lock().unwrap_or_else(PoisonError::into_inner).pop_front();
+        if item.is_some() {
+            self.shared.slot_available.notify_one();
+        }
+        item
+    }
+
+    /// Returns the number of buffered items.
+    pub fn len(&self) -> usize {
+        self.shared.queue.lock().unwrap_or_else(PoisonError::into_inner).len()
+    }
+
+    /// Returns whether the channel holds no items.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns how many items were discarded under `DropOldest`.
+    pub fn dropped_count(&self) -> u64 {
+        self.shared.dropped.load(Ordering::Relaxed)
+    }
+
+    fn record_drop(&self) {
+        self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+        if !self.shared.warned.swap(true, Ordering::Relaxed) {
+            tracing::warn!(
+                "Consumer is falling behind, dropping oldest items (capacity {})",
+                self.shared.capacity
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_drop_oldest_when_full() {
+        let channel = BoundedChannel::new(2, BackpressurePolicy::DropOldest);
+
+        channel.send(1).await;
+        channel.send(2).await;
+        channel.send(3).await;
+
+        assert_eq!(channel.dropped_count(), 1);
+        assert_eq!(channel.try_recv(), Some(2));
+        assert_eq!(channel.try_recv(), Some(3));
+        assert_eq!(channel.try_recv(), None);
+    }
+
+    #[tokio::test]
+    async fn test_block_waits_for_consumer() {
+        let channel = BoundedChannel::new(1, BackpressurePolicy::Block);
+        channel.send(1).await;
+
+        let blocked = tokio::time::timeout(Duration::from_millis(50), channel.send(2)).await;
+        assert!(blocked.is_err());
+
+        let producer = tokio::spawn({
+            let channel = channel.clone();
+            async move { channel.send(3).await }
+        });
+        assert_eq!(channel.recv().await, 1);
+        producer.await.unwrap();
+
+        assert_eq!(channel.recv().await, 3);
+        assert_eq!(channel.dropped_count(), 0);
+    }
+}
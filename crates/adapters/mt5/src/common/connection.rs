@@ -0,0 +1,571 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Connection state tracking shared by the data and execution clients.
+
+use std::{
+    sync::{
+        atomic::{AtomicU64, AtomicU8, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+    time::Duration,
+};
+
+use futures_util::future::BoxFuture;
+use tokio::{runtime::Handle, sync::broadcast};
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    common::{enums::ConnectionState, runtime::spawn_cancellable},
+    http::client::{unwrap_result, Mt5HttpClient},
+    http::error::Mt5HttpError,
+};
+
+/// Interval at which [`ConnectionStateTracker::wait_until`] re-reads the state.
+const WAIT_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// A change of connection state, as emitted to subscribers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConnectionTransition {
+    pub from: ConnectionState,
+    pub to: ConnectionState,
+}
+
+/// Atomically stored connection state with a broadcast of every transition.
+///
+/// Also counts established sessions: the generation is bumped each time the
+/// bridge is (re)initialized, so per-session caches can detect staleness.
+/// Cloning shares the underlying state, so clones of a client observe the same session.
+#[derive(Debug, Clone)]
+pub struct ConnectionStateTracker {
+    state: Arc<AtomicU8>,
+    generation: Arc<AtomicU64>,
+    tx: broadcast::Sender<ConnectionTransition>,
+}
+
+impl Default for ConnectionStateTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConnectionStateTracker {
+    pub fn new() -> Self {
+        let (tx, _) = broadcast::channel(64);
+        Self {
+            state: Arc::new(AtomicU8::new(ConnectionState::Disconnected as u8)),
+            generation: Arc::new(AtomicU64::new(0)),
+            tx,
+        }
+    }
+
+    /// Returns the current state.
+    pub fn state(&self) -> ConnectionState {
+        ConnectionState::from_u8(self.state.load(Ordering::Acquire))
+    }
+
+    /// Returns the current session generation (zero before the first connect).
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    /// Starts a new session generation, returning it.
+    pub fn next_generation(&self) -> u64 {
+        self.generation.fetch_add(1, Ordering::AcqRel) + 1
+    }
+
+    /// Moves to `to`, notifying subscribers if the state changed.
+    pub fn transition(&self, to: ConnectionState) {
+        let from = ConnectionState::from_u8(self.state.swap(to as u8, Ordering::AcqRel));
+        if from != to {
+            tracing::debug!("Connection state {from} -> {to}");
+            // No receivers is fine; observing transitions is optional
+            let _ = self.tx.send(ConnectionTransition { from, to });
+        }
+    }
+
+    /// Subscribes to subsequent state transitions.
+    pub fn subscribe(&self) -> broadcast::Receiver<ConnectionTransition> {
+        self.tx.subscribe()
+    }
+
+    /// Polls the state until it is `target` or `timeout` elapses.
+    ///
+    /// # Errors
+    ///
+    /// Returns the last observed state if `target` was not reached in time.
+    pub async fn wait_until(&self, target: ConnectionState, timeout: Duration) -> Result<(), ConnectionState> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            let state = self.state();
+            if state == target {
+                return Ok(());
+            }
+            if tokio::time::Instant::now() >= deadline {
+                return Err(state);
+            }
+            tokio::time::sleep_until(deadline.min(tokio::time::Instant::now() + WAIT_POLL_INTERVAL)).await;
+        }
+    }
+}
+
+/// Initializes the terminal and logs in, recording each step on `tracker`.
+///
+/// Starts from `Reconnecting` when `reconnect` is set, otherwise from
+/// `Initializing`. When the client has a minimum build configured, the
+/// terminal version is checked right after initialization. A successful
+/// session starts a new generation; any failure leaves the tracker `Faulted`.
+pub async fn establish_session(
+    http_client: &Mt5HttpClient,
+    tracker: &ConnectionStateTracker,
+    reconnect: bool,
+) -> Result<(), Mt5HttpError> {
+    open_session(http_client, tracker, reconnect).await?;
+    complete_session(tracker);
+    Ok(())
+}
+
+/// Runs the initialize and login steps of [`establish_session`], leaving the
+/// tracker in `LoggingIn` so the caller can vet the session before it is
+/// reported `Connected` by [`complete_session`].
+///
+/// Any failure leaves the tracker `Faulted`.
+pub async fn open_session(
+    http_client: &Mt5HttpClient,
+    tracker: &ConnectionStateTracker,
+    reconnect: bool,
+) -> Result<(), Mt5HttpError> {
+    tracker.transition(if reconnect {
+        ConnectionState::Reconnecting
+    } else {
+        ConnectionState::Initializing
+    });
+
+    let result = async {
+        http_client.initialize().await?;
+        if let Some(min_build) = http_client.min_build() {
+            http_client.require_min_build(min_build).await?;
+        }
+        tracker.transition(ConnectionState::LoggingIn);
+        http_client.login().await?;
+        Ok(())
+    }
+    .await;
+
+    if result.is_err() {
+        tracker.transition(ConnectionState::Faulted);
+    }
+    result
+}
+
+/// Reports a session opened by [`open_session`] as `Connected`, starting a new generation.
+pub fn complete_session(tracker: &ConnectionStateTracker) {
+    tracker.next_generation();
+    tracker.transition(ConnectionState::Connected);
+}
+
+/// Shuts down a session opened by [`open_session`] that must not be used,
+/// leaving the tracker `Disconnected`.
+///
+/// A failed `shutdown` is only logged: the session is never reported
+/// `Connected`, so nothing is sent over it either way.
+pub async fn abandon_session(http_client: &Mt5HttpClient, tracker: &ConnectionStateTracker) {
+    if let Err(e) = http_client.shutdown().await {
+        tracing::warn!("Failed to shut down the bridge session: {e}");
+    }
+    tracker.transition(ConnectionState::Disconnected);
+}
+
+/// Work run by [`ConnectivityMonitor`] each time it re-establishes a session,
+/// e.g. resolving orders whose responses were lost while the terminal was down.
+pub type ReconnectHook = Arc<dyn Fn() -> BoxFuture<'static, ()> + Send + Sync>;
+
+/// Background check that the terminal behind the bridge is still connected to its broker.
+///
+/// The bridge keeps answering while the terminal itself has lost its broker
+/// connection, so requests fail silently. The monitor polls `terminal_info`
+/// and, when `connected` turns false on a `Connected` session, moves the
+/// tracker to `Faulted`; subscribers see the transition. Once the terminal
+/// reports `connected` again the session is re-established, passing through
+/// `Reconnecting`, and the `on_reconnect` hook runs. Failures to reach the
+/// bridge are logged but leave the state alone. Cloning shares the running task.
+#[derive(Debug, Clone, Default)]
+pub struct ConnectivityMonitor {
+    task: Arc<Mutex<Option<tokio::task::JoinHandle<()>>>>,
+}
+
+impl ConnectivityMonitor {
+    /// Starts polling every `interval` on `runtime` (the current runtime if `None`),
+    /// replacing any running monitor. The task ends when `token` is cancelled.
+    pub fn start(
+        &self,
+        http_client: Arc<Mt5HttpClient>,
+        tracker: ConnectionStateTracker,
+        interval: Duration,
+        runtime: Option<&Handle>,
+        token: CancellationToken,
+        on_reconnect: Option<ReconnectHook>,
+    ) {
+        let task = spawn_cancellable(runtime, token, async move {
+            let mut terminal_lost = false;
+            loop {
+                tokio::time::sleep(interval).await;
+                let state = tracker.state();
+                if state != ConnectionState::Connected && !terminal_lost {
+                    continue;
+                }
+
+                match terminal_connected(&http_client).await {
+                    Ok(false) if state == ConnectionState::Connected => {
+                        tracing::warn!("MT5 terminal lost its broker connection");
+                        terminal_lost = true;
+                        tracker.transition(ConnectionState::Faulted);
+                    }
+                    Ok(true) if terminal_lost => {
+                        tracing::info!("MT5 terminal reconnected to its broker");
+                        match establish_session(&http_client, &tracker, true).await {
+                            Ok(()) => {
+                                terminal_lost = false;
+                                if let Some(on_reconnect) = &on_reconnect {
+                                    on_reconnect().await;
+                                }
+                            }
+                            Err(e) => tracing::warn!("Failed to re-establish session: {e}"),
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) => tracing::warn!("Terminal connectivity check failed: {e}"),
+                }
+            }
+        });
+
+        if let Some(previous) = self.task.lock().unwrap_or_else(PoisonError::into_inner).replace(task) {
+            previous.abort();
+        }
+    }
+
+    /// Stops the monitor, if running.
+    pub fn stop(&self) {
+        if let Some(task) = self.task.lock().unwrap_or_else(PoisonError::into_inner).take() {
+            task.abort();
+        }
+    }
+
+    /// Returns whether a monitor task is running.
+    pub fn is_running(&self) -> bool {
+        self.task
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+            .is_some_and(|task| !task.is_finished())
+    }
+}
+
+/// Background pinger that keeps an idle bridge session warm.
+///
+/// Some brokers drop the terminal's server connection after a long quiet
+/// period, failing the next request. Once the client has been idle for a full
+/// `interval`, a cheap `version` call is issued; any real traffic in the
+/// meantime postpones the ping. Cloning shares the running task.
+#[derive(Debug, Clone, Default)]
+pub struct KeepAlive {
+    task: Arc<Mutex<Option<tokio::task::JoinHandle<()>>>>,
+}
+
+impl KeepAlive {
+    /// Starts pinging after each idle `interval` on `runtime` (the current runtime
+    /// if `None`), replacing any running pinger. The task ends when `token` is cancelled.
+    pub fn start(
+        &self,
+        http_client: Arc<Mt5HttpClient>,
+        interval: Duration,
+        runtime: Option<&Handle>,
+        token: CancellationToken,
+    ) {
+        let task = spawn_cancellable(runtime, token, async move {
+            loop {
+                let idle = http_client.idle_time();
+                if idle < interval {
+                    tokio::time::sleep(interval - idle).await;
+                    continue;
+                }
+                tracing::trace!("Bridge idle for {idle:?}, sending keep-alive");
+                if let Err(e) = http_client.version().await {
+                    tracing::warn!("Keep-alive ping failed: {e}");
+                    tokio::time::sleep(interval).await;
+                }
+            }
+        });
+
+        if let Some(previous) = self.task.lock().unwrap_or_else(PoisonError::into_inner).replace(task) {
+            previous.abort();
+        }
+    }
+
+    /// Stops the pinger, if running.
+    pub fn stop(&self) {
+        if let Some(task) = self.task.lock().unwrap_or_else(PoisonError::into_inner).take() {
+            task.abort();
+        }
+    }
+
+    /// Returns whether a pinger task is running.
+    pub fn is_running(&self) -> bool {
+        self.task
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+            .is_some_and(|task| !task.is_finished())
+    }
+}
+
+/// Returns `terminal_info.connected`, treating a bridge that omits it as connected.
+pub(crate) async fn terminal_connected(http_client: &Mt5HttpClient) -> Result<bool, Mt5HttpError> {
+    let info = unwrap_result(http_client.terminal_info().await?)?;
+    Ok(reports_connected(&info))
+}
+
+/// Returns the `connected` flag of a `terminal_info` result, `true` when omitted.
+pub(crate) fn reports_connected(info: &serde_json::Value) -> bool {
+    info.get("connected")
+        .and_then(serde_json::Value::as_bool)
+        .unwrap_or(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{common::testing::mock_bridge::MockMt5Bridge, config::Mt5Config};
+    use serde_json::json;
+
+    fn drain(rx: &mut broadcast::Receiver<ConnectionTransition>) -> Vec<ConnectionState> {
+        let mut states = Vec::new();
+        while let Ok(transition) = rx.try_recv() {
+            states.push(transition.to);
+        }
+        states
+    }
+
+    #[test]
+    fn test_transition_ignores_same_state() {
+        let tracker = ConnectionStateTracker::new();
+        let mut rx = tracker.subscribe();
+
+        tracker.transition(ConnectionState::Disconnected);
+        tracker.transition(ConnectionState::Faulted);
+
+        assert_eq!(drain(&mut rx), vec![ConnectionState::Faulted]);
+        assert_eq!(tracker.state(), ConnectionState::Faulted);
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_returns_once_state_reached() {
+        let tracker = ConnectionStateTracker::new();
+        let setter = tracker.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(30)).await;
+            setter.transition(ConnectionState::Connected);
+        });
+
+        let result = tracker
+            .wait_until(ConnectionState::Connected, Duration::from_secs(5))
+            .await;
+        let timed_out = tracker
+            .wait_until(ConnectionState::Disconnected, Duration::from_millis(20))
+            .await;
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(timed_out, Err(ConnectionState::Connected));
+    }
+
+    #[tokio::test]
+    async fn test_connect_fault_reconnect_sequence() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("initialize", json!({"result": true}));
+        bridge.respond("login", json!({"result": true}));
+        let (addr, handle) = bridge.start().await;
+        let base_url = format!("http://{addr}");
+        let http_client =
+            Mt5HttpClient::new(Mt5Config::with_base_url(base_url.clone()), base_url).unwrap();
+
+        let tracker = ConnectionStateTracker::new();
+        let mut rx = tracker.subscribe();
+
+        establish_session(&http_client, &tracker, false)
+            .await
+            .unwrap();
+        bridge.fail("login", 503, "terminal offline");
+        assert!(establish_session(&http_client, &tracker, true)
+            .await
+            .is_err());
+        bridge.respond("login", json!({"result": true}));
+        establish_session(&http_client, &tracker, true)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            drain(&mut rx),
+            vec![
+                ConnectionState::Initializing,
+                ConnectionState::LoggingIn,
+                ConnectionState::Connected,
+                ConnectionState::Reconnecting,
+                ConnectionState::LoggingIn,
+                ConnectionState::Faulted,
+                ConnectionState::Reconnecting,
+                ConnectionState::LoggingIn,
+                ConnectionState::Connected,
+            ]
+        );
+        // Only the two successful sessions start a generation
+        assert_eq!(tracker.generation(), 2);
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_monitor_faults_on_terminal_disconnect_and_recovers() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("initialize", json!({"result": true}));
+        bridge.respond("login", json!({"result": true}));
+        bridge.respond("terminal_info", json!({"result": {"connected": true}}));
+        let (addr, handle) = bridge.start().await;
+        let base_url = format!("http://{addr}");
+        let http_client =
+            Arc::new(Mt5HttpClient::new(Mt5Config::with_base_url(base_url.clone()), base_url).unwrap());
+        let tracker = ConnectionStateTracker::new();
+        establish_session(&http_client, &tracker, false).await.unwrap();
+        let mut rx = tracker.subscribe();
+        let monitor = ConnectivityMonitor::default();
+        let interval = Duration::from_millis(5);
+        monitor.start(Arc::clone(&http_client), tracker.clone(), interval, None, CancellationToken::new(), None);
+
+        tokio::time::sleep(interval * 4).await;
+        assert!(drain(&mut rx).is_empty());
+
+        bridge.respond("terminal_info", json!({"result": {"connected": false}}));
+        tokio::time::sleep(interval * 4).await;
+        assert_eq!(tracker.state(), ConnectionState::Faulted);
+        assert_eq!(drain(&mut rx), vec![ConnectionState::Faulted]);
+
+        bridge.respond("terminal_info", json!({"result": {"connected": true}}));
+        tokio::time::sleep(interval * 4).await;
+        assert_eq!(
+            drain(&mut rx),
+            vec![
+                ConnectionState::Reconnecting,
+                ConnectionState::LoggingIn,
+                ConnectionState::Connected,
+            ]
+        );
+        assert_eq!(tracker.generation(), 2);
+
+        monitor.stop();
+        assert!(!monitor.is_running());
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_keep_alive_pings_while_idle() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("version", json!({"result": [500, 4150, "01 Jan 2024"]}));
+        bridge.respond("account_info", json!({"result": {}}));
+        let (addr, handle) = bridge.start().await;
+        let base_url = format!("http://{addr}");
+        let http_client =
+            Arc::new(Mt5HttpClient::new(Mt5Config::with_base_url(base_url.clone()), base_url).unwrap());
+        let keep_alive = KeepAlive::default();
+        let interval = Duration::from_millis(20);
+        keep_alive.start(Arc::clone(&http_client), interval, None, CancellationToken::new());
+
+        tokio::time::sleep(interval * 5).await;
+        assert!(bridge.requests_for("version").len() >= 3);
+
+        // Steady traffic keeps the session warm, so no pings are needed
+        keep_alive.stop();
+        let pings = bridge.requests_for("version").len();
+        http_client.account_info().await.unwrap();
+        keep_alive.start(Arc::clone(&http_client), interval * 5, None, CancellationToken::new());
+        for _ in 0..10 {
+            tokio::time::sleep(interval).await;
+            http_client.account_info().await.unwrap();
+        }
+        assert_eq!(bridge.requests_for("version").len(), pings);
+
+        keep_alive.stop();
+        assert!(!keep_alive.is_running());
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_background_tasks_end_with_their_token() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("version", json!({"result": [500, 4150, "01 Jan 2024"]}));
+        bridge.respond("terminal_info", json!({"result": {"connected": true}}));
+        let (addr, handle) = bridge.start().await;
+        let base_url = format!("http://{addr}");
+        let http_client =
+            Arc::new(Mt5HttpClient::new(Mt5Config::with_base_url(base_url.clone()), base_url).unwrap());
+        let token = CancellationToken::new();
+        let monitor = ConnectivityMonitor::default();
+        let keep_alive = KeepAlive::default();
+        let interval = Duration::from_millis(10);
+        monitor.start(
+            Arc::clone(&http_client),
+            ConnectionStateTracker::new(),
+            interval,
+            None,
+            token.child_token(),
+            None,
+        );
+        keep_alive.start(Arc::clone(&http_client), interval, None, token.child_token());
+        assert!(monitor.is_running() && keep_alive.is_running());
+
+        token.cancel();
+        tokio::time::sleep(interval * 5).await;
+
+        assert!(!monitor.is_running());
+        assert!(!keep_alive.is_running());
+        // Neither task still holds the client
+        assert_eq!(Arc::strong_count(&http_client), 1);
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_session_refused_below_min_build() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("initialize", json!({"result": true}));
+        bridge.respond("login", json!({"result": true}));
+        bridge.respond("version", json!({"result": [500, 3700, "01 Mar 2023"]}));
+        let (addr, handle) = bridge.start().await;
+        let base_url = format!("http://{addr}");
+        let config = Mt5Config {
+            min_build: Some(3802),
+            ..Mt5Config::with_base_url(base_url.clone())
+        };
+        let http_client = Mt5HttpClient::new(config, base_url).unwrap();
+        let tracker = ConnectionStateTracker::new();
+
+        let result = establish_session(&http_client, &tracker, false).await;
+
+        assert!(matches!(result, Err(Mt5HttpError::UnsupportedVersion(_))));
+        assert_eq!(tracker.state(), ConnectionState::Faulted);
+        assert!(bridge.requests_for("login").is_empty());
+
+        bridge.respond("version", json!({"result": [500, 3802, "15 Jul 2024"]}));
+        establish_session(&http_client, &tracker, true).await.unwrap();
+        assert_eq!(tracker.state(), ConnectionState::Connected);
+        handle.abort();
+    }
+}
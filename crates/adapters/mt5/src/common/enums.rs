@@ -1,5 +1,6 @@
 //! Enumerations for the MetaTrader 5 adapter.
 
+use nautilus_model::enums::{OrderSide, OrderStatus, OrderType};
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "python-bindings")]
@@ -37,3 +38,1127 @@ impl ConnectionStatus {
         format!("ConnectionStatus.{}", self.to_string())
     }
 }
+
+/// Lifecycle state of a client's session with the MT5 bridge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "python-bindings", pyclass)]
+#[serde(rename_all = "UPPERCASE")]
+#[repr(u8)]
+pub enum ConnectionState {
+    Disconnected = 0,
+    Initializing = 1,
+    LoggingIn = 2,
+    Connected = 3,
+    Reconnecting = 4,
+    Faulted = 5,
+}
+
+impl ConnectionState {
+    /// Converts the raw `u8` stored in an atomic back into a state.
+    pub fn from_u8(value: u8) -> Self {
+        match value {
+            1 => ConnectionState::Initializing,
+            2 => ConnectionState::LoggingIn,
+            3 => ConnectionState::Connected,
+            4 => ConnectionState::Reconnecting,
+            5 => ConnectionState::Faulted,
+            _ => ConnectionState::Disconnected,
+        }
+    }
+}
+
+impl std::fmt::Display for ConnectionState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConnectionState::Disconnected => write!(f, "DISCONNECTED"),
+            ConnectionState::Initializing => write!(f, "INITIALIZING"),
+            ConnectionState::LoggingIn => write!(f, "LOGGING_IN"),
+            ConnectionState::Connected => write!(f, "CONNECTED"),
+            ConnectionState::Reconnecting => write!(f, "RECONNECTING"),
+            ConnectionState::Faulted => write!(f, "FAULTED"),
+        }
+    }
+}
+
+/// How order volumes are rounded to the symbol's `volume_step` before submission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "python-bindings", pyclass)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum VolumeRounding {
+    /// Round down to the step below (never increases exposure).
+    Down,
+    /// Round to the nearest step.
+    #[default]
+    Nearest,
+}
+
+impl std::fmt::Display for VolumeRounding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VolumeRounding::Down => write!(f, "DOWN"),
+            VolumeRounding::Nearest => write!(f, "NEAREST"),
+        }
+    }
+}
+
+/// Which MT5 rate volume populates `Bar.volume`.
+///
+/// Forex symbols usually report `real_volume` as zero, leaving `tick_volume` as
+/// the only activity measure, while exchange-traded CFDs carry real volume.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "python-bindings", pyclass)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum VolumeSource {
+    /// Number of ticks in the bar.
+    #[default]
+    Tick,
+    /// Exchange-reported traded volume.
+    Real,
+    /// `real_volume` when non-zero, otherwise `tick_volume`.
+    Auto,
+}
+
+impl std::fmt::Display for VolumeSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VolumeSource::Tick => write!(f, "TICK"),
+            VolumeSource::Real => write!(f, "REAL"),
+            VolumeSource::Auto => write!(f, "AUTO"),
+        }
+    }
+}
+
+/// Which size a `QuoteTick` built from an MT5 tick carries on both sides.
+///
+/// MT5 ticks report no top-of-book sizes (`volume` is traded volume), so the
+/// size is synthesized. Whichever source is chosen, a size that is zero at the
+/// instrument's size precision falls back to one unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "python-bindings", pyclass)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum QuoteSizeSource {
+    /// The symbol's minimum lot (`volume_min`).
+    MinVolume,
+    /// One unit.
+    #[default]
+    One,
+    /// The tick's `volume_real`, or `volume` when that is zero.
+    TickVolume,
+}
+
+impl std::fmt::Display for QuoteSizeSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuoteSizeSource::MinVolume => write!(f, "MIN_VOLUME"),
+            QuoteSizeSource::One => write!(f, "ONE"),
+            QuoteSizeSource::TickVolume => write!(f, "TICK_VOLUME"),
+        }
+    }
+}
+
+/// How the HTTP client shares the MT5 bridge between concurrent callers.
+///
+/// The MT5 Python API is single-threaded, so interleaved calls (e.g. an
+/// `order_send` during a long `copy_rates_range`) can return mixed-up results.
+/// `Serialized` sends one request at a time, at the cost of an order waiting
+/// behind any in-flight market data pull. The permit belongs to one
+/// `Mt5HttpClient` and its clones, so a data and an execution client of the same
+/// bridge only share it when handed the same client (`set_http_client`).
+/// `Concurrent` lets requests overlap and is only safe with a bridge that
+/// handles them independently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "python-bindings", pyclass)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum BridgeAccessMode {
+    /// One request in flight at a time.
+    #[default]
+    Serialized,
+    /// Requests are sent as soon as they are made.
+    Concurrent,
+}
+
+impl std::fmt::Display for BridgeAccessMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BridgeAccessMode::Serialized => write!(f, "SERIALIZED"),
+            BridgeAccessMode::Concurrent => write!(f, "CONCURRENT"),
+        }
+    }
+}
+
+/// What a bounded stream does when its consumer falls behind and the buffer is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "python-bindings", pyclass)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BackpressurePolicy {
+    /// Discard the oldest buffered item to make room (the producer never waits).
+    #[default]
+    DropOldest,
+    /// Wait until the consumer frees a slot.
+    Block,
+}
+
+impl std::fmt::Display for BackpressurePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackpressurePolicy::DropOldest => write!(f, "DROP_OLDEST"),
+            BackpressurePolicy::Block => write!(f, "BLOCK"),
+        }
+    }
+}
+
+/// Which ticks an MT5 tick copy returns (`COPY_TICKS_*`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "python-bindings", pyclass)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TickKind {
+    /// Every tick.
+    All,
+    /// Ticks that changed the bid or ask.
+    Info,
+    /// Ticks that changed the last price or volume.
+    Trade,
+}
+
+impl TickKind {
+    /// Returns the `flags` value the bridge expects.
+    pub fn flags(self) -> i64 {
+        match self {
+            TickKind::All => -1,
+            TickKind::Info => 1,
+            TickKind::Trade => 2,
+        }
+    }
+}
+
+impl std::fmt::Display for TickKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TickKind::All => write!(f, "ALL"),
+            TickKind::Info => write!(f, "INFO"),
+            TickKind::Trade => write!(f, "TRADE"),
+        }
+    }
+}
+
+/// Direction of an MT5 deal relative to its position (`DEAL_ENTRY_*`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(feature = "python-bindings", pyclass)]
+#[serde(into = "i64")]
+pub enum Mt5DealEntry {
+    /// Opened or increased a position.
+    In = 0,
+    /// Closed or reduced a position.
+    Out = 1,
+    /// Reversed a position: closed it and opened the opposite side.
+    InOut = 2,
+    /// Closed a position by an opposite one.
+    OutBy = 3,
+}
+
+impl Mt5DealEntry {
+    /// Returns whether the deal opened (part of) a position.
+    pub fn opens_position(&self) -> bool {
+        matches!(self, Mt5DealEntry::In | Mt5DealEntry::InOut)
+    }
+
+    /// Returns whether the deal closed (part of) a position.
+    pub fn closes_position(&self) -> bool {
+        !matches!(self, Mt5DealEntry::In)
+    }
+}
+
+impl TryFrom<i64> for Mt5DealEntry {
+    type Error = String;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Mt5DealEntry::In),
+            1 => Ok(Mt5DealEntry::Out),
+            2 => Ok(Mt5DealEntry::InOut),
+            3 => Ok(Mt5DealEntry::OutBy),
+            _ => Err(format!("unknown DEAL_ENTRY value {value}")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Mt5DealEntry {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = crate::common::parse::de_i64_flexible(deserializer)?;
+        Self::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<Mt5DealEntry> for i64 {
+    fn from(entry: Mt5DealEntry) -> Self {
+        entry as i64
+    }
+}
+
+impl std::fmt::Display for Mt5DealEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Mt5DealEntry::In => write!(f, "IN"),
+            Mt5DealEntry::Out => write!(f, "OUT"),
+            Mt5DealEntry::InOut => write!(f, "INOUT"),
+            Mt5DealEntry::OutBy => write!(f, "OUT_BY"),
+        }
+    }
+}
+
+/// Type of an MT5 order (`ORDER_TYPE_*`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize)]
+#[cfg_attr(feature = "python-bindings", pyclass)]
+#[serde(into = "i64")]
+pub enum Mt5OrderType {
+    /// Market buy.
+    #[default]
+    Buy = 0,
+    /// Market sell.
+    Sell = 1,
+    /// Buy limit pending order.
+    BuyLimit = 2,
+    /// Sell limit pending order.
+    SellLimit = 3,
+    /// Buy stop pending order.
+    BuyStop = 4,
+    /// Sell stop pending order.
+    SellStop = 5,
+    /// Buy limit placed once the stop price is reached.
+    BuyStopLimit = 6,
+    /// Sell limit placed once the stop price is reached.
+    SellStopLimit = 7,
+    /// Closes a position by an opposite one.
+    CloseBy = 8,
+}
+
+impl Mt5OrderType {
+    /// Returns the order type for an `ORDER_TYPE_*` value, or `None` if unknown.
+    pub fn from_i64(value: i64) -> Option<Self> {
+        match value {
+            0 => Some(Mt5OrderType::Buy),
+            1 => Some(Mt5OrderType::Sell),
+            2 => Some(Mt5OrderType::BuyLimit),
+            3 => Some(Mt5OrderType::SellLimit),
+            4 => Some(Mt5OrderType::BuyStop),
+            5 => Some(Mt5OrderType::SellStop),
+            6 => Some(Mt5OrderType::BuyStopLimit),
+            7 => Some(Mt5OrderType::SellStopLimit),
+            8 => Some(Mt5OrderType::CloseBy),
+            _ => None,
+        }
+    }
+
+    /// Returns the `ORDER_TYPE_*` value.
+    pub fn as_i64(&self) -> i64 {
+        *self as i64
+    }
+
+    /// Maps a Nautilus order type and side to the MT5 order type.
+    ///
+    /// Returns `None` for order types MT5 cannot place, such as trailing stops.
+    pub fn from_nautilus(order_type: OrderType, side: OrderSide) -> Option<Self> {
+        let is_buy = match side {
+            OrderSide::Buy => true,
+            OrderSide::Sell => false,
+            _ => return None,
+        };
+        Some(match (order_type, is_buy) {
+            (OrderType::Market, true) => Mt5OrderType::Buy,
+            (OrderType::Market, false) => Mt5OrderType::Sell,
+            (OrderType::Limit, true) => Mt5OrderType::BuyLimit,
+            (OrderType::Limit, false) => Mt5OrderType::SellLimit,
+            (OrderType::StopMarket, true) => Mt5OrderType::BuyStop,
+            (OrderType::StopMarket, false) => Mt5OrderType::SellStop,
+            (OrderType::StopLimit, true) => Mt5OrderType::BuyStopLimit,
+            (OrderType::StopLimit, false) => Mt5OrderType::SellStopLimit,
+            _ => return None,
+        })
+    }
+
+    /// Maps the order type to the Nautilus order type; `None` for `CloseBy`.
+    pub fn to_order_type(&self) -> Option<OrderType> {
+        match self {
+            Mt5OrderType::Buy | Mt5OrderType::Sell => Some(OrderType::Market),
+            Mt5OrderType::BuyLimit | Mt5OrderType::SellLimit => Some(OrderType::Limit),
+            Mt5OrderType::BuyStop | Mt5OrderType::SellStop => Some(OrderType::StopMarket),
+            Mt5OrderType::BuyStopLimit | Mt5OrderType::SellStopLimit => Some(OrderType::StopLimit),
+            Mt5OrderType::CloseBy => None,
+        }
+    }
+
+    /// Returns the side of the order; `None` for `CloseBy`, which has no side of its own.
+    pub fn order_side(&self) -> Option<OrderSide> {
+        match self {
+            Mt5OrderType::Buy
+            | Mt5OrderType::BuyLimit
+            | Mt5OrderType::BuyStop
+            | Mt5OrderType::BuyStopLimit => Some(OrderSide::Buy),
+            Mt5OrderType::Sell
+            | Mt5OrderType::SellLimit
+            | Mt5OrderType::SellStop
+            | Mt5OrderType::SellStopLimit => Some(OrderSide::Sell),
+            Mt5OrderType::CloseBy => None,
+        }
+    }
+
+    /// Returns true for the buy side (market, limit, stop or stop-limit).
+    pub fn is_buy(&self) -> bool {
+        self.order_side() == Some(OrderSide::Buy)
+    }
+
+    /// Returns true for stop-limit types, which carry a separate `stoplimit` price.
+    pub fn is_stop_limit(&self) -> bool {
+        matches!(self, Mt5OrderType::BuyStopLimit | Mt5OrderType::SellStopLimit)
+    }
+
+    /// Returns true for pending (limit/stop) order types.
+    pub fn is_pending(&self) -> bool {
+        !matches!(self, Mt5OrderType::Buy | Mt5OrderType::Sell | Mt5OrderType::CloseBy)
+    }
+}
+
+impl TryFrom<i64> for Mt5OrderType {
+    type Error = String;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        Self::from_i64(value).ok_or_else(|| format!("unknown ORDER_TYPE value {value}"))
+    }
+}
+
+impl<'de> Deserialize<'de> for Mt5OrderType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = crate::common::parse::de_i64_flexible(deserializer)?;
+        Self::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<Mt5OrderType> for i64 {
+    fn from(order_type: Mt5OrderType) -> Self {
+        order_type.as_i64()
+    }
+}
+
+impl std::fmt::Display for Mt5OrderType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Mt5OrderType::Buy => write!(f, "BUY"),
+            Mt5OrderType::Sell => write!(f, "SELL"),
+            Mt5OrderType::BuyLimit => write!(f, "BUY_LIMIT"),
+            Mt5OrderType::SellLimit => write!(f, "SELL_LIMIT"),
+            Mt5OrderType::BuyStop => write!(f, "BUY_STOP"),
+            Mt5OrderType::SellStop => write!(f, "SELL_STOP"),
+            Mt5OrderType::BuyStopLimit => write!(f, "BUY_STOP_LIMIT"),
+            Mt5OrderType::SellStopLimit => write!(f, "SELL_STOP_LIMIT"),
+            Mt5OrderType::CloseBy => write!(f, "CLOSE_BY"),
+        }
+    }
+}
+
+/// Type of an MT5 deal (`DEAL_TYPE_*`).
+///
+/// Only `Buy` and `Sell` are trades; the rest are balance operations booked as
+/// deals. Types added by newer terminals deserialize as `Other` rather than failing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize)]
+#[serde(into = "i64")]
+pub enum Mt5DealType {
+    /// Buy trade.
+    #[default]
+    Buy,
+    /// Sell trade.
+    Sell,
+    /// Deposit or withdrawal.
+    Balance,
+    /// Credit.
+    Credit,
+    /// Additional charge.
+    Charge,
+    /// Correction.
+    Correction,
+    /// Bonus.
+    Bonus,
+    /// Additional commission.
+    Commission,
+    /// Daily commission.
+    CommissionDaily,
+    /// Monthly commission.
+    CommissionMonthly,
+    /// Daily agent commission.
+    CommissionAgentDaily,
+    /// Monthly agent commission.
+    CommissionAgentMonthly,
+    /// Interest rate charge.
+    Interest,
+    /// Canceled buy deal.
+    BuyCanceled,
+    /// Canceled sell deal.
+    SellCanceled,
+    /// Dividend.
+    Dividend,
+    /// Franked (non-taxable) dividend.
+    DividendFranked,
+    /// Tax.
+    Tax,
+    /// A type this adapter does not know.
+    Other(i64),
+}
+
+impl Mt5DealType {
+    /// Returns the deal type for a `DEAL_TYPE_*` value.
+    pub fn from_i64(value: i64) -> Self {
+        match value {
+            0 => Mt5DealType::Buy,
+            1 => Mt5DealType::Sell,
+            2 => Mt5DealType::Balance,
+            3 => Mt5DealType::Credit,
+            4 => Mt5DealType::Charge,
+            5 => Mt5DealType::Correction,
+            6 => Mt5DealType::Bonus,
+            7 => Mt5DealType::Commission,
+            8 => Mt5DealType::CommissionDaily,
+            9 => Mt5DealType::CommissionMonthly,
+            10 => Mt5DealType::CommissionAgentDaily,
+            11 => Mt5DealType::CommissionAgentMonthly,
+            12 => Mt5DealType::Interest,
+            13 => Mt5DealType::BuyCanceled,
+            14 => Mt5DealType::SellCanceled,
+            15 => Mt5DealType::Dividend,
+            16 => Mt5DealType::DividendFranked,
+            17 => Mt5DealType::Tax,
+            other => Mt5DealType::Other(other),
+        }
+    }
+
+    /// Returns the `DEAL_TYPE_*` value.
+    pub fn as_i64(&self) -> i64 {
+        match self {
+            Mt5DealType::Buy => 0,
+            Mt5DealType::Sell => 1,
+            Mt5DealType::Balance => 2,
+            Mt5DealType::Credit => 3,
+            Mt5DealType::Charge => 4,
+            Mt5DealType::Correction => 5,
+            Mt5DealType::Bonus => 6,
+            Mt5DealType::Commission => 7,
+            Mt5DealType::CommissionDaily => 8,
+            Mt5DealType::CommissionMonthly => 9,
+            Mt5DealType::CommissionAgentDaily => 10,
+            Mt5DealType::CommissionAgentMonthly => 11,
+            Mt5DealType::Interest => 12,
+            Mt5DealType::BuyCanceled => 13,
+            Mt5DealType::SellCanceled => 14,
+            Mt5DealType::Dividend => 15,
+            Mt5DealType::DividendFranked => 16,
+            Mt5DealType::Tax => 17,
+            Mt5DealType::Other(value) => *value,
+        }
+    }
+
+    /// Returns whether this is a trade (buy/sell) rather than a balance, credit or similar operation.
+    pub fn is_trade(&self) -> bool {
+        matches!(self, Mt5DealType::Buy | Mt5DealType::Sell)
+    }
+
+    /// Returns the side of a trade deal; `None` for balance operations.
+    pub fn order_side(&self) -> Option<OrderSide> {
+        match self {
+            Mt5DealType::Buy => Some(OrderSide::Buy),
+            Mt5DealType::Sell => Some(OrderSide::Sell),
+            _ => None,
+        }
+    }
+}
+
+impl From<i64> for Mt5DealType {
+    fn from(value: i64) -> Self {
+        Self::from_i64(value)
+    }
+}
+
+impl<'de> Deserialize<'de> for Mt5DealType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::common::parse::de_i64_flexible(deserializer).map(Self::from)
+    }
+}
+
+impl From<Mt5DealType> for i64 {
+    fn from(deal_type: Mt5DealType) -> Self {
+        deal_type.as_i64()
+    }
+}
+
+impl std::fmt::Display for Mt5DealType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Mt5DealType::Buy => write!(f, "BUY"),
+            Mt5DealType::Sell => write!(f, "SELL"),
+            Mt5DealType::Balance => write!(f, "BALANCE"),
+            Mt5DealType::Credit => write!(f, "CREDIT"),
+            Mt5DealType::Charge => write!(f, "CHARGE"),
+            Mt5DealType::Correction => write!(f, "CORRECTION"),
+            Mt5DealType::Bonus => write!(f, "BONUS"),
+            Mt5DealType::Commission => write!(f, "COMMISSION"),
+            Mt5DealType::CommissionDaily => write!(f, "COMMISSION_DAILY"),
+            Mt5DealType::CommissionMonthly => write!(f, "COMMISSION_MONTHLY"),
+            Mt5DealType::CommissionAgentDaily => write!(f, "COMMISSION_AGENT_DAILY"),
+            Mt5DealType::CommissionAgentMonthly => write!(f, "COMMISSION_AGENT_MONTHLY"),
+            Mt5DealType::Interest => write!(f, "INTEREST"),
+            Mt5DealType::BuyCanceled => write!(f, "BUY_CANCELED"),
+            Mt5DealType::SellCanceled => write!(f, "SELL_CANCELED"),
+            Mt5DealType::Dividend => write!(f, "DIVIDEND"),
+            Mt5DealType::DividendFranked => write!(f, "DIVIDEND_FRANKED"),
+            Mt5DealType::Tax => write!(f, "TAX"),
+            Mt5DealType::Other(value) => write!(f, "OTHER({value})"),
+        }
+    }
+}
+
+/// How an account holds positions (`ACCOUNT_MARGIN_MODE_*`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize)]
+#[cfg_attr(feature = "python-bindings", pyclass)]
+#[serde(into = "i64")]
+pub enum Mt5MarginMode {
+    /// One position per symbol; opposite deals reduce it.
+    #[default]
+    RetailNetting = 0,
+    /// Exchange netting: one position per symbol, margined by exchange rules.
+    Exchange = 1,
+    /// Positions are independent; an opposite deal opens a new one unless it
+    /// names the `position` to close.
+    RetailHedging = 2,
+}
+
+impl Mt5MarginMode {
+    /// Returns whether the account can hold several positions in one symbol.
+    pub fn is_hedging(&self) -> bool {
+        matches!(self, Mt5MarginMode::RetailHedging)
+    }
+}
+
+impl TryFrom<i64> for Mt5MarginMode {
+    type Error = String;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Mt5MarginMode::RetailNetting),
+            1 => Ok(Mt5MarginMode::Exchange),
+            2 => Ok(Mt5MarginMode::RetailHedging),
+            _ => Err(format!("unknown ACCOUNT_MARGIN_MODE value {value}")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Mt5MarginMode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = crate::common::parse::de_i64_flexible(deserializer)?;
+        Self::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<Mt5MarginMode> for i64 {
+    fn from(mode: Mt5MarginMode) -> Self {
+        mode as i64
+    }
+}
+
+impl std::fmt::Display for Mt5MarginMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Mt5MarginMode::RetailNetting => write!(f, "RETAIL_NETTING"),
+            Mt5MarginMode::Exchange => write!(f, "EXCHANGE"),
+            Mt5MarginMode::RetailHedging => write!(f, "RETAIL_HEDGING"),
+        }
+    }
+}
+
+/// How an account's margin call and stop out levels are set (`ACCOUNT_STOPOUT_MODE_*`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize)]
+#[cfg_attr(feature = "python-bindings", pyclass)]
+#[serde(into = "i64")]
+pub enum Mt5StopOutMode {
+    /// Levels are margin level percentages (equity / margin * 100).
+    #[default]
+    Percent = 0,
+    /// Levels are equity amounts in the account currency.
+    Money = 1,
+}
+
+impl TryFrom<i64> for Mt5StopOutMode {
+    type Error = String;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Mt5StopOutMode::Percent),
+            1 => Ok(Mt5StopOutMode::Money),
+            _ => Err(format!("unknown ACCOUNT_STOPOUT_MODE value {value}")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Mt5StopOutMode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = crate::common::parse::de_i64_flexible(deserializer)?;
+        Self::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<Mt5StopOutMode> for i64 {
+    fn from(mode: Mt5StopOutMode) -> Self {
+        mode as i64
+    }
+}
+
+impl std::fmt::Display for Mt5StopOutMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Mt5StopOutMode::Percent => write!(f, "PERCENT"),
+            Mt5StopOutMode::Money => write!(f, "MONEY"),
+        }
+    }
+}
+
+/// Kind of trade server account (`ACCOUNT_TRADE_MODE_*`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(feature = "python-bindings", pyclass)]
+#[serde(into = "i64")]
+pub enum Mt5Environment {
+    /// Demo account with virtual money.
+    Demo = 0,
+    /// Contest account.
+    Contest = 1,
+    /// Real account trading real money.
+    Real = 2,
+}
+
+impl TryFrom<i64> for Mt5Environment {
+    type Error = String;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Mt5Environment::Demo),
+            1 => Ok(Mt5Environment::Contest),
+            2 => Ok(Mt5Environment::Real),
+            _ => Err(format!("unknown ACCOUNT_TRADE_MODE value {value}")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Mt5Environment {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = crate::common::parse::de_i64_flexible(deserializer)?;
+        Self::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<Mt5Environment> for i64 {
+    fn from(environment: Mt5Environment) -> Self {
+        environment as i64
+    }
+}
+
+impl std::fmt::Display for Mt5Environment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Mt5Environment::Demo => write!(f, "DEMO"),
+            Mt5Environment::Contest => write!(f, "CONTEST"),
+            Mt5Environment::Real => write!(f, "REAL"),
+        }
+    }
+}
+
+/// Lifecycle state of an MT5 order (`ORDER_STATE_*`).
+///
+/// States added by newer terminals deserialize as `Other` rather than failing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(into = "i64")]
+pub enum Mt5OrderState {
+    /// Checked for correctness, not yet accepted by the broker.
+    Started,
+    /// Accepted.
+    Placed,
+    /// Canceled by the client.
+    Canceled,
+    /// Partially executed.
+    Partial,
+    /// Fully executed.
+    Filled,
+    /// Rejected.
+    Rejected,
+    /// Expired.
+    Expired,
+    /// Being registered (placing to the trading system).
+    RequestAdd,
+    /// Being modified.
+    RequestModify,
+    /// Being deleted.
+    RequestCancel,
+    /// A state this adapter does not know.
+    Other(i64),
+}
+
+impl Mt5OrderState {
+    /// Maps the state to the Nautilus order status; `None` for `Other`.
+    pub fn to_order_status(&self) -> Option<OrderStatus> {
+        match self {
+            Mt5OrderState::Started | Mt5OrderState::RequestAdd => Some(OrderStatus::Submitted),
+            Mt5OrderState::Placed => Some(OrderStatus::Accepted),
+            Mt5OrderState::Canceled => Some(OrderStatus::Canceled),
+            Mt5OrderState::Partial => Some(OrderStatus::PartiallyFilled),
+            Mt5OrderState::Filled => Some(OrderStatus::Filled),
+            Mt5OrderState::Rejected => Some(OrderStatus::Rejected),
+            Mt5OrderState::Expired => Some(OrderStatus::Expired),
+            Mt5OrderState::RequestModify => Some(OrderStatus::PendingUpdate),
+            Mt5OrderState::RequestCancel => Some(OrderStatus::PendingCancel),
+            Mt5OrderState::Other(_) => None,
+        }
+    }
+}
+
+impl From<i64> for Mt5OrderState {
+    fn from(value: i64) -> Self {
+        match value {
+            0 => Mt5OrderState::Started,
+            1 => Mt5OrderState::Placed,
+            2 => Mt5OrderState::Canceled,
+            3 => Mt5OrderState::Partial,
+            4 => Mt5OrderState::Filled,
+            5 => Mt5OrderState::Rejected,
+            6 => Mt5OrderState::Expired,
+            7 => Mt5OrderState::RequestAdd,
+            8 => Mt5OrderState::RequestModify,
+            9 => Mt5OrderState::RequestCancel,
+            other => Mt5OrderState::Other(other),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Mt5OrderState {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::common::parse::de_i64_flexible(deserializer).map(Self::from)
+    }
+}
+
+impl From<Mt5OrderState> for i64 {
+    fn from(state: Mt5OrderState) -> Self {
+        match state {
+            Mt5OrderState::Started => 0,
+            Mt5OrderState::Placed => 1,
+            Mt5OrderState::Canceled => 2,
+            Mt5OrderState::Partial => 3,
+            Mt5OrderState::Filled => 4,
+            Mt5OrderState::Rejected => 5,
+            Mt5OrderState::Expired => 6,
+            Mt5OrderState::RequestAdd => 7,
+            Mt5OrderState::RequestModify => 8,
+            Mt5OrderState::RequestCancel => 9,
+            Mt5OrderState::Other(value) => value,
+        }
+    }
+}
+
+impl std::fmt::Display for Mt5OrderState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Mt5OrderState::Started => write!(f, "STARTED"),
+            Mt5OrderState::Placed => write!(f, "PLACED"),
+            Mt5OrderState::Canceled => write!(f, "CANCELED"),
+            Mt5OrderState::Partial => write!(f, "PARTIAL"),
+            Mt5OrderState::Filled => write!(f, "FILLED"),
+            Mt5OrderState::Rejected => write!(f, "REJECTED"),
+            Mt5OrderState::Expired => write!(f, "EXPIRED"),
+            Mt5OrderState::RequestAdd => write!(f, "REQUEST_ADD"),
+            Mt5OrderState::RequestModify => write!(f, "REQUEST_MODIFY"),
+            Mt5OrderState::RequestCancel => write!(f, "REQUEST_CANCEL"),
+            Mt5OrderState::Other(value) => write!(f, "OTHER({value})"),
+        }
+    }
+}
+
+/// Which trades MT5 allows on a symbol (`SYMBOL_TRADE_MODE_*`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize)]
+#[cfg_attr(feature = "python-bindings", pyclass)]
+#[serde(into = "i64")]
+pub enum Mt5TradeMode {
+    /// No trading.
+    Disabled = 0,
+    /// Only buy positions may be opened.
+    LongOnly = 1,
+    /// Only sell positions may be opened.
+    ShortOnly = 2,
+    /// Positions may only be closed.
+    CloseOnly = 3,
+    /// No restrictions.
+    #[default]
+    Full = 4,
+}
+
+impl Mt5TradeMode {
+    /// Returns whether an order on the `is_buy` side may open a new position.
+    pub fn allows_open(&self, is_buy: bool) -> bool {
+        match self {
+            Mt5TradeMode::Full => true,
+            Mt5TradeMode::LongOnly => is_buy,
+            Mt5TradeMode::ShortOnly => !is_buy,
+            Mt5TradeMode::Disabled | Mt5TradeMode::CloseOnly => false,
+        }
+    }
+
+    /// Returns whether an existing position may be closed.
+    pub fn allows_close(&self) -> bool {
+        !matches!(self, Mt5TradeMode::Disabled)
+    }
+}
+
+impl TryFrom<i64> for Mt5TradeMode {
+    type Error = String;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Mt5TradeMode::Disabled),
+            1 => Ok(Mt5TradeMode::LongOnly),
+            2 => Ok(Mt5TradeMode::ShortOnly),
+            3 => Ok(Mt5TradeMode::CloseOnly),
+            4 => Ok(Mt5TradeMode::Full),
+            _ => Err(format!("unknown SYMBOL_TRADE_MODE value {value}")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Mt5TradeMode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = crate::common::parse::de_i64_flexible(deserializer)?;
+        Self::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<Mt5TradeMode> for i64 {
+    fn from(mode: Mt5TradeMode) -> Self {
+        mode as i64
+    }
+}
+
+impl std::fmt::Display for Mt5TradeMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Mt5TradeMode::Disabled => write!(f, "DISABLED"),
+            Mt5TradeMode::LongOnly => write!(f, "LONGONLY"),
+            Mt5TradeMode::ShortOnly => write!(f, "SHORTONLY"),
+            Mt5TradeMode::CloseOnly => write!(f, "CLOSEONLY"),
+            Mt5TradeMode::Full => write!(f, "FULL"),
+        }
+    }
+}
+
+/// How the trade server executes market orders on a symbol (`SYMBOL_TRADE_EXECUTION_*`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize)]
+#[cfg_attr(feature = "python-bindings", pyclass)]
+#[serde(into = "i64")]
+pub enum Mt5ExecutionMode {
+    /// The dealer quotes a price on request, which the order then accepts.
+    Request = 0,
+    /// Orders fill at the sent price within `deviation`, or are requoted.
+    Instant = 1,
+    /// The server fills at its own price; `ORDER_FILLING_RETURN` is refused.
+    #[default]
+    Market = 2,
+    /// Orders go to an external exchange.
+    Exchange = 3,
+}
+
+impl TryFrom<i64> for Mt5ExecutionMode {
+    type Error = String;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Mt5ExecutionMode::Request),
+            1 => Ok(Mt5ExecutionMode::Instant),
+            2 => Ok(Mt5ExecutionMode::Market),
+            3 => Ok(Mt5ExecutionMode::Exchange),
+            _ => Err(format!("unknown SYMBOL_TRADE_EXECUTION value {value}")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Mt5ExecutionMode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = crate::common::parse::de_i64_flexible(deserializer)?;
+        Self::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<Mt5ExecutionMode> for i64 {
+    fn from(mode: Mt5ExecutionMode) -> Self {
+        mode as i64
+    }
+}
+
+impl std::fmt::Display for Mt5ExecutionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Mt5ExecutionMode::Request => write!(f, "REQUEST"),
+            Mt5ExecutionMode::Instant => write!(f, "INSTANT"),
+            Mt5ExecutionMode::Market => write!(f, "MARKET"),
+            Mt5ExecutionMode::Exchange => write!(f, "EXCHANGE"),
+        }
+    }
+}
+
+/// How MT5 charges swap on positions held over rollover (`SYMBOL_SWAP_MODE_*`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(feature = "python-bindings", pyclass)]
+#[serde(into = "i64")]
+pub enum Mt5SwapMode {
+    /// No swap is charged.
+    Disabled = 0,
+    /// Swap in points.
+    Points = 1,
+    /// Swap in money, in the symbol's base currency.
+    CurrencySymbol = 2,
+    /// Swap in money, in the symbol's margin currency.
+    CurrencyMargin = 3,
+    /// Swap in money, in the account's deposit currency.
+    CurrencyDeposit = 4,
+    /// Annual interest in percent of the current instrument price.
+    InterestCurrent = 5,
+    /// Annual interest in percent of the position's open price.
+    InterestOpen = 6,
+    /// Position is reopened at the close price plus/minus the swap in points.
+    ReopenCurrent = 7,
+    /// Position is reopened at the current bid plus/minus the swap in points.
+    ReopenBid = 8,
+    /// Swap in money, in the symbol's profit currency.
+    CurrencyProfit = 9,
+}
+
+/// Unit in which the `swap_long` / `swap_short` rates of a [`Mt5SwapMode`] are expressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mt5SwapUnit {
+    /// No swap is charged.
+    None,
+    /// Price points per lot.
+    Points,
+    /// Money per lot, in the currency given by the swap mode.
+    Money,
+    /// Annual percent of the position value.
+    Percent,
+}
+
+impl Mt5SwapMode {
+    /// Returns the unit of the swap rates under this mode.
+    pub fn unit(&self) -> Mt5SwapUnit {
+        match self {
+            Mt5SwapMode::Disabled => Mt5SwapUnit::None,
+            Mt5SwapMode::Points | Mt5SwapMode::ReopenCurrent | Mt5SwapMode::ReopenBid => {
+                Mt5SwapUnit::Points
+            }
+            Mt5SwapMode::CurrencySymbol
+            | Mt5SwapMode::CurrencyMargin
+            | Mt5SwapMode::CurrencyDeposit
+            | Mt5SwapMode::CurrencyProfit => Mt5SwapUnit::Money,
+            Mt5SwapMode::InterestCurrent | Mt5SwapMode::InterestOpen => Mt5SwapUnit::Percent,
+        }
+    }
+}
+
+impl TryFrom<i64> for Mt5SwapMode {
+    type Error = String;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Mt5SwapMode::Disabled),
+            1 => Ok(Mt5SwapMode::Points),
+            2 => Ok(Mt5SwapMode::CurrencySymbol),
+            3 => Ok(Mt5SwapMode::CurrencyMargin),
+            4 => Ok(Mt5SwapMode::CurrencyDeposit),
+            5 => Ok(Mt5SwapMode::InterestCurrent),
+            6 => Ok(Mt5SwapMode::InterestOpen),
+            7 => Ok(Mt5SwapMode::ReopenCurrent),
+            8 => Ok(Mt5SwapMode::ReopenBid),
+            9 => Ok(Mt5SwapMode::CurrencyProfit),
+            _ => Err(format!("unknown SYMBOL_SWAP_MODE value {value}")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Mt5SwapMode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = crate::common::parse::de_i64_flexible(deserializer)?;
+        Self::try_from(value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<Mt5SwapMode> for i64 {
+    fn from(mode: Mt5SwapMode) -> Self {
+        mode as i64
+    }
+}
+
+impl std::fmt::Display for Mt5SwapMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Mt5SwapMode::Disabled => write!(f, "DISABLED"),
+            Mt5SwapMode::Points => write!(f, "POINTS"),
+            Mt5SwapMode::CurrencySymbol => write!(f, "CURRENCY_SYMBOL"),
+            Mt5SwapMode::CurrencyMargin => write!(f, "CURRENCY_MARGIN"),
+            Mt5SwapMode::CurrencyDeposit => write!(f, "CURRENCY_DEPOSIT"),
+            Mt5SwapMode::InterestCurrent => write!(f, "INTEREST_CURRENT"),
+            Mt5SwapMode::InterestOpen => write!(f, "INTEREST_OPEN"),
+            Mt5SwapMode::ReopenCurrent => write!(f, "REOPEN_CURRENT"),
+            Mt5SwapMode::ReopenBid => write!(f, "REOPEN_BID"),
+            Mt5SwapMode::CurrencyProfit => write!(f, "CURRENCY_PROFIT"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_type_round_trip() {
+        for value in 0..=8 {
+            let order_type = Mt5OrderType::from_i64(value).unwrap();
+            assert_eq!(order_type.as_i64(), value);
+            let json = serde_json::to_string(&order_type).unwrap();
+            assert_eq!(serde_json::from_str::<Mt5OrderType>(&json).unwrap(), order_type);
+        }
+        assert_eq!(Mt5OrderType::from_i64(9), None);
+        assert!(serde_json::from_str::<Mt5OrderType>("9").is_err());
+    }
+
+    #[test]
+    fn test_deal_type_round_trip() {
+        for value in 0..=18 {
+            let deal_type = Mt5DealType::from_i64(value);
+            assert_eq!(deal_type.as_i64(), value);
+            let json = serde_json::to_string(&deal_type).unwrap();
+            assert_eq!(serde_json::from_str::<Mt5DealType>(&json).unwrap(), deal_type);
+        }
+        assert_eq!(Mt5DealType::from_i64(18), Mt5DealType::Other(18));
+    }
+
+    #[test]
+    fn test_tradable_order_types_map_to_nautilus() {
+        for value in 0..=7 {
+            let order_type = Mt5OrderType::from_i64(value).unwrap();
+            let nautilus_type = order_type.to_order_type().unwrap();
+            let side = order_type.order_side().unwrap();
+            assert_eq!(Mt5OrderType::from_nautilus(nautilus_type, side), Some(order_type));
+            assert_eq!(order_type.is_buy(), side == OrderSide::Buy);
+        }
+        assert_eq!(Mt5OrderType::CloseBy.to_order_type(), None);
+        assert!(!Mt5OrderType::CloseBy.is_buy());
+        assert_eq!(Mt5OrderType::from_nautilus(OrderType::TrailingStopMarket, OrderSide::Buy), None);
+    }
+
+    #[test]
+    fn test_only_trade_deals_have_a_side() {
+        assert_eq!(Mt5DealType::Buy.order_side(), Some(OrderSide::Buy));
+        assert_eq!(Mt5DealType::Sell.order_side(), Some(OrderSide::Sell));
+        for value in 2..=18 {
+            let deal_type = Mt5DealType::from_i64(value);
+            assert!(!deal_type.is_trade(), "{deal_type}");
+            assert_eq!(deal_type.order_side(), None);
+        }
+    }
+}
@@ -0,0 +1,229 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Error types for the MT5 adapter.
+//!
+//! `Mt5Error` is the single error type callers can match on. The HTTP, parsing,
+//! data, execution and instrument provider layers keep their specific error
+//! types internally and convert into `Mt5Error` via `From`, so `?` works across
+//! layer boundaries. The WebSocket client and order book requests return it
+//! directly, and every Python binding raises through it.
+
+use std::fmt::Debug;
+
+use thiserror::Error;
+
+use crate::{
+    common::parse::ParseError,
+    data_client::DataClientError,
+    execution_client::ExecutionClientError,
+    http::error::Mt5HttpError,
+    instrument_provider::InstrumentProviderError,
+};
+
+#[derive(Error, Debug)]
+pub enum Mt5Error {
+    #[error("HTTP request failed: {0}")]
+    HttpError(String),
+    
+    #[error("WebSocket error: {0}")]
+    WebSocketError(String),
+    
+    #[error("Serialization error: {0}")]
+    SerializationError(String),
+    
+    #[error("Authentication failed: {0}")]
+    AuthenticationError(String),
+    
+    #[error("Invalid configuration: {0}")]
+    ConfigError(String),
+    
+    #[error("Connection timeout")]
+    TimeoutError,
+    
+    #[error("Rate limit exceeded")]
+    RateLimitError,
+    
+    #[error("Symbol not found: {0}")]
+    SymbolNotFound(String),
+    
+    #[error("Order error: {0}")]
+    OrderError(String),
+    
+    #[error("Insufficient balance")]
+    InsufficientBalance,
+
+    #[error("Market depth not supported for {symbol}")]
+    DepthUnsupported { symbol: String },
+    
+    #[error("Unknown error: {0}")]
+    UnknownError(String),
+
+    #[error(transparent)]
+    Http(Mt5HttpError),
+
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+
+    #[error(transparent)]
+    DataClient(#[from] DataClientError),
+
+    #[error(transparent)]
+    ExecutionClient(#[from] ExecutionClientError),
+
+    #[error(transparent)]
+    InstrumentProvider(#[from] InstrumentProviderError),
+}
+
+impl Mt5Error {
+    /// Returns the underlying HTTP error, if this error originated from a bridge request.
+    pub fn http_error(&self) -> Option<&Mt5HttpError> {
+        match self {
+            Mt5Error::Http(e)
+            | Mt5Error::DataClient(DataClientError::HttpClient(e))
+            | Mt5Error::ExecutionClient(ExecutionClientError::HttpClient(e))
+            | Mt5Error::InstrumentProvider(InstrumentProviderError::HttpClient(e)) => Some(e),
+            _ => None,
+        }
+    }
+
+    /// Determines if the operation that produced this error may succeed if retried.
+    pub fn is_retryable(&self) -> bool {
+        if let Some(e) = self.http_error() {
+            return e.is_retryable();
+        }
+        matches!(
+            self,
+            Mt5Error::TimeoutError
+                | Mt5Error::RateLimitError
+                | Mt5Error::WebSocketError(_)
+                | Mt5Error::DataClient(DataClientError::ConnectionError(_))
+                | Mt5Error::ExecutionClient(ExecutionClientError::ConnectionError(_))
+                | Mt5Error::InstrumentProvider(InstrumentProviderError::ConnectionError(_))
+        )
+    }
+
+    /// Determines if this is an authentication or authorization failure.
+    pub fn is_auth(&self) -> bool {
+        if let Some(e) = self.http_error() {
+            return matches!(
+                e,
+                Mt5HttpError::AuthenticationError(_) | Mt5HttpError::AuthorizationError(_)
+            );
+        }
+        matches!(self, Mt5Error::AuthenticationError(_))
+    }
+}
+
+/// Maps bridge authentication, rate-limit, not-found and decoding failures onto
+/// the matching `Mt5Error` variants; any other HTTP error is kept as `Http`.
+impl From<Mt5HttpError> for Mt5Error {
+    fn from(err: Mt5HttpError) -> Self {
+        match err {
+            Mt5HttpError::AuthenticationError(msg) | Mt5HttpError::AuthorizationError(msg) => {
+                Mt5Error::AuthenticationError(msg)
+            }
+            Mt5HttpError::RateLimitError(_) => Mt5Error::RateLimitError,
+            Mt5HttpError::NotFoundError(msg) => Mt5Error::SymbolNotFound(msg),
+            Mt5HttpError::JsonDecodeError(_) | Mt5HttpError::ParseError(_) => {
+                Mt5Error::SerializationError(err.to_string())
+            }
+            err => Mt5Error::Http(err),
+        }
+    }
+}
+
+#[cfg(feature = "python-bindings")]
+impl From<Mt5Error> for pyo3::PyErr {
+    fn from(err: Mt5Error) -> Self {
+        pyo3::exceptions::PyRuntimeError::new_err(err.to_string())
+    }
+}
+
+/// Converts a layer error into a Python `RuntimeError` by way of [`Mt5Error`].
+#[cfg(feature = "python-bindings")]
+pub fn to_pyerr(err: impl Into<Mt5Error>) -> pyo3::PyErr {
+    err.into().into()
+}
+
+pub type Mt5Result<T> = Result<T, Mt5Error>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_http_error() {
+        let err: Mt5Error = Mt5HttpError::TimeoutError("slow".to_string()).into();
+
+        assert!(matches!(err, Mt5Error::Http(Mt5HttpError::TimeoutError(_))));
+        assert!(err.is_retryable());
+        assert!(!err.is_auth());
+    }
+
+    #[test]
+    fn test_from_http_error_maps_auth_rate_limit_and_decoding() {
+        for http in [
+            Mt5HttpError::AuthenticationError("bad password".to_string()),
+            Mt5HttpError::AuthorizationError("trading disabled".to_string()),
+        ] {
+            let err: Mt5Error = http.into();
+            assert!(matches!(err, Mt5Error::AuthenticationError(_)));
+            assert!(err.is_auth());
+            assert!(!err.is_retryable());
+        }
+
+        let limited: Mt5Error = Mt5HttpError::RateLimitError("slow down".to_string()).into();
+        assert!(matches!(limited, Mt5Error::RateLimitError));
+        assert!(limited.is_retryable());
+        let missing: Mt5Error = Mt5HttpError::NotFoundError("XYZ".to_string()).into();
+        assert!(matches!(missing, Mt5Error::SymbolNotFound(symbol) if symbol == "XYZ"));
+        let garbled: Mt5Error = Mt5HttpError::JsonDecodeError("eof".to_string()).into();
+        assert!(matches!(garbled, Mt5Error::SerializationError(_)));
+    }
+
+    #[test]
+    fn test_from_layer_errors_keeps_http_classification() {
+        let data: Mt5Error =
+            DataClientError::HttpClient(Mt5HttpError::AuthenticationError("denied".to_string())).into();
+        let exec: Mt5Error =
+            ExecutionClientError::HttpClient(Mt5HttpError::ServerError("down".to_string())).into();
+        let provider: Mt5Error =
+            InstrumentProviderError::HttpClient(Mt5HttpError::NotFoundError("XYZ".to_string())).into();
+
+        assert!(data.is_auth());
+        assert!(!data.is_retryable());
+        assert!(exec.is_retryable());
+        assert!(!provider.is_retryable());
+        assert!(!provider.is_auth());
+    }
+
+    #[test]
+    fn test_from_parse_error() {
+        let err: Mt5Error = ParseError::MissingField("digits".to_string()).into();
+
+        assert!(matches!(err, Mt5Error::Parse(_)));
+        assert!(!err.is_retryable());
+        assert_eq!(err.to_string(), "Missing field: digits");
+    }
+
+    #[test]
+    fn test_classifiers_on_direct_variants() {
+        assert!(Mt5Error::RateLimitError.is_retryable());
+        assert!(Mt5Error::AuthenticationError("bad".to_string()).is_auth());
+        assert!(!Mt5Error::OrderError("rejected".to_string()).is_retryable());
+        assert!(Mt5Error::DataClient(DataClientError::ConnectionError("refused".to_string())).is_retryable());
+    }
+}
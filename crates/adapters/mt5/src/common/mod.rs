@@ -1,21 +1,32 @@
 //! Common types and utilities for the MetaTrader 5 adapter.
 //!
 //! This module contains shared functionality including:
+//! - Bounded streaming channels
+//! - Connection state tracking
+//! - Adapter constants
 //! - Authentication credentials
 //! - Common enums and data structures
 //! - Parsing utilities
+//! - Background task runtime selection
 //! - Symbol handling
 //! - Testing utilities
 
+pub mod channel;
+pub mod connection;
+pub mod consts;
 pub mod credential;
 pub mod enums;
+pub mod error;
 pub mod models;
 pub mod parse;
+pub mod runtime;
 pub mod symbol;
+#[cfg(any(test, feature = "test-utils"))]
 pub mod testing;
 
 pub use credential::*;
 pub use enums::*;
+pub use error::*;
 pub use models::*;
 pub use parse::*;
 pub use symbol::*;
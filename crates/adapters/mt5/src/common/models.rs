@@ -17,10 +17,14 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::common::enums::{Mt5Environment, Mt5MarginMode, Mt5OrderType, Mt5StopOutMode};
+use crate::common::parse::{de_f64_flexible, de_u32_flexible, de_u64_flexible};
+
 /// Represents account information from MT5.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Mt5AccountInfo {
     /// Account number
+    #[serde(deserialize_with = "de_u64_flexible")]
     pub login: u64,
     /// Account name
     pub name: String,
@@ -29,19 +33,41 @@ pub struct Mt5AccountInfo {
     /// Account currency
     pub currency: String,
     /// Account leverage
+    #[serde(deserialize_with = "de_u32_flexible")]
     pub leverage: u32,
     /// Account balance
+    #[serde(deserialize_with = "de_f64_flexible")]
     pub balance: f64,
     /// Account equity
+    #[serde(deserialize_with = "de_f64_flexible")]
     pub equity: f64,
     /// Account profit/loss
+    #[serde(deserialize_with = "de_f64_flexible")]
     pub profit: f64,
     /// Account margin used
+    #[serde(deserialize_with = "de_f64_flexible")]
     pub margin: f64,
     /// Account free margin
+    #[serde(deserialize_with = "de_f64_flexible")]
     pub margin_free: f64,
     /// Account margin level
+    #[serde(deserialize_with = "de_f64_flexible")]
     pub margin_level: f64,
+    /// Whether `margin_so_call` and `margin_so_so` are percentages or money
+    #[serde(default)]
+    pub margin_so_mode: Mt5StopOutMode,
+    /// Margin call level, per `margin_so_mode`
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub margin_so_call: f64,
+    /// Stop out level, at which the broker starts closing positions, per `margin_so_mode`
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub margin_so_so: f64,
+    /// Whether positions are netted or hedged
+    #[serde(default)]
+    pub margin_mode: Mt5MarginMode,
+    /// Demo, contest or real account; `None` when the bridge omits `trade_mode`
+    #[serde(default)]
+    pub trade_mode: Option<Mt5Environment>,
 }
 
 impl Mt5AccountInfo {
@@ -71,7 +97,62 @@ impl Mt5AccountInfo {
             margin,
             margin_free,
             margin_level,
+            margin_so_mode: Mt5StopOutMode::default(),
+            margin_so_call: 0.0,
+            margin_so_so: 0.0,
+            margin_mode: Mt5MarginMode::default(),
+            trade_mode: None,
+        }
+    }
+
+    /// Returns the effective leverage applied to margin requirements.
+    ///
+    /// Exchange accounts are margined by the exchange's own rates, so account
+    /// leverage only applies in the retail modes. A leverage of 0 is read as 1:1.
+    pub fn effective_leverage(&self) -> f64 {
+        match self.margin_mode {
+            Mt5MarginMode::Exchange => 1.0,
+            Mt5MarginMode::RetailNetting | Mt5MarginMode::RetailHedging => f64::from(self.leverage.max(1)),
+        }
+    }
+
+    /// Returns the largest position value, in account currency, the free margin can open.
+    ///
+    /// `symbol_margin_rate` is the symbol's initial margin rate (1.0 for most
+    /// forex symbols), so a position of value `v` requires
+    /// `v * symbol_margin_rate / effective_leverage()` margin. A non-positive
+    /// rate means the symbol needs no margin and gives `f64::INFINITY`; an
+    /// account without free margin gives 0.
+    pub fn max_position_value(&self, symbol_margin_rate: f64) -> f64 {
+        if symbol_margin_rate <= 0.0 {
+            return f64::INFINITY;
         }
+        self.margin_free.max(0.0) * self.effective_leverage() / symbol_margin_rate
+    }
+
+    /// Returns the free margin left after an order requiring `order_margin`,
+    /// as reported by `order_calc_margin`, is filled. Negative when the order
+    /// would not be covered.
+    pub fn free_margin_after(&self, order_margin: f64) -> f64 {
+        self.margin_free - order_margin
+    }
+
+    /// Returns whether the account is within `buffer_pct` percent of its margin call.
+    ///
+    /// The call level is raised by `buffer_pct` percent of itself, e.g. a 50%
+    /// call with a 20% buffer triggers at a margin level of 60%. In `Money`
+    /// stop out mode equity is compared against the call amount instead. An
+    /// account without margin in use or without a call level is never near one.
+    pub fn is_near_margin_call(&self, buffer_pct: f64) -> bool {
+        if self.margin <= 0.0 || self.margin_so_call <= 0.0 {
+            return false;
+        }
+        let threshold = self.margin_so_call * (1.0 + buffer_pct.max(0.0) / 100.0);
+        let current = match self.margin_so_mode {
+            Mt5StopOutMode::Percent => self.margin_level,
+            Mt5StopOutMode::Money => self.equity,
+        };
+        current <= threshold
     }
 }
 
@@ -203,26 +284,41 @@ impl Mt5Trade {
 }
 
 /// Represents the type of trade in MT5.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-pub enum Mt5TradeType {
-    /// Buy trade
-    #[serde(rename = "buy")]
-    Buy,
-    /// Sell trade
-    #[serde(rename = "sell")]
-    Sell,
-    /// Buy limit order
-    #[serde(rename = "buy_limit")]
-    BuyLimit,
-    /// Sell limit order
-    #[serde(rename = "sell_limit")]
-    SellLimit,
-    /// Buy stop order
-    #[serde(rename = "buy_stop")]
-    BuyStop,
-    /// Sell stop order
-    #[serde(rename = "sell_stop")]
-    SellStop,
+///
+/// Alias of [`Mt5OrderType`], which owns the `ORDER_TYPE_*` mapping and the
+/// Nautilus conversions.
+pub type Mt5TradeType = Mt5OrderType;
+
+/// Terminal version as reported by the MT5 `version` endpoint.
+///
+/// Ordered by `build` first, since the build number is what feature support
+/// and known bugs are tied to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Mt5Version {
+    /// Terminal API version, e.g. `500`
+    pub api: u32,
+    /// Terminal build number, e.g. `3802`
+    pub build: u32,
+    /// Build release date, e.g. `"15 Jul 2024"`
+    pub date: String,
+}
+
+impl Ord for Mt5Version {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.build, self.api, &self.date).cmp(&(other.build, other.api, &other.date))
+    }
+}
+
+impl PartialOrd for Mt5Version {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::fmt::Display for Mt5Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} build {} ({})", self.api, self.build, self.date)
+    }
 }
 
 #[cfg(test)]
@@ -250,6 +346,91 @@ mod tests {
         assert_eq!(account.currency, "USD");
     }
 
+    fn netting_account() -> Mt5AccountInfo {
+        Mt5AccountInfo::new(
+            12345678,
+            "Test Account".to_string(),
+            "TestServer".to_string(),
+            "USD".to_string(),
+            100,
+            10000.0,
+            9987.5,
+            -12.5,
+            216.4,
+            9771.1,
+            4615.3,
+        )
+    }
+
+    #[test]
+    fn test_netting_account_risk_helpers() {
+        let account = netting_account();
+
+        assert_eq!(account.margin_mode, Mt5MarginMode::RetailNetting);
+        assert_eq!(account.effective_leverage(), 100.0);
+        assert!((account.max_position_value(1.0) - 977_110.0).abs() < 1e-6);
+        assert!((account.max_position_value(0.5) - 1_954_220.0).abs() < 1e-6);
+        assert!((account.free_margin_after(1085.0) - 8686.1).abs() < 1e-9);
+        assert!(account.free_margin_after(10_000.0) < 0.0);
+    }
+
+    #[test]
+    fn test_exchange_account_ignores_leverage() {
+        let account = Mt5AccountInfo {
+            margin_mode: Mt5MarginMode::Exchange,
+            ..netting_account()
+        };
+
+        assert!((account.max_position_value(0.1) - 97_711.0).abs() < 1e-6);
+        assert_eq!(account.max_position_value(0.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_no_free_margin_caps_position_value_at_zero() {
+        let account = Mt5AccountInfo {
+            margin_free: -50.0,
+            ..netting_account()
+        };
+
+        assert_eq!(account.max_position_value(1.0), 0.0);
+    }
+
+    #[test]
+    fn test_account_comfortably_above_margin_call() {
+        let account = crate::common::testing::load_account_info_fixture();
+
+        assert_eq!(account.margin_so_mode, Mt5StopOutMode::Percent);
+        assert_eq!(account.margin_so_call, 50.0);
+        assert_eq!(account.margin_so_so, 30.0);
+        assert!(!account.is_near_margin_call(20.0));
+        // No open positions: MT5 reports a margin level of 0
+        assert!(!Mt5AccountInfo { margin: 0.0, margin_level: 0.0, ..account }.is_near_margin_call(20.0));
+    }
+
+    #[test]
+    fn test_account_near_margin_call() {
+        let account = Mt5AccountInfo {
+            equity: 1100.0,
+            margin: 2000.0,
+            margin_level: 55.0,
+            margin_so_call: 50.0,
+            margin_so_so: 30.0,
+            ..netting_account()
+        };
+
+        assert!(account.is_near_margin_call(20.0));
+        assert!(!account.is_near_margin_call(5.0));
+
+        let money = Mt5AccountInfo {
+            margin_so_mode: Mt5StopOutMode::Money,
+            margin_so_call: 1000.0,
+            margin_so_so: 500.0,
+            ..account
+        };
+        assert!(money.is_near_margin_call(10.0));
+        assert!(!money.is_near_margin_call(5.0));
+    }
+
     #[test]
     fn test_position_creation() {
         let position = Mt5Position::new(
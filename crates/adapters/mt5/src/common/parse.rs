@@ -15,10 +15,22 @@
 
 //! Parsing utilities for MetaTrader 5 data.
 
+use std::{borrow::Cow, str::FromStr};
+
 use chrono::{DateTime, Utc};
+use nautilus_model::{
+    enums::CurrencyType,
+    types::{AccountBalance, Currency, Money, Quantity},
+};
+use serde::{de::Error as _, Deserialize, Deserializer};
 use serde_json::Value;
 use thiserror::Error;
 
+use crate::common::{
+    enums::QuoteSizeSource,
+    models::{Mt5AccountInfo, Mt5Version},
+};
+
 #[derive(Debug, Error)]
 pub enum ParseError {
     #[error("Invalid JSON: {0}")]
@@ -66,6 +78,125 @@ pub fn extract_i64_field(obj: &Value, field: &str) -> Result<i64, ParseError> {
         .ok_or_else(|| ParseError::MissingField(field.to_string()))
 }
 
+/// Bare tokens Python's `json` module writes for non-finite floats.
+const NON_FINITE_TOKENS: [&str; 3] = ["-Infinity", "Infinity", "NaN"];
+
+/// Quotes the bare `NaN`, `Infinity` and `-Infinity` tokens the Python bridge
+/// emits for unset floats, turning its output into standard JSON.
+///
+/// Tokens inside strings are left alone. The quoted values then reach the
+/// flexible number deserializers, which reject them for required fields and
+/// read them as `None` for optional ones.
+pub fn quote_non_finite_json(text: &str) -> Cow<'_, str> {
+    if !NON_FINITE_TOKENS.iter().any(|token| text.contains(token)) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut out = String::with_capacity(text.len() + 16);
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else if let Some(token) = NON_FINITE_TOKENS.iter().find(|token| rest.starts_with(*token)) {
+            out.push('"');
+            out.push_str(token);
+            out.push('"');
+            rest = &rest[token.len()..];
+            continue;
+        }
+        out.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    Cow::Owned(out)
+}
+
+/// A JSON number or a string holding one, as different bridges serialize numbers differently.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FlexibleNumber {
+    Number(serde_json::Number),
+    String(String),
+}
+
+impl FlexibleNumber {
+    fn into_f64(self) -> Result<f64, String> {
+        let value = match self {
+            FlexibleNumber::Number(n) => n.as_f64().ok_or_else(|| format!("invalid number {n}"))?,
+            FlexibleNumber::String(s) => s.trim().parse().map_err(|_| format!("invalid number '{s}'"))?,
+        };
+        if value.is_finite() {
+            Ok(value)
+        } else {
+            Err(format!("required number is {value}"))
+        }
+    }
+
+    fn into_i64(self) -> Result<i64, String> {
+        match self {
+            FlexibleNumber::Number(n) => n.as_i64().ok_or_else(|| format!("invalid integer {n}")),
+            FlexibleNumber::String(s) => s.trim().parse().map_err(|_| format!("invalid integer '{s}'")),
+        }
+    }
+
+    fn into_u64(self) -> Result<u64, String> {
+        match self {
+            FlexibleNumber::Number(n) => n.as_u64().ok_or_else(|| format!("invalid unsigned integer {n}")),
+            FlexibleNumber::String(s) => s
+                .trim()
+                .parse()
+                .map_err(|_| format!("invalid unsigned integer '{s}'")),
+        }
+    }
+}
+
+/// Deserializes an `f64` given as a JSON number or a numeric string.
+pub fn de_f64_flexible<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+    FlexibleNumber::deserialize(deserializer)?
+        .into_f64()
+        .map_err(D::Error::custom)
+}
+
+/// Deserializes an optional `f64` given as a JSON number or a numeric string.
+///
+/// `null`, `NaN` and `±Infinity`, which the bridge uses for unset values, all
+/// read as `None`; anything else that is not a number is an error.
+pub fn de_opt_f64_flexible<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<f64>, D::Error> {
+    match Option::<FlexibleNumber>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(FlexibleNumber::String(s)) if NON_FINITE_TOKENS.contains(&s.trim()) => Ok(None),
+        Some(number) => number.into_f64().map(Some).map_err(D::Error::custom),
+    }
+}
+
+/// Deserializes an `i64` given as a JSON number or a numeric string.
+pub fn de_i64_flexible<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+    FlexibleNumber::deserialize(deserializer)?
+        .into_i64()
+        .map_err(D::Error::custom)
+}
+
+/// Deserializes a `u64` given as a JSON number or a numeric string.
+pub fn de_u64_flexible<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    FlexibleNumber::deserialize(deserializer)?
+        .into_u64()
+        .map_err(D::Error::custom)
+}
+
+/// Deserializes a `u32` given as a JSON number or a numeric string.
+pub fn de_u32_flexible<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+    let value = de_u64_flexible(deserializer)?;
+    u32::try_from(value).map_err(D::Error::custom)
+}
+
 /// Parse MT5 timestamp (seconds since epoch) to DateTime<Utc>
 pub fn parse_mt5_timestamp(timestamp: i64) -> Result<DateTime<Utc>, ParseError> {
     DateTime::from_timestamp(timestamp, 0)
@@ -118,6 +249,36 @@ pub fn parse_price(price: f64, digits: u8) -> Result<f64, ParseError> {
     }
 }
 
+/// Returns the number of decimal places of a step such as `volume_step` (e.g. 0.01 -> 2).
+pub fn step_decimals(step: f64) -> u32 {
+    // `Display` for f64 never uses scientific notation, so 1e-8 prints as 0.00000001
+    let formatted = format!("{step}");
+    formatted
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.trim_end_matches('0').len() as u32)
+}
+
+/// Formats `price` with exactly `digits` decimals (the symbol's `digits`).
+pub fn format_price(price: f64, digits: u32) -> String {
+    let formatted = format!("{:.*}", digits as usize, price);
+    // Avoid "-0.00000" for prices that round to zero
+    if formatted.trim_start_matches('-').chars().all(|c| c == '0' || c == '.') {
+        formatted.trim_start_matches('-').to_string()
+    } else {
+        formatted
+    }
+}
+
+/// Formats `volume` to the precision of `volume_step`, without trailing zeros.
+pub fn format_volume(volume: f64, volume_step: f64) -> String {
+    let formatted = format_price(volume, step_decimals(volume_step));
+    if formatted.contains('.') {
+        formatted.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        formatted
+    }
+}
+
 /// Parse volume/lot size
 pub fn parse_volume(volume: f64, min_lot: f64, max_lot: f64, lot_step: f64) -> Result<f64, ParseError> {
     if volume.is_finite() && volume >= min_lot && volume <= max_lot {
@@ -129,6 +290,98 @@ pub fn parse_volume(volume: f64, min_lot: f64, max_lot: f64, lot_step: f64) -> R
     }
 }
 
+/// Returns the size quoted on both sides of a tick, which MT5 does not report.
+///
+/// `tick_volume` is the tick's traded volume and `volume_min` the symbol's
+/// minimum lot. A size that is missing or zero at `size_prec` falls back to one
+/// unit, since Nautilus rejects zero-sized quotes downstream.
+pub fn quote_size(
+    source: QuoteSizeSource,
+    tick_volume: f64,
+    volume_min: Option<f64>,
+    size_prec: u8,
+) -> Option<Quantity> {
+    let size = match source {
+        QuoteSizeSource::MinVolume => volume_min.unwrap_or(0.0),
+        QuoteSizeSource::One => 1.0,
+        QuoteSizeSource::TickVolume => tick_volume,
+    };
+    Quantity::from_f64(size, size_prec)
+        .ok()
+        .filter(|size| size.as_f64() > 0.0)
+        .or_else(|| Quantity::from_f64(1.0, size_prec).ok())
+}
+
+/// Resolves an MT5 currency code (e.g. an account's `currency`) to a Nautilus `Currency`.
+///
+/// Registered codes (ISO fiat and the common crypto assets) come from the Nautilus
+/// registry. Unregistered three-letter codes are treated as 2-decimal fiat and
+/// anything else as an 8-decimal crypto asset.
+pub fn resolve_mt5_currency(code: &str) -> Result<Currency, ParseError> {
+    let code = code.trim().to_uppercase();
+    if code.is_empty() || !code.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(ParseError::InvalidValue(format!("currency code '{code}'")));
+    }
+
+    if let Ok(currency) = Currency::from_str(&code) {
+        return Ok(currency);
+    }
+
+    let currency = if code.len() == 3 && code.chars().all(|c| c.is_ascii_alphabetic()) {
+        Currency::new(&code, 2, 0, &code, CurrencyType::Fiat)
+    } else {
+        Currency::new(&code, 8, 0, &code, CurrencyType::Crypto)
+    };
+    tracing::warn!("Currency {code} not registered, using {} decimals", currency.precision);
+    Ok(currency)
+}
+
+/// Builds the account balance from MT5 `account_info`.
+///
+/// Total is equity, split into used margin (locked) and free margin.
+///
+/// # Errors
+///
+/// Returns `ParseError::InvalidValue` for an unknown currency code or a margin
+/// that is not a finite amount within `Money`'s range.
+pub fn parse_account_balance(info: &Mt5AccountInfo) -> Result<AccountBalance, ParseError> {
+    let currency = resolve_mt5_currency(&info.currency)?;
+    let money = |amount: f64, name: &str| {
+        Money::new_checked(amount, currency)
+            .map_err(|e| ParseError::InvalidValue(format!("account_info.{name}: {e}")))
+    };
+    let locked = money(info.margin, "margin")?;
+    let free = money(info.margin_free, "margin_free")?;
+    AccountBalance::new_checked(locked + free, locked, free)
+        .map_err(|e| ParseError::InvalidValue(e.to_string()))
+}
+
+/// Parses the MT5 `version` response, `[api, build, date]`, with or without the `result` wrapper.
+pub fn parse_version(value: &Value) -> Result<Mt5Version, ParseError> {
+    let fields = value
+        .get("result")
+        .unwrap_or(value)
+        .as_array()
+        .ok_or_else(|| ParseError::InvalidValue(format!("version: expected array, got {value}")))?;
+    let number = |index: usize, name: &str| {
+        fields
+            .get(index)
+            .and_then(Value::as_u64)
+            .and_then(|n| u32::try_from(n).ok())
+            .ok_or_else(|| ParseError::MissingField(format!("version.{name}")))
+    };
+
+    Ok(Mt5Version {
+        api: number(0, "api")?,
+        build: number(1, "build")?,
+        date: fields
+            .get(2)
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string(),
+    })
+}
+
 /// Extract and parse instrument metadata
 pub fn parse_instrument_metadata(obj: &Value) -> Result<InstrumentMetadata, ParseError> {
     let symbol = extract_string_field(obj, "symbol")?;
@@ -183,9 +436,157 @@ pub struct InstrumentMetadata {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::testing::load_account_info_fixture;
     use chrono::Datelike;
     use serde_json::json;
 
+    #[derive(Debug, Deserialize)]
+    struct Quote {
+        #[serde(deserialize_with = "de_f64_flexible")]
+        bid: f64,
+        #[serde(default, deserialize_with = "de_opt_f64_flexible")]
+        last: Option<f64>,
+    }
+
+    fn parse_quote(text: &str) -> Result<Quote, serde_json::Error> {
+        serde_json::from_str(&quote_non_finite_json(text))
+    }
+
+    #[test]
+    fn test_non_finite_optional_fields_read_as_none() {
+        for last in ["NaN", "Infinity", "-Infinity", "null"] {
+            let quote = parse_quote(&format!(r#"{{"bid": 1.1041, "last": {last}}}"#)).unwrap();
+
+            assert_eq!(quote.bid, 1.1041);
+            assert_eq!(quote.last, None, "last: {last}");
+        }
+        assert_eq!(parse_quote(r#"{"bid": 1.1041}"#).unwrap().last, None);
+        assert_eq!(parse_quote(r#"{"bid": 1.1041, "last": "1.5"}"#).unwrap().last, Some(1.5));
+        assert!(parse_quote(r#"{"bid": 1.1041, "last": "abc"}"#).is_err());
+    }
+
+    #[test]
+    fn test_non_finite_required_fields_fail_clearly() {
+        for bid in ["NaN", "Infinity", "-Infinity"] {
+            let error = parse_quote(&format!(r#"{{"bid": {bid}}}"#)).unwrap_err();
+
+            assert!(error.to_string().contains("required number is"), "bid: {bid}: {error}");
+        }
+        assert!(parse_quote(r#"{"bid": null}"#).is_err());
+    }
+
+    #[test]
+    fn test_quote_non_finite_json_leaves_strings_alone() {
+        let text = r#"{"comment": "NaN \"Infinity\"", "values": [NaN, -Infinity, 1.0]}"#;
+
+        let quoted = quote_non_finite_json(text);
+
+        assert_eq!(
+            quoted,
+            r#"{"comment": "NaN \"Infinity\"", "values": ["NaN", "-Infinity", 1.0]}"#
+        );
+        assert!(matches!(quote_non_finite_json(r#"{"bid": 1.0}"#), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_resolve_mt5_currency_fiat_account() {
+        let info = load_account_info_fixture();
+        let currency = resolve_mt5_currency(&info.currency).unwrap();
+
+        assert_eq!(currency.code.as_str(), "USD");
+        assert_eq!(currency.precision, 2);
+        assert_eq!(currency.currency_type, CurrencyType::Fiat);
+
+        let balance = parse_account_balance(&info).unwrap();
+        assert_eq!(balance.total.as_f64(), 9987.5);
+        assert_eq!(balance.locked.as_f64(), 216.4);
+    }
+
+    #[test]
+    fn test_resolve_mt5_currency_usdt_account() {
+        let mut info = load_account_info_fixture();
+        info.currency = "USDT".to_string();
+
+        let balance = parse_account_balance(&info).unwrap();
+
+        assert_eq!(balance.currency.code.as_str(), "USDT");
+        assert_eq!(balance.currency.currency_type, CurrencyType::Crypto);
+    }
+
+    #[test]
+    fn test_resolve_mt5_currency_unregistered() {
+        let crypto = resolve_mt5_currency("ZZTOKEN").unwrap();
+        assert_eq!(crypto.precision, 8);
+        assert_eq!(crypto.currency_type, CurrencyType::Crypto);
+
+        assert!(resolve_mt5_currency("").is_err());
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Flexible {
+        #[serde(deserialize_with = "de_f64_flexible")]
+        price: f64,
+        #[serde(deserialize_with = "de_i64_flexible")]
+        spread: i64,
+        #[serde(deserialize_with = "de_u64_flexible")]
+        ticket: u64,
+        #[serde(deserialize_with = "de_u32_flexible")]
+        digits: u32,
+    }
+
+    #[test]
+    fn test_flexible_numbers_accept_numbers_and_strings() {
+        let from_numbers: Flexible =
+            serde_json::from_value(json!({"price": 1.10412, "spread": -7, "ticket": 50012345, "digits": 5}))
+                .unwrap();
+        let from_strings: Flexible = serde_json::from_value(
+            json!({"price": "1.10412", "spread": "-7", "ticket": "50012345", "digits": " 5 "}),
+        )
+        .unwrap();
+
+        assert_eq!(from_numbers, from_strings);
+        assert_eq!(from_numbers.price, 1.10412);
+    }
+
+    #[test]
+    fn test_flexible_numbers_reject_invalid() {
+        assert!(serde_json::from_value::<Flexible>(
+            json!({"price": "abc", "spread": 0, "ticket": 1, "digits": 5})
+        )
+        .is_err());
+        assert!(serde_json::from_value::<Flexible>(
+            json!({"price": 1.0, "spread": 0, "ticket": -1, "digits": 5})
+        )
+        .is_err());
+        assert!(serde_json::from_value::<Flexible>(
+            json!({"price": 1.0, "spread": 1.5, "ticket": 1, "digits": 5})
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_quote_size_sources() {
+        assert_eq!(
+            quote_size(QuoteSizeSource::MinVolume, 3.0, Some(0.01), 2),
+            Some(Quantity::from("0.01"))
+        );
+        assert_eq!(quote_size(QuoteSizeSource::One, 3.0, Some(0.01), 2), Some(Quantity::from("1.00")));
+        assert_eq!(
+            quote_size(QuoteSizeSource::TickVolume, 3.0, Some(0.01), 2),
+            Some(Quantity::from("3.00"))
+        );
+    }
+
+    #[test]
+    fn test_zero_quote_size_falls_back_to_one() {
+        let one = Some(Quantity::from("1.00"));
+        assert_eq!(quote_size(QuoteSizeSource::TickVolume, 0.0, Some(0.01), 2), one);
+        assert_eq!(quote_size(QuoteSizeSource::TickVolume, f64::NAN, Some(0.01), 2), one);
+        assert_eq!(quote_size(QuoteSizeSource::MinVolume, 3.0, None, 2), one);
+        // Rounds to zero at the size precision
+        assert_eq!(quote_size(QuoteSizeSource::MinVolume, 3.0, Some(0.001), 2), one);
+    }
+
     #[test]
     fn test_parse_json_response() {
         let data = r#"{"status": "ok"}"#;
@@ -251,6 +652,27 @@ mod tests {
         assert_eq!(result.unwrap(), 1.23457);
     }
 
+    #[test]
+    fn test_format_price_forex_five_digits() {
+        assert_eq!(format_price(1.104123456, 5), "1.10412");
+        assert_eq!(format_price(1.1040000000000001, 5), "1.10400");
+        assert_eq!(format_price(-0.000001, 5), "0.00000");
+    }
+
+    #[test]
+    fn test_format_price_index_two_digits() {
+        assert_eq!(format_price(37715.4, 2), "37715.40");
+        assert_eq!(format_price(4500.0, 0), "4500");
+    }
+
+    #[test]
+    fn test_format_volume_step() {
+        assert_eq!(format_volume(0.1, 0.01), "0.1");
+        assert_eq!(format_volume(0.12999999999, 0.01), "0.13");
+        assert_eq!(format_volume(2.0, 0.01), "2");
+        assert_eq!(format_volume(0.00000003, 0.00000001), "0.00000003");
+    }
+
     #[test]
     fn test_parse_volume() {
         let result = parse_volume(0.1, 0.01, 100.0, 0.01);
@@ -275,4 +697,40 @@ mod tests {
         assert_eq!(metadata.symbol, "EURUSD");
         assert_eq!(metadata.digits, 5);
     }
+
+    #[test]
+    fn test_parse_version_array_forms() {
+        let expected = Mt5Version {
+            api: 500,
+            build: 3802,
+            date: "15 Jul 2024".to_string(),
+        };
+
+        assert_eq!(parse_version(&json!([500, 3802, "15 Jul 2024"])).unwrap(), expected);
+        assert_eq!(
+            parse_version(&json!({"result": [500, 3802, "15 Jul 2024"]})).unwrap(),
+            expected
+        );
+        assert!(matches!(
+            parse_version(&json!({"result": [500]})),
+            Err(ParseError::MissingField(_))
+        ));
+        assert!(parse_version(&json!({"result": null})).is_err());
+    }
+
+    #[test]
+    fn test_versions_order_by_build() {
+        let version = |api, build| Mt5Version {
+            api,
+            build,
+            date: String::new(),
+        };
+
+        assert!(version(500, 3802) > version(500, 3700));
+        assert!(version(400, 4000) > version(500, 3802));
+        assert_eq!(
+            [version(500, 3802), version(500, 2875), version(500, 3000)].iter().max(),
+            Some(&version(500, 3802))
+        );
+    }
 }
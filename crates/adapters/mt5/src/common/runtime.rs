@@ -0,0 +1,67 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Placement of the adapter's background tasks.
+//!
+//! Polling feeds, the connectivity monitor and the keep-alive run as spawned
+//! tasks. By default they land on whichever Tokio runtime is current; a client
+//! given a runtime handle (e.g. via `set_runtime`) spawns them there instead,
+//! so a dedicated runtime can keep the adapter's I/O apart from the application.
+//!
+//! Polling subscriptions, the connectivity monitor and the keep-alive are spawned
+//! with [`spawn_cancellable`] under a child of their client's cancellation token,
+//! so `disconnect` or dropping the client stops all of them at once while
+//! unsubscribing cancels only the one.
+
+use std::future::Future;
+
+use tokio::{runtime::Handle, task::JoinHandle};
+use tokio_util::sync::CancellationToken;
+
+/// Spawns `future` on `runtime`, or on the current runtime when `None`.
+///
+/// # Panics
+///
+/// Panics if `runtime` is `None` and there is no current Tokio runtime.
+pub fn spawn_on<F>(runtime: Option<&Handle>, future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    match runtime {
+        Some(handle) => handle.spawn(future),
+        None => tokio::spawn(future),
+    }
+}
+
+/// Spawns `future` like [`spawn_on`], dropping it as soon as `token` is cancelled.
+///
+/// The future is interrupted at its current await point, including an
+/// in-flight bridge request, so shutdown does not wait out a poll interval.
+///
+/// # Panics
+///
+/// Panics if `runtime` is `None` and there is no current Tokio runtime.
+pub fn spawn_cancellable<F>(runtime: Option<&Handle>, token: CancellationToken, future: F) -> JoinHandle<()>
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    spawn_on(runtime, async move {
+        tokio::select! {
+            () = token.cancelled() => {}
+            () = future => {}
+        }
+    })
+}
@@ -15,7 +15,7 @@
 
 //! Symbol handling for the MT5 adapter.
 
-use std::fmt;
+use std::{collections::HashMap, fmt};
 
 use serde::{Deserialize, Serialize};
 
@@ -97,6 +97,124 @@ impl fmt::Display for Mt5Symbol {
     }
 }
 
+/// Returns whether `name` matches an MT5 symbol `group` pattern.
+///
+/// Mirrors the `group` argument of `symbols_get`: a comma-separated list of
+/// conditions where `*` matches any run of characters and a leading `!`
+/// excludes the names it matches. Conditions apply in order, so a later one
+/// overrides an earlier one, e.g. `"*,!*EUR*"` is every symbol without `EUR`.
+/// Matching ignores ASCII case, like the terminal.
+pub fn matches_group(name: &str, group: &str) -> bool {
+    let mut matched = false;
+    for condition in group.split(',').map(str::trim).filter(|c| !c.is_empty()) {
+        let (exclude, pattern) = match condition.strip_prefix('!') {
+            Some(pattern) => (true, pattern),
+            None => (false, condition),
+        };
+        if matches_wildcard(name, pattern) {
+            matched = !exclude;
+        }
+    }
+    matched
+}
+
+/// Matches `name` against `pattern`, where `*` stands for any run of characters.
+fn matches_wildcard(name: &str, pattern: &str) -> bool {
+    let name = name.to_ascii_uppercase().into_bytes();
+    let pattern = pattern.to_ascii_uppercase().into_bytes();
+    let (mut n, mut p) = (0, 0);
+    // Position after the last `*` and the name index it is currently covering up to
+    let mut backtrack = None;
+
+    while n < name.len() {
+        if p < pattern.len() && pattern[p] == b'*' {
+            p += 1;
+            backtrack = Some((p, n));
+        } else if p < pattern.len() && pattern[p] == name[n] {
+            p += 1;
+            n += 1;
+        } else if let Some((star_p, star_n)) = backtrack {
+            p = star_p;
+            n = star_n + 1;
+            backtrack = Some((star_p, n));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == b'*')
+}
+
+/// Translates symbol names between Nautilus and the MT5 terminal.
+///
+/// An explicit `symbol_map` entry (Nautilus name to MT5 name, e.g. `XAUUSD` to
+/// `GOLD`) wins. Otherwise a configured broker `suffix` is appended towards
+/// MT5 and stripped from MT5 names, and any other name passes through as is.
+#[derive(Debug, Clone, Default)]
+pub struct SymbolMapper {
+    to_mt5: HashMap<String, String>,
+    to_nautilus: HashMap<String, String>,
+    suffix: Option<String>,
+}
+
+impl SymbolMapper {
+    /// Creates a mapper from `symbol_map` and an optional broker `suffix`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a name in `symbol_map` is empty or two Nautilus
+    /// names map to the same MT5 name, since the map must be one-to-one.
+    pub fn new(
+        symbol_map: &HashMap<String, String>,
+        suffix: Option<&str>,
+    ) -> Result<Self, String> {
+        let mut to_nautilus = HashMap::with_capacity(symbol_map.len());
+        for (nautilus, mt5) in symbol_map {
+            if nautilus.is_empty() || mt5.is_empty() {
+                return Err(format!("symbol_map entry '{nautilus}' -> '{mt5}' has an empty name"));
+            }
+            if let Some(other) = to_nautilus.insert(mt5.clone(), nautilus.clone()) {
+                let (first, second) = if other < *nautilus {
+                    (other, nautilus.clone())
+                } else {
+                    (nautilus.clone(), other)
+                };
+                return Err(format!(
+                    "symbol_map is not one-to-one: '{first}' and '{second}' both map to '{mt5}'"
+                ));
+            }
+        }
+        Ok(Self {
+            to_mt5: symbol_map.clone(),
+            to_nautilus,
+            suffix: suffix.filter(|suffix| !suffix.is_empty()).map(str::to_string),
+        })
+    }
+
+    /// Returns the MT5 name for the Nautilus symbol `symbol`.
+    pub fn nautilus_to_mt5(&self, symbol: &str) -> String {
+        if let Some(mt5) = self.to_mt5.get(symbol) {
+            return mt5.clone();
+        }
+        match &self.suffix {
+            Some(suffix) => format!("{symbol}{suffix}"),
+            None => symbol.to_string(),
+        }
+    }
+
+    /// Returns the Nautilus symbol for the MT5 name `symbol`.
+    pub fn mt5_to_nautilus(&self, symbol: &str) -> String {
+        if let Some(nautilus) = self.to_nautilus.get(symbol) {
+            return nautilus.clone();
+        }
+        self.suffix
+            .as_deref()
+            .and_then(|suffix| symbol.strip_suffix(suffix))
+            .filter(|stripped| !stripped.is_empty())
+            .unwrap_or(symbol)
+            .to_string()
+    }
+}
+
 /// Represents the type of MT5 symbol.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum Mt5SymbolType {
@@ -188,4 +306,71 @@ mod tests {
 
         assert_eq!(format!("{}", symbol), "EURUSD");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_matches_group_wildcards() {
+        assert!(matches_group("EURUSD", "*"));
+        assert!(matches_group("EURUSD", "*USD*"));
+        assert!(matches_group("EURUSD", "EUR*"));
+        assert!(matches_group("EURUSD", "*USD"));
+        assert!(matches_group("EURUSD", "eurusd"));
+        assert!(matches_group("EURUSD", "E*R*D"));
+        assert!(!matches_group("EURUSD", "*JPY*"));
+        assert!(!matches_group("EURUSD", "USD*"));
+        assert!(!matches_group("EURUSD", "EURUSDX"));
+        assert!(!matches_group("EURUSD", ""));
+    }
+
+    #[test]
+    fn test_matches_group_negation() {
+        // Documented `symbols_get` example: everything except the majors' currencies
+        let group = "*,!*USD*,!*EUR*,!*JPY*,!*GBP*";
+        assert!(!matches_group("EURUSD", group));
+        assert!(!matches_group("GBPJPY", group));
+        assert!(matches_group("AUDNZD", group));
+
+        // A later inclusion re-admits names an earlier exclusion dropped
+        assert!(matches_group("EURUSD", "*,!*USD*,EUR*"));
+        assert!(!matches_group("USDJPY", "*,!*USD*,EUR*"));
+
+        // An exclusion alone matches nothing
+        assert!(!matches_group("AUDNZD", "!*USD*"));
+    }
+
+    fn mapper(entries: &[(&str, &str)], suffix: Option<&str>) -> Result<SymbolMapper, String> {
+        let map = entries
+            .iter()
+            .map(|(nautilus, mt5)| (nautilus.to_string(), mt5.to_string()))
+            .collect();
+        SymbolMapper::new(&map, suffix)
+    }
+
+    #[test]
+    fn test_symbol_mapper_mapped_symbol() {
+        let mapper = mapper(&[("XAUUSD", "GOLD")], Some(".m")).unwrap();
+
+        assert_eq!(mapper.nautilus_to_mt5("XAUUSD"), "GOLD");
+        assert_eq!(mapper.mt5_to_nautilus("GOLD"), "XAUUSD");
+    }
+
+    #[test]
+    fn test_symbol_mapper_suffix_only_symbol() {
+        let mapper = mapper(&[("XAUUSD", "GOLD")], Some(".m")).unwrap();
+
+        assert_eq!(mapper.nautilus_to_mt5("EURUSD"), "EURUSD.m");
+        assert_eq!(mapper.mt5_to_nautilus("EURUSD.m"), "EURUSD");
+
+        // Without a suffix every unmapped name is unchanged
+        let identity = SymbolMapper::default();
+        assert_eq!(identity.nautilus_to_mt5("EURUSD"), "EURUSD");
+        assert_eq!(identity.mt5_to_nautilus("EURUSD"), "EURUSD");
+    }
+
+    #[test]
+    fn test_symbol_mapper_rejects_conflicting_map() {
+        let err = mapper(&[("XAUUSD", "GOLD"), ("GOLD.spot", "GOLD")], None).unwrap_err();
+
+        assert!(err.contains("not one-to-one"), "{err}");
+        assert!(mapper(&[("XAUUSD", "")], None).is_err());
+    }
+}
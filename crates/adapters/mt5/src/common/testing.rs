@@ -16,4 +16,510 @@
 //! Testing utilities and fixtures for the MetaTrader 5 adapter.
 
 // This module contains shared fixtures and payload loaders for use across HTTP and WebSocket unit tests.
-// It keeps #[cfg(test)] helpers out of production modules and encourages reuse.
\ No newline at end of file
+// It keeps #[cfg(test)] helpers out of production modules and encourages reuse. It is only built
+// for tests, or for downstream tests with the `test-utils` feature.
+
+use std::path::PathBuf;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{
+    common::models::Mt5AccountInfo,
+    http::models::{
+        Mt5Deal, Mt5OrderCheckResult, Mt5OrderSendResult, Mt5SymbolInfo, Mt5TradePosition,
+    },
+};
+
+/// Returns the path of the MT5 fixture directory (`test_data/mt5/`).
+pub fn mt5_fixture_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_data").join("mt5")
+}
+
+/// Loads the bridge response fixture `test_data/mt5/{name}.json`.
+///
+/// Fixtures are stored exactly as the bridge returns them, i.e. wrapped in
+/// `{"result": ...}`, so they can be served as-is by the mock bridge.
+///
+/// # Panics
+///
+/// Panics if the fixture does not exist or is not valid JSON.
+pub fn load_mt5_fixture(name: &str) -> Value {
+    let path = mt5_fixture_dir().join(format!("{name}.json"));
+    let content = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Failed to read fixture {}: {e}", path.display()));
+    serde_json::from_str(&content)
+        .unwrap_or_else(|e| panic!("Invalid JSON in fixture {}: {e}", path.display()))
+}
+
+/// Returns the path of the WebSocket frame fixture directory (`test_data/mt5_ws/`).
+pub fn ws_fixture_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_data").join("mt5_ws")
+}
+
+/// Loads the bridge WebSocket frame fixture `test_data/mt5_ws/{name}.json`.
+///
+/// # Panics
+///
+/// Panics if the fixture does not exist or is not valid JSON.
+pub fn load_ws_fixture(name: &str) -> Value {
+    let path = ws_fixture_dir().join(format!("{name}.json"));
+    let content = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Failed to read fixture {}: {e}", path.display()));
+    serde_json::from_str(&content)
+        .unwrap_or_else(|e| panic!("Invalid JSON in fixture {}: {e}", path.display()))
+}
+
+/// Loads a fixture and deserializes its `result` payload into `T`.
+///
+/// # Panics
+///
+/// Panics if the fixture cannot be loaded or does not match `T`.
+pub fn load_mt5_fixture_result<T: DeserializeOwned>(name: &str) -> T {
+    let value = load_mt5_fixture(name);
+    serde_json::from_value(value["result"].clone())
+        .unwrap_or_else(|e| panic!("Fixture {name} does not match the expected model: {e}"))
+}
+
+/// Loads the `account_info` fixture.
+///
+/// # Panics
+///
+/// Panics if the fixture cannot be loaded or deserialized.
+pub fn load_account_info_fixture() -> Mt5AccountInfo {
+    load_mt5_fixture_result("account_info")
+}
+
+/// Loads the `symbol_info` fixture for a forex pair (EURUSD).
+///
+/// # Panics
+///
+/// Panics if the fixture cannot be loaded or deserialized.
+pub fn load_symbol_info_fixture() -> Mt5SymbolInfo {
+    load_mt5_fixture_result("symbol_info_eurusd")
+}
+
+/// Loads the `symbol_info` fixture for an index CFD (US30).
+///
+/// # Panics
+///
+/// Panics if the fixture cannot be loaded or deserialized.
+pub fn load_index_symbol_info_fixture() -> Mt5SymbolInfo {
+    load_mt5_fixture_result("symbol_info_us30")
+}
+
+/// Loads the `positions_get` fixture (one EURUSD buy, one US30 sell).
+///
+/// # Panics
+///
+/// Panics if the fixture cannot be loaded or deserialized.
+pub fn load_positions_fixture() -> Vec<Mt5TradePosition> {
+    load_mt5_fixture_result("positions_get")
+}
+
+/// Loads the `history_deals_round_trip` fixture: a deposit, then an EURUSD
+/// `IN` buy and `OUT` sell on the same position.
+///
+/// # Panics
+///
+/// Panics if the fixture cannot be loaded or deserialized.
+pub fn load_deals_fixture() -> Vec<Mt5Deal> {
+    load_mt5_fixture_result("history_deals_round_trip")
+}
+
+/// Loads the `history_deals_scale_in` fixture: a deposit, then two EURUSD `IN`
+/// buys and a single `OUT` sell that closes the combined position.
+///
+/// # Panics
+///
+/// Panics if the fixture cannot be loaded or deserialized.
+pub fn load_scale_in_deals_fixture() -> Vec<Mt5Deal> {
+    load_mt5_fixture_result("history_deals_scale_in")
+}
+
+/// Loads an `order_check` fixture: `order_check_sufficient` or `order_check_insufficient`.
+///
+/// # Panics
+///
+/// Panics if the fixture cannot be loaded or deserialized.
+pub fn load_order_check_fixture(name: &str) -> Mt5OrderCheckResult {
+    load_mt5_fixture_result(name)
+}
+
+/// Loads an `order_send` fixture: `order_send_success` or `order_send_rejected`.
+///
+/// # Panics
+///
+/// Panics if the fixture cannot be loaded or deserialized.
+pub fn load_order_send_fixture(name: &str) -> Mt5OrderSendResult {
+    load_mt5_fixture_result(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_fixtures_deserialize() {
+        let account = load_account_info_fixture();
+        assert_eq!(account.currency, "USD");
+
+        let eurusd = load_symbol_info_fixture();
+        assert_eq!(eurusd.name, "EURUSD");
+        assert_eq!(eurusd.digits, 5);
+
+        let us30 = load_index_symbol_info_fixture();
+        assert_eq!(us30.name, "US30");
+        assert!(!us30.spread_float);
+
+        let positions = load_positions_fixture();
+        assert_eq!(positions.len(), 2);
+        assert_eq!(positions[1].position_type, 1);
+
+        assert_eq!(load_order_send_fixture("order_send_success").retcode, 10009);
+        assert_eq!(load_order_send_fixture("order_send_rejected").retcode, 10014);
+    }
+
+    #[test]
+    fn test_every_fixture_file_is_covered() {
+        let mut names: Vec<String> = std::fs::read_dir(mt5_fixture_dir())
+            .unwrap()
+            .map(|entry| entry.unwrap().path().file_stem().unwrap().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+
+        assert_eq!(
+            names,
+            vec![
+                "account_info",
+                "copy_rates_from_pos_eurusd_m1",
+                "copy_rates_range_eurusd_m1",
+                "copy_rates_range_eurusd_m1_unsorted",
+                "copy_rates_range_us30_m1",
+                "copy_ticks_range_eurusd_trades",
+                "history_deals_audit",
+                "history_deals_round_trip",
+                "history_deals_scale_in",
+                "history_orders_audit",
+                "order_check_insufficient",
+                "order_check_sufficient",
+                "order_send_rejected",
+                "order_send_success",
+                "orders_get",
+                "positions_get",
+                "symbol_info_eurusd",
+                "symbol_info_us30",
+                "terminal_info_small_maxbars",
+                "trade_transactions_cancel",
+                "trade_transactions_fill",
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+pub mod mock_bridge {
+    //! In-process mock of the MT5 REST bridge for unit tests.
+    //!
+    //! Register a response per endpoint, start the server, then point a client at
+    //! `http://{addr}`:
+    //!
+    //! ```ignore
+    //! let bridge = MockMt5Bridge::new();
+    //! bridge.respond("version", json!({"result": [500, 4000, "01 Jan 2024"]}));
+    //! let (addr, _handle) = bridge.start().await;
+    //! let client = Mt5HttpClient::new(Mt5Config::with_base_url(format!("http://{addr}")), format!("http://{addr}"))?;
+    //! client.version().await?;
+    //! assert_eq!(bridge.requests_for("version").len(), 1);
+    //! ```
+    //!
+    //! Endpoints are named without the `/api/` prefix. Unregistered endpoints
+    //! answer with HTTP 404 and an `{"error": ...}` body.
+
+    use std::{
+        collections::{HashMap, VecDeque},
+        net::SocketAddr,
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use axum::{
+        body::Bytes,
+        extract::{Path, State},
+        http::{HeaderMap, StatusCode},
+        response::{IntoResponse, Response},
+        routing::post,
+        Json, Router,
+    };
+    use serde_json::{json, Value};
+    use tokio::task::JoinHandle;
+
+    use crate::{config::execution_client::Mt5ExecutionClientConfig, execution_client::Mt5ExecutionClient};
+
+    /// Canned behaviour for a single endpoint.
+    #[derive(Debug, Clone)]
+    pub enum MockResponse {
+        /// Respond with HTTP 200 and this JSON body.
+        Json(Value),
+        /// Respond with the given HTTP status and an `{"error": message}` body.
+        Error(u16, String),
+        /// Sleep for the duration before responding with `{"result": null}`.
+        Delay(Duration),
+        /// Sleep for the duration before responding with HTTP 200 and this JSON body.
+        Delayed(Duration, Value),
+        /// Respond with HTTP 200 and this JSON body compressed with the
+        /// `gzip` or `deflate` content encoding.
+        Compressed(String, Value),
+    }
+
+    #[derive(Debug, Default)]
+    struct MockState {
+        responses: HashMap<String, MockResponse>,
+        /// One-shot responses served, in order, before the standing one.
+        queued: HashMap<String, VecDeque<MockResponse>>,
+        requests: Vec<(String, Value)>,
+        /// `X-Request-Id` of each request that carried one, in arrival order.
+        request_ids: Vec<u64>,
+        /// `Accept-Encoding` header of each request, empty when absent.
+        accept_encodings: Vec<String>,
+        in_flight: usize,
+        max_in_flight: usize,
+    }
+
+    /// Configurable mock MT5 bridge recording every request it receives.
+    #[derive(Debug, Clone, Default)]
+    pub struct MockMt5Bridge {
+        state: Arc<Mutex<MockState>>,
+    }
+
+    impl MockMt5Bridge {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Sets the JSON body returned for `endpoint`.
+        pub fn respond(&self, endpoint: &str, body: Value) -> &Self {
+            self.set(endpoint, MockResponse::Json(body))
+        }
+
+        /// Queues a JSON body returned for the next request to `endpoint` only.
+        ///
+        /// Queued bodies are served in order, after which `endpoint` falls back
+        /// to the response set with [`Self::respond`].
+        pub fn respond_once(&self, endpoint: &str, body: Value) -> &Self {
+            self.state
+                .lock()
+                .unwrap()
+                .queued
+                .entry(endpoint.to_string())
+                .or_default()
+                .push_back(MockResponse::Json(body));
+            self
+        }
+
+        /// Sets the JSON body returned for `endpoint`, compressed with `encoding`.
+        pub fn respond_compressed(&self, endpoint: &str, body: Value, encoding: &str) -> &Self {
+            self.set(endpoint, MockResponse::Compressed(encoding.to_string(), body))
+        }
+
+        /// Makes `endpoint` fail with the given HTTP status.
+        pub fn fail(&self, endpoint: &str, status: u16, message: &str) -> &Self {
+            self.set(endpoint, MockResponse::Error(status, message.to_string()))
+        }
+
+        /// Makes `endpoint` stall for `delay`, for exercising client timeouts.
+        pub fn delay(&self, endpoint: &str, delay: Duration) -> &Self {
+            self.set(endpoint, MockResponse::Delay(delay))
+        }
+
+        /// Sets the JSON body returned for `endpoint` once `delay` has passed.
+        pub fn respond_delayed(&self, endpoint: &str, body: Value, delay: Duration) -> &Self {
+            self.set(endpoint, MockResponse::Delayed(delay, body))
+        }
+
+        fn set(&self, endpoint: &str, response: MockResponse) -> &Self {
+            self.state
+                .lock()
+                .unwrap()
+                .responses
+                .insert(endpoint.to_string(), response);
+            self
+        }
+
+        /// Returns every `(endpoint, body)` pair received so far, in order.
+        pub fn requests(&self) -> Vec<(String, Value)> {
+            self.state.lock().unwrap().requests.clone()
+        }
+
+        /// Returns the request bodies received for `endpoint`, in order.
+        pub fn requests_for(&self, endpoint: &str) -> Vec<Value> {
+            self.requests()
+                .into_iter()
+                .filter(|(e, _)| e == endpoint)
+                .map(|(_, body)| body)
+                .collect()
+        }
+
+        /// Returns the `X-Request-Id` header of every request that carried one, in arrival order.
+        pub fn request_ids(&self) -> Vec<u64> {
+            self.state.lock().unwrap().request_ids.clone()
+        }
+
+        /// Returns the `Accept-Encoding` header of every request, in arrival order.
+        pub fn accept_encodings(&self) -> Vec<String> {
+            self.state.lock().unwrap().accept_encodings.clone()
+        }
+
+        /// Returns the most requests that were being handled at the same time.
+        pub fn max_in_flight(&self) -> usize {
+            self.state.lock().unwrap().max_in_flight
+        }
+
+        /// Binds to an ephemeral local port and serves until the handle is aborted.
+        pub async fn start(&self) -> (SocketAddr, JoinHandle<()>) {
+            let router = Router::new()
+                .route("/api/{endpoint}", post(handle_request))
+                .with_state(self.clone());
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+            let handle = tokio::spawn(async move {
+                axum::serve(listener, router).await.unwrap();
+            });
+            (addr, handle)
+        }
+    }
+
+    /// Starts `bridge` and builds an execution client pointed at it.
+    ///
+    /// `config.base_url` is replaced by the bridge address; every other field is kept.
+    pub async fn mock_exec_client(
+        bridge: &MockMt5Bridge,
+        config: Mt5ExecutionClientConfig,
+    ) -> (Mt5ExecutionClient, JoinHandle<()>) {
+        let (addr, handle) = bridge.start().await;
+        let client = Mt5ExecutionClient::new(Mt5ExecutionClientConfig {
+            base_url: format!("http://{addr}"),
+            ..config
+        })
+        .unwrap();
+        (client, handle)
+    }
+
+    async fn handle_request(
+        State(bridge): State<MockMt5Bridge>,
+        Path(endpoint): Path<String>,
+        headers: HeaderMap,
+        body: Bytes,
+    ) -> Response {
+        let body = serde_json::from_slice(&body).unwrap_or(Value::Null);
+        let request_id = headers
+            .get("x-request-id")
+            .and_then(|value| value.to_str().ok()?.parse().ok());
+        let response = {
+            let mut state = bridge.state.lock().unwrap();
+            state.requests.push((endpoint.clone(), body));
+            state.request_ids.extend(request_id);
+            state.accept_encodings.push(
+                headers
+                    .get("accept-encoding")
+                    .and_then(|value| value.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string(),
+            );
+            state.in_flight += 1;
+            state.max_in_flight = state.max_in_flight.max(state.in_flight);
+            match state.queued.get_mut(&endpoint).and_then(VecDeque::pop_front) {
+                Some(response) => Some(response),
+                None => state.responses.get(&endpoint).cloned(),
+            }
+        };
+
+        let response = match response {
+            Some(MockResponse::Json(value)) => Json(value).into_response(),
+            Some(MockResponse::Error(status, message)) => (
+                StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+                Json(json!({ "error": message })),
+            )
+                .into_response(),
+            Some(MockResponse::Delay(delay)) => {
+                tokio::time::sleep(delay).await;
+                Json(json!({ "result": null })).into_response()
+            }
+            Some(MockResponse::Delayed(delay, value)) => {
+                tokio::time::sleep(delay).await;
+                Json(value).into_response()
+            }
+            Some(MockResponse::Compressed(encoding, value)) => {
+                let body = compress(&encoding, &serde_json::to_vec(&value).unwrap());
+                (
+                    [("content-type", "application/json"), ("content-encoding", encoding.as_str())],
+                    body,
+                )
+                    .into_response()
+            }
+            None => (
+                StatusCode::NOT_FOUND,
+                Json(json!({ "error": format!("no mock for {endpoint}") })),
+            )
+                .into_response(),
+        };
+
+        bridge.state.lock().unwrap().in_flight -= 1;
+        response
+    }
+
+    fn compress(encoding: &str, data: &[u8]) -> Vec<u8> {
+        use std::io::Write;
+
+        let level = flate2::Compression::default();
+        match encoding {
+            "gzip" => {
+                let mut encoder = flate2::write::GzEncoder::new(Vec::new(), level);
+                encoder.write_all(data).unwrap();
+                encoder.finish().unwrap()
+            }
+            "deflate" => {
+                let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), level);
+                encoder.write_all(data).unwrap();
+                encoder.finish().unwrap()
+            }
+            other => panic!("unsupported mock encoding {other}"),
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::{config::Mt5Config, http::Mt5HttpClient, http::error::Mt5HttpError};
+
+        fn client_for(addr: SocketAddr) -> Mt5HttpClient {
+            let base_url = format!("http://{addr}");
+            Mt5HttpClient::new(Mt5Config::with_base_url(base_url.clone()), base_url).unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_serves_configured_response_and_records_body() {
+            let bridge = MockMt5Bridge::new();
+            bridge.respond("symbol_info", json!({"result": {"name": "EURUSD"}}));
+            let (addr, handle) = bridge.start().await;
+
+            let response = client_for(addr).symbol_info(&json!(["EURUSD"])).await.unwrap();
+
+            assert_eq!(response["result"]["name"], "EURUSD");
+            assert_eq!(bridge.requests_for("symbol_info"), vec![json!(["EURUSD"])]);
+            handle.abort();
+        }
+
+        #[tokio::test]
+        async fn test_configured_error_maps_to_http_error() {
+            let bridge = MockMt5Bridge::new();
+            bridge.fail("order_send", 500, "terminal busy");
+            let (addr, handle) = bridge.start().await;
+
+            let result = client_for(addr).order_send(&json!({})).await;
+
+            assert!(matches!(result, Err(Mt5HttpError::ServerError(_))));
+            handle.abort();
+        }
+    }
+}
@@ -26,6 +26,7 @@ use pyo3::prelude::*;
 pub mod instrument_provider;
 pub mod data_client;
 pub mod execution_client;
+pub mod websocket;
 
 /// Main configuration for the MT5 adapter.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -38,6 +39,47 @@ pub struct Mt5Config {
     /// Optional proxy URL.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub proxy: Option<String>,
+    /// Maximum retries for a rate-limited (HTTP 429) request.
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    /// Initial backoff in milliseconds when no `Retry-After` header is sent.
+    #[serde(default = "default_retry_delay_ms")]
+    pub retry_delay_ms: u64,
+    /// Upper bound in milliseconds for any single retry delay.
+    #[serde(default = "default_retry_delay_max_ms")]
+    pub retry_delay_max_ms: u64,
+    /// Whether requests to the bridge are serialized or may overlap.
+    #[serde(default)]
+    pub bridge_access: crate::common::enums::BridgeAccessMode,
+    /// Oldest terminal build accepted on connect; `None` skips the check.
+    #[serde(default)]
+    pub min_build: Option<u32>,
+    /// Advertise `Accept-Encoding: gzip, deflate` so large payloads arrive compressed.
+    #[serde(default = "default_accept_compression")]
+    pub accept_compression: bool,
+    /// Fail list responses containing a malformed row instead of skipping the row.
+    #[serde(default)]
+    pub strict_parsing: bool,
+    /// Log a warning when a list endpoint returns no rows, noting whether the
+    /// bridge sent `[]` or `null`.
+    #[serde(default)]
+    pub warn_on_empty: bool,
+}
+
+fn default_max_retries() -> u32 {
+    3
+}
+
+fn default_retry_delay_ms() -> u64 {
+    500
+}
+
+fn default_retry_delay_max_ms() -> u64 {
+    10_000
+}
+
+fn default_accept_compression() -> bool {
+    true
 }
 
 impl Default for Mt5Config {
@@ -46,6 +88,14 @@ impl Default for Mt5Config {
             base_url: "http://localhost:5000".to_string(),
             http_timeout: 30,
             proxy: None,
+            max_retries: default_max_retries(),
+            retry_delay_ms: default_retry_delay_ms(),
+            retry_delay_max_ms: default_retry_delay_max_ms(),
+            bridge_access: crate::common::enums::BridgeAccessMode::default(),
+            min_build: None,
+            accept_compression: default_accept_compression(),
+            strict_parsing: false,
+            warn_on_empty: false,
         }
     }
 }
@@ -77,6 +127,7 @@ impl Mt5Config {
             base_url: base_url.unwrap_or_else(|| "http://localhost:5000".to_string()),
             http_timeout: http_timeout.unwrap_or(30),
             proxy,
+            ..Self::default()
         }
     }
 }
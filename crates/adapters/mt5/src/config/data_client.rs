@@ -17,6 +17,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::data_client::DataClientError;
+
 #[cfg(feature = "python-bindings")]
 use pyo3::prelude::*;
 
@@ -31,6 +33,123 @@ pub struct Mt5DataClientConfig {
     pub credential: crate::common::credential::Mt5Credential,
     /// Enable client-side logging
     pub enable_logging: bool,
+    /// Window length in seconds for splitting tick range requests
+    #[serde(default = "default_tick_chunk_secs")]
+    pub tick_chunk_secs: u64,
+    /// Retries for a failed tick window before the request is abandoned
+    #[serde(default = "default_tick_chunk_retries")]
+    pub tick_chunk_retries: u32,
+    /// Initial backoff in milliseconds between retries, doubling per attempt
+    #[serde(default = "default_retry_delay_ms")]
+    pub retry_delay_ms: u64,
+    /// Upper bound in milliseconds for any single retry delay
+    #[serde(default = "default_retry_delay_max_ms")]
+    pub retry_delay_max_ms: u64,
+    /// Interval in milliseconds between polls of a tick subscription
+    #[serde(default = "default_tick_poll_interval_ms")]
+    pub tick_poll_interval_ms: u64,
+    /// Maximum ticks buffered per subscription before backpressure applies
+    #[serde(default = "default_tick_buffer_capacity")]
+    pub tick_buffer_capacity: usize,
+    /// Behaviour when a subscription's buffer is full
+    #[serde(default)]
+    pub tick_backpressure: crate::common::enums::BackpressurePolicy,
+    /// Answer order book requests with a one-level book from the latest tick
+    /// when the broker does not offer market depth for the symbol
+    #[serde(default)]
+    pub fallback_to_top_of_book: bool,
+    /// Oldest terminal build accepted on connect; `None` skips the check
+    #[serde(default)]
+    pub min_build: Option<u32>,
+    /// Interval in milliseconds between `terminal_info` connectivity checks while
+    /// connected; `None` disables the monitor
+    #[serde(default = "default_terminal_check_interval_ms")]
+    pub terminal_check_interval_ms: Option<u64>,
+    /// Idle time in milliseconds after which a `version` ping keeps the bridge
+    /// session warm; `None` disables the keep-alive
+    #[serde(default)]
+    pub keep_alive_interval_ms: Option<u64>,
+    /// Rate volume used for `Bar.volume`
+    #[serde(default)]
+    pub volume_source: crate::common::enums::VolumeSource,
+    /// Size quoted on both sides of a `QuoteTick` built from an MT5 tick
+    #[serde(default)]
+    pub quote_size_source: crate::common::enums::QuoteSizeSource,
+    /// Bar ranges kept by the in-memory `request_bars` cache; 0 disables caching
+    #[serde(default)]
+    pub bar_cache_max_entries: usize,
+    /// Seconds a cached bar range stays valid; `None` keeps it until evicted
+    #[serde(default)]
+    pub bar_cache_ttl_secs: Option<u64>,
+    /// Instruments loaded into the cache by `connect`, which fails if any
+    /// cannot be loaded
+    #[serde(default)]
+    pub warmup_instruments: Vec<nautilus_model::identifiers::InstrumentId>,
+    /// Ticks whose spread exceeds this many points are dropped from tick
+    /// subscriptions; `None` disables the filter
+    #[serde(default)]
+    pub max_spread_points: Option<f64>,
+    /// Per-symbol overrides of `max_spread_points`
+    #[serde(default)]
+    pub max_spread_points_by_symbol: std::collections::HashMap<String, f64>,
+    /// Ask the bridge for gzip or deflate compressed responses
+    #[serde(default = "default_accept_compression")]
+    pub accept_compression: bool,
+    /// Nautilus symbol to MT5 symbol names, consulted before `symbol_suffix`;
+    /// must be one-to-one
+    #[serde(default)]
+    pub symbol_map: std::collections::HashMap<String, String>,
+    /// Broker suffix appended to Nautilus symbols to form MT5 names
+    #[serde(default)]
+    pub symbol_suffix: Option<String>,
+    /// Fail a response containing a malformed bar or tick row instead of
+    /// skipping the row with a warning
+    #[serde(default)]
+    pub strict_parsing: bool,
+    /// Log a warning when a bar, tick or symbol request returns no rows
+    /// (`[]` or `null`); both still yield an empty result
+    #[serde(default)]
+    pub warn_on_empty: bool,
+    /// MT5 symbols selected in Market Watch by `connect`, so their ticks and
+    /// bars are available immediately; failed selections do not fail `connect`
+    #[serde(default)]
+    pub watchlist: Vec<String>,
+    /// Offset in seconds of the trade server's clock from UTC (e.g. 7200 for
+    /// UTC+2), applied when the Python bindings convert `datetime` inputs
+    #[serde(default)]
+    pub server_utc_offset_secs: i32,
+}
+
+fn default_tick_chunk_secs() -> u64 {
+    3600
+}
+
+fn default_tick_chunk_retries() -> u32 {
+    2
+}
+
+fn default_retry_delay_ms() -> u64 {
+    500
+}
+
+fn default_retry_delay_max_ms() -> u64 {
+    10_000
+}
+
+fn default_tick_poll_interval_ms() -> u64 {
+    100
+}
+
+fn default_tick_buffer_capacity() -> usize {
+    100_000
+}
+
+fn default_terminal_check_interval_ms() -> Option<u64> {
+    Some(5_000)
+}
+
+fn default_accept_compression() -> bool {
+    true
 }
 
 impl Default for Mt5DataClientConfig {
@@ -45,11 +164,85 @@ impl Default for Mt5DataClientConfig {
                 .build()
                 .unwrap(),
             enable_logging: true,
+            tick_chunk_secs: default_tick_chunk_secs(),
+            tick_chunk_retries: default_tick_chunk_retries(),
+            retry_delay_ms: default_retry_delay_ms(),
+            retry_delay_max_ms: default_retry_delay_max_ms(),
+            tick_poll_interval_ms: default_tick_poll_interval_ms(),
+            tick_buffer_capacity: default_tick_buffer_capacity(),
+            tick_backpressure: crate::common::enums::BackpressurePolicy::default(),
+            fallback_to_top_of_book: false,
+            min_build: None,
+            terminal_check_interval_ms: default_terminal_check_interval_ms(),
+            keep_alive_interval_ms: None,
+            volume_source: crate::common::enums::VolumeSource::default(),
+            quote_size_source: crate::common::enums::QuoteSizeSource::default(),
+            bar_cache_max_entries: 0,
+            bar_cache_ttl_secs: None,
+            warmup_instruments: Vec::new(),
+            max_spread_points: None,
+            max_spread_points_by_symbol: std::collections::HashMap::new(),
+            accept_compression: default_accept_compression(),
+            symbol_map: std::collections::HashMap::new(),
+            symbol_suffix: None,
+            strict_parsing: false,
+            warn_on_empty: false,
+            watchlist: Vec::new(),
+            server_utc_offset_secs: 0,
         }
     }
 }
 
 impl Mt5DataClientConfig {
+    /// Checks the settings needed to reach the bridge.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DataClientError::ConnectionError` if `base_url` is empty or not an
+    /// absolute http(s) URL, or if `http_timeout` is zero, and
+    /// `DataClientError::ParseError` if `symbol_map` is not one-to-one.
+    pub fn validate(&self) -> Result<(), DataClientError> {
+        let invalid = |message: String| Err(DataClientError::ConnectionError(message));
+
+        if self.base_url.trim().is_empty() {
+            return invalid("base_url must not be empty".to_string());
+        }
+        match reqwest::Url::parse(&self.base_url) {
+            Ok(url) if matches!(url.scheme(), "http" | "https") && url.has_host() => {}
+            Ok(url) => {
+                return invalid(format!(
+                    "base_url '{}' must be an http(s) URL with a host, got scheme '{}'",
+                    self.base_url,
+                    url.scheme()
+                ))
+            }
+            Err(e) => return invalid(format!("base_url '{}' is malformed: {e}", self.base_url)),
+        }
+        if self.http_timeout == 0 {
+            return invalid("http_timeout must be at least 1 second".to_string());
+        }
+        self.symbol_mapper()?;
+        Ok(())
+    }
+
+    /// Builds the symbol mapper from `symbol_map` and `symbol_suffix`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DataClientError::ParseError` if `symbol_map` is not one-to-one.
+    pub fn symbol_mapper(&self) -> Result<crate::common::symbol::SymbolMapper, DataClientError> {
+        crate::common::symbol::SymbolMapper::new(&self.symbol_map, self.symbol_suffix.as_deref())
+            .map_err(DataClientError::ParseError)
+    }
+
+    /// Returns the spread limit in points for `symbol`, if any.
+    pub fn max_spread_points_for(&self, symbol: &str) -> Option<f64> {
+        self.max_spread_points_by_symbol
+            .get(symbol)
+            .copied()
+            .or(self.max_spread_points)
+    }
+
     pub fn with_credentials(login: String, password: String, server: String) -> Self {
         let mut config = Self::default();
         config.credential = crate::common::credential::Mt5Credential::builder()
@@ -74,4 +267,50 @@ impl Mt5DataClientConfig {
         config.enable_logging = enable_logging;
         config
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(base_url: &str, http_timeout: u64) -> Mt5DataClientConfig {
+        Mt5DataClientConfig {
+            base_url: base_url.to_string(),
+            http_timeout,
+            ..Default::default()
+        }
+    }
+
+    fn error_message(config: &Mt5DataClientConfig) -> String {
+        match config.validate() {
+            Err(DataClientError::ConnectionError(message)) => message,
+            other => panic!("expected ConnectionError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_validate_accepts_default_and_https() {
+        assert!(Mt5DataClientConfig::default().validate().is_ok());
+        assert!(config("https://bridge.example.com:8443/mt5", 5).validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_base_url() {
+        assert_eq!(error_message(&config("  ", 30)), "base_url must not be empty");
+    }
+
+    #[test]
+    fn test_validate_rejects_malformed_base_url() {
+        assert!(error_message(&config("localhost:5000", 30)).contains("http(s)"));
+        assert!(error_message(&config("http://", 30)).contains("malformed"));
+        assert!(error_message(&config("not a url", 30)).contains("malformed"));
+    }
+
+    #[test]
+    fn test_validate_rejects_zero_timeout() {
+        assert_eq!(
+            error_message(&config("http://localhost:5000", 0)),
+            "http_timeout must be at least 1 second"
+        );
+    }
+}
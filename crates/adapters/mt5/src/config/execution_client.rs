@@ -17,6 +17,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::execution_client::ExecutionClientError;
+
 #[cfg(feature = "python-bindings")]
 use pyo3::prelude::*;
 
@@ -35,6 +37,76 @@ pub struct Mt5ExecutionClientConfig {
     pub enable_logging: bool,
     /// Simulation mode for backtesting
     pub simulate_orders: bool,
+    /// Rounding applied to order volumes to match the symbol's `volume_step`
+    #[serde(default)]
+    pub volume_rounding: crate::common::enums::VolumeRounding,
+    /// Clamp out-of-range volumes to `[volume_min, volume_max]` instead of rejecting
+    #[serde(default = "default_clamp_volume")]
+    pub clamp_volume: bool,
+    /// Run `order_check` before `order_send` and abort if free margin would go negative.
+    /// Costs an extra bridge round-trip per order, so leave off for latency-sensitive strategies
+    #[serde(default)]
+    pub precheck_margin: bool,
+    /// Oldest terminal build accepted on connect; `None` skips the check
+    #[serde(default)]
+    pub min_build: Option<u32>,
+    /// Interval in milliseconds between `terminal_info` connectivity checks while
+    /// connected; `None` disables the monitor
+    #[serde(default = "default_terminal_check_interval_ms")]
+    pub terminal_check_interval_ms: Option<u64>,
+    /// Idle time in milliseconds after which a `version` ping keeps the bridge
+    /// session warm; `None` disables the keep-alive
+    #[serde(default)]
+    pub keep_alive_interval_ms: Option<u64>,
+    /// Select a symbol missing from Market Watch before submitting an order for it,
+    /// instead of rejecting the order
+    #[serde(default = "default_auto_select_symbols")]
+    pub auto_select_symbols: bool,
+    /// Fail a position, order or deal listing containing a malformed row instead
+    /// of skipping the row with a warning
+    #[serde(default)]
+    pub strict_parsing: bool,
+    /// Refuse to connect unless the account is of this kind, e.g. `Demo` so a
+    /// strategy meant for a demo account never trades a real one
+    #[serde(default)]
+    pub require_environment: Option<crate::common::enums::Mt5Environment>,
+    /// Number of per-lot `order_calc_margin` results kept by `order_margin`; 0 disables the cache
+    #[serde(default = "default_margin_cache_max_entries")]
+    pub margin_cache_max_entries: usize,
+    /// Milliseconds a cached per-lot margin stays valid
+    #[serde(default = "default_margin_cache_ttl_ms")]
+    pub margin_cache_ttl_ms: u64,
+    /// Nautilus symbol to MT5 symbol names, consulted before `symbol_suffix`;
+    /// must be one-to-one
+    #[serde(default)]
+    pub symbol_map: std::collections::HashMap<String, String>,
+    /// Broker suffix appended to Nautilus symbols to form MT5 names
+    #[serde(default)]
+    pub symbol_suffix: Option<String>,
+    /// Offset in seconds of the trade server's clock from UTC (e.g. 7200 for
+    /// UTC+2), applied to the `expiration` of GTD orders
+    #[serde(default)]
+    pub server_utc_offset_secs: i32,
+}
+
+fn default_clamp_volume() -> bool {
+    true
+}
+
+fn default_terminal_check_interval_ms() -> Option<u64> {
+    Some(5_000)
+}
+
+fn default_auto_select_symbols() -> bool {
+    true
+}
+
+fn default_margin_cache_max_entries() -> usize {
+    256
+}
+
+fn default_margin_cache_ttl_ms() -> u64 {
+    60_000
 }
 
 impl Default for Mt5ExecutionClientConfig {
@@ -51,11 +123,35 @@ impl Default for Mt5ExecutionClientConfig {
             max_concurrent_orders: 50,
             enable_logging: true,
             simulate_orders: true,
+            volume_rounding: crate::common::enums::VolumeRounding::default(),
+            clamp_volume: default_clamp_volume(),
+            precheck_margin: false,
+            min_build: None,
+            terminal_check_interval_ms: default_terminal_check_interval_ms(),
+            keep_alive_interval_ms: None,
+            auto_select_symbols: default_auto_select_symbols(),
+            strict_parsing: false,
+            require_environment: None,
+            margin_cache_max_entries: default_margin_cache_max_entries(),
+            margin_cache_ttl_ms: default_margin_cache_ttl_ms(),
+            symbol_map: std::collections::HashMap::new(),
+            symbol_suffix: None,
+            server_utc_offset_secs: 0,
         }
     }
 }
 
 impl Mt5ExecutionClientConfig {
+    /// Builds the symbol mapper from `symbol_map` and `symbol_suffix`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ExecutionClientError::ParseError` if `symbol_map` is not one-to-one.
+    pub fn symbol_mapper(&self) -> Result<crate::common::symbol::SymbolMapper, ExecutionClientError> {
+        crate::common::symbol::SymbolMapper::new(&self.symbol_map, self.symbol_suffix.as_deref())
+            .map_err(ExecutionClientError::ParseError)
+    }
+
     pub fn with_credentials(login: String, password: String, server: String) -> Self {
         let mut config = Self::default();
         config.credential = crate::common::credential::Mt5Credential::builder()
@@ -15,8 +15,12 @@
 
 //! Configuration for MT5 Instrument Provider.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
+use crate::common::symbol::SymbolMapper;
+
 #[cfg(feature = "python-bindings")]
 use pyo3::prelude::*;
 
@@ -33,6 +37,44 @@ pub struct Mt5InstrumentProviderConfig {
     pub auto_discover_instruments: bool,
     pub cache_expiry: u32,
     pub enable_logging: bool,
+    /// MT5 `group` pattern limiting the discovered symbols, e.g. `"*,!*RUB*"`.
+    #[serde(default)]
+    pub symbol_group: Option<String>,
+    /// Nautilus symbol to MT5 symbol names, consulted before `symbol_suffix`,
+    /// e.g. `XAUUSD` to `GOLD`. Must be one-to-one.
+    #[serde(default)]
+    pub symbol_map: HashMap<String, String>,
+    /// Broker suffix appended to Nautilus symbols to form MT5 names, e.g. `".m"`.
+    #[serde(default)]
+    pub symbol_suffix: Option<String>,
+    /// Symbols converted and cached per step of a load, between progress reports.
+    #[serde(default = "default_load_chunk_size")]
+    pub load_chunk_size: usize,
+    /// Refuse to load when `symbols_total` exceeds this; `None` disables the guard.
+    #[serde(default)]
+    pub max_symbols: Option<usize>,
+    /// Timeout in seconds for the symbol listing, which is large on ECN accounts.
+    #[serde(default = "default_market_data_timeout_secs")]
+    pub market_data_timeout_secs: u64,
+    /// Retries for a symbol listing that timed out or failed with a retryable error.
+    #[serde(default = "default_market_data_retries")]
+    pub market_data_retries: u32,
+    /// Fail a load in which two MT5 symbols map to the same Nautilus instrument
+    /// id, e.g. `EURUSD.pro` and `EURUSD.raw`, instead of keeping one with a warning.
+    #[serde(default)]
+    pub strict_instrument_ids: bool,
+}
+
+fn default_load_chunk_size() -> usize {
+    500
+}
+
+fn default_market_data_timeout_secs() -> u64 {
+    120
+}
+
+fn default_market_data_retries() -> u32 {
+    2
 }
 
 impl Default for Mt5InstrumentProviderConfig {
@@ -71,10 +113,29 @@ impl Default for Mt5InstrumentProviderConfig {
             auto_discover_instruments: true,
             cache_expiry: 300, // 5 minutes
             enable_logging: true,
+            symbol_group: None,
+            symbol_map: HashMap::new(),
+            symbol_suffix: None,
+            load_chunk_size: default_load_chunk_size(),
+            max_symbols: None,
+            market_data_timeout_secs: default_market_data_timeout_secs(),
+            market_data_retries: default_market_data_retries(),
+            strict_instrument_ids: false,
         }
     }
 }
 
+impl Mt5InstrumentProviderConfig {
+    /// Builds the symbol mapper from `symbol_map` and `symbol_suffix`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `symbol_map` is not one-to-one.
+    pub fn symbol_mapper(&self) -> Result<SymbolMapper, String> {
+        SymbolMapper::new(&self.symbol_map, self.symbol_suffix.as_deref())
+    }
+}
+
 #[cfg(feature = "python-bindings")]
 #[pymethods]
 impl Mt5InstrumentProviderConfig {
@@ -0,0 +1,103 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Configuration for the MT5 bridge WebSocket client.
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "python-bindings")]
+use pyo3::prelude::*;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "python-bindings", pyclass(get_all, set_all))]
+pub struct Mt5WebSocketConfig {
+    /// WebSocket URL of the bridge event stream (e.g. "ws://localhost:5000/ws")
+    pub url: String,
+    /// Delay in milliseconds before the first connection retry
+    #[serde(default = "default_reconnect_delay_initial_ms")]
+    pub reconnect_delay_initial_ms: u64,
+    /// Upper bound in milliseconds for any single retry delay
+    #[serde(default = "default_reconnect_delay_max_ms")]
+    pub reconnect_delay_max_ms: u64,
+    /// Factor the retry delay grows by after each failed attempt
+    #[serde(default = "default_reconnect_backoff_factor")]
+    pub reconnect_backoff_factor: f64,
+    /// Maximum random jitter in milliseconds added to each retry delay
+    #[serde(default = "default_reconnect_jitter_ms")]
+    pub reconnect_jitter_ms: u64,
+    /// Connection attempts, including the first, before `connect` gives up
+    #[serde(default = "default_reconnect_max_attempts")]
+    pub reconnect_max_attempts: u32,
+    /// Rate volume used for `Bar.volume` of streamed bars
+    #[serde(default)]
+    pub volume_source: crate::common::enums::VolumeSource,
+    /// Size quoted on both sides of a streamed `QuoteTick`
+    #[serde(default)]
+    pub quote_size_source: crate::common::enums::QuoteSizeSource,
+    /// Ticks whose spread exceeds this many points are not emitted as quotes;
+    /// `None` disables the filter
+    #[serde(default)]
+    pub max_spread_points: Option<f64>,
+    /// Per-symbol overrides of `max_spread_points`, keyed by MT5 symbol
+    #[serde(default)]
+    pub max_spread_points_by_symbol: std::collections::HashMap<String, f64>,
+}
+
+fn default_reconnect_delay_initial_ms() -> u64 {
+    500
+}
+
+fn default_reconnect_delay_max_ms() -> u64 {
+    10_000
+}
+
+fn default_reconnect_backoff_factor() -> f64 {
+    2.0
+}
+
+fn default_reconnect_jitter_ms() -> u64 {
+    100
+}
+
+fn default_reconnect_max_attempts() -> u32 {
+    5
+}
+
+impl Default for Mt5WebSocketConfig {
+    fn default() -> Self {
+        Self {
+            url: "ws://localhost:5000/ws".to_string(),
+            reconnect_delay_initial_ms: default_reconnect_delay_initial_ms(),
+            reconnect_delay_max_ms: default_reconnect_delay_max_ms(),
+            reconnect_backoff_factor: default_reconnect_backoff_factor(),
+            reconnect_jitter_ms: default_reconnect_jitter_ms(),
+            reconnect_max_attempts: default_reconnect_max_attempts(),
+            volume_source: crate::common::enums::VolumeSource::default(),
+            quote_size_source: crate::common::enums::QuoteSizeSource::default(),
+            max_spread_points: None,
+            max_spread_points_by_symbol: std::collections::HashMap::new(),
+        }
+    }
+}
+
+impl Mt5WebSocketConfig {
+    /// Returns the spread limit in points for `symbol`, if any.
+    pub fn max_spread_points_for(&self, symbol: &str) -> Option<f64> {
+        self.max_spread_points_by_symbol
+            .get(symbol)
+            .copied()
+            .or(self.max_spread_points)
+    }
+}
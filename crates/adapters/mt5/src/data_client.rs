@@ -18,11 +18,31 @@
 //! This module implements the data client for the MetaTrader 5 adapter,
 //! providing market data functionality including subscriptions and requests.
 
-use crate::config::{Mt5Config, Mt5DataClientConfig};
-use crate::http::client::Mt5HttpClient;
+use crate::bar_cache::{BarCache, BarCacheKey, InMemoryBarCache};
+use crate::common::channel::BoundedChannel;
+use crate::common::connection::{
+    establish_session, reports_connected, ConnectionStateTracker, ConnectionTransition,
+    ConnectivityMonitor, KeepAlive,
+};
+use crate::common::enums::{ConnectionState, QuoteSizeSource, TickKind, VolumeSource};
+use crate::common::parse::quote_size;
+use crate::common::runtime::spawn_cancellable;
+use crate::common::symbol::SymbolMapper;
+use crate::config::{Mt5Config, Mt5DataClientConfig, Mt5InstrumentProviderConfig};
+use crate::common::error::{Mt5Error, Mt5Result};
+use crate::http::client::{unwrap_result, Mt5HttpClient};
 use crate::http::error::Mt5HttpError as HttpClientError;
-use std::sync::Arc;
+use crate::http::models::{Mt5BookEntry, Mt5Rate, BOOK_TYPE_BUY, BOOK_TYPE_SELL};
+use crate::instrument_provider::Mt5InstrumentProvider;
+use dashmap::DashMap;
+use futures_util::StreamExt;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
 use thiserror::Error;
+use tokio_util::sync::CancellationToken;
 
 #[derive(Debug, Error)]
 pub enum DataClientError {
@@ -32,6 +52,31 @@ pub enum DataClientError {
     HttpClient(#[from] HttpClientError),
     #[error("Parse error: {0}")]
     ParseError(String),
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+    #[error("MT5 bridge unreachable ({0}); check the bridge is running and `base_url` points at it")]
+    BridgeUnreachable(String),
+    #[error("MT5 terminal not running ({0}); start the terminal on the bridge host and check its install path")]
+    TerminalNotRunning(String),
+    #[error("MT5 login rejected ({0}); check the login, password and server in the credential")]
+    InvalidCredentials(String),
+    #[error("MT5 terminal is not connected to the broker; check the terminal's network and that the server name is correct")]
+    BrokerDisconnected,
+    #[error("Not connected after {timeout_secs}s (state {state})")]
+    ConnectionTimeout { timeout_secs: f64, state: ConnectionState },
+}
+
+/// Maps a failure to establish a session to the error naming its likely cause.
+fn session_error(error: HttpClientError) -> DataClientError {
+    match error {
+        HttpClientError::NetworkError(e) | HttpClientError::TimeoutError(e) => {
+            DataClientError::BridgeUnreachable(e)
+        }
+        // Only a `false` from `initialize` surfaces as a connection error here
+        HttpClientError::ConnectionError(e) => DataClientError::TerminalNotRunning(e),
+        HttpClientError::AuthenticationError(e) => DataClientError::InvalidCredentials(e),
+        e => DataClientError::ConnectionError(e.to_string()),
+    }
 }
 
 impl From<String> for DataClientError {
@@ -43,14 +88,25 @@ impl From<String> for DataClientError {
 #[cfg(feature = "python-bindings")]
 use pyo3::prelude::*;
 #[cfg(feature = "python-bindings")]
-use nautilus_core::nanos::UnixNanos;
-#[cfg(feature = "python-bindings")]
+use crate::common::error::to_pyerr;
+use nautilus_core::{
+    nanos::UnixNanos,
+    time::{get_atomic_clock_realtime, AtomicTime},
+};
+use nautilus_data::aggregation::{BarAggregator, TickBarAggregator, VolumeBarAggregator};
 use nautilus_model::{
-    data::{Bar, BarType},
-    enums::BarAggregation,
+    data::{Bar, BarType, QuoteTick},
+    enums::{BarAggregation, PriceType},
+    identifiers::InstrumentId,
+    instruments::{Instrument, InstrumentAny},
     types::{Price, Quantity},
 };
 #[cfg(feature = "python-bindings")]
+use nautilus_model::{
+    data::TradeTick,
+    enums::AggressorSide,
+    identifiers::TradeId,
+};
 use chrono::{DateTime, Utc};
 
 #[cfg(feature = "python-bindings")]
@@ -60,286 +116,3519 @@ pub struct Mt5DataClient {
     #[pyo3(get)]
     config: Mt5DataClientConfig,
     http_client: Arc<Mt5HttpClient>,
+    connection: ConnectionStateTracker,
+    connectivity_monitor: ConnectivityMonitor,
+    keep_alive: KeepAlive,
+    bar_cache: Option<Arc<dyn BarCache>>,
+    /// Symbols added to Market Watch, keyed to the session generation they were selected in.
+    selected_symbols: Arc<Mutex<HashMap<String, u64>>>,
+    instruments: Arc<DashMap<InstrumentId, InstrumentAny>>,
+    /// Provider set by `set_instrument_provider`, used to load `warmup_instruments`.
+    instrument_provider: Option<Arc<Mt5InstrumentProvider>>,
+    /// Source of `ts_init` for parsed bars and ticks.
+    clock: &'static AtomicTime,
+    symbol_mapper: SymbolMapper,
+    /// Outcome of the last watchlist selection run by `connect`.
+    watchlist_report: Arc<Mutex<Option<WatchlistReport>>>,
+    /// Runtime background tasks are spawned on; the current runtime if `None`.
+    runtime: Option<tokio::runtime::Handle>,
+    /// Parent of every background task's token; cancelled and replaced by `disconnect`.
+    cancellation_token: Arc<Mutex<CancellationToken>>,
+    /// The terminal's `maxbars` read by `connect`; 0 until known.
+    max_bars: Arc<AtomicU64>,
 }
 
 #[cfg(not(feature = "python-bindings"))]
+#[derive(Clone, Debug)]
 pub struct Mt5DataClient {
     pub config: Mt5DataClientConfig,
     http_client: Arc<Mt5HttpClient>,
+    connection: ConnectionStateTracker,
+    connectivity_monitor: ConnectivityMonitor,
+    keep_alive: KeepAlive,
+    bar_cache: Option<Arc<dyn BarCache>>,
+    /// Symbols added to Market Watch, keyed to the session generation they were selected in.
+    selected_symbols: Arc<Mutex<HashMap<String, u64>>>,
+    instruments: Arc<DashMap<InstrumentId, InstrumentAny>>,
+    /// Provider set by `set_instrument_provider`, used to load `warmup_instruments`.
+    instrument_provider: Option<Arc<Mt5InstrumentProvider>>,
+    /// Source of `ts_init` for parsed bars and ticks.
+    clock: &'static AtomicTime,
+    symbol_mapper: SymbolMapper,
+    /// Outcome of the last watchlist selection run by `connect`.
+    watchlist_report: Arc<Mutex<Option<WatchlistReport>>>,
+    /// Runtime background tasks are spawned on; the current runtime if `None`.
+    runtime: Option<tokio::runtime::Handle>,
+    /// Parent of every background task's token; cancelled and replaced by `disconnect`.
+    cancellation_token: Arc<Mutex<CancellationToken>>,
+    /// The terminal's `maxbars` read by `connect`; 0 until known.
+    max_bars: Arc<AtomicU64>,
 }
 
 impl Mt5DataClient {
     /// Creates a new instance of the MT5 data client.
     pub fn new(config: Mt5DataClientConfig) -> Result<Self, DataClientError> {
+        config.validate()?;
+        let symbol_mapper = config.symbol_mapper()?;
         let base_url = config.base_url.clone();
         let http_config = Mt5Config {
             base_url: base_url.clone(),
             http_timeout: config.http_timeout,
             proxy: None,
+            retry_delay_ms: config.retry_delay_ms,
+            retry_delay_max_ms: config.retry_delay_max_ms,
+            min_build: config.min_build,
+            accept_compression: config.accept_compression,
+            strict_parsing: config.strict_parsing,
+            warn_on_empty: config.warn_on_empty,
+            ..Default::default()
         };
 
         let http_client = Arc::new(Mt5HttpClient::new(http_config, base_url).map_err(|e| DataClientError::ConnectionError(e.to_string()))?);
+        let bar_cache = (config.bar_cache_max_entries > 0).then(|| {
+            Arc::new(InMemoryBarCache::new(
+                config.bar_cache_max_entries,
+                config.bar_cache_ttl_secs.map(std::time::Duration::from_secs),
+            )) as Arc<dyn BarCache>
+        });
+
+        Ok(Self {
+            config,
+            http_client,
+            connection: ConnectionStateTracker::new(),
+            connectivity_monitor: ConnectivityMonitor::default(),
+            keep_alive: KeepAlive::default(),
+            bar_cache,
+            selected_symbols: Arc::new(Mutex::new(HashMap::new())),
+            instruments: Arc::new(DashMap::new()),
+            instrument_provider: None,
+            clock: get_atomic_clock_realtime(),
+            symbol_mapper,
+            watchlist_report: Arc::new(Mutex::new(None)),
+            runtime: None,
+            cancellation_token: Arc::new(Mutex::new(CancellationToken::new())),
+            max_bars: Arc::new(AtomicU64::new(0)),
+        })
+    }
+
+    /// Returns the MT5 symbol name for `instrument_id`.
+    pub fn mt5_symbol(&self, instrument_id: &InstrumentId) -> String {
+        self.symbol_mapper.nautilus_to_mt5(instrument_id.symbol.as_str())
+    }
+
+    /// Replaces the clock stamping `ts_init` on parsed data, e.g. with a static
+    /// clock so tests see fixed timestamps.
+    pub fn set_clock(&mut self, clock: &'static AtomicTime) {
+        self.clock = clock;
+    }
+
+    /// Spawns the client's background tasks (tick polling, connectivity monitor
+    /// and keep-alive) on `runtime`, e.g. a dedicated runtime isolating the
+    /// adapter's I/O. Tasks already running stay where they are.
+    pub fn set_runtime(&mut self, runtime: tokio::runtime::Handle) {
+        self.runtime = Some(runtime);
+    }
+
+    /// Replaces the `request_bars` cache, e.g. with a persistent [`BarCache`]; `None` disables caching.
+    pub fn set_bar_cache(&mut self, bar_cache: Option<Arc<dyn BarCache>>) {
+        self.bar_cache = bar_cache;
+    }
+
+    /// Sends requests through `http_client`, e.g. one shared with another client of
+    /// the same bridge so that `Serialized` access holds across both. Its own
+    /// config (timeouts, retries) then applies instead of this client's.
+    pub fn set_http_client(&mut self, http_client: &Mt5HttpClient) {
+        self.http_client = Arc::new(http_client.clone());
+    }
+
+    /// Returns the HTTP client requests are sent through.
+    pub fn http_client(&self) -> Mt5HttpClient {
+        (*self.http_client).clone()
+    }
+
+    /// Shares `provider`'s instrument cache, so instruments it loads become visible here.
+    ///
+    /// `connect` also loads `warmup_instruments` through `provider`.
+    pub fn set_instrument_provider(&mut self, provider: &Mt5InstrumentProvider) {
+        self.instruments = provider.instruments_cache();
+        self.instrument_provider = Some(Arc::new(provider.clone()));
+    }
 
-        Ok(Self { config, http_client })
+    /// Returns a cached instrument, or `None` if no provider has loaded it.
+    pub fn instrument(&self, instrument_id: &InstrumentId) -> Option<InstrumentAny> {
+        self.instruments.get(instrument_id).map(|entry| entry.value().clone())
     }
 
-    /// Performs a login to validate connectivity with the MT5 bridge.
+    /// Initializes the terminal and logs in to validate connectivity with the MT5 bridge.
+    ///
+    /// Then reads the terminal's `maxbars` (see [`Self::max_bars`]), loads
+    /// `warmup_instruments` into the instrument cache, so data for them never
+    /// arrives for an unknown instrument, and starts the terminal connectivity
+    /// monitor unless `terminal_check_interval_ms` is `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DataClientError::BridgeUnreachable`, `TerminalNotRunning` or
+    /// `InvalidCredentials` when the session cannot be established for that
+    /// reason, `BrokerDisconnected` if the terminal has no broker connection, and
+    /// `DataClientError::ConnectionError` for other session failures or if a
+    /// warmup instrument cannot be loaded. Symbols in `watchlist` that cannot
+    /// be selected are recorded in [`Self::watchlist_report`] instead.
     pub async fn connect(&self) -> Result<(), DataClientError> {
-        self.http_client.login().await.map_err(|e| DataClientError::ConnectionError(e.to_string()))?;
+        establish_session(&self.http_client, &self.connection, false)
+            .await
+            .map_err(session_error)?;
+        // Only an explicit `connected: false` fails; bridges without `terminal_info` pass
+        let terminal = self.http_client.terminal_info().await.and_then(unwrap_result).ok();
+        if terminal.as_ref().is_some_and(|info| !reports_connected(info)) {
+            self.connection.transition(ConnectionState::Disconnected);
+            return Err(DataClientError::BrokerDisconnected);
+        }
+        let max_bars = terminal
+            .as_ref()
+            .and_then(|info| info.get("maxbars"))
+            .and_then(serde_json::Value::as_u64);
+        self.max_bars.store(max_bars.unwrap_or(0), Ordering::Relaxed);
+        if let Err(e) = self.warmup_instruments().await {
+            self.connection.transition(ConnectionState::Disconnected);
+            return Err(e);
+        }
+        if !self.config.watchlist.is_empty() {
+            let report = self.select_watchlist().await;
+            *self.watchlist_report.lock().unwrap_or_else(PoisonError::into_inner) = Some(report);
+        }
+        self.start_connectivity_monitor();
+        self.start_keep_alive();
+        Ok(())
+    }
+
+    /// Selects every `watchlist` symbol in Market Watch, a few requests at a time.
+    ///
+    /// Failures are logged and reported rather than returned, so one delisted
+    /// symbol does not stop the rest from being selected.
+    pub async fn select_watchlist(&self) -> WatchlistReport {
+        let mut results: Vec<(usize, &String, Result<(), DataClientError>)> =
+            futures_util::stream::iter(self.config.watchlist.iter().enumerate())
+                .map(|(index, symbol)| async move {
+                    (index, symbol, self.ensure_symbol_selected(symbol).await)
+                })
+                .buffer_unordered(WATCHLIST_CONCURRENCY)
+                .collect()
+                .await;
+        results.sort_by_key(|(index, _, _)| *index);
+
+        let mut report = WatchlistReport::default();
+        for (_, symbol, result) in results {
+            match result {
+                Ok(()) => report.selected.push(symbol.clone()),
+                Err(e) => {
+                    tracing::warn!("Could not select watchlist symbol {symbol}: {e}");
+                    report.failed.push((symbol.clone(), e.to_string()));
+                }
+            }
+        }
+        tracing::info!(
+            "Selected {} of {} watchlist symbols",
+            report.selected.len(),
+            self.config.watchlist.len()
+        );
+        report
+    }
+
+    /// Returns the terminal's `maxbars` setting read by `connect`: the most bars
+    /// of history it keeps per symbol and timeframe. `None` before connecting or
+    /// if the bridge does not report it.
+    pub fn max_bars(&self) -> Option<u64> {
+        Some(self.max_bars.load(Ordering::Relaxed)).filter(|max_bars| *max_bars > 0)
+    }
+
+    /// Returns the outcome of the watchlist selection made by the last `connect`,
+    /// or `None` if it has not run.
+    pub fn watchlist_report(&self) -> Option<WatchlistReport> {
+        self.watchlist_report.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// Loads `warmup_instruments` through the configured instrument provider, or
+    /// one built from this client's config if none was set.
+    async fn warmup_instruments(&self) -> Result<(), DataClientError> {
+        let instrument_ids = &self.config.warmup_instruments;
+        if instrument_ids.is_empty() {
+            return Ok(());
+        }
+
+        let provider = match &self.instrument_provider {
+            Some(provider) => Arc::clone(provider),
+            None => Arc::new(
+                Mt5InstrumentProvider::new(Mt5InstrumentProviderConfig {
+                    base_url: self.config.base_url.clone(),
+                    http_timeout: Some(self.config.http_timeout),
+                    credential: self.config.credential.clone(),
+                    symbol_map: self.config.symbol_map.clone(),
+                    symbol_suffix: self.config.symbol_suffix.clone(),
+                    ..Default::default()
+                })
+                .map_err(|e| DataClientError::ConnectionError(e.to_string()))?,
+            ),
+        };
+        let symbols = instrument_ids.iter().map(|id| self.mt5_symbol(id)).collect();
+        provider
+            .load_ids_async(symbols, None)
+            .await
+            .map_err(|e| DataClientError::ConnectionError(format!("Instrument warmup failed: {e}")))?;
+
+        let mut missing = Vec::new();
+        for instrument_id in instrument_ids {
+            match provider.instrument(instrument_id) {
+                Some(instrument) => {
+                    self.instruments.insert(*instrument_id, instrument);
+                }
+                None => missing.push(instrument_id.to_string()),
+            }
+        }
+        if !missing.is_empty() {
+            return Err(DataClientError::ConnectionError(format!(
+                "Instrument warmup could not load {}",
+                missing.join(", ")
+            )));
+        }
+        tracing::info!("Warmed up {} instruments", instrument_ids.len());
+        Ok(())
+    }
+
+    /// Re-establishes the bridge session after a fault.
+    pub async fn reconnect(&self) -> Result<(), DataClientError> {
+        establish_session(&self.http_client, &self.connection, true)
+            .await
+            .map_err(session_error)?;
+        self.start_connectivity_monitor();
+        self.start_keep_alive();
+        Ok(())
+    }
+
+    /// Disconnects from the MT5 bridge.
+    ///
+    /// The bridge is stateless over HTTP so there is no session to tear down,
+    /// but every tick subscription stops polling.
+    pub async fn disconnect(&self) -> Result<(), DataClientError> {
+        self.cancel_subscriptions();
+        self.connectivity_monitor.stop();
+        self.keep_alive.stop();
+        self.connection.transition(ConnectionState::Disconnected);
+        Ok(())
+    }
+
+    /// Returns a token for a new background task, cancelled by `disconnect`.
+    fn subscription_token(&self) -> CancellationToken {
+        self.cancellation_token.lock().unwrap_or_else(PoisonError::into_inner).child_token()
+    }
+
+    /// Cancels every polling task, leaving a fresh token for later subscriptions.
+    fn cancel_subscriptions(&self) {
+        let mut token = self.cancellation_token.lock().unwrap_or_else(PoisonError::into_inner);
+        std::mem::replace(&mut *token, CancellationToken::new()).cancel();
+    }
+
+    fn start_keep_alive(&self) {
+        if let Some(interval_ms) = self.config.keep_alive_interval_ms {
+            self.keep_alive.start(
+                Arc::clone(&self.http_client),
+                std::time::Duration::from_millis(interval_ms),
+                self.runtime.as_ref(),
+                self.subscription_token(),
+            );
+        }
+    }
+
+    fn start_connectivity_monitor(&self) {
+        if let Some(interval_ms) = self.config.terminal_check_interval_ms {
+            self.connectivity_monitor.start(
+                Arc::clone(&self.http_client),
+                self.connection.clone(),
+                std::time::Duration::from_millis(interval_ms),
+                self.runtime.as_ref(),
+                self.subscription_token(),
+                None,
+            );
+        }
+    }
+
+    /// Returns the current connection state.
+    pub fn connection_state(&self) -> ConnectionState {
+        self.connection.state()
+    }
+
+    /// Subscribes to connection state transitions.
+    pub fn subscribe_connection_state(&self) -> tokio::sync::broadcast::Receiver<ConnectionTransition> {
+        self.connection.subscribe()
+    }
+
+    /// Waits until the client is `Connected`, polling its connection state.
+    ///
+    /// Gives startup code a deterministic point to continue from, e.g. while
+    /// `connect` runs on another task.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DataClientError::ConnectionTimeout` with the last observed state if the
+    /// client is not connected within `timeout_secs`.
+    pub async fn wait_until_connected(&self, timeout_secs: f64) -> Result<(), DataClientError> {
+        let timeout = std::time::Duration::try_from_secs_f64(timeout_secs).unwrap_or_default();
+        self.connection
+            .wait_until(ConnectionState::Connected, timeout)
+            .await
+            .map_err(|state| DataClientError::ConnectionTimeout { timeout_secs, state })
+    }
+
+    /// Adds `symbol` to Market Watch unless already selected in the current session.
+    ///
+    /// Selections made before a reconnect are treated as stale, since a
+    /// re-initialized terminal may have dropped them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bridge fails to select `symbol`.
+    pub async fn ensure_symbol_selected(&self, symbol: &str) -> Result<(), DataClientError> {
+        let generation = self.connection.generation();
+        if self.selected_symbols.lock().unwrap_or_else(PoisonError::into_inner).get(symbol) == Some(&generation) {
+            return Ok(());
+        }
+
+        self.http_client.symbol_select_checked(symbol).await?;
+
+        self.selected_symbols
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(symbol.to_string(), generation);
         Ok(())
     }
 
+    /// Streams new ticks for `symbol` by polling `symbol_info_tick`.
+    ///
+    /// Ticks are buffered in a bounded channel sized by `tick_buffer_capacity`;
+    /// when the consumer falls behind, `tick_backpressure` decides whether the
+    /// oldest ticks are dropped or polling waits. After a reconnect the stream
+    /// yields [`TickStreamItem::Reconnected`] before any further tick, and the
+    /// last-seen tick is forgotten so the current quote is re-emitted. Polling
+    /// stops when the subscription is dropped.
+    ///
+    /// When a spread limit applies to `symbol` (see `max_spread_points`), ticks
+    /// whose `ask - bid` exceeds it, as is common around rollover and news, are
+    /// dropped before reaching the stream.
+    pub async fn subscribe_ticks(&self, symbol: &str) -> Result<TickSubscription, DataClientError> {
+        self.ensure_symbol_selected(symbol).await?;
+
+        let max_spread = match self.config.max_spread_points_for(symbol) {
+            Some(points) => Some(points * self.http_client.symbol_info_typed(symbol).await?.point),
+            None => None,
+        };
+
+        let channel = BoundedChannel::new(self.config.tick_buffer_capacity, self.config.tick_backpressure);
+        let http_client = Arc::clone(&self.http_client);
+        let interval = std::time::Duration::from_millis(self.config.tick_poll_interval_ms);
+        let body = serde_json::json!([symbol]);
+        let sender = channel.clone();
+        let connection = self.connection.clone();
+        let token = self.subscription_token();
+
+        let task = spawn_cancellable(self.runtime.as_ref(), token.clone(), async move {
+            let mut last_time_msc = None;
+            let mut generation = connection.generation();
+            loop {
+                if connection.generation() != generation {
+                    generation = connection.generation();
+                    last_time_msc = None;
+                    sender.send(TickStreamItem::Reconnected).await;
+                }
+                let response = http_client.symbol_info_tick(&body).await.and_then(unwrap_result);
+                // A tick fetched across a reconnect belongs to the old session; poll afresh
+                if connection.generation() != generation {
+                    continue;
+                }
+                match response {
+                    Ok(tick) if tick.is_object() => {
+                        let time_msc = tick.get("time_msc").and_then(serde_json::Value::as_i64);
+                        if time_msc != last_time_msc {
+                            last_time_msc = time_msc;
+                            if exceeds_spread(&tick, max_spread) {
+                                tracing::debug!("Dropping tick for {body} with excessive spread: {tick}");
+                            } else {
+                                sender.send(TickStreamItem::Tick(tick)).await;
+                            }
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) => tracing::warn!("Tick poll for {body} failed: {e}"),
+                }
+                tokio::time::sleep(interval).await;
+            }
+        });
+
+        Ok(TickSubscription { channel, task, token })
+    }
+
+    /// Requests a depth of market snapshot for `symbol`.
+    ///
+    /// Brokers reject `market_book_add` for symbols without depth. That yields
+    /// `Mt5Error::DepthUnsupported`, or with `fallback_to_top_of_book` a one-level
+    /// book built from the latest tick (sizes default to one unit).
+    pub async fn request_order_book(&self, symbol: &str) -> Mt5Result<Vec<Mt5BookEntry>> {
+        self.ensure_symbol_selected(symbol).await?;
+
+        let body = serde_json::json!([symbol]);
+        let added = unwrap_result(self.http_client.market_book_add(&body).await?)?;
+        if added != serde_json::Value::Bool(true) {
+            if self.config.fallback_to_top_of_book {
+                return self.top_of_book(symbol).await;
+            }
+            return Err(Mt5Error::DepthUnsupported {
+                symbol: symbol.to_string(),
+            });
+        }
+
+        let response = self.http_client.market_book_get(&body).await;
+        // Release even if the read failed so the terminal stops collecting depth
+        if let Err(e) = self.http_client.market_book_release(&body).await {
+            tracing::warn!("market_book_release for {symbol} failed: {e}");
+        }
+        match unwrap_result(response?)? {
+            serde_json::Value::Null => Ok(Vec::new()),
+            result => serde_json::from_value(result)
+                .map_err(|e| DataClientError::ParseError(e.to_string()).into()),
+        }
+    }
+
+    /// Builds a one-level book (ask, then bid) from the latest `symbol_info_tick`.
+    async fn top_of_book(&self, symbol: &str) -> Mt5Result<Vec<Mt5BookEntry>> {
+        let response = self
+            .http_client
+            .symbol_info_tick(&serde_json::json!([symbol]))
+            .await?;
+        let tick = Some(unwrap_result(response)?)
+            .filter(|tick| tick.is_object())
+            .ok_or_else(|| DataClientError::ParseError(format!("No tick for {symbol}")))?;
+        let level = |book_type, field: &str| {
+            let price = tick.get(field).and_then(serde_json::Value::as_f64).ok_or_else(|| {
+                DataClientError::ParseError(format!("Tick for {symbol} has no {field}"))
+            })?;
+            Ok::<_, DataClientError>(Mt5BookEntry {
+                book_type,
+                price,
+                volume: 1.0,
+                volume_dbl: 1.0,
+            })
+        };
+        Ok(vec![level(BOOK_TYPE_SELL, "ask")?, level(BOOK_TYPE_BUY, "bid")?])
+    }
+
+    /// Requests the latest `symbol_info_tick` of `instrument_id` as a `QuoteTick`.
+    ///
+    /// Both sides carry the size chosen by `quote_size_source`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DataClientError::InvalidRequest` if the instrument is not loaded,
+    /// `DataClientError::ParseError` if the bridge has no two-sided tick for it,
+    /// and propagates bridge errors.
+    pub async fn request_quote(&self, instrument_id: &InstrumentId) -> Result<QuoteTick, DataClientError> {
+        let instrument = self.instrument(instrument_id).ok_or_else(|| {
+            DataClientError::InvalidRequest(format!("{instrument_id} has not been loaded"))
+        })?;
+        let symbol = self.mt5_symbol(instrument_id);
+        self.ensure_symbol_selected(&symbol).await?;
+
+        let tick = unwrap_result(
+            self.http_client
+                .symbol_info_tick(&serde_json::json!([symbol]))
+                .await?,
+        )?;
+        parse_tick_quote(
+            &tick,
+            *instrument_id,
+            instrument.price_precision(),
+            instrument.size_precision(),
+            self.config.quote_size_source,
+            instrument.min_quantity().map(|quantity| quantity.as_f64()),
+            self.clock.get_time_ns(),
+        )
+        .ok_or_else(|| DataClientError::ParseError(format!("No two-sided tick for {symbol}")))
+    }
+
     /// Fetches all symbols from the MT5 bridge.
     pub async fn get_symbols(&self) -> Result<Vec<crate::http::models::Mt5Symbol>, DataClientError> {
         let body = serde_json::json!({});
         let response = self.http_client.symbols_get(&body).await.map_err(|e| DataClientError::ConnectionError(e.to_string()))?;
-        let symbols: Vec<crate::http::models::Mt5Symbol> = serde_json::from_value(response)
-            .map_err(|e| DataClientError::ParseError(e.to_string()))?;
-        Ok(symbols)
+        Ok(self.http_client.parse_list(response, "symbols_get")?)
     }
-}
 
-#[cfg(feature = "python-bindings")]
-#[pymethods]
-impl Mt5DataClient {
-    #[new]
-    pub fn py_new(config: Mt5DataClientConfig) -> Result<Self, PyErr> {
-        Self::new(config).map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))
+    /// Fetches the swap (rollover) terms of `symbol` from `symbol_info`.
+    pub async fn swap_info(&self, symbol: &str) -> Result<crate::http::models::Mt5SwapInfo, DataClientError> {
+        let info = self
+            .http_client
+            .symbol_info_typed(symbol)
+            .await
+            .map_err(|e| DataClientError::ConnectionError(e.to_string()))?;
+        info.swap_info().map_err(DataClientError::ParseError)
     }
 
-    #[pyo3(name = "connect")]
-    pub fn py_connect<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        let client = self.clone();
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            client.connect().await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
-        })
+    /// Fetches raw tick rows for `symbol` between `start` and `end` (Unix seconds).
+    ///
+    /// The range is requested in `tick_chunk_secs` windows to stay within the
+    /// bridge's memory and timeout limits. A window failing with a retryable
+    /// error is retried up to `tick_chunk_retries` times, backing off from
+    /// `retry_delay_ms` up to `retry_delay_max_ms`, while earlier windows are
+    /// kept. Ticks repeated at window boundaries are dropped.
+    /// `kind` selects which ticks the terminal returns, so quote requests need
+    /// not carry trade ticks and vice versa.
+    /// Each row is `[time, bid, ask, last, volume, time_msc, flags, volume_real]`.
+    pub async fn request_tick_rows(
+        &self,
+        symbol: &str,
+        start: i64,
+        end: i64,
+        kind: TickKind,
+    ) -> Result<Vec<Vec<serde_json::Value>>, DataClientError> {
+        self.ensure_symbol_selected(symbol).await?;
+
+        let chunk_size = self.config.tick_chunk_secs.max(1) as i64;
+        let mut rows = Vec::new();
+        let mut current_start = start;
+
+        while current_start < end {
+            let current_end = std::cmp::min(current_start + chunk_size, end);
+            // [symbol, start, end, flags]
+            let body = serde_json::json!([symbol, current_start, current_end, kind.flags()]);
+            let chunk = self.fetch_tick_chunk(&body).await?;
+            append_dedup_ticks(&mut rows, chunk);
+
+            current_start = current_end;
+            tokio::task::yield_now().await;
+        }
+
+        sort_by_ts_event(&mut rows, |row| tick_row_time_msc(row), &format!("{symbol} ticks"));
+        Ok(rows)
     }
 
-    #[pyo3(name = "get_symbols")]
-    pub fn py_get_symbols<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        let client = self.clone();
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let symbols = client.get_symbols().await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
-            // Return as JSON string
-            serde_json::to_string(&symbols)
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
-        })
+    /// Fetches up to `count` raw tick rows of `kind` for `symbol` from `from` (Unix seconds) onward.
+    ///
+    /// Rows have the same layout as [`Self::request_tick_rows`].
+    pub async fn request_ticks_from(
+        &self,
+        symbol: &str,
+        from: i64,
+        count: u32,
+        kind: TickKind,
+    ) -> Result<Vec<Vec<serde_json::Value>>, DataClientError> {
+        self.ensure_symbol_selected(symbol).await?;
+
+        // [symbol, from, count, flags]
+        let body = serde_json::json!([symbol, from, count, kind.flags()]);
+        let response = self.http_client.copy_ticks_from(&body).await?;
+        let mut rows: Vec<Vec<serde_json::Value>> =
+            self.http_client.parse_list(response, "copy_ticks_from")?;
+        sort_by_ts_event(&mut rows, |row| tick_row_time_msc(row), &format!("{symbol} ticks"));
+        Ok(rows)
     }
 
-    /// Fetches historical bars for a specific instrument.
-    #[pyo3(name = "fetch_bars")]
-    pub fn py_fetch_bars<'py>(&self, py: Python<'py>, symbol: String, timeframe: u32, start_time: i64, count: u32) -> PyResult<Bound<'py, PyAny>> {
-        let client = self.clone();
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            // Construct the request array expected by the MT5 middleware [symbol, timeframe, start_time, count]
-            let body = serde_json::json!([symbol, timeframe, start_time, count]);
-            
-            // Use the underlying http_client to send the request
-            let result = client.http_client.copy_rates_from(&body).await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
-            
-            // Check for error field in response
-            if let Some(error) = result.get("error") {
-                return Err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(error.to_string()));
+    async fn fetch_tick_chunk(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<Vec<Vec<serde_json::Value>>, DataClientError> {
+        let mut attempt = 0;
+        let response = loop {
+            match self.http_client.copy_ticks_range(body).await {
+                Ok(response) => break response,
+                Err(e) if e.is_retryable() && attempt < self.config.tick_chunk_retries => {
+                    let delay = self.http_client.retry_delay(attempt);
+                    attempt += 1;
+                    tracing::warn!("Retrying tick window {body} in {delay:?} ({attempt}): {e}");
+                    tokio::time::sleep(delay).await;
+                }
+                Err(e) => return Err(e.into()),
             }
-                
-            // Extract the result list
-            let bars_value = result.get("result")
-                .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("No result field in response"))?;
-
-            // Deserialize into Vec of tuples/arrays to avoid string parsing in Python
-            // MT5 returns: [time, open, high, low, close, tick_volume, spread, real_volume]
-            let bars_raw: Vec<(i64, f64, f64, f64, f64, u64, i32, u64)> = serde_json::from_value(bars_value.clone())
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Failed to parse bars: {}", e)))?;
+        };
 
-            // We need to return a Python object. Since we are in an async block that returns a Result<T>,
-            // and T must directly convert to Python object, we can construct the list of dicts here?
-            // No, we cannot access Python GIL here easily to create PyDicts if we are in a separate thread.
-            // But `future_into_py` handles the return value conversion ON THE MAIN THREAD (with GIL).
-            // So we can return a struct that implements IntoPy<PyObject>.
-            
-            Ok(Mt5BarList(bars_raw))
-        })
+        Ok(self.http_client.parse_list(response, "copy_ticks_range")?)
     }
 
-    /// Requests historical bars and returns Nautilus Bar objects (Paginated).
-    #[pyo3(name = "request_bars")]
-    #[allow(clippy::too_many_arguments)]
-    pub fn py_request_bars<'py>(
+    /// Requests historical bars for `bar_type` between `start` and `end`.
+    ///
+    /// The range is requested in 30 day chunks via `copy_rates_range`. Bars are
+    /// kept by open time according to `bounds`, so a bar opening exactly on `start`
+    /// or `end` is included only if that side is inclusive. Chunk edges never
+    /// produce the same bar twice.
+    ///
+    /// With a bar cache configured, a range already cached is served without the
+    /// bridge, and a cached range starting at the same bar is extended by fetching
+    /// only the bars after it. Ranges ending in the current bar cache that bar
+    /// while still forming, so set `bar_cache_ttl_secs` when requesting live data.
+    ///
+    /// Tick, volume and second bars, which MT5 does not provide, are aggregated
+    /// from ticks instead; see [`Self::aggregate_tick_bars`]. These bypass the cache.
+    ///
+    /// The terminal keeps at most [`Self::max_bars`] bars of history, counted back
+    /// from its newest bar, so a range spanning more may come back short; that is
+    /// logged as a warning when the first bar opens after `start`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DataClientError::InvalidRequest` if `start` is not before `end`,
+    /// and propagates bridge and parsing errors.
+    pub async fn request_bars(
         &self,
-        py: Python<'py>,
-        bar_type: BarType,
-        instrument: Bound<'py, PyAny>,
-        start: Option<DateTime<Utc>>,
-        end: Option<DateTime<Utc>>,
-        count: Option<u32>,
-    ) -> PyResult<Bound<'py, PyAny>> {
-        let client = self.clone();
-        
-        // Extract symbol and precisions
-        let symbol = bar_type.instrument_id().symbol().as_str().to_string();
-        let price_precision: u8 = instrument.getattr("price_precision")?.extract()?;
-        let size_precision: u8 = instrument.getattr("size_precision")?.extract()?;
-        let bar_type_clone = bar_type.clone();
+        bar_type: &BarType,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        price_precision: u8,
+        size_precision: u8,
+        bounds: RangeBounds,
+    ) -> Result<Vec<Bar>, DataClientError> {
+        if start >= end {
+            return Err(DataClientError::InvalidRequest(format!(
+                "start ({start}) must be before end ({end})"
+            )));
+        }
 
-        // Calculate timeframe in seconds for bar close calculation
-        let tf_seconds = match bar_type.spec().aggregation() {
-            BarAggregation::Second => bar_type.spec().step(),
-            BarAggregation::Minute => bar_type.spec().step() * 60,
-            BarAggregation::Hour => bar_type.spec().step() * 3600,
-            BarAggregation::Day => bar_type.spec().step() * 86400,
-            _ => 60, // Default to 1 minute
+        if is_tick_aggregated(bar_type) {
+            return self
+                .aggregate_tick_bars(
+                    bar_type,
+                    start.timestamp(),
+                    end.timestamp(),
+                    price_precision,
+                    size_precision,
+                )
+                .await;
+        }
+
+        let Some(cache) = &self.bar_cache else {
+            let bars = self
+                .fetch_bars(
+                    bar_type,
+                    start.timestamp(),
+                    end.timestamp(),
+                    price_precision,
+                    size_precision,
+                    bounds,
+                )
+                .await?;
+            self.warn_if_beyond_max_bars(bar_type, start, end, &bars);
+            return Ok(bars);
         };
 
-        // Determine MT5 timeframe value
-        let mt5_tf = match tf_seconds {
-            60 => 1,
-            300 => 5,
-            900 => 15,
-            1800 => 30,
-            3600 => 16385, // H1
-            14400 => 16388, // H4
-            86400 => 16408, // D1
-            _ => 1, // Fallback M1
+        let tf_seconds = bar_type_seconds(bar_type) as i64;
+        let align = |ts: i64| ts - ts.rem_euclid(tf_seconds);
+        let key = BarCacheKey {
+            bar_type: bar_type.clone(),
+            price_precision,
+            size_precision,
+            start: align(start.timestamp()),
+            end: align(end.timestamp()),
         };
 
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let mut bars: Vec<Bar> = Vec::new();
-            
-            // Logic:
-            // 1. If start and end provided -> Range Request (Loop)
-            // 2. Else -> Count Request from now
-            
-            if let (Some(start_dt), Some(end_dt)) = (start, end) {
-                let mut current_start = start_dt.timestamp();
-                let end_ts = end_dt.timestamp();
-                let chunk_size = 30 * 24 * 3600; // 30 days chunk
-                
-                while current_start < end_ts {
-                    let current_end = std::cmp::min(current_start + chunk_size, end_ts);
-                     // [symbol, timeframe, start, end]
-                    let body = serde_json::json!([symbol, mt5_tf, current_start, current_end]);
-                    
-                    match client.http_client.copy_rates_range(&body).await {
-                        Ok(val) => {
-                             if let Some(res) = val.get("result") {
-                                 if let Ok(rows) = serde_json::from_value::<Vec<Vec<serde_json::Value>>>(res.clone()) {
-                                    for row in rows {
-                                        if let Some(bar) = parse_bar_row(&row, &bar_type_clone, tf_seconds, price_precision, size_precision) {
-                                            bars.push(bar);
-                                        }
-                                    }
-                                 }
-                             }
-                        },
-                        Err(e) => tracing::error!("Error fetching bars chunk: {}", e),
+        let cached = match cache.get(&key) {
+            Some(bars) => bars,
+            None => {
+                // Any cached prefix ends before `key.end`, otherwise `get` would have hit
+                let (mut bars, fetch_start, fetch_bounds) = match cache.get_prefix(&key) {
+                    Some((prefix, bars)) => {
+                        cache.remove(&prefix);
+                        let tail = RangeBounds {
+                            start_inclusive: false,
+                            end_inclusive: true,
+                        };
+                        (bars, prefix.end, tail)
                     }
-                    
-                    current_start = current_end;
-                    // Yield to avoid blocking executor too long (optional)
-                    tokio::task::yield_now().await; 
-                }
-            } else {
-                // Count request
-                let count_val = count.unwrap_or(1000);
-                let now = Utc::now().timestamp();
-                // [symbol, timeframe, start, count]
-                let body = serde_json::json!([symbol, mt5_tf, now, count_val]);
-                
-                 let result = client.http_client.copy_rates_from(&body).await
-                    .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
-                
-                 if let Some(res) = result.get("result") {
-                     let rows: Vec<Vec<serde_json::Value>> = serde_json::from_value(res.clone())
-                        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
-                     
-                     for row in rows {
-                        if let Some(bar) = parse_bar_row(&row, &bar_type_clone, tf_seconds, price_precision, size_precision) {
-                            bars.push(bar);
-                        }
-                     }
-                 }
+                    None => (Vec::new(), key.start, RangeBounds::default()),
+                };
+                bars.extend(
+                    self.fetch_bars(
+                        bar_type,
+                        fetch_start,
+                        key.end,
+                        price_precision,
+                        size_precision,
+                        fetch_bounds,
+                    )
+                    .await?,
+                );
+                cache.insert(key, bars.clone());
+                bars
             }
+        };
 
-            Python::attach(|py| {
-                let py_bars: PyResult<Vec<_>> = bars.into_iter().map(|bar| bar.into_py_any(py)).collect();
-                Ok(py_bars?)
+        let (start_ts, end_ts) = (start.timestamp(), end.timestamp());
+        let bars: Vec<Bar> = cached
+            .into_iter()
+            .filter(|bar| {
+                let open = (bar.ts_event.as_u64() / 1_000_000_000) as i64 - tf_seconds;
+                bounds.contains(open, start_ts, end_ts)
             })
-        })
+            .collect();
+        self.warn_if_beyond_max_bars(bar_type, start, end, &bars);
+        Ok(bars)
     }
-}
 
-// Helper struct to handle conversion to Python List[Dict]
-struct Mt5BarList(Vec<(i64, f64, f64, f64, f64, u64, i32, u64)>);
+    /// Warns when a range spanning more than `maxbars` bar periods came back
+    /// starting after `start`, i.e. when the terminal's history limit likely cut it.
+    ///
+    /// The limit counts back from the terminal's newest bar rather than from
+    /// `end`, so the request itself is left as is.
+    fn warn_if_beyond_max_bars(
+        &self,
+        bar_type: &BarType,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        bars: &[Bar],
+    ) {
+        let Some(max_bars) = self.max_bars() else {
+            return;
+        };
+        let tf_seconds = bar_type_seconds(bar_type) as i64;
+        let periods = (end.timestamp() - start.timestamp()) / tf_seconds.max(1);
+        if u64::try_from(periods).unwrap_or(0) <= max_bars {
+            return;
+        }
+        let first_open = bars
+            .first()
+            .map(|bar| (bar.ts_event.as_u64() / 1_000_000_000) as i64 - tf_seconds);
+        if first_open.map_or(true, |open| open > start.timestamp() + tf_seconds) {
+            tracing::warn!(
+                "{bar_type} bars from {start} to {end} exceed the terminal's maxbars {max_bars}; \
+                 only {} bars returned, the oldest after {start}",
+                bars.len()
+            );
+        }
+    }
 
-impl<'py> IntoPyObject<'py> for Mt5BarList {
-    type Target = pyo3::types::PyList;
-    type Output = pyo3::Bound<'py, Self::Target>;
-    type Error = pyo3::PyErr;
+    /// Fetches bars opening in `[start_ts, end_ts]` (Unix seconds, edges per `bounds`) from the bridge.
+    async fn fetch_bars(
+        &self,
+        bar_type: &BarType,
+        start_ts: i64,
+        end_ts: i64,
+        price_precision: u8,
+        size_precision: u8,
+        bounds: RangeBounds,
+    ) -> Result<Vec<Bar>, DataClientError> {
+        let symbol = self.mt5_symbol(&bar_type.instrument_id());
+        self.ensure_symbol_selected(&symbol).await?;
 
-    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
-        let list = pyo3::types::PyList::empty(py);
-        for (time, open, high, low, close, tick_vol, spread, real_vol) in self.0 {
-            let dict = pyo3::types::PyDict::new(py);
-            dict.set_item("time", time)?;
-            dict.set_item("open", open)?;
-            dict.set_item("high", high)?;
-            dict.set_item("low", low)?;
-            dict.set_item("close", close)?;
-            dict.set_item("tick_volume", tick_vol)?;
-            dict.set_item("spread", spread)?;
-            dict.set_item("real_volume", real_vol)?;
-            list.append(dict)?;
+        let tf_seconds = bar_type_seconds(bar_type);
+        let mt5_tf = mt5_timeframe(tf_seconds);
+
+        let chunk_size = 30 * 24 * 3600;
+        let mut current_start = start_ts;
+        let mut bars = Vec::new();
+
+        // At least one request, so a range within a single bar still fetches it
+        loop {
+            let current_end = std::cmp::min(current_start + chunk_size, end_ts);
+            // The bridge returns both edges; the next chunk owns the shared one
+            let chunk_bounds = RangeBounds {
+                start_inclusive: current_start != start_ts || bounds.start_inclusive,
+                end_inclusive: current_end == end_ts && bounds.end_inclusive,
+            };
+            // [symbol, timeframe, start, end]
+            let body = serde_json::json!([symbol, mt5_tf, current_start, current_end]);
+            let response = self.http_client.copy_rates_range(&body).await?;
+
+            let rates: Vec<Mt5Rate> = self.http_client.parse_list(response, "copy_rates_range")?;
+            let ts_init = self.clock.get_time_ns();
+            bars.extend(
+                rates
+                    .into_iter()
+                    .filter(|rate| chunk_bounds.contains(rate.time, current_start, current_end))
+                    .filter_map(|rate| {
+                        parse_bar_row(
+                            &rate,
+                            self.config.volume_source,
+                            bar_type,
+                            tf_seconds,
+                            price_precision,
+                            size_precision,
+                            ts_init,
+                        )
+                    }),
+            );
+
+            if current_end >= end_ts {
+                break;
+            }
+            current_start = current_end;
+            tokio::task::yield_now().await;
         }
-        Ok(list)
+
+        sort_by_ts_event(&mut bars, |bar| bar.ts_event, &format!("{bar_type} bars"));
+        Ok(bars)
     }
-}
 
-/// Parses a raw MT5 bar row into a Nautilus `Bar` object.
-///
-/// # Arguments
-///
-/// * `row` - Raw JSON array [time, open, high, low, close, tick_vol, spread, real_vol]
-/// * `bar_type` - The bar type specification
-/// * `tf_seconds` - Timeframe in seconds for calculating bar close time
-/// * `price_prec` - Price precision from instrument
-/// * `size_prec` - Size precision from instrument
-///
-/// # Returns
-///
-/// Returns `Some(Bar)` on success, `None` if parsing fails.
-#[cfg(feature = "python-bindings")]
-fn parse_bar_row(
-    row: &[serde_json::Value],
-    bar_type: &BarType,
-    tf_seconds: u64,
-    price_prec: u8,
-    size_prec: u8,
-) -> Option<Bar> {
-    if row.len() < 6 {
-        return None;
+    /// Builds tick, volume or second bars from `copy_ticks_range` ticks between
+    /// `start_ts` and `end_ts` (Unix seconds).
+    ///
+    /// Tick and volume bars use the Nautilus bar aggregators; second bars are
+    /// bucketed by tick time (see [`aggregate_second_bars`]). `LAST` bars are
+    /// built from trade ticks and their real volume; `BID`, `ASK` and `MID` bars
+    /// from quote ticks, each counting as one unit of volume. The trailing
+    /// partial bar is not returned.
+    ///
+    /// Every tick in the range crosses the bridge, so this is far slower than
+    /// `copy_rates_range`: an hour of a liquid symbol can be tens of thousands
+    /// of ticks where `M1` rates are 60 rows. Keep second-bar ranges short.
+    async fn aggregate_tick_bars(
+        &self,
+        bar_type: &BarType,
+        start_ts: i64,
+        end_ts: i64,
+        price_precision: u8,
+        size_precision: u8,
+    ) -> Result<Vec<Bar>, DataClientError> {
+        let symbol = self.mt5_symbol(&bar_type.instrument_id());
+        let price_type = bar_type.spec().price_type();
+        let kind = if price_type == PriceType::Last {
+            TickKind::Trade
+        } else {
+            TickKind::Info
+        };
+        let rows = self.request_tick_rows(&symbol, start_ts, end_ts, kind).await?;
+
+        if bar_type.spec().aggregation() == BarAggregation::Second {
+            return Ok(aggregate_second_bars(
+                &rows,
+                bar_type,
+                start_ts,
+                end_ts,
+                price_precision,
+                size_precision,
+                self.clock.get_time_ns(),
+            ));
+        }
+
+        let bars = Rc::new(RefCell::new(Vec::new()));
+        let sink = Rc::clone(&bars);
+        let handler = move |bar: Bar| sink.borrow_mut().push(bar);
+        let mut aggregator: Box<dyn BarAggregator> = match bar_type.spec().aggregation() {
+            BarAggregation::Tick => Box::new(TickBarAggregator::new(
+                bar_type.clone(),
+                price_precision,
+                size_precision,
+                handler,
+            )),
+            _ => Box::new(VolumeBarAggregator::new(
+                bar_type.clone(),
+                price_precision,
+                size_precision,
+                handler,
+            )),
+        };
+
+        for row in &rows {
+            let Some((price, size)) = tick_row_price_size(row, price_type) else {
+                continue;
+            };
+            let (Ok(price), Ok(size), Some(ts)) = (
+                Price::from_f64(price, price_precision),
+                Quantity::from_f64(size, size_precision),
+                tick_row_ts(row),
+            ) else {
+                continue;
+            };
+            aggregator.update(price, size, ts);
+        }
+        drop(aggregator);
+
+        Ok(bars.take())
     }
 
-    let time_sec = row[0].as_i64()?;
-    let open = row[1].as_f64()?;
-    let high = row[2].as_f64()?;
-    let low = row[3].as_f64()?;
-    let close = row[4].as_f64()?;
-    let tick_vol = row[5].as_f64()?;
+    /// Returns the most recently closed bar of `bar_type`, or `None` if the bridge has none.
+    ///
+    /// Fetches the last two bars with `copy_rates_from_pos`. The newest is usually
+    /// still forming, so the one before it is returned, unless the symbol's last
+    /// tick is already past the newest bar's close, e.g. once the market has closed.
+    /// Bar and tick times both come from the trade server's clock, so the check
+    /// holds whatever the server's time zone.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DataClientError::InvalidRequest` if `bar_type` is not for
+    /// `instrument_id`, is aggregated from ticks, or the instrument is not loaded,
+    /// and propagates bridge and parsing errors.
+    pub async fn last_closed_bar(
+        &self,
+        instrument_id: &InstrumentId,
+        bar_type: &BarType,
+    ) -> Result<Option<Bar>, DataClientError> {
+        if bar_type.instrument_id() != *instrument_id || is_tick_aggregated(bar_type) {
+            return Err(DataClientError::InvalidRequest(format!(
+                "{bar_type} is not a time bar type for {instrument_id}"
+            )));
+        }
+        let (price_precision, size_precision) = self
+            .instrument(instrument_id)
+            .map(|instrument| (instrument.price_precision(), instrument.size_precision()))
+            .ok_or_else(|| {
+                DataClientError::InvalidRequest(format!("{instrument_id} has not been loaded"))
+            })?;
 
-    let ts_open = time_sec as u64;
-    let ts_init_ns = UnixNanos::from((ts_open + tf_seconds) * 1_000_000_000);
+        let symbol = self.mt5_symbol(instrument_id);
+        self.ensure_symbol_selected(&symbol).await?;
+        let tf_seconds = bar_type_seconds(bar_type);
+
+        // [symbol, timeframe, start_pos, count]
+        let body = serde_json::json!([symbol, mt5_timeframe(tf_seconds), 0, 2]);
+        let response = self.http_client.copy_rates_from_pos(&body).await?;
+        let rates: Vec<Mt5Rate> = self.http_client.parse_list(response, "copy_rates_from_pos")?;
+        let Some(newest) = rates.last() else {
+            return Ok(None);
+        };
+
+        let tick = unwrap_result(
+            self.http_client
+                .symbol_info_tick(&serde_json::json!([symbol]))
+                .await?,
+        )?;
+        let last_tick_ms = tick
+            .get("time_msc")
+            .and_then(serde_json::Value::as_i64)
+            .or_else(|| tick.get("time").and_then(serde_json::Value::as_i64).map(|t| t * 1000));
+        let newest_closed = last_tick_ms
+            .is_some_and(|ms| ms >= (newest.time + tf_seconds as i64) * 1000);
+
+        let closed = if newest_closed {
+            Some(newest)
+        } else {
+            rates.iter().rev().nth(1)
+        };
+        let ts_init = self.clock.get_time_ns();
+        Ok(closed.and_then(|rate| {
+            parse_bar_row(
+                rate,
+                self.config.volume_source,
+                bar_type,
+                tf_seconds,
+                price_precision,
+                size_precision,
+                ts_init,
+            )
+        }))
+    }
+
+    /// Requests historical bars like [`Self::request_bars`] and reports missing bars.
+    ///
+    /// Intervals where `schedule` has the market closed are not reported as gaps.
+    pub async fn request_bars_with_gaps(
+        &self,
+        bar_type: &BarType,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        price_precision: u8,
+        size_precision: u8,
+        schedule: &TradingSchedule,
+    ) -> Result<BarsWithGaps, DataClientError> {
+        let bars = self
+            .request_bars(
+                bar_type,
+                start,
+                end,
+                price_precision,
+                size_precision,
+                RangeBounds::default(),
+            )
+            .await?;
+        let gaps = detect_bar_gaps(&bars, bar_type_seconds(bar_type), schedule);
+        Ok(BarsWithGaps { bars, gaps })
+    }
+}
+
+/// Maximum number of watchlist `symbol_select` requests in flight at once.
+const WATCHLIST_CONCURRENCY: usize = 8;
+
+/// Outcome of selecting the configured watchlist in Market Watch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WatchlistReport {
+    /// Symbols now selected, in watchlist order.
+    pub selected: Vec<String>,
+    /// Symbols that could not be selected, with the reason, in watchlist order.
+    pub failed: Vec<(String, String)>,
+}
+
+impl Drop for Mt5DataClient {
+    /// Stops tick polling, the connectivity monitor and the keep-alive once the
+    /// last clone of the client is dropped.
+    fn drop(&mut self) {
+        if Arc::strong_count(&self.cancellation_token) == 1 {
+            if let Ok(token) = self.cancellation_token.lock() {
+                token.cancel();
+            }
+        }
+    }
+}
+
+/// Item yielded by a [`TickSubscription`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum TickStreamItem {
+    /// A raw `symbol_info_tick` object.
+    Tick(serde_json::Value),
+    /// The bridge session was re-established; state derived from earlier ticks may be stale.
+    Reconnected,
+}
+
+/// Live tick stream returned by [`Mt5DataClient::subscribe_ticks`].
+///
+/// Dropping the subscription stops polling, as does disconnecting the client.
+#[derive(Debug)]
+pub struct TickSubscription {
+    channel: BoundedChannel<TickStreamItem>,
+    task: tokio::task::JoinHandle<()>,
+    token: CancellationToken,
+}
+
+impl TickSubscription {
+    /// Waits for the next item.
+    pub async fn recv(&self) -> TickStreamItem {
+        self.channel.recv().await
+    }
+
+    /// Returns the next buffered item, if any.
+    pub fn try_recv(&self) -> Option<TickStreamItem> {
+        self.channel.try_recv()
+    }
+
+    /// Returns how many ticks were dropped because the consumer fell behind.
+    pub fn dropped_count(&self) -> u64 {
+        self.channel.dropped_count()
+    }
+
+    /// Stops polling; equivalent to dropping the subscription.
+    pub fn unsubscribe(self) {
+        drop(self);
+    }
+
+    /// Returns whether polling has stopped.
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+}
+
+impl Drop for TickSubscription {
+    fn drop(&mut self) {
+        self.token.cancel();
+    }
+}
+
+/// Which edges of a `[start, end]` bar request are included, by bar open time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangeBounds {
+    pub start_inclusive: bool,
+    pub end_inclusive: bool,
+}
+
+impl Default for RangeBounds {
+    /// Matches `copy_rates_range`, which returns bars opening at either edge.
+    fn default() -> Self {
+        Self {
+            start_inclusive: true,
+            end_inclusive: true,
+        }
+    }
+}
+
+impl RangeBounds {
+    /// Returns whether `value` lies between `start` and `end` under these bounds.
+    pub fn contains(&self, value: i64, start: i64, end: i64) -> bool {
+        let after_start = if self.start_inclusive { value >= start } else { value > start };
+        let before_end = if self.end_inclusive { value <= end } else { value < end };
+        after_start && before_end
+    }
+}
+
+/// Bars returned with the intervals missing from them.
+#[derive(Debug, Clone)]
+pub struct BarsWithGaps {
+    pub bars: Vec<Bar>,
+    /// Missing intervals as `(first missing bar open, last missing bar close)`.
+    pub gaps: Vec<(UnixNanos, UnixNanos)>,
+}
+
+const SECONDS_PER_WEEK: i64 = 7 * 86400;
+/// Offset of the first Monday (1970-01-05) from the Unix epoch.
+const FIRST_MONDAY_SECS: i64 = 4 * 86400;
+
+/// Weekly trading hours in UTC, used to tell feed gaps from market closures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TradingSchedule {
+    /// Closed windows as `[start, end)` offsets in seconds from Monday 00:00 UTC.
+    pub closed: Vec<(i64, i64)>,
+}
+
+impl Default for TradingSchedule {
+    fn default() -> Self {
+        Self::forex()
+    }
+}
+
+impl TradingSchedule {
+    /// A market that never closes (e.g. crypto CFDs).
+    pub fn always_open() -> Self {
+        Self { closed: Vec::new() }
+    }
+
+    /// Forex hours: closed from Friday 22:00 to Sunday 22:00 UTC.
+    pub fn forex() -> Self {
+        Self {
+            closed: vec![(4 * 86400 + 22 * 3600, 6 * 86400 + 22 * 3600)],
+        }
+    }
+
+    /// Returns whether the market is open at `unix_secs`.
+    pub fn is_open(&self, unix_secs: i64) -> bool {
+        let offset = (unix_secs - FIRST_MONDAY_SECS).rem_euclid(SECONDS_PER_WEEK);
+        !self
+            .closed
+            .iter()
+            .any(|(start, end)| offset >= *start && offset < *end)
+    }
+}
+
+/// Finds bars missing between consecutive `bars` while `schedule` has the market open.
+///
+/// Only gaps between returned bars are reported, not before the first or after the last.
+pub fn detect_bar_gaps(
+    bars: &[Bar],
+    tf_seconds: u64,
+    schedule: &TradingSchedule,
+) -> Vec<(UnixNanos, UnixNanos)> {
+    let step = tf_seconds as i64;
+    let opens: Vec<i64> = bars
+        .iter()
+        .map(|bar| (bar.ts_event.as_u64() / 1_000_000_000) as i64 - step)
+        .collect();
+    let to_nanos = |secs: i64| UnixNanos::from(secs as u64 * 1_000_000_000);
+
+    let mut gaps = Vec::new();
+    for pair in opens.windows(2) {
+        let mut gap_start: Option<i64> = None;
+        let mut t = pair[0] + step;
+        while t < pair[1] {
+            match (schedule.is_open(t), gap_start) {
+                (true, None) => gap_start = Some(t),
+                (false, Some(from)) => {
+                    gaps.push((to_nanos(from), to_nanos(t)));
+                    gap_start = None;
+                }
+                _ => {}
+            }
+            t += step;
+        }
+        if let Some(from) = gap_start {
+            gaps.push((to_nanos(from), to_nanos(pair[1])));
+        }
+    }
+    gaps
+}
+
+/// Appends `chunk` to `rows`, skipping ticks already returned by the previous window.
+///
+/// Adjacent windows share their boundary, so ticks at the previous window's last
+/// `time_msc` may be returned twice. Distinct ticks in the same millisecond are kept.
+fn append_dedup_ticks(rows: &mut Vec<Vec<serde_json::Value>>, chunk: Vec<Vec<serde_json::Value>>) {
+    let time_msc = |row: &Vec<serde_json::Value>| row.get(5).and_then(serde_json::Value::as_i64);
+    let Some(last_msc) = rows.last().and_then(time_msc) else {
+        rows.extend(chunk);
+        return;
+    };
+
+    let boundary_start = rows
+        .iter()
+        .rposition(|row| time_msc(row) != Some(last_msc))
+        .map_or(0, |i| i + 1);
+    let boundary: Vec<_> = rows[boundary_start..].to_vec();
+
+    rows.extend(chunk.into_iter().filter(|row| match time_msc(row) {
+        Some(msc) if msc < last_msc => false,
+        Some(msc) if msc == last_msc => !boundary.contains(row),
+        _ => true,
+    }));
+}
+
+/// Returns the `time_msc` of a raw tick row, falling back to its `time` in seconds.
+fn tick_row_time_msc(row: &[serde_json::Value]) -> Option<i64> {
+    row.get(5)
+        .and_then(serde_json::Value::as_i64)
+        .or_else(|| Some(row.first()?.as_i64()? * 1000))
+}
+
+/// Stable-sorts `items` by `ts_event`, warning if the bridge returned them out of order.
+///
+/// Nautilus requires non-decreasing timestamps within a batch; items sharing a
+/// timestamp keep their original order.
+fn sort_by_ts_event<T, K: Ord>(items: &mut [T], ts_event: impl Fn(&T) -> K, what: &str) {
+    if items.windows(2).all(|pair| ts_event(&pair[0]) <= ts_event(&pair[1])) {
+        return;
+    }
+    tracing::warn!("Bridge returned {what} out of order, sorting by timestamp");
+    items.sort_by_key(ts_event);
+}
+
+/// Returns whether the tick's `ask - bid` is wider than `max_spread` (in price units).
+///
+/// Ticks missing either side are let through.
+fn exceeds_spread(tick: &serde_json::Value, max_spread: Option<f64>) -> bool {
+    let (Some(max_spread), Some(bid), Some(ask)) = (
+        max_spread,
+        tick.get("bid").and_then(serde_json::Value::as_f64),
+        tick.get("ask").and_then(serde_json::Value::as_f64),
+    ) else {
+        return false;
+    };
+    // Tolerates float error when the spread sits exactly on the limit
+    ask - bid > max_spread + 1e-12
+}
+
+/// Returns whether `bar_type` is aggregated from ticks rather than served by `copy_rates_*`.
+///
+/// MT5's shortest timeframe is `M1`, so second bars are aggregated from ticks
+/// along with tick and volume bars.
+pub fn is_tick_aggregated(bar_type: &BarType) -> bool {
+    matches!(
+        bar_type.spec().aggregation(),
+        BarAggregation::Tick | BarAggregation::Volume | BarAggregation::Second
+    )
+}
+
+/// Buckets tick rows into second bars of `bar_type` opening in `[start_ts, end_ts)`.
+///
+/// Like `copy_rates_*` bars, each bar is stamped with its close time. Bars still
+/// open at `end_ts` are dropped and intervals without ticks produce no bar.
+fn aggregate_second_bars(
+    rows: &[Vec<serde_json::Value>],
+    bar_type: &BarType,
+    start_ts: i64,
+    end_ts: i64,
+    price_precision: u8,
+    size_precision: u8,
+    ts_init: UnixNanos,
+) -> Vec<Bar> {
+    let step_ns = bar_type_seconds(bar_type).max(1) * 1_000_000_000;
+    let start_ns = start_ts.max(0) as u64 * 1_000_000_000;
+    let end_ns = end_ts.max(0) as u64 * 1_000_000_000;
+    let price_type = bar_type.spec().price_type();
+
+    // Open time -> (open, high, low, close, volume)
+    let mut buckets: BTreeMap<u64, (f64, f64, f64, f64, f64)> = BTreeMap::new();
+    let mut ticks: Vec<(u64, f64, f64)> = rows
+        .iter()
+        .filter_map(|row| {
+            let (price, size) = tick_row_price_size(row, price_type)?;
+            Some((tick_row_ts(row)?.as_u64(), price, size))
+        })
+        .filter(|(ts, _, _)| (start_ns..end_ns).contains(ts))
+        .collect();
+    ticks.sort_by_key(|(ts, _, _)| *ts);
+    for (ts, price, size) in ticks {
+        let open_ns = ts - ts % step_ns;
+        buckets
+            .entry(open_ns)
+            .and_modify(|(_, high, low, close, volume)| {
+                *high = high.max(price);
+                *low = low.min(price);
+                *close = price;
+                *volume += size;
+            })
+            .or_insert((price, price, price, price, size));
+    }
+
+    buckets
+        .into_iter()
+        .filter(|(open_ns, _)| open_ns + step_ns <= end_ns)
+        .filter_map(|(open_ns, (open, high, low, close, volume))| {
+            Some(Bar::new(
+                bar_type.clone(),
+                Price::from_f64(open, price_precision).ok()?,
+                Price::from_f64(high, price_precision).ok()?,
+                Price::from_f64(low, price_precision).ok()?,
+                Price::from_f64(close, price_precision).ok()?,
+                Quantity::from_f64(volume, size_precision).ok()?,
+                UnixNanos::from(open_ns + step_ns),
+                ts_init,
+            ))
+        })
+        .collect()
+}
+
+/// Returns the price and size a tick row contributes to a bar of `price_type`.
+///
+/// Trade rows without a `last` price (quote-only ticks) yield `None`, and fall
+/// back to `volume` when `volume_real` is missing; quote rows count as one unit.
+fn tick_row_price_size(row: &[serde_json::Value], price_type: PriceType) -> Option<(f64, f64)> {
+    let bid = row.get(1)?.as_f64()?;
+    let ask = row.get(2)?.as_f64()?;
+    match price_type {
+        PriceType::Bid => Some((bid, 1.0)),
+        PriceType::Ask => Some((ask, 1.0)),
+        PriceType::Mid => Some(((bid + ask) / 2.0, 1.0)),
+        _ => {
+            let last = row.get(3)?.as_f64().filter(|v| *v > 0.0)?;
+            let volume = row
+                .get(7)
+                .and_then(serde_json::Value::as_f64)
+                .filter(|v| *v > 0.0)
+                .or_else(|| row.get(4)?.as_f64())
+                .filter(|v| *v > 0.0)
+                .unwrap_or(1.0);
+            Some((last, volume))
+        }
+    }
+}
+
+/// Returns the bar interval in seconds, defaulting to one minute for
+/// aggregations MT5 cannot serve natively.
+pub fn bar_type_seconds(bar_type: &BarType) -> u64 {
+    match bar_type.spec().aggregation() {
+        BarAggregation::Second => bar_type.spec().step(),
+        BarAggregation::Minute => bar_type.spec().step() * 60,
+        BarAggregation::Hour => bar_type.spec().step() * 3600,
+        BarAggregation::Day => bar_type.spec().step() * 86400,
+        _ => 60,
+    }
+}
+
+/// Maps a bar interval in seconds to the MT5 `TIMEFRAME_*` value, falling back to M1.
+pub fn mt5_timeframe(tf_seconds: u64) -> u32 {
+    match tf_seconds {
+        60 => 1,
+        300 => 5,
+        900 => 15,
+        1800 => 30,
+        3600 => 16385, // H1
+        14400 => 16388, // H4
+        86400 => 16408, // D1
+        _ => 1, // Fallback M1
+    }
+}
+
+#[cfg(feature = "python-bindings")]
+#[pymethods]
+impl Mt5DataClient {
+    #[new]
+    pub fn py_new(config: Mt5DataClientConfig) -> Result<Self, PyErr> {
+        Self::new(config).map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))
+    }
+
+    #[pyo3(name = "connect")]
+    pub fn py_connect<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            client.connect().await
+                .map_err(to_pyerr)
+        })
+    }
+
+    #[pyo3(name = "disconnect")]
+    pub fn py_disconnect<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            client.disconnect().await
+                .map_err(to_pyerr)
+        })
+    }
+
+    #[pyo3(name = "reconnect")]
+    pub fn py_reconnect<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            client.reconnect().await
+                .map_err(to_pyerr)
+        })
+    }
+
+    #[getter]
+    #[pyo3(name = "connection_state")]
+    pub fn py_connection_state(&self) -> ConnectionState {
+        self.connection_state()
+    }
+
+    #[getter]
+    #[pyo3(name = "max_bars")]
+    pub fn py_max_bars(&self) -> Option<u64> {
+        self.max_bars()
+    }
+
+    #[pyo3(name = "set_http_client")]
+    pub fn py_set_http_client(&mut self, http_client: PyRef<'_, Mt5HttpClient>) {
+        self.set_http_client(&http_client);
+    }
+
+    #[pyo3(name = "set_instrument_provider")]
+    pub fn py_set_instrument_provider(&mut self, provider: PyRef<'_, Mt5InstrumentProvider>) {
+        self.set_instrument_provider(&provider);
+    }
+
+    /// Connects on `async with` entry and returns the client itself.
+    fn __aenter__<'py>(slf: PyRef<'py, Self>, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = (*slf).clone();
+        let this: Py<Self> = slf.into();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            client.connect().await
+                .map_err(to_pyerr)?;
+            Ok(this)
+        })
+    }
+
+    /// Disconnects on `async with` exit; exceptions raised in the block are not suppressed.
+    #[pyo3(signature = (_exc_type=None, _exc_value=None, _traceback=None))]
+    fn __aexit__<'py>(
+        &self,
+        py: Python<'py>,
+        _exc_type: Option<Bound<'py, PyAny>>,
+        _exc_value: Option<Bound<'py, PyAny>>,
+        _traceback: Option<Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            client.disconnect().await
+                .map_err(to_pyerr)?;
+            Ok(false)
+        })
+    }
+
+    #[pyo3(name = "get_symbols")]
+    pub fn py_get_symbols<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let symbols = client.get_symbols().await
+                .map_err(to_pyerr)?;
+            // Return as JSON string
+            serde_json::to_string(&symbols)
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+        })
+    }
+
+    /// Fetches historical bars for a specific instrument.
+    #[pyo3(name = "fetch_bars")]
+    pub fn py_fetch_bars<'py>(&self, py: Python<'py>, symbol: String, timeframe: u32, start_time: i64, count: u32) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            // Construct the request array expected by the MT5 middleware [symbol, timeframe, start_time, count]
+            let body = serde_json::json!([symbol, timeframe, start_time, count]);
+            
+            // Use the underlying http_client to send the request
+            let result = client.http_client.copy_rates_from(&body).await
+                .map_err(to_pyerr)?;
+            
+            // Extract the result list, surfacing a bridge error
+            let bars_value = unwrap_result(result)
+                .map_err(to_pyerr)?;
+
+            // Deserialize into Vec of tuples/arrays to avoid string parsing in Python
+            // MT5 returns: [time, open, high, low, close, tick_volume, spread, real_volume]
+            let bars_raw: Vec<(i64, f64, f64, f64, f64, u64, i32, u64)> = serde_json::from_value(bars_value.clone())
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Failed to parse bars: {}", e)))?;
+
+            // We need to return a Python object. Since we are in an async block that returns a Result<T>,
+            // and T must directly convert to Python object, we can construct the list of dicts here?
+            // No, we cannot access Python GIL here easily to create PyDicts if we are in a separate thread.
+            // But `future_into_py` handles the return value conversion ON THE MAIN THREAD (with GIL).
+            // So we can return a struct that implements IntoPy<PyObject>.
+            
+            Ok(Mt5BarList(bars_raw))
+        })
+    }
+
+    /// Fetches raw rates of `symbol` between `start` and `end` via `copy_rates_range`.
+    ///
+    /// `start` and `end` must be timezone-aware and are sent as server-time epoch
+    /// seconds; see `server_utc_offset_secs`.
+    #[pyo3(name = "copy_rates_range")]
+    pub fn py_copy_rates_range<'py>(
+        &self,
+        py: Python<'py>,
+        symbol: String,
+        timeframe: u32,
+        start: Bound<'py, PyAny>,
+        end: Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let start = self.py_server_time("start", &start)?.timestamp();
+        let end = self.py_server_time("end", &end)?.timestamp();
+        let client = self.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            // [symbol, timeframe, start, end]
+            let body = serde_json::json!([symbol, timeframe, start, end]);
+            let result = client.http_client.copy_rates_range(&body).await
+                .map_err(to_pyerr)?;
+            let bars_value = unwrap_result(result)
+                .map_err(to_pyerr)?;
+            let bars_raw: Vec<(i64, f64, f64, f64, f64, u64, i32, u64)> = serde_json::from_value(bars_value)
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Failed to parse bars: {}", e)))?;
+            Ok(Mt5BarList(bars_raw))
+        })
+    }
+
+    /// Requests historical bars and returns Nautilus Bar objects (Paginated).
+    ///
+    /// When `start` and `end` are given the range is fetched via `request_bars`,
+    /// otherwise the latest `count` bars (default 1000) are returned. `start` and
+    /// `end` must be timezone-aware and are converted to server time with
+    /// `server_utc_offset_secs`.
+    #[pyo3(name = "request_bars")]
+    #[pyo3(signature = (instrument_id, bar_type, instrument, start=None, end=None, count=None))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn py_request_bars<'py>(
+        &self,
+        py: Python<'py>,
+        instrument_id: InstrumentId,
+        bar_type: BarType,
+        instrument: Bound<'py, PyAny>,
+        start: Option<Bound<'py, PyAny>>,
+        end: Option<Bound<'py, PyAny>>,
+        count: Option<u32>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let (price_precision, size_precision) = bar_request_precisions(instrument_id, &bar_type, &instrument)?;
+        let start = start.map(|start| self.py_server_time("start", &start)).transpose()?;
+        let end = end.map(|end| self.py_server_time("end", &end)).transpose()?;
+        let client = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let bars = client
+                .fetch_py_bars(&bar_type, start, end, count, price_precision, size_precision)
+                .await?;
+
+            Python::attach(|py| {
+                let py_bars: PyResult<Vec<_>> = bars.into_iter().map(|bar| bar.into_py_any(py)).collect();
+                Ok(py_bars?)
+            })
+        })
+    }
+
+    /// Requests historical bars like `request_bars` but returns columns instead of objects.
+    ///
+    /// The result is a dict of equal-length lists (`ts_event` in nanoseconds, `open`,
+    /// `high`, `low`, `close`, `volume`) that can be passed straight to numpy or pandas,
+    /// avoiding one Python object per bar on large pulls.
+    #[pyo3(name = "request_bars_arrays")]
+    #[pyo3(signature = (instrument_id, bar_type, instrument, start=None, end=None, count=None))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn py_request_bars_arrays<'py>(
+        &self,
+        py: Python<'py>,
+        instrument_id: InstrumentId,
+        bar_type: BarType,
+        instrument: Bound<'py, PyAny>,
+        start: Option<Bound<'py, PyAny>>,
+        end: Option<Bound<'py, PyAny>>,
+        count: Option<u32>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let (price_precision, size_precision) = bar_request_precisions(instrument_id, &bar_type, &instrument)?;
+        let start = start.map(|start| self.py_server_time("start", &start)).transpose()?;
+        let end = end.map(|end| self.py_server_time("end", &end)).transpose()?;
+        let client = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let bars = client
+                .fetch_py_bars(&bar_type, start, end, count, price_precision, size_precision)
+                .await?;
+            Ok(Mt5BarArrays(bars))
+        })
+    }
+
+    /// Requests historical quote ticks and returns Nautilus `QuoteTick` objects.
+    ///
+    /// `kind` defaults to `TickKind.Info`.
+    #[pyo3(name = "request_quote_ticks")]
+    #[pyo3(signature = (instrument_id, instrument, start, end, kind=None))]
+    pub fn py_request_quote_ticks<'py>(
+        &self,
+        py: Python<'py>,
+        instrument_id: InstrumentId,
+        instrument: Bound<'py, PyAny>,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        kind: Option<TickKind>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        let symbol = self.mt5_symbol(&instrument_id);
+        let price_precision: u8 = instrument.getattr("price_precision")?.extract()?;
+        let size_precision: u8 = instrument.getattr("size_precision")?.extract()?;
+        let volume_min: Option<f64> = match instrument.getattr("min_quantity").ok() {
+            Some(min_quantity) if !min_quantity.is_none() => {
+                Some(min_quantity.call_method0("as_double")?.extract()?)
+            }
+            _ => None,
+        };
+        let size_source = self.config.quote_size_source;
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let rows = client
+                .request_tick_rows(
+                    &symbol,
+                    start.timestamp(),
+                    end.timestamp(),
+                    kind.unwrap_or(TickKind::Info),
+                )
+                .await
+                .map_err(to_pyerr)?;
+
+            let ts_init = client.clock.get_time_ns();
+            let ticks: Vec<QuoteTick> = rows
+                .iter()
+                .filter_map(|row| {
+                    parse_quote_tick_row(
+                        row,
+                        instrument_id,
+                        price_precision,
+                        size_precision,
+                        size_source,
+                        volume_min,
+                        ts_init,
+                    )
+                })
+                .collect();
+
+            Python::attach(|py| {
+                let py_ticks: PyResult<Vec<_>> = ticks.into_iter().map(|tick| tick.into_py_any(py)).collect();
+                Ok(py_ticks?)
+            })
+        })
+    }
+
+    /// Requests historical trade ticks and returns Nautilus `TradeTick` objects.
+    ///
+    /// `kind` defaults to `TickKind.Trade`.
+    #[pyo3(name = "request_trade_ticks")]
+    #[pyo3(signature = (instrument_id, instrument, start, end, kind=None))]
+    pub fn py_request_trade_ticks<'py>(
+        &self,
+        py: Python<'py>,
+        instrument_id: InstrumentId,
+        instrument: Bound<'py, PyAny>,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        kind: Option<TickKind>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        let symbol = self.mt5_symbol(&instrument_id);
+        let price_precision: u8 = instrument.getattr("price_precision")?.extract()?;
+        let size_precision: u8 = instrument.getattr("size_precision")?.extract()?;
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let rows = client
+                .request_tick_rows(
+                    &symbol,
+                    start.timestamp(),
+                    end.timestamp(),
+                    kind.unwrap_or(TickKind::Trade),
+                )
+                .await
+                .map_err(to_pyerr)?;
+
+            let ts_init = client.clock.get_time_ns();
+            let ticks: Vec<TradeTick> = rows
+                .iter()
+                .enumerate()
+                .filter_map(|(i, row)| {
+                    parse_trade_tick_row(row, i, instrument_id, price_precision, size_precision, ts_init)
+                })
+                .collect();
+
+            Python::attach(|py| {
+                let py_ticks: PyResult<Vec<_>> = ticks.into_iter().map(|tick| tick.into_py_any(py)).collect();
+                Ok(py_ticks?)
+            })
+        })
+    }
+}
+
+#[cfg(feature = "python-bindings")]
+impl Mt5DataClient {
+    /// Converts a timezone-aware Python `datetime` to the trade server's clock.
+    ///
+    /// MT5 reads request times as server-time epoch seconds, so the result is the
+    /// UTC instant shifted by `server_utc_offset_secs`. Naive datetimes are
+    /// rejected, as the time zone they were meant in is unknown.
+    fn py_server_time(&self, name: &str, value: &Bound<'_, PyAny>) -> PyResult<DateTime<Utc>> {
+        let aware = value.hasattr("utcoffset")? && !value.call_method0("utcoffset")?.is_none();
+        if !aware {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "{name} must be a timezone-aware datetime, was {value}; \
+                 attach a tzinfo such as datetime.timezone.utc"
+            )));
+        }
+        let time: DateTime<chrono::FixedOffset> = value.extract()?;
+        Ok(time.with_timezone(&Utc)
+            + chrono::Duration::seconds(i64::from(self.config.server_utc_offset_secs)))
+    }
+
+    /// Fetches bars for the Python bindings.
+    ///
+    /// When `start` and `end` are given the range is fetched via `request_bars`,
+    /// otherwise the latest `count` bars (default 1000) are returned.
+    async fn fetch_py_bars(
+        &self,
+        bar_type: &BarType,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+        count: Option<u32>,
+        price_precision: u8,
+        size_precision: u8,
+    ) -> PyResult<Vec<Bar>> {
+        if let (Some(start_dt), Some(end_dt)) = (start, end) {
+            return self
+                .request_bars(
+                    bar_type,
+                    start_dt,
+                    end_dt,
+                    price_precision,
+                    size_precision,
+                    RangeBounds::default(),
+                )
+                .await
+                .map_err(to_pyerr);
+        }
+
+        // Count request
+        let tf_seconds = bar_type_seconds(bar_type);
+        let symbol = self.mt5_symbol(&bar_type.instrument_id());
+        let count_val = count.unwrap_or(1000);
+        let now = Utc::now().timestamp();
+        // [symbol, timeframe, start, count]
+        let body = serde_json::json!([symbol, mt5_timeframe(tf_seconds), now, count_val]);
+
+        let result = self.http_client.copy_rates_from(&body).await
+            .map_err(to_pyerr)?;
+
+        let rates: Vec<Mt5Rate> = self
+            .http_client
+            .parse_list(result, "copy_rates_from")
+            .map_err(to_pyerr)?;
+
+        let mut bars = Vec::new();
+        let ts_init = self.clock.get_time_ns();
+        for rate in rates {
+            if let Some(bar) = parse_bar_row(&rate, self.config.volume_source, bar_type, tf_seconds, price_precision, size_precision, ts_init) {
+                bars.push(bar);
+            }
+        }
+        Ok(bars)
+    }
+}
+
+/// Checks `bar_type` belongs to `instrument_id` and reads the instrument's precisions.
+#[cfg(feature = "python-bindings")]
+fn bar_request_precisions(
+    instrument_id: InstrumentId,
+    bar_type: &BarType,
+    instrument: &Bound<'_, PyAny>,
+) -> PyResult<(u8, u8)> {
+    if bar_type.instrument_id() != instrument_id {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "bar_type instrument {} does not match {}",
+            bar_type.instrument_id(),
+            instrument_id
+        )));
+    }
+    Ok((
+        instrument.getattr("price_precision")?.extract()?,
+        instrument.getattr("size_precision")?.extract()?,
+    ))
+}
+
+/// Bars converted to a dict of columns for `request_bars_arrays`.
+#[cfg(feature = "python-bindings")]
+struct Mt5BarArrays(Vec<Bar>);
+
+#[cfg(feature = "python-bindings")]
+impl<'py> IntoPyObject<'py> for Mt5BarArrays {
+    type Target = pyo3::types::PyDict;
+    type Output = pyo3::Bound<'py, Self::Target>;
+    type Error = pyo3::PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let bars = self.0;
+        let dict = pyo3::types::PyDict::new(py);
+        dict.set_item("ts_event", bars.iter().map(|bar| bar.ts_event.as_u64()).collect::<Vec<_>>())?;
+        dict.set_item("open", bars.iter().map(|bar| bar.open.as_f64()).collect::<Vec<_>>())?;
+        dict.set_item("high", bars.iter().map(|bar| bar.high.as_f64()).collect::<Vec<_>>())?;
+        dict.set_item("low", bars.iter().map(|bar| bar.low.as_f64()).collect::<Vec<_>>())?;
+        dict.set_item("close", bars.iter().map(|bar| bar.close.as_f64()).collect::<Vec<_>>())?;
+        dict.set_item("volume", bars.iter().map(|bar| bar.volume.as_f64()).collect::<Vec<_>>())?;
+        Ok(dict)
+    }
+}
+
+#[cfg(feature = "python-bindings")]
+struct Mt5BarList(Vec<(i64, f64, f64, f64, f64, u64, i32, u64)>);
+
+#[cfg(feature = "python-bindings")]
+impl<'py> IntoPyObject<'py> for Mt5BarList {
+    type Target = pyo3::types::PyList;
+    type Output = pyo3::Bound<'py, Self::Target>;
+    type Error = pyo3::PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let list = pyo3::types::PyList::empty(py);
+        for (time, open, high, low, close, tick_vol, spread, real_vol) in self.0 {
+            let dict = pyo3::types::PyDict::new(py);
+            dict.set_item("time", time)?;
+            dict.set_item("open", open)?;
+            dict.set_item("high", high)?;
+            dict.set_item("low", low)?;
+            dict.set_item("close", close)?;
+            dict.set_item("tick_volume", tick_vol)?;
+            dict.set_item("spread", spread)?;
+            dict.set_item("real_volume", real_vol)?;
+            list.append(dict)?;
+        }
+        Ok(list)
+    }
+}
+
+/// Parses an MT5 rate into a Nautilus `Bar` object.
+///
+/// # Arguments
+///
+/// * `rate` - Rate decoded from either the array or the object encoding
+/// * `volume_source` - Which rate volume becomes the bar volume
+/// * `bar_type` - The bar type specification
+/// * `tf_seconds` - Timeframe in seconds for calculating bar close time
+/// * `price_prec` - Price precision from instrument
+/// * `size_prec` - Size precision from instrument
+/// * `ts_init` - When the bar was parsed, from the client's clock
+///
+/// # Returns
+///
+/// Returns `Some(Bar)` on success, `None` if a value cannot be represented.
+fn parse_bar_row(
+    rate: &Mt5Rate,
+    volume_source: VolumeSource,
+    bar_type: &BarType,
+    tf_seconds: u64,
+    price_prec: u8,
+    size_prec: u8,
+    ts_init: UnixNanos,
+) -> Option<Bar> {
+    let ts_open = u64::try_from(rate.time).ok()?;
+    let ts_event = UnixNanos::from((ts_open + tf_seconds) * 1_000_000_000);
 
     Some(Bar::new(
         bar_type.clone(),
-        Price::from_f64(open, price_prec).ok()?,
-        Price::from_f64(high, price_prec).ok()?,
-        Price::from_f64(low, price_prec).ok()?,
-        Price::from_f64(close, price_prec).ok()?,
-        Quantity::from_f64(tick_vol, size_prec).ok()?,
-        ts_init_ns,
-        ts_init_ns,
+        Price::from_f64(rate.open, price_prec).ok()?,
+        Price::from_f64(rate.high, price_prec).ok()?,
+        Price::from_f64(rate.low, price_prec).ok()?,
+        Price::from_f64(rate.close, price_prec).ok()?,
+        Quantity::from_f64(rate.volume(volume_source), size_prec).ok()?,
+        ts_event,
+        ts_init,
+    ))
+}
+
+/// Returns the tick timestamp in nanoseconds, preferring `time_msc` over `time`.
+fn tick_row_ts(row: &[serde_json::Value]) -> Option<UnixNanos> {
+    let ts_ns = match row.get(5).and_then(serde_json::Value::as_u64) {
+        Some(time_msc) => time_msc * 1_000_000,
+        None => row.first()?.as_u64()? * 1_000_000_000,
+    };
+    Some(UnixNanos::from(ts_ns))
+}
+
+/// Parses a `symbol_info_tick` object into a Nautilus `QuoteTick`.
+///
+/// Both sides carry the size from `size_source`, where tick volume is
+/// `volume_real` when non-zero and `volume` otherwise.
+///
+/// Returns `None` for one-sided ticks and values that cannot be represented.
+fn parse_tick_quote(
+    tick: &serde_json::Value,
+    instrument_id: InstrumentId,
+    price_prec: u8,
+    size_prec: u8,
+    size_source: QuoteSizeSource,
+    volume_min: Option<f64>,
+    ts_init: UnixNanos,
+) -> Option<QuoteTick> {
+    let field = |name: &str| tick.get(name).and_then(serde_json::Value::as_f64);
+    let (bid, ask) = (field("bid")?, field("ask")?);
+    if bid <= 0.0 || ask <= 0.0 {
+        return None;
+    }
+    let ts_event = match tick.get("time_msc").and_then(serde_json::Value::as_u64) {
+        Some(ms) if ms > 0 => ms * 1_000_000,
+        _ => tick.get("time").and_then(serde_json::Value::as_u64)? * 1_000_000_000,
+    };
+    let tick_volume = field("volume_real")
+        .filter(|volume| *volume > 0.0)
+        .or_else(|| field("volume"))
+        .unwrap_or(0.0);
+    let size = quote_size(size_source, tick_volume, volume_min, size_prec)?;
+
+    Some(QuoteTick::new(
+        instrument_id,
+        Price::from_f64(bid, price_prec).ok()?,
+        Price::from_f64(ask, price_prec).ok()?,
+        size,
+        size,
+        UnixNanos::from(ts_event),
+        ts_init,
+    ))
+}
+
+/// Parses a raw MT5 tick row into a Nautilus `QuoteTick`.
+///
+/// MT5 does not report top-of-book sizes, so both sides carry the size from
+/// `size_source`, like [`parse_tick_quote`].
+///
+/// Returns `None` if the row is too short or a price cannot be represented.
+#[cfg(feature = "python-bindings")]
+fn parse_quote_tick_row(
+    row: &[serde_json::Value],
+    instrument_id: InstrumentId,
+    price_prec: u8,
+    size_prec: u8,
+    size_source: QuoteSizeSource,
+    volume_min: Option<f64>,
+    ts_init: UnixNanos,
+) -> Option<QuoteTick> {
+    if row.len() < 3 {
+        return None;
+    }
+
+    let ts = tick_row_ts(row)?;
+    let tick_volume = row
+        .get(7)
+        .and_then(serde_json::Value::as_f64)
+        .filter(|v| *v > 0.0)
+        .or_else(|| row.get(4).and_then(serde_json::Value::as_f64))
+        .unwrap_or(0.0);
+    let size = quote_size(size_source, tick_volume, volume_min, size_prec)?;
+
+    Some(QuoteTick::new(
+        instrument_id,
+        Price::from_f64(row[1].as_f64()?, price_prec).ok()?,
+        Price::from_f64(row[2].as_f64()?, price_prec).ok()?,
+        size,
+        size,
+        ts,
+        ts_init,
+    ))
+}
+
+/// Parses a raw MT5 tick row into a Nautilus `TradeTick`.
+///
+/// Falls back to `volume` when `volume_real` is missing. The trade ID is
+/// synthesized from the timestamp and row index.
+///
+/// Returns `None` if the row is too short, has no `last` price (a quote-only
+/// tick), or a value cannot be represented.
+#[cfg(feature = "python-bindings")]
+fn parse_trade_tick_row(
+    row: &[serde_json::Value],
+    index: usize,
+    instrument_id: InstrumentId,
+    price_prec: u8,
+    size_prec: u8,
+    ts_init: UnixNanos,
+) -> Option<TradeTick> {
+    if row.len() < 5 {
+        return None;
+    }
+
+    let ts = tick_row_ts(row)?;
+    let last = row[3].as_f64().filter(|v| *v > 0.0)?;
+    let volume = row
+        .get(7)
+        .and_then(serde_json::Value::as_f64)
+        .filter(|v| *v > 0.0)
+        .or_else(|| row[4].as_f64())
+        .filter(|v| *v > 0.0)
+        .unwrap_or(1.0);
+
+    Some(TradeTick::new(
+        instrument_id,
+        Price::from_f64(last, price_prec).ok()?,
+        Quantity::from_f64(volume, size_prec).ok()?,
+        AggressorSide::NoAggressor,
+        TradeId::new(&format!("{}_{}", ts.as_u64(), index)),
+        ts,
+        ts_init,
     ))
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::testing::{load_mt5_fixture, mock_bridge::MockMt5Bridge};
+    use chrono::TimeZone;
+    use serde_json::json;
+    use tracing_test::traced_test;
+
+    fn bar_type() -> BarType {
+        BarType::from("EURUSD.MT5-1-MINUTE-LAST-EXTERNAL")
+    }
+
+    #[test]
+    fn test_tick_rows_without_last_are_not_trades() {
+        let quote_only = [json!(1_700_000_000), json!(1.1), json!(1.1002), json!(0.0), json!(0)];
+        let traded = [json!(1_700_000_000), json!(1.1), json!(1.1002), json!(1.1001), json!(3)];
+
+        assert_eq!(tick_row_price_size(&quote_only, PriceType::Last), None);
+        assert_eq!(tick_row_price_size(&quote_only, PriceType::Bid), Some((1.1, 1.0)));
+        assert_eq!(tick_row_price_size(&traded, PriceType::Last), Some((1.1001, 3.0)));
+    }
+
+    #[test]
+    fn test_bar_type_timeframe_mapping() {
+        let seconds = bar_type_seconds(&bar_type());
+        assert_eq!(seconds, 60);
+        assert_eq!(mt5_timeframe(seconds), 1);
+        assert_eq!(mt5_timeframe(3600), 16385);
+        assert_eq!(mt5_timeframe(7), 1);
+    }
+
+    #[tokio::test]
+    async fn test_tick_bars_aggregated_from_trade_ticks() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_select", json!({"result": true}));
+        bridge.respond("copy_ticks_range", load_mt5_fixture("copy_ticks_range_eurusd_trades"));
+        let (addr, handle) = bridge.start().await;
+        let config = Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            ..Default::default()
+        };
+        let client = Mt5DataClient::new(config).unwrap();
+        let bar_type = BarType::from("EURUSD.MT5-100-TICK-LAST-EXTERNAL");
+        let start = Utc.timestamp_opt(1704110400, 0).unwrap();
+        let end = Utc.timestamp_opt(1704110900, 0).unwrap();
+
+        let bars = client
+            .request_bars(&bar_type, start, end, 5, 0, RangeBounds::default())
+            .await
+            .unwrap();
+
+        // 250 ticks make two full bars; the partial third is dropped
+        assert_eq!(bars.len(), 2);
+        let first = &bars[0];
+        assert_eq!(first.bar_type, bar_type);
+        assert_eq!(first.open, Price::from("1.10000"));
+        assert_eq!(first.high, Price::from("1.10120"));
+        assert_eq!(first.low, Price::from("1.10000"));
+        assert_eq!(first.close, Price::from("1.10040"));
+        assert_eq!(first.volume, Quantity::from(199));
+        assert_eq!(first.ts_event, UnixNanos::from(1704110598250 * 1_000_000));
+        assert_eq!(bars[1].open, Price::from("1.10110"));
+        assert_eq!(bars[1].close, Price::from("1.10020"));
+        assert_eq!(bars[1].volume, Quantity::from(200));
+
+        // Ticks are requested as trades and never as rates
+        let requests = bridge.requests_for("copy_ticks_range");
+        assert_eq!(requests, vec![json!(["EURUSD", 1704110400, 1704110900, 2])]);
+        assert!(bridge.requests_for("copy_rates_range").is_empty());
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_second_bars_aggregated_from_ticks() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_select", json!({"result": true}));
+        bridge.respond("copy_ticks_range", load_mt5_fixture("copy_ticks_range_eurusd_trades"));
+        let (addr, handle) = bridge.start().await;
+        let config = Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            ..Default::default()
+        };
+        let client = Mt5DataClient::new(config).unwrap();
+        let bar_type = BarType::from("EURUSD.MT5-5-SECOND-LAST-EXTERNAL");
+        let start = Utc.timestamp_opt(1704110400, 0).unwrap();
+        let end = Utc.timestamp_opt(1704110420, 0).unwrap();
+
+        let bars = client
+            .request_bars(&bar_type, start, end, 5, 0, RangeBounds::default())
+            .await
+            .unwrap();
+
+        // Ticks arrive every 2 seconds, so bars alternate between three and two ticks
+        assert_eq!(bars.len(), 4);
+        let first = &bars[0];
+        assert_eq!(first.bar_type, bar_type);
+        assert_eq!(first.open, Price::from("1.10000"));
+        assert_eq!(first.high, Price::from("1.10070"));
+        assert_eq!(first.low, Price::from("1.10000"));
+        assert_eq!(first.close, Price::from("1.10010"));
+        assert_eq!(first.volume, Quantity::from(6));
+        assert_eq!(first.ts_event, UnixNanos::from(1704110405 * 1_000_000_000));
+        assert_eq!(bars[1].open, Price::from("1.10080"));
+        assert_eq!(bars[1].close, Price::from("1.10020"));
+        assert_eq!(bars[1].volume, Quantity::from(3));
+        assert_eq!(bars[3].ts_event, UnixNanos::from(1704110420 * 1_000_000_000));
+
+        let requests = bridge.requests_for("copy_ticks_range");
+        assert_eq!(requests, vec![json!(["EURUSD", 1704110400, 1704110420, 2])]);
+        assert!(bridge.requests_for("copy_rates_range").is_empty());
+        handle.abort();
+    }
+
+    #[test]
+    fn test_only_tick_volume_and_second_bars_are_tick_aggregated() {
+        assert!(is_tick_aggregated(&BarType::from("EURUSD.MT5-100-TICK-LAST-EXTERNAL")));
+        assert!(is_tick_aggregated(&BarType::from("EURUSD.MT5-50-VOLUME-MID-EXTERNAL")));
+        assert!(is_tick_aggregated(&BarType::from("EURUSD.MT5-5-SECOND-BID-EXTERNAL")));
+        assert!(!is_tick_aggregated(&bar_type()));
+    }
+
+    fn rate(row: serde_json::Value) -> Mt5Rate {
+        serde_json::from_value(row).unwrap()
+    }
+
+    #[test]
+    fn test_parse_bar_row() {
+        let row = json!([1704067200, 1.1, 1.2, 1.0, 1.15, 42]);
+        let bar = parse_bar_row(&rate(row), VolumeSource::Tick, &bar_type(), 60, 5, 0, UnixNanos::default()).unwrap();
+
+        assert_eq!(bar.close.as_f64(), 1.15);
+        assert_eq!(bar.ts_event.as_u64(), (1704067200 + 60) * 1_000_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_fixed_clock_pins_bar_ts_init() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_select", json!({"result": true}));
+        bridge.respond("copy_rates_range", load_mt5_fixture("copy_rates_range_eurusd_m1"));
+        let (addr, handle) = bridge.start().await;
+        let config = Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            ..Default::default()
+        };
+        let mut client = Mt5DataClient::new(config).unwrap();
+        let ts_init = UnixNanos::from(1_700_000_000_000_000_000);
+        client.set_clock(Box::leak(Box::new(AtomicTime::new(false, ts_init))));
+
+        let bars = client
+            .request_bars(
+                &bar_type(),
+                Utc.with_ymd_and_hms(2024, 1, 10, 10, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 10, 11, 0, 0).unwrap(),
+                5,
+                0,
+                RangeBounds::default(),
+            )
+            .await
+            .unwrap();
+
+        assert!(!bars.is_empty());
+        assert!(bars.iter().all(|bar| bar.ts_init == ts_init));
+        assert!(bars.iter().all(|bar| bar.ts_event != ts_init));
+        handle.abort();
+    }
+
+    #[test]
+    fn test_rate_row_too_short() {
+        assert!(serde_json::from_value::<Mt5Rate>(json!([1704067200, 1.1])).is_err());
+    }
+
+    #[test]
+    fn test_array_and_object_rates_produce_identical_bars() {
+        let positional = json!([1704880800, 1.09712, 1.09731, 1.09705, 1.09726, 85, 7, 0]);
+        let named = json!({
+            "time": 1704880800,
+            "open": 1.09712,
+            "high": 1.09731,
+            "low": 1.09705,
+            "close": 1.09726,
+            "tick_volume": 85,
+            "spread": 7,
+            "real_volume": 0
+        });
+
+        assert_eq!(rate(positional.clone()), rate(named.clone()));
+        assert_eq!(
+            parse_bar_row(&rate(positional), VolumeSource::Tick, &bar_type(), 60, 5, 0, UnixNanos::default()),
+            parse_bar_row(&rate(named), VolumeSource::Tick, &bar_type(), 60, 5, 0, UnixNanos::default())
+        );
+    }
+
+    #[test]
+    fn test_bar_volume_source() {
+        let fixture = load_mt5_fixture("copy_rates_range_us30_m1");
+        let rates: Vec<Mt5Rate> = serde_json::from_value(fixture["result"].clone()).unwrap();
+        let volumes = |source| {
+            rates
+                .iter()
+                .map(|rate| parse_bar_row(rate, source, &bar_type(), 60, 1, 0, UnixNanos::default()).unwrap())
+                .map(|bar| bar.volume.as_f64())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(volumes(VolumeSource::Tick), vec![142.0, 97.0]);
+        assert_eq!(volumes(VolumeSource::Real), vec![3850.0, 0.0]);
+        // The second bar has no real volume and falls back to ticks
+        assert_eq!(volumes(VolumeSource::Auto), vec![3850.0, 97.0]);
+    }
+
+    #[tokio::test]
+    async fn test_request_bars_accepts_object_encoded_rates() {
+        let fixture = load_mt5_fixture("copy_rates_range_eurusd_m1");
+        let keys = ["time", "open", "high", "low", "close", "tick_volume", "spread", "real_volume"];
+        let named: Vec<serde_json::Value> = fixture["result"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|row| {
+                let fields = row.as_array().unwrap();
+                serde_json::Value::Object(
+                    keys.iter().map(|k| k.to_string()).zip(fields.iter().cloned()).collect(),
+                )
+            })
+            .collect();
+        let start = Utc.with_ymd_and_hms(2024, 1, 10, 10, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 1, 10, 10, 5, 0).unwrap();
+
+        let mut results = Vec::new();
+        for result in [fixture["result"].clone(), serde_json::Value::Array(named)] {
+            let bridge = MockMt5Bridge::new();
+            bridge.respond("symbol_select", json!({"result": true}));
+            bridge.respond("copy_rates_range", json!({ "result": result }));
+            let (addr, handle) = bridge.start().await;
+            let config = Mt5DataClientConfig {
+                base_url: format!("http://{addr}"),
+                ..Default::default()
+            };
+            let client = Mt5DataClient::new(config).unwrap();
+            results.push(
+                client
+                    .request_bars(&bar_type(), start, end, 5, 0, RangeBounds::default())
+                    .await
+                    .unwrap(),
+            );
+            handle.abort();
+        }
+
+        assert_eq!(results[0].len(), 4);
+        assert_eq!(results[0], results[1]);
+    }
+
+    #[tokio::test]
+    async fn test_request_bars_rejects_inverted_range() {
+        let client = Mt5DataClient::new(Mt5DataClientConfig::default()).unwrap();
+        let start = Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        let result = client
+            .request_bars(&bar_type(), start, end, 5, 0, RangeBounds::default())
+            .await;
+
+        assert!(matches!(result, Err(DataClientError::InvalidRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_connection_state_follows_connect_and_disconnect() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("initialize", json!({"result": true}));
+        bridge.respond("login", json!({"result": true}));
+        let (addr, handle) = bridge.start().await;
+        let config = Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            ..Default::default()
+        };
+        let client = Mt5DataClient::new(config).unwrap();
+        assert_eq!(client.connection_state(), ConnectionState::Disconnected);
+
+        client.connect().await.unwrap();
+        assert_eq!(client.connection_state(), ConnectionState::Connected);
+
+        client.disconnect().await.unwrap();
+        assert_eq!(client.connection_state(), ConnectionState::Disconnected);
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_connected_follows_background_connect() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("initialize", json!({"result": true}));
+        bridge.respond("login", json!({"result": true}));
+        bridge.delay("initialize", std::time::Duration::from_millis(50));
+        let (addr, handle) = bridge.start().await;
+        let client = Mt5DataClient::new(Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            terminal_check_interval_ms: None,
+            ..Default::default()
+        })
+        .unwrap();
+
+        let (connected, waited) = tokio::join!(client.connect(), client.wait_until_connected(5.0));
+
+        connected.unwrap();
+        waited.unwrap();
+        assert_eq!(client.connection_state(), ConnectionState::Connected);
+        handle.abort();
+    }
+
+    async fn connect_with(bridge: &MockMt5Bridge) -> (Mt5DataClient, Result<(), DataClientError>) {
+        let (addr, handle) = bridge.start().await;
+        let client = Mt5DataClient::new(Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            terminal_check_interval_ms: None,
+            ..Default::default()
+        })
+        .unwrap();
+        let result = client.connect().await;
+        handle.abort();
+        (client, result)
+    }
+
+    #[tokio::test]
+    async fn test_connect_reports_unreachable_bridge() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+        let client = Mt5DataClient::new(Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            ..Default::default()
+        })
+        .unwrap();
+
+        let result = client.connect().await;
+
+        assert!(matches!(result, Err(DataClientError::BridgeUnreachable(_))));
+        assert!(result.unwrap_err().to_string().contains("base_url"));
+    }
+
+    #[tokio::test]
+    async fn test_connect_reports_terminal_not_running() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("initialize", json!({"result": false}));
+        bridge.respond("last_error", json!({"result": [-10003, "IPC initialize failed"]}));
+
+        let (client, result) = connect_with(&bridge).await;
+
+        assert!(matches!(
+            result,
+            Err(DataClientError::TerminalNotRunning(message)) if message.contains("IPC initialize failed")
+        ));
+        assert_eq!(client.connection_state(), ConnectionState::Faulted);
+    }
+
+    #[tokio::test]
+    async fn test_connect_reports_invalid_credentials() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("initialize", json!({"result": true}));
+        bridge.respond("login", json!({"result": false}));
+        bridge.respond("last_error", json!({"result": [-6, "Terminal: Authorization failed"]}));
+
+        let (_, result) = connect_with(&bridge).await;
+
+        assert!(matches!(
+            result,
+            Err(DataClientError::InvalidCredentials(message)) if message.contains("Authorization failed")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_connect_reports_terminal_without_broker() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("initialize", json!({"result": true}));
+        bridge.respond("login", json!({"result": true}));
+        bridge.respond("terminal_info", json!({"result": {"connected": false}}));
+
+        let (client, result) = connect_with(&bridge).await;
+
+        assert!(matches!(result, Err(DataClientError::BrokerDisconnected)));
+        assert_eq!(client.connection_state(), ConnectionState::Disconnected);
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_request_bars_beyond_terminal_maxbars_warns_when_short() {
+        // The terminal keeps the newest bars only, so the oldest two are missing
+        let mut rates = load_mt5_fixture("copy_rates_range_eurusd_m1");
+        rates["result"].as_array_mut().unwrap().drain(..2);
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("initialize", json!({"result": true}));
+        bridge.respond("login", json!({"result": true}));
+        bridge.respond("terminal_info", load_mt5_fixture("terminal_info_small_maxbars"));
+        bridge.respond("symbol_select", json!({"result": true}));
+        bridge.respond_once("copy_rates_range", load_mt5_fixture("copy_rates_range_eurusd_m1"));
+        bridge.respond_once("copy_rates_range", rates);
+        let (addr, handle) = bridge.start().await;
+        let client = Mt5DataClient::new(Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            terminal_check_interval_ms: None,
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(client.max_bars(), None);
+        client.connect().await.unwrap();
+        assert_eq!(client.max_bars(), Some(3));
+
+        // Five M1 periods in the range, two more than the terminal keeps
+        let start = Utc.timestamp_opt(1704880800, 0).unwrap();
+        let end = Utc.timestamp_opt(1704881040, 0).unwrap();
+        let complete = client
+            .request_bars(&bar_type(), start, end, 5, 0, RangeBounds::default())
+            .await
+            .unwrap();
+
+        assert_eq!(complete.len(), 4);
+        assert!(!logs_contain("exceed the terminal's maxbars"));
+
+        let short = client
+            .request_bars(&bar_type(), start, end, 5, 0, RangeBounds::default())
+            .await
+            .unwrap();
+
+        assert!(logs_contain("exceed the terminal's maxbars 3"));
+        // The range is requested as is rather than cut relative to `end`
+        assert_eq!(
+            bridge.requests_for("copy_rates_range"),
+            vec![json!(["EURUSD", 1, 1704880800, 1704881040]); 2]
+        );
+        let opens: Vec<u64> = short.iter().map(|bar| bar.ts_event.as_u64() / 1_000_000_000 - 60).collect();
+        assert_eq!(opens, vec![1704880980, 1704881040]);
+        handle.abort();
+    }
+
+    fn warmup_bridge() -> MockMt5Bridge {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("initialize", json!({"result": true}));
+        bridge.respond("login", json!({"result": true}));
+        bridge.respond(
+            "symbols_get",
+            json!([{
+                "symbol": "EURUSD",
+                "digits": 5,
+                "point_size": 0.00001,
+                "volume_min": 0.01,
+                "volume_max": 100.0,
+                "volume_step": 0.01,
+                "contract_size": 100000.0,
+                "margin_initial": null,
+                "margin_maintenance": null,
+                "type": "forex"
+            }]),
+        );
+        bridge
+    }
+
+    async fn warmup_client(warmup: &[&str]) -> (Mt5DataClient, tokio::task::JoinHandle<()>) {
+        warmup_client_on(&warmup_bridge(), warmup).await
+    }
+
+    async fn warmup_client_on(
+        bridge: &MockMt5Bridge,
+        warmup: &[&str],
+    ) -> (Mt5DataClient, tokio::task::JoinHandle<()>) {
+        let (addr, handle) = bridge.start().await;
+        let config = Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            terminal_check_interval_ms: None,
+            warmup_instruments: warmup.iter().map(|id| InstrumentId::from(*id)).collect(),
+            ..Default::default()
+        };
+        (Mt5DataClient::new(config).unwrap(), handle)
+    }
+
+    #[tokio::test]
+    async fn test_connect_selects_watchlist_and_reports_failures() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("initialize", json!({"result": true}));
+        bridge.respond("login", json!({"result": true}));
+        bridge.respond("symbol_select", json!({"result": true}));
+        // Requests run concurrently, so which symbol draws the failure varies
+        bridge.respond_once("symbol_select", json!({"result": false}));
+        let (addr, handle) = bridge.start().await;
+        let watchlist = vec!["EURUSD".to_string(), "GBPUSD".to_string(), "US30".to_string()];
+        let client = Mt5DataClient::new(Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            terminal_check_interval_ms: None,
+            watchlist: watchlist.clone(),
+            ..Default::default()
+        })
+        .unwrap();
+
+        client.connect().await.unwrap();
+        let report = client.watchlist_report().unwrap();
+
+        let mut requested = bridge.requests_for("symbol_select");
+        requested.sort_by_key(|body| body[0].as_str().unwrap().to_string());
+        assert_eq!(
+            requested,
+            watchlist.iter().map(|symbol| json!([symbol, true])).collect::<Vec<_>>()
+        );
+        assert_eq!(report.selected.len(), 2);
+        assert_eq!(report.failed.len(), 1);
+        let (failed, reason) = &report.failed[0];
+        assert!(!report.selected.contains(failed));
+        assert!(reason.contains(failed.as_str()));
+        assert_eq!(client.connection_state(), ConnectionState::Connected);
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_last_closed_bar_skips_forming_bar() {
+        let bridge = warmup_bridge();
+        bridge.respond("symbol_select", json!({"result": true}));
+        bridge.respond("copy_rates_from_pos", load_mt5_fixture("copy_rates_from_pos_eurusd_m1"));
+        // Last tick inside the newest bar, which is still forming
+        bridge.respond(
+            "symbol_info_tick",
+            json!({"result": {"time": 1704881130, "time_msc": 1704881130250_i64}}),
+        );
+        let (client, handle) = warmup_client_on(&bridge, &["EURUSD.MT5"]).await;
+        client.connect().await.unwrap();
+        let instrument_id = InstrumentId::from("EURUSD.MT5");
+
+        let bar = client.last_closed_bar(&instrument_id, &bar_type()).await.unwrap().unwrap();
+
+        assert_eq!(bar.ts_event, UnixNanos::from(1_704_881_100_000_000_000));
+        assert_eq!(bar.close, Price::from("1.09714"));
+        assert_eq!(bridge.requests_for("copy_rates_from_pos"), vec![json!(["EURUSD", 1, 0, 2])]);
+
+        // Once the server clock passes the newest bar's close, that bar is the closed one
+        bridge.respond("symbol_info_tick", json!({"result": {"time": 1704881160}}));
+        let bar = client.last_closed_bar(&instrument_id, &bar_type()).await.unwrap().unwrap();
+        assert_eq!(bar.close, Price::from("1.09719"));
+        handle.abort();
+    }
+
+    async fn request_quote_with(source: QuoteSizeSource, tick: serde_json::Value) -> QuoteTick {
+        let bridge = warmup_bridge();
+        bridge.respond("symbol_select", json!({"result": true}));
+        bridge.respond("symbol_info_tick", json!({"result": tick}));
+        let (addr, handle) = bridge.start().await;
+        let client = Mt5DataClient::new(Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            terminal_check_interval_ms: None,
+            warmup_instruments: vec![InstrumentId::from("EURUSD.MT5")],
+            quote_size_source: source,
+            ..Default::default()
+        })
+        .unwrap();
+        client.connect().await.unwrap();
+
+        let quote = client.request_quote(&InstrumentId::from("EURUSD.MT5")).await.unwrap();
+        assert_eq!(bridge.requests_for("symbol_info_tick"), vec![json!(["EURUSD"])]);
+        handle.abort();
+        quote
+    }
+
+    #[tokio::test]
+    async fn test_request_quote_applies_size_source() {
+        let tick = json!({"bid": 1.10412, "ask": 1.10419, "volume": 3, "time_msc": 1704103200123_i64});
+
+        let quote = request_quote_with(QuoteSizeSource::MinVolume, tick.clone()).await;
+        assert_eq!(quote.bid_price, Price::from("1.10412"));
+        assert_eq!(quote.ask_price, Price::from("1.10419"));
+        assert_eq!(quote.bid_size, Quantity::from("0.01"));
+        assert_eq!(quote.ask_size, Quantity::from("0.01"));
+        assert_eq!(quote.ts_event, UnixNanos::from(1_704_103_200_123_000_000));
+
+        let quote = request_quote_with(QuoteSizeSource::TickVolume, tick).await;
+        assert_eq!(quote.bid_size, Quantity::from("3.00"));
+    }
+
+    #[tokio::test]
+    async fn test_request_quote_zero_size_falls_back_to_one() {
+        let tick = json!({"bid": 1.10412, "ask": 1.10419, "volume": 0, "volume_real": 0.0, "time": 1704103200});
+
+        let quote = request_quote_with(QuoteSizeSource::TickVolume, tick).await;
+
+        assert_eq!(quote.bid_size, Quantity::from("1.00"));
+        assert_eq!(quote.ask_size, Quantity::from("1.00"));
+        assert_eq!(quote.ts_event, UnixNanos::from(1_704_103_200_000_000_000));
+    }
+
+    #[tokio::test]
+    async fn test_connect_warms_up_instruments() {
+        let (client, handle) = warmup_client(&["EURUSD.MT5"]).await;
+        let instrument_id = InstrumentId::from("EURUSD.MT5");
+        assert!(client.instrument(&instrument_id).is_none());
+
+        client.connect().await.unwrap();
+
+        assert_eq!(client.instrument(&instrument_id).unwrap().id(), instrument_id);
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_warmup_uses_configured_provider() {
+        let provider_bridge = warmup_bridge();
+        let (provider_addr, provider_handle) = provider_bridge.start().await;
+        let provider = Mt5InstrumentProvider::new(Mt5InstrumentProviderConfig {
+            base_url: format!("http://{provider_addr}"),
+            ..Default::default()
+        })
+        .unwrap();
+        // The client's own bridge lists no symbols
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("initialize", json!({"result": true}));
+        bridge.respond("login", json!({"result": true}));
+        let (mut client, handle) = warmup_client_on(&bridge, &["EURUSD.MT5"]).await;
+        client.set_instrument_provider(&provider);
+
+        client.connect().await.unwrap();
+
+        let instrument_id = InstrumentId::from("EURUSD.MT5");
+        assert!(provider.instrument(&instrument_id).is_some());
+        assert!(bridge.requests_for("symbols_get").is_empty());
+        handle.abort();
+        provider_handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_connect_fails_when_warmup_instrument_missing() {
+        let (client, handle) = warmup_client(&["EURUSD.MT5", "GBPUSD.MT5"]).await;
+
+        let result = client.connect().await;
+
+        assert!(matches!(
+            result,
+            Err(DataClientError::ConnectionError(message)) if message.contains("GBPUSD.MT5")
+        ));
+        assert_eq!(client.connection_state(), ConnectionState::Disconnected);
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_rejected_symbol_select_fails_request() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_select", json!({"result": false}));
+        bridge.respond("copy_ticks_range", json!({"result": []}));
+        let (addr, handle) = bridge.start().await;
+        let config = Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            ..Default::default()
+        };
+        let client = Mt5DataClient::new(config).unwrap();
+
+        let result = client.request_tick_rows("EURUSDX", 0, 60, TickKind::Info).await;
+
+        assert!(matches!(
+            result,
+            Err(DataClientError::HttpClient(HttpClientError::SelectFailed(symbol))) if symbol == "EURUSDX"
+        ));
+        assert!(bridge.requests_for("copy_ticks_range").is_empty());
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_symbol_select_reissued_after_reconnect() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("initialize", json!({"result": true}));
+        bridge.respond("login", json!({"result": true}));
+        bridge.respond("symbol_select", json!({"result": true}));
+        bridge.respond("copy_ticks_range", json!({"result": []}));
+        let (addr, handle) = bridge.start().await;
+        let config = Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            ..Default::default()
+        };
+        let client = Mt5DataClient::new(config).unwrap();
+        client.connect().await.unwrap();
+
+        client.request_tick_rows("EURUSD", 0, 60, TickKind::Info).await.unwrap();
+        client.request_tick_rows("EURUSD", 60, 120, TickKind::Info).await.unwrap();
+        assert_eq!(bridge.requests_for("symbol_select").len(), 1);
+
+        client.reconnect().await.unwrap();
+        client.request_tick_rows("EURUSD", 120, 180, TickKind::Info).await.unwrap();
+
+        assert_eq!(
+            bridge.requests_for("symbol_select"),
+            vec![json!(["EURUSD", true]), json!(["EURUSD", true])]
+        );
+        handle.abort();
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_request_bars_sorts_unsorted_rates() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_select", json!({"result": true}));
+        bridge.respond("copy_rates_range", load_mt5_fixture("copy_rates_range_eurusd_m1_unsorted"));
+        let (addr, handle) = bridge.start().await;
+        let config = Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            ..Default::default()
+        };
+        let client = Mt5DataClient::new(config).unwrap();
+        let start = Utc.timestamp_opt(1704880800, 0).unwrap();
+        let end = Utc.timestamp_opt(1704881040, 0).unwrap();
+
+        let bars = client
+            .request_bars(&bar_type(), start, end, 5, 0, RangeBounds::default())
+            .await
+            .unwrap();
+
+        let opens: Vec<u64> = bars.iter().map(|bar| bar.ts_event.as_u64() / 1_000_000_000 - 60).collect();
+        assert_eq!(opens, vec![1704880800, 1704880860, 1704880980, 1704881040]);
+        assert!(logs_contain("out of order"));
+        handle.abort();
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_request_bars_empty_and_null_results() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_select", json!({"result": true}));
+        let (addr, handle) = bridge.start().await;
+        let config = Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            warn_on_empty: true,
+            ..Default::default()
+        };
+        let client = Mt5DataClient::new(config).unwrap();
+        let start = Utc.timestamp_opt(1704880800, 0).unwrap();
+        let end = Utc.timestamp_opt(1704881040, 0).unwrap();
+
+        for result in [json!([]), json!(null)] {
+            bridge.respond("copy_rates_range", json!({ "result": result }));
+            let bars = client
+                .request_bars(&bar_type(), start, end, 5, 0, RangeBounds::default())
+                .await
+                .unwrap();
+            assert!(bars.is_empty());
+        }
+        assert!(logs_contain("copy_rates_range returned no rows (result: [])"));
+        assert!(logs_contain("copy_rates_range returned no rows (result: null)"));
+
+        bridge.respond("copy_rates_range", load_mt5_fixture("copy_rates_range_eurusd_m1_unsorted"));
+        let bars = client
+            .request_bars(&bar_type(), start, end, 5, 0, RangeBounds::default())
+            .await
+            .unwrap();
+        assert_eq!(bars.len(), 4);
+
+        bridge.respond("copy_rates_range", json!({"error": [-2, "Invalid params"]}));
+        assert!(client
+            .request_bars(&bar_type(), start, end, 5, 0, RangeBounds::default())
+            .await
+            .is_err());
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_tick_kind_sent_as_copy_flags() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_select", json!({"result": true}));
+        bridge.respond("copy_ticks_range", json!({"result": []}));
+        bridge.respond("copy_ticks_from", json!({"result": [tick(1000, 1.1)]}));
+        let (addr, handle) = bridge.start().await;
+        let config = Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            ..Default::default()
+        };
+        let client = Mt5DataClient::new(config).unwrap();
+
+        for kind in [TickKind::Info, TickKind::Trade, TickKind::All] {
+            client.request_tick_rows("EURUSD", 0, 60, kind).await.unwrap();
+            client.request_ticks_from("EURUSD", 0, 500, kind).await.unwrap();
+        }
+
+        assert_eq!(
+            bridge.requests_for("copy_ticks_range"),
+            vec![
+                json!(["EURUSD", 0, 60, 1]),
+                json!(["EURUSD", 0, 60, 2]),
+                json!(["EURUSD", 0, 60, -1]),
+            ]
+        );
+        assert_eq!(
+            bridge.requests_for("copy_ticks_from"),
+            vec![
+                json!(["EURUSD", 0, 500, 1]),
+                json!(["EURUSD", 0, 500, 2]),
+                json!(["EURUSD", 0, 500, -1]),
+            ]
+        );
+        handle.abort();
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_request_tick_rows_sorts_keeping_same_millisecond_order() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_select", json!({"result": true}));
+        bridge.respond(
+            "copy_ticks_range",
+            json!({"result": [tick(2000, 1.2), tick(1000, 1.1), tick(2000, 1.3), tick(1500, 1.15)]}),
+        );
+        let (addr, handle) = bridge.start().await;
+        let config = Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            ..Default::default()
+        };
+        let client = Mt5DataClient::new(config).unwrap();
+
+        let rows = client.request_tick_rows("EURUSD", 0, 60, TickKind::Info).await.unwrap();
+
+        let bids: Vec<f64> = rows.iter().map(|row| row[1].as_f64().unwrap()).collect();
+        assert_eq!(bids, vec![1.1, 1.15, 1.2, 1.3]);
+        assert!(logs_contain("out of order"));
+        handle.abort();
+    }
+
+    #[test]
+    #[traced_test]
+    fn test_sorted_batch_is_not_reported() {
+        let mut rows: Vec<Vec<serde_json::Value>> = [tick(1000, 1.1), tick(1000, 1.2)]
+            .into_iter()
+            .map(|row| serde_json::from_value(row).unwrap())
+            .collect();
+        sort_by_ts_event(&mut rows, |row| tick_row_time_msc(row), "ticks");
+        assert!(!logs_contain("out of order"));
+    }
+
+    /// Sends a `version` request through every client at once and returns the
+    /// most the bridge handled concurrently.
+    async fn max_overlap(bridge: &MockMt5Bridge, clients: &[Mt5HttpClient]) -> usize {
+        futures_util::future::join_all(clients.iter().map(Mt5HttpClient::version)).await;
+        bridge.max_in_flight()
+    }
+
+    #[tokio::test]
+    async fn test_clients_of_one_bridge_share_nothing_unless_handed_a_client() {
+        use crate::config::{Mt5Config, Mt5ExecutionClientConfig};
+        use crate::execution_client::Mt5ExecutionClient;
+
+        for shared in [false, true] {
+            let bridge = MockMt5Bridge::new();
+            bridge.delay("version", std::time::Duration::from_millis(100));
+            let (addr, handle) = bridge.start().await;
+            let base_url = format!("http://{addr}");
+            let data = Mt5DataClient::new(Mt5DataClientConfig {
+                base_url: base_url.clone(),
+                ..Default::default()
+            })
+            .unwrap();
+            let mut execs: Vec<_> = (0..2)
+                .map(|_| {
+                    Mt5ExecutionClient::new(Mt5ExecutionClientConfig {
+                        base_url: base_url.clone(),
+                        ..Default::default()
+                    })
+                    .unwrap()
+                })
+                .collect();
+            let http = if shared {
+                execs.iter_mut().for_each(|exec| exec.set_http_client(&data.http_client()));
+                data.http_client()
+            } else {
+                Mt5HttpClient::new(Mt5Config::with_base_url(base_url.clone()), base_url).unwrap()
+            };
+
+            let mut clients = vec![data.http_client(), http];
+            clients.extend(execs.iter().map(Mt5ExecutionClient::http_client));
+            let expected = if shared { 1 } else { clients.len() };
+            assert_eq!(max_overlap(&bridge, &clients).await, expected, "shared: {shared}");
+            handle.abort();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_clients_for_distinct_terminals_are_independent() {
+        let mut bridges = Vec::new();
+        let mut clients = Vec::new();
+        for _ in 0..2 {
+            let bridge = MockMt5Bridge::new();
+            bridge.respond("initialize", json!({"result": true}));
+            bridge.respond("login", json!({"result": true}));
+            bridge.respond("symbol_select", json!({"result": true}));
+            let (addr, handle) = bridge.start().await;
+            let config = Mt5DataClientConfig {
+                base_url: format!("http://{addr}"),
+                terminal_check_interval_ms: None,
+                ..Default::default()
+            };
+            clients.push(Mt5DataClient::new(config).unwrap());
+            bridges.push((bridge, handle));
+        }
+        let (first, second) = (&clients[0], &clients[1]);
+
+        first.connect().await.unwrap();
+        first.ensure_symbol_selected("EURUSD").await.unwrap();
+        assert_eq!(second.connection_state(), ConnectionState::Disconnected);
+        assert!(bridges[1].0.requests_for("initialize").is_empty());
+
+        // Market Watch selections are per terminal, so the second still selects
+        second.connect().await.unwrap();
+        second.ensure_symbol_selected("EURUSD").await.unwrap();
+        second.ensure_symbol_selected("GBPUSD").await.unwrap();
+        first.disconnect().await.unwrap();
+
+        assert_eq!(bridges[0].0.requests_for("symbol_select"), vec![json!(["EURUSD", true])]);
+        assert_eq!(
+            bridges[1].0.requests_for("symbol_select"),
+            vec![json!(["EURUSD", true]), json!(["GBPUSD", true])]
+        );
+        assert_eq!(first.connection_state(), ConnectionState::Disconnected);
+        assert_eq!(second.connection_state(), ConnectionState::Connected);
+        for (_, handle) in bridges {
+            handle.abort();
+        }
+    }
+
+    fn tick(time_msc: i64, bid: f64) -> serde_json::Value {
+        json!([time_msc / 1000, bid, bid + 0.0001, 0.0, 0, time_msc, 6, 0.0])
+    }
+
+    #[tokio::test]
+    async fn test_tick_rows_span_three_windows_without_duplicates() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_select", json!({"result": true}));
+        // The bridge returns boundary ticks in both adjacent windows
+        bridge.respond(
+            "copy_ticks_range",
+            json!({"result": [tick(3_600_000, 1.1), tick(3_600_000, 1.2), tick(3_600_500, 1.3)]}),
+        );
+        let (addr, handle) = bridge.start().await;
+        let config = Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            tick_chunk_secs: 3600,
+            ..Default::default()
+        };
+        let client = Mt5DataClient::new(config).unwrap();
+
+        let rows = client.request_tick_rows("EURUSD", 0, 3 * 3600, TickKind::Info).await.unwrap();
+
+        let windows: Vec<_> = bridge
+            .requests_for("copy_ticks_range")
+            .iter()
+            .map(|body| (body[1].as_i64().unwrap(), body[2].as_i64().unwrap()))
+            .collect();
+        assert_eq!(windows, vec![(0, 3600), (3600, 7200), (7200, 10800)]);
+        assert_eq!(
+            rows.into_iter().map(serde_json::Value::from).collect::<Vec<_>>(),
+            vec![tick(3_600_000, 1.1), tick(3_600_000, 1.2), tick(3_600_500, 1.3)]
+        );
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_failed_tick_window_retries_with_backoff() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_select", json!({"result": true}));
+        bridge.fail("copy_ticks_range", 500, "terminal busy");
+        let (addr, handle) = bridge.start().await;
+        let config = Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            tick_chunk_retries: 3,
+            retry_delay_ms: 40,
+            retry_delay_max_ms: 60,
+            ..Default::default()
+        };
+        let client = Mt5DataClient::new(config).unwrap();
+        let started = std::time::Instant::now();
+
+        let result = client.request_tick_rows("EURUSD", 0, 60, TickKind::Info).await;
+
+        // Waits 40ms, then 80ms capped to 60ms, then 60ms again
+        assert!(result.is_err());
+        assert_eq!(bridge.requests_for("copy_ticks_range").len(), 4);
+        assert!(started.elapsed() >= std::time::Duration::from_millis(160));
+        handle.abort();
+    }
+
+    #[test]
+    fn test_append_dedup_ticks_keeps_distinct_same_millisecond_ticks() {
+        let as_row = |v: serde_json::Value| v.as_array().unwrap().clone();
+        let mut rows = vec![as_row(tick(1_000, 1.1))];
+
+        append_dedup_ticks(&mut rows, vec![as_row(tick(1_000, 1.1)), as_row(tick(1_000, 1.2)), as_row(tick(2_000, 1.3))]);
+
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[1], as_row(tick(1_000, 1.2)));
+    }
+
+    #[tokio::test]
+    async fn test_request_bars_with_gaps_reports_missing_mid_session_bar() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_select", json!({"result": true}));
+        bridge.respond("copy_rates_range", load_mt5_fixture("copy_rates_range_eurusd_m1"));
+        let (addr, handle) = bridge.start().await;
+        let config = Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            ..Default::default()
+        };
+        let client = Mt5DataClient::new(config).unwrap();
+        let start = Utc.with_ymd_and_hms(2024, 1, 10, 10, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 1, 10, 10, 5, 0).unwrap();
+
+        let result = client
+            .request_bars_with_gaps(&bar_type(), start, end, 5, 0, &TradingSchedule::forex())
+            .await
+            .unwrap();
+
+        let missing = Utc.with_ymd_and_hms(2024, 1, 10, 10, 2, 0).unwrap().timestamp() as u64;
+        assert_eq!(result.bars.len(), 4);
+        assert_eq!(
+            result.gaps,
+            vec![(
+                UnixNanos::from(missing * 1_000_000_000),
+                UnixNanos::from((missing + 60) * 1_000_000_000)
+            )]
+        );
+        handle.abort();
+    }
+
+    #[test]
+    fn test_detect_bar_gaps_ignores_weekend_close() {
+        let friday_close = Utc.with_ymd_and_hms(2024, 1, 12, 21, 59, 0).unwrap().timestamp();
+        let sunday_open = Utc.with_ymd_and_hms(2024, 1, 14, 22, 0, 0).unwrap().timestamp();
+        let bars: Vec<Bar> = [friday_close, sunday_open]
+            .iter()
+            .map(|t| {
+                let row = json!([t, 1.1, 1.1, 1.1, 1.1, 1]);
+                parse_bar_row(&rate(row), VolumeSource::Tick, &bar_type(), 60, 5, 0, UnixNanos::default()).unwrap()
+            })
+            .collect();
+
+        assert!(detect_bar_gaps(&bars, 60, &TradingSchedule::forex()).is_empty());
+        assert_eq!(detect_bar_gaps(&bars, 60, &TradingSchedule::always_open()).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_ticks_drops_oldest_when_consumer_lags() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_select", json!({"result": true}));
+        let (addr, handle) = bridge.start().await;
+        let config = Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            tick_poll_interval_ms: 5,
+            tick_buffer_capacity: 2,
+            ..Default::default()
+        };
+        let client = Mt5DataClient::new(config).unwrap();
+        let subscription = client.subscribe_ticks("EURUSD").await.unwrap();
+
+        // Nothing consumes while four distinct ticks are published
+        for time_msc in 1..=4 {
+            let polled = bridge.requests_for("symbol_info_tick").len();
+            bridge.respond("symbol_info_tick", json!({"result": tick_quote(time_msc)}));
+            // Two further polls guarantee one was served the new tick
+            while bridge.requests_for("symbol_info_tick").len() < polled + 2 {
+                tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+            }
+        }
+
+        assert_eq!(subscription.dropped_count(), 2);
+        assert_eq!(subscription.try_recv(), Some(TickStreamItem::Tick(tick_quote(3))));
+        assert_eq!(subscription.try_recv(), Some(TickStreamItem::Tick(tick_quote(4))));
+        handle.abort();
+    }
+
+    async fn wait_finished(subscriptions: &[&TickSubscription]) {
+        tokio::time::timeout(std::time::Duration::from_secs(1), async {
+            while !subscriptions.iter().all(|subscription| subscription.is_finished()) {
+                tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("tick polling tasks did not stop");
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_stops_all_tick_subscriptions() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_select", json!({"result": true}));
+        // Polls stay in flight, which must not hold up shutdown
+        bridge.delay("symbol_info_tick", std::time::Duration::from_secs(30));
+        let (addr, handle) = bridge.start().await;
+        let config = Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            tick_poll_interval_ms: 5,
+            ..Default::default()
+        };
+        let client = Mt5DataClient::new(config).unwrap();
+        let eurusd = client.subscribe_ticks("EURUSD").await.unwrap();
+        let gbpusd = client.subscribe_ticks("GBPUSD").await.unwrap();
+        let usdjpy = client.subscribe_ticks("USDJPY").await.unwrap();
+
+        // Unsubscribing stops only that subscription
+        gbpusd.unsubscribe();
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        assert!(!eurusd.is_finished());
+        assert!(!usdjpy.is_finished());
+
+        client.disconnect().await.unwrap();
+        wait_finished(&[&eurusd, &usdjpy]).await;
+
+        // Subscriptions after a disconnect poll again
+        let resumed = client.subscribe_ticks("EURUSD").await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        assert!(!resumed.is_finished());
+
+        drop(client);
+        wait_finished(&[&resumed]).await;
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_drop_stops_connectivity_monitor_and_keep_alive() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("version", json!({"result": [500, 4150, "01 Jan 2024"]}));
+        bridge.respond("terminal_info", json!({"result": {"connected": true}}));
+        let (addr, handle) = bridge.start().await;
+        let client = Mt5DataClient::new(Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            terminal_check_interval_ms: Some(5),
+            keep_alive_interval_ms: Some(5),
+            ..Default::default()
+        })
+        .unwrap();
+        client.start_connectivity_monitor();
+        client.start_keep_alive();
+        let monitor = client.connectivity_monitor.clone();
+        let keep_alive = client.keep_alive.clone();
+        assert!(monitor.is_running() && keep_alive.is_running());
+
+        // Dropped without `disconnect`
+        drop(client);
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        assert!(!monitor.is_running());
+        assert!(!keep_alive.is_running());
+        handle.abort();
+    }
+
+    fn tick_quote(time_msc: i64) -> serde_json::Value {
+        json!({"bid": 1.1, "ask": 1.2, "time_msc": time_msc})
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_ticks_drops_blown_out_spread() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_select", json!({"result": true}));
+        bridge.respond("symbol_info", load_mt5_fixture("symbol_info_eurusd"));
+        // 150 points wide, as around rollover
+        bridge.respond(
+            "symbol_info_tick",
+            json!({"result": {"bid": 1.10000, "ask": 1.10150, "time_msc": 1}}),
+        );
+        let (addr, handle) = bridge.start().await;
+        let config = Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            tick_poll_interval_ms: 5,
+            max_spread_points: Some(1000.0),
+            max_spread_points_by_symbol: HashMap::from([("EURUSD".to_string(), 20.0)]),
+            ..Default::default()
+        };
+        let client = Mt5DataClient::new(config).unwrap();
+        let subscription = client.subscribe_ticks("EURUSD").await.unwrap();
+
+        while bridge.requests_for("symbol_info_tick").len() < 3 {
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        }
+        assert_eq!(subscription.try_recv(), None);
+
+        let normal = json!({"bid": 1.10000, "ask": 1.10012, "time_msc": 2});
+        bridge.respond("symbol_info_tick", json!({"result": normal.clone()}));
+        assert_eq!(subscription.recv().await, TickStreamItem::Tick(normal));
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_ticks_emits_reconnected_before_resumed_ticks() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("initialize", json!({"result": true}));
+        bridge.respond("login", json!({"result": true}));
+        bridge.respond("symbol_select", json!({"result": true}));
+        bridge.respond("symbol_info_tick", json!({"result": tick_quote(1)}));
+        let (addr, handle) = bridge.start().await;
+        let config = Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            tick_poll_interval_ms: 5,
+            ..Default::default()
+        };
+        let client = Mt5DataClient::new(config).unwrap();
+        client.connect().await.unwrap();
+        let subscription = client.subscribe_ticks("EURUSD").await.unwrap();
+        assert_eq!(subscription.recv().await, TickStreamItem::Tick(tick_quote(1)));
+
+        // The terminal drops out, then the session is re-established
+        bridge.fail("symbol_info_tick", 503, "terminal disconnected");
+        client.reconnect().await.unwrap();
+        bridge.respond("symbol_info_tick", json!({"result": tick_quote(1)}));
+
+        assert_eq!(subscription.recv().await, TickStreamItem::Reconnected);
+        // The unchanged quote is re-emitted since the dedup cache was cleared
+        assert_eq!(subscription.recv().await, TickStreamItem::Tick(tick_quote(1)));
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_tick_fetched_across_reconnect_is_not_published() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_select", json!({"result": true}));
+        bridge.respond_delayed(
+            "symbol_info_tick",
+            json!({"result": tick_quote(1)}),
+            std::time::Duration::from_millis(200),
+        );
+        let (addr, handle) = bridge.start().await;
+        let config = Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            tick_poll_interval_ms: 5,
+            ..Default::default()
+        };
+        let client = Mt5DataClient::new(config).unwrap();
+        let subscription = client.subscribe_ticks("EURUSD").await.unwrap();
+
+        // A new session starts while the first poll is still in flight
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        client.connection.next_generation();
+        bridge.respond("symbol_info_tick", json!({"result": tick_quote(2)}));
+
+        assert_eq!(subscription.recv().await, TickStreamItem::Reconnected);
+        assert_eq!(subscription.recv().await, TickStreamItem::Tick(tick_quote(2)));
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_tick_polling_runs_on_configured_runtime() {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .unwrap();
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_select", json!({"result": true}));
+        bridge.respond("symbol_info_tick", json!({"result": tick_quote(1)}));
+        let (addr, handle) = bridge.start().await;
+        let config = Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            tick_poll_interval_ms: 5,
+            ..Default::default()
+        };
+        let mut client = Mt5DataClient::new(config).unwrap();
+        client.set_runtime(runtime.handle().clone());
+
+        let subscription = client.subscribe_ticks("EURUSD").await.unwrap();
+        assert_eq!(subscription.recv().await, TickStreamItem::Tick(tick_quote(1)));
+
+        // Polling stops with the runtime it was spawned on
+        runtime.shutdown_background();
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        let polled = bridge.requests_for("symbol_info_tick").len();
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert_eq!(bridge.requests_for("symbol_info_tick").len(), polled);
+        handle.abort();
+    }
+
+    async fn bar_cache_client(
+        bar_cache_max_entries: usize,
+    ) -> (Mt5DataClient, MockMt5Bridge, tokio::task::JoinHandle<()>) {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_select", json!({"result": true}));
+        bridge.respond("copy_rates_range", load_mt5_fixture("copy_rates_range_eurusd_m1"));
+        let (addr, handle) = bridge.start().await;
+        let config = Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            bar_cache_max_entries,
+            ..Default::default()
+        };
+        (Mt5DataClient::new(config).unwrap(), bridge, handle)
+    }
+
+    fn minute(minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 10, 10, minute, 0).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_bar_cache_exact_hit_skips_bridge() {
+        let (client, bridge, handle) = bar_cache_client(8).await;
+        let request = || {
+            client.request_bars(&bar_type(), minute(0), minute(4), 5, 0, RangeBounds::default())
+        };
+
+        let first = request().await.unwrap();
+        let second = request().await.unwrap();
+        // Times within the same bars share the entry, and bounds apply to the hit
+        let offset = client
+            .request_bars(
+                &bar_type(),
+                minute(0) + chrono::Duration::seconds(20),
+                minute(4) + chrono::Duration::seconds(30),
+                5,
+                0,
+                RangeBounds {
+                    start_inclusive: true,
+                    end_inclusive: false,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(first.len(), 4);
+        assert_eq!(first, second);
+        assert_eq!(offset, first[1..]);
+        assert_eq!(bridge.requests_for("copy_rates_range").len(), 1);
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_bar_cache_partial_hit_fetches_tail_only() {
+        let (client, bridge, handle) = bar_cache_client(8).await;
+
+        let head = client
+            .request_bars(&bar_type(), minute(0), minute(1), 5, 0, RangeBounds::default())
+            .await
+            .unwrap();
+        let full = client
+            .request_bars(&bar_type(), minute(0), minute(4), 5, 0, RangeBounds::default())
+            .await
+            .unwrap();
+
+        assert_eq!(head.len(), 2);
+        assert_eq!(full.len(), 4);
+        assert_eq!(full[..2], head[..]);
+        let requests = bridge.requests_for("copy_rates_range");
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1][2], minute(1).timestamp());
+        assert_eq!(requests[1][3], minute(4).timestamp());
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_bar_cache_disabled_always_hits_bridge() {
+        let (client, bridge, handle) = bar_cache_client(0).await;
+
+        for _ in 0..2 {
+            client
+                .request_bars(&bar_type(), minute(0), minute(4), 5, 0, RangeBounds::default())
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(bridge.requests_for("copy_rates_range").len(), 2);
+        handle.abort();
+    }
+
+    async fn request_fixture_bars(bounds: RangeBounds) -> Vec<Bar> {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_select", json!({"result": true}));
+        bridge.respond("copy_rates_range", load_mt5_fixture("copy_rates_range_eurusd_m1"));
+        let (addr, handle) = bridge.start().await;
+        let config = Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            ..Default::default()
+        };
+        let client = Mt5DataClient::new(config).unwrap();
+        // The fixture's first and last bars open exactly on start and end
+        let start = Utc.with_ymd_and_hms(2024, 1, 10, 10, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 1, 10, 10, 4, 0).unwrap();
+
+        let bars = client
+            .request_bars(&bar_type(), start, end, 5, 0, bounds)
+            .await
+            .unwrap();
+        handle.abort();
+        bars
+    }
+
+    fn open_secs(bars: &[Bar]) -> Vec<u64> {
+        bars.iter()
+            .map(|bar| bar.ts_event.as_u64() / 1_000_000_000 - 60)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_request_bars_keeps_boundary_bars_by_default() {
+        let bars = request_fixture_bars(RangeBounds::default()).await;
+
+        assert_eq!(
+            open_secs(&bars),
+            vec![1704880800, 1704880860, 1704880980, 1704881040]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_bars_drops_exclusive_boundary_bars() {
+        let bars = request_fixture_bars(RangeBounds {
+            start_inclusive: false,
+            end_inclusive: false,
+        })
+        .await;
+
+        assert_eq!(open_secs(&bars), vec![1704880860, 1704880980]);
+    }
+
+    #[tokio::test]
+    async fn test_request_bars_half_open_range() {
+        let bars = request_fixture_bars(RangeBounds {
+            start_inclusive: true,
+            end_inclusive: false,
+        })
+        .await;
+
+        assert_eq!(open_secs(&bars), vec![1704880800, 1704880860, 1704880980]);
+    }
+
+    fn book_client_config(addr: std::net::SocketAddr) -> Mt5DataClientConfig {
+        Mt5DataClientConfig {
+            base_url: format!("http://{addr}"),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_order_book_reads_and_releases_depth() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_select", json!({"result": true}));
+        bridge.respond("market_book_add", json!({"result": true}));
+        bridge.respond(
+            "market_book_get",
+            json!({"result": [
+                {"type": 1, "price": 1.10425, "volume": 5, "volume_dbl": 5.0},
+                {"type": 2, "price": 1.10419, "volume": 3, "volume_dbl": 3.0}
+            ]}),
+        );
+        bridge.respond("market_book_release", json!({"result": true}));
+        let (addr, handle) = bridge.start().await;
+        let client = Mt5DataClient::new(book_client_config(addr)).unwrap();
+
+        let book = client.request_order_book("EURUSD").await.unwrap();
+
+        assert_eq!(book.len(), 2);
+        assert_eq!(book[1].book_type, BOOK_TYPE_BUY);
+        assert_eq!(book[1].volume_dbl, 3.0);
+        assert_eq!(bridge.requests_for("market_book_release"), vec![json!(["EURUSD"])]);
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_request_order_book_reports_unsupported_depth() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_select", json!({"result": true}));
+        bridge.respond("market_book_add", json!({"result": false}));
+        let (addr, handle) = bridge.start().await;
+        let client = Mt5DataClient::new(book_client_config(addr)).unwrap();
+
+        let result = client.request_order_book("US30").await;
+
+        assert!(matches!(
+            result,
+            Err(Mt5Error::DepthUnsupported { symbol }) if symbol == "US30"
+        ));
+        assert!(bridge.requests_for("market_book_get").is_empty());
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_request_order_book_falls_back_to_top_of_book() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_select", json!({"result": true}));
+        bridge.respond("market_book_add", json!({"result": false}));
+        bridge.respond("symbol_info_tick", json!({"result": tick_quote(1)}));
+        let (addr, handle) = bridge.start().await;
+        let config = Mt5DataClientConfig {
+            fallback_to_top_of_book: true,
+            ..book_client_config(addr)
+        };
+        let client = Mt5DataClient::new(config).unwrap();
+
+        let book = client.request_order_book("US30").await.unwrap();
+
+        assert_eq!(
+            book.iter().map(|level| (level.book_type, level.price)).collect::<Vec<_>>(),
+            vec![(BOOK_TYPE_SELL, 1.2), (BOOK_TYPE_BUY, 1.1)]
+        );
+        assert!(bridge.requests_for("market_book_get").is_empty());
+        handle.abort();
+    }
+}
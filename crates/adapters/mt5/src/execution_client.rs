@@ -18,12 +18,107 @@
 //! This module implements the execution client for the MetaTrader 5 adapter,
 //! providing order management and execution functionality.
 
+use crate::account_state::{
+    account_state, closed_position_report, AccountSnapshotDiffer, AccountUpdate,
+};
+use crate::common::connection::{
+    abandon_session, complete_session, establish_session, open_session, ConnectionStateTracker,
+    ConnectionTransition, ConnectivityMonitor, KeepAlive, ReconnectHook,
+};
+use crate::common::enums::{
+    ConnectionState, Mt5DealEntry, Mt5Environment, Mt5ExecutionMode, Mt5MarginMode, Mt5OrderState,
+    Mt5TradeMode, VolumeRounding,
+};
+use crate::common::models::{Mt5AccountInfo, Mt5TradeType};
+use crate::common::runtime::spawn_cancellable;
+use crate::common::parse::{
+    format_price, format_volume, parse_account_balance, parse_price, step_decimals,
+};
 use crate::config::{Mt5Config, Mt5ExecutionClientConfig};
-use crate::http::client::Mt5HttpClient;
+use crate::http::client::{unwrap_result, Mt5HttpClient};
 use crate::http::error::Mt5HttpError as HttpClientError;
-use std::sync::Arc;
+use crate::http::models::{
+    Mt5Deal, Mt5OrderCheckResult, Mt5OrderSendResult, Mt5SymbolInfo, Mt5TradeOrder,
+    Mt5TradePosition,
+};
+use crate::instrument_provider::Mt5InstrumentProvider;
+use crate::common::symbol::SymbolMapper;
+use crate::margin_cache::{MarginCache, MarginCacheKey};
+use crate::trade_transactions::{Mt5TradeTransaction, Mt5TradeTransactionHandler, PendingOrder};
+use dashmap::DashMap;
+use derive_builder::Builder;
+use nautilus_core::{nanos::UnixNanos, UUID4};
+use nautilus_model::{
+    enums::{OrderStatus, TimeInForce},
+    events::{
+        AccountState, OrderAccepted, OrderCancelRejected, OrderCanceled, OrderEventAny,
+        OrderRejected,
+    },
+    identifiers::{
+        AccountId, ClientOrderId, InstrumentId, PositionId, StrategyId, TraderId, VenueOrderId,
+    },
+    instruments::{Instrument, InstrumentAny},
+    reports::PositionStatusReport,
+};
+use serde_json::{json, Value};
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, OnceLock, PoisonError},
+    time::Duration,
+};
+use tokio::sync::broadcast;
+use tokio_util::sync::CancellationToken;
 use thiserror::Error;
 
+/// `TRADE_ACTION_DEAL`: market order.
+const TRADE_ACTION_DEAL: i64 = 1;
+/// `TRADE_ACTION_PENDING`: limit/stop order.
+const TRADE_ACTION_PENDING: i64 = 5;
+/// `TRADE_ACTION_SLTP`: modify the SL/TP of an open position.
+const TRADE_ACTION_SLTP: i64 = 6;
+/// `TRADE_ACTION_REMOVE`: delete a pending order.
+const TRADE_ACTION_REMOVE: i64 = 8;
+/// `ORDER_TIME_GTC`: good till cancelled.
+const ORDER_TIME_GTC: i64 = 0;
+/// `ORDER_TIME_DAY`: good till the end of the trading day.
+const ORDER_TIME_DAY: i64 = 1;
+/// `ORDER_TIME_SPECIFIED`: good till `expiration`.
+const ORDER_TIME_SPECIFIED: i64 = 2;
+/// Longest `comment` MT5 stores unchanged; longer comments are truncated.
+pub const MAX_COMMENT_LEN: usize = 31;
+/// Prefix of the hashed comment used when a `ClientOrderId` cannot be sent as-is.
+const HASHED_COMMENT_PREFIX: &str = "NT-";
+/// `TRADE_RETCODE_NO_MONEY`: not enough money to complete the request.
+const TRADE_RETCODE_NO_MONEY: u32 = 10019;
+/// `TRADE_RETCODE_PLACED`: pending order placed.
+const TRADE_RETCODE_PLACED: u32 = 10008;
+/// `TRADE_RETCODE_DONE`: request completed.
+const TRADE_RETCODE_DONE: u32 = 10009;
+/// `TRADE_RETCODE_INVALID_FILL`: the filling mode is not supported for the symbol.
+const TRADE_RETCODE_INVALID_FILL: u32 = 10030;
+/// `SYMBOL_FILLING_FOK` bit of a symbol's `filling_mode`.
+const SYMBOL_FILLING_FOK: i64 = 1;
+/// `SYMBOL_FILLING_IOC` bit of a symbol's `filling_mode`.
+const SYMBOL_FILLING_IOC: i64 = 2;
+/// `ORDER_FILLING_FOK`: fill completely or cancel; the terminal's default.
+const ORDER_FILLING_FOK: i64 = 0;
+/// `ORDER_FILLING_IOC`: fill what is available and cancel the rest.
+const ORDER_FILLING_IOC: i64 = 1;
+/// `ORDER_FILLING_RETURN`: keep the unfilled remainder working.
+const ORDER_FILLING_RETURN: i64 = 2;
+/// Tolerance when comparing lot volumes.
+const VOLUME_EPSILON: f64 = 1e-9;
+/// Slack, in seconds, on both sides of an order history search window; trade
+/// servers stamp history in broker time, not UTC.
+const HISTORY_SEARCH_MARGIN_SECS: i64 = 86_400;
+/// Lookups made for an order whose response was lost before it is rejected as
+/// never placed; the terminal can take a moment to list a just-placed order.
+const LOST_ORDER_LOOKUP_ATTEMPTS: u32 = 2;
+/// Pause between lookups for orders whose response was lost.
+const LOST_ORDER_RETRY_DELAY: Duration = Duration::from_millis(500);
+/// Capacity of the order event channel shared by all subscribers.
+const ORDER_EVENT_CAPACITY: usize = 1024;
+
 #[derive(Debug, Error)]
 pub enum ExecutionClientError {
     #[error("Connection error: {0}")]
@@ -32,6 +127,33 @@ pub enum ExecutionClientError {
     HttpClient(#[from] HttpClientError),
     #[error("Parse error: {0}")]
     ParseError(String),
+    #[error("Invalid order: {0}")]
+    InvalidOrder(String),
+    #[error("Order rejected (retcode {retcode}): {comment}")]
+    OrderRejected { retcode: u32, comment: String },
+    #[error("{symbol} trade mode {mode} does not allow {action}")]
+    TradeModeRestricted {
+        symbol: String,
+        mode: Mt5TradeMode,
+        action: String,
+    },
+    #[error("{symbol} does not accept market orders ({reason})")]
+    MarketOrderNotAllowed { symbol: String, reason: String },
+    #[error("Symbol {0} does not exist on the trade server")]
+    SymbolNotFound(String),
+    #[error("Symbol {0} is not selected in Market Watch")]
+    SymbolNotSelected(String),
+    #[error("{symbol} does not support filling mode {filling}")]
+    UnsupportedFilling { symbol: String, filling: i64 },
+    #[error("Not connected after {timeout_secs}s (state {state})")]
+    ConnectionTimeout { timeout_secs: f64, state: ConnectionState },
+    #[error("order_send response lost: {0}")]
+    ResponseLost(String),
+    #[error("Account is {actual} but the client requires {required}")]
+    EnvironmentMismatch {
+        required: Mt5Environment,
+        actual: Mt5Environment,
+    },
 }
 
 impl From<String> for ExecutionClientError {
@@ -42,6 +164,8 @@ impl From<String> for ExecutionClientError {
 
 #[cfg(feature = "python-bindings")]
 use pyo3::prelude::*;
+#[cfg(feature = "python-bindings")]
+use crate::common::error::to_pyerr;
 
 #[cfg(feature = "python-bindings")]
 #[derive(Clone, Debug)]
@@ -50,12 +174,92 @@ pub struct Mt5ExecutionClient {
     #[pyo3(get)]
     config: Mt5ExecutionClientConfig,
     http_client: Arc<Mt5HttpClient>,
+    connection: ConnectionStateTracker,
+    connectivity_monitor: ConnectivityMonitor,
+    keep_alive: KeepAlive,
+    instruments: Arc<DashMap<InstrumentId, InstrumentAny>>,
+    symbol_mapper: SymbolMapper,
+    pending_place_requests: Arc<DashMap<ClientOrderId, PendingPlace>>,
+    /// Order events produced outside a call; see [`Mt5ExecutionClient::subscribe_order_events`].
+    order_events: broadcast::Sender<OrderEventAny>,
+    /// Submitted orders, for attributing trade transactions to them.
+    transactions: Arc<Mt5TradeTransactionHandler>,
+    account_feed: Arc<std::sync::Mutex<Option<AccountStateFeed>>>,
+    /// The account's margin mode, fetched on the first reduce-only order.
+    margin_mode: Arc<OnceLock<Mt5MarginMode>>,
+    /// The account's trade mode, read on connect.
+    environment: Arc<OnceLock<Mt5Environment>>,
+    margin_cache: Arc<MarginCache>,
+    /// Runtime background tasks are spawned on; the current runtime if `None`.
+    runtime: Option<tokio::runtime::Handle>,
+    /// Parent of the background tasks' tokens; cancelled and replaced by `disconnect`.
+    cancellation_token: Arc<std::sync::Mutex<CancellationToken>>,
 }
 
 #[cfg(not(feature = "python-bindings"))]
+#[derive(Clone, Debug)]
 pub struct Mt5ExecutionClient {
     pub config: Mt5ExecutionClientConfig,
     http_client: Arc<Mt5HttpClient>,
+    connection: ConnectionStateTracker,
+    connectivity_monitor: ConnectivityMonitor,
+    keep_alive: KeepAlive,
+    instruments: Arc<DashMap<InstrumentId, InstrumentAny>>,
+    symbol_mapper: SymbolMapper,
+    pending_place_requests: Arc<DashMap<ClientOrderId, PendingPlace>>,
+    /// Order events produced outside a call; see [`Mt5ExecutionClient::subscribe_order_events`].
+    order_events: broadcast::Sender<OrderEventAny>,
+    /// Submitted orders, for attributing trade transactions to them.
+    transactions: Arc<Mt5TradeTransactionHandler>,
+    account_feed: Arc<std::sync::Mutex<Option<AccountStateFeed>>>,
+    /// The account's margin mode, fetched on the first reduce-only order.
+    margin_mode: Arc<OnceLock<Mt5MarginMode>>,
+    /// The account's trade mode, read on connect.
+    environment: Arc<OnceLock<Mt5Environment>>,
+    margin_cache: Arc<MarginCache>,
+    /// Runtime background tasks are spawned on; the current runtime if `None`.
+    runtime: Option<tokio::runtime::Handle>,
+    /// Parent of the background tasks' tokens; cancelled and replaced by `disconnect`.
+    cancellation_token: Arc<std::sync::Mutex<CancellationToken>>,
+}
+
+impl Drop for Mt5ExecutionClient {
+    /// Stops account state polling, the connectivity monitor and the keep-alive
+    /// once the last clone of the client is dropped.
+    fn drop(&mut self) {
+        if Arc::strong_count(&self.cancellation_token) == 1 {
+            if let Ok(token) = self.cancellation_token.lock() {
+                token.cancel();
+            }
+        }
+    }
+}
+
+/// Capacity of the account update channel shared by all subscribers.
+const ACCOUNT_UPDATE_CAPACITY: usize = 256;
+
+/// The polling task behind [`Mt5ExecutionClient::subscribe_account_state`].
+#[derive(Debug)]
+struct AccountStateFeed {
+    sender: broadcast::Sender<AccountUpdate>,
+    task: tokio::task::JoinHandle<()>,
+    token: CancellationToken,
+}
+
+/// Identity of an order awaiting its `order_send` response: trader, strategy and instrument.
+pub type PendingPlaceRequest = (TraderId, StrategyId, InstrumentId);
+
+/// An order awaiting its `order_send` response.
+#[derive(Debug, Clone)]
+struct PendingPlace {
+    ids: PendingPlaceRequest,
+    /// The `comment` and `magic` the order was sent with.
+    correlation: OrderCorrelation,
+    /// Unix seconds when the order was sent.
+    sent_at: i64,
+    /// Set when the response was lost in transit; see
+    /// [`Mt5ExecutionClient::reconcile_pending_orders`].
+    response_lost: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -67,6 +271,816 @@ pub struct FillReport {
     pub fill_timestamp: std::time::SystemTime,
     pub commission: f64,
     pub swap: f64,
+    /// Whether the fill opened, closed or reversed its position.
+    pub entry: Mt5DealEntry,
+    /// MT5 position the fill belongs to.
+    pub position_id: u64,
+}
+
+/// Builds fill reports from `history_deals_get` deals.
+///
+/// Balance, credit and other non-trade deals are skipped so they are not
+/// counted as fills. Each report carries the deal's entry direction and
+/// position, which is how netting consumers tell an opening fill from the
+/// fill that closes or reverses the same position.
+pub fn generate_fill_reports(deals: &[Mt5Deal]) -> Vec<FillReport> {
+    deals
+        .iter()
+        .filter(|deal| deal.is_trade())
+        .map(|deal| FillReport {
+            order_id: deal.order.to_string(),
+            fill_id: deal.ticket.to_string(),
+            fill_price: deal.price,
+            fill_quantity: deal.volume,
+            fill_timestamp: std::time::UNIX_EPOCH
+                + std::time::Duration::from_millis(deal.time_msc.max(deal.time * 1000) as u64),
+            commission: deal.commission,
+            swap: deal.swap,
+            entry: deal.entry,
+            position_id: deal.position_id,
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+pub struct Mt5OrderStatusReport {
+    pub order_id: String,
+    pub symbol: String,
+    /// Raw MT5 state the status was derived from.
+    pub state: Mt5OrderState,
+    /// `None` when the terminal reported a state this adapter does not know.
+    pub status: Option<OrderStatus>,
+    pub quantity: f64,
+    pub filled_qty: f64,
+    pub price: f64,
+    pub ts_accepted: std::time::SystemTime,
+}
+
+/// Builds order status reports from `orders_get` orders.
+///
+/// Orders in an unknown state are still reported, with `status` left empty
+/// and a warning logged, so callers can decide how to treat them.
+pub fn generate_order_status_reports(orders: &[Mt5TradeOrder]) -> Vec<Mt5OrderStatusReport> {
+    orders
+        .iter()
+        .map(|order| {
+            let status = order.state.to_order_status();
+            if status.is_none() {
+                tracing::warn!("Order {} has unknown MT5 state {}", order.ticket, order.state);
+            }
+            Mt5OrderStatusReport {
+                order_id: order.ticket.to_string(),
+                symbol: order.symbol.clone(),
+                state: order.state,
+                status,
+                quantity: order.volume_initial,
+                filled_qty: (order.volume_initial - order.volume_current).max(0.0),
+                price: order.price_open,
+                ts_accepted: std::time::UNIX_EPOCH
+                    + std::time::Duration::from_millis(
+                        order.time_setup_msc.max(order.time_setup * 1000) as u64,
+                    ),
+            }
+        })
+        .collect()
+}
+
+/// Venue state gathered by [`Mt5ExecutionClient::reconcile`] for startup reconciliation.
+#[derive(Debug, Clone, Default)]
+pub struct ReconciliationReport {
+    /// Open orders followed by orders that left the book during the lookback.
+    pub order_reports: Vec<Mt5OrderStatusReport>,
+    pub position_reports: Vec<PositionStatusReport>,
+    pub fill_reports: Vec<FillReport>,
+    pub account_state: Option<AccountState>,
+    /// One entry per section that could not be fetched.
+    pub errors: Vec<String>,
+}
+
+/// An order found by [`Mt5ExecutionClient::find_order_by_client_id`], with the deals that filled it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderHistoryMatch {
+    pub order: Mt5TradeOrder,
+    /// Deals executed for the order's ticket, in history order.
+    pub deals: Vec<Mt5Deal>,
+}
+
+/// Realized P&L of one MT5 position, summed over all of its deals.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mt5PositionPnl {
+    pub position_id: u64,
+    pub symbol: String,
+    /// Number of deals that opened, scaled or closed the position.
+    pub deal_count: usize,
+    pub commission: f64,
+    pub swap: f64,
+    pub fee: f64,
+    /// Gross profit booked on the closing deals.
+    pub profit: f64,
+    /// `profit` plus commission, swap and fee.
+    pub net_profit: f64,
+}
+
+/// Aggregates `history_deals_get` deals into realized P&L per position.
+///
+/// Every trade deal of a position is counted, so a position scaled in or out
+/// over several deals reports its total commission and swap. Non-trade deals
+/// are skipped. Results are ordered by position id.
+pub fn aggregate_position_pnl(deals: &[Mt5Deal]) -> Vec<Mt5PositionPnl> {
+    let mut positions: BTreeMap<u64, Mt5PositionPnl> = BTreeMap::new();
+    for deal in deals.iter().filter(|deal| deal.is_trade()) {
+        let pnl = positions
+            .entry(deal.position_id)
+            .or_insert_with(|| Mt5PositionPnl {
+                position_id: deal.position_id,
+                symbol: deal.symbol.clone(),
+                deal_count: 0,
+                commission: 0.0,
+                swap: 0.0,
+                fee: 0.0,
+                profit: 0.0,
+                net_profit: 0.0,
+            });
+        pnl.deal_count += 1;
+        pnl.commission += deal.commission;
+        pnl.swap += deal.swap;
+        pnl.fee += deal.fee;
+        pnl.profit += deal.profit;
+        pnl.net_profit += deal.profit + deal.commission + deal.swap + deal.fee;
+    }
+    positions.into_values().collect()
+}
+
+/// An order to submit through MT5 `order_send`.
+///
+/// Stop-loss and take-profit may each be given either as an absolute price
+/// (`sl`/`tp`) or as a distance in points from the entry price
+/// (`sl_points`/`tp_points`), but not both.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(into))]
+pub struct Mt5OrderRequest {
+    pub symbol: String,
+    pub order_type: Mt5TradeType,
+    pub volume: f64,
+    /// Entry price; required for pending orders, ignored for market orders.
+    #[builder(default)]
+    pub price: Option<f64>,
+    #[builder(default)]
+    pub sl: Option<f64>,
+    #[builder(default)]
+    pub tp: Option<f64>,
+    #[builder(default)]
+    pub sl_points: Option<f64>,
+    #[builder(default)]
+    pub tp_points: Option<f64>,
+    /// Maximum price deviation in points for market orders.
+    #[builder(default = "10")]
+    pub deviation: u32,
+    #[builder(default)]
+    pub magic: u64,
+    #[builder(default)]
+    pub comment: Option<String>,
+    /// Lifetime of a pending order: `Gtc`, `Day`, or `Gtd` with `expire_time`.
+    #[builder(default = "TimeInForce::Gtc")]
+    pub time_in_force: TimeInForce,
+    /// Expiry for `Gtd` pending orders.
+    #[builder(default)]
+    pub expire_time: Option<UnixNanos>,
+    /// Ticket of the position this order closes; `None` opens or adds to a position.
+    #[builder(default)]
+    pub position: Option<u64>,
+    /// Only reduce or close an opposite position in `symbol`, never open one.
+    ///
+    /// On hedging accounts the position to close is chosen when `position` is `None`.
+    #[builder(default)]
+    pub reduce_only: bool,
+    /// Filling mode (`ORDER_FILLING_*`); `None` leaves it to the terminal's default.
+    #[builder(default)]
+    pub type_filling: Option<i64>,
+}
+
+impl Mt5OrderRequest {
+    pub fn builder() -> Mt5OrderRequestBuilder {
+        Mt5OrderRequestBuilder::default()
+    }
+}
+
+impl Mt5OrderRequestBuilder {
+    /// Sets the prices of a stop-limit order from a Nautilus `StopLimit` order:
+    /// the trigger goes to `price` and the limit to `stoplimit`.
+    pub fn stop_limit(&mut self, trigger_price: f64, limit_price: f64) -> &mut Self {
+        self.price(trigger_price).stoplimit(limit_price)
+    }
+}
+
+/// Resolves a protective price given either as an absolute price or as points from `entry`.
+///
+/// Stop-losses sit below the entry for buys and above it for sells; take-profits
+/// sit on the opposite side.
+fn resolve_protective_price(
+    name: &str,
+    price: Option<f64>,
+    points: Option<f64>,
+    entry: f64,
+    symbol_info: &Mt5SymbolInfo,
+    is_buy: bool,
+    is_stop_loss: bool,
+) -> Result<Option<f64>, ExecutionClientError> {
+    match (price, points) {
+        (Some(_), Some(_)) => Err(ExecutionClientError::InvalidOrder(format!(
+            "specify either {name} or {name}_points, not both"
+        ))),
+        (Some(price), None) => Ok(Some(price)),
+        (None, Some(points)) => {
+            if !points.is_finite() || points <= 0.0 {
+                return Err(ExecutionClientError::InvalidOrder(format!(
+                    "{name}_points must be positive, was {points}"
+                )));
+            }
+            let distance = points * symbol_info.point;
+            let below = is_buy == is_stop_loss;
+            let price = if below { entry - distance } else { entry + distance };
+            parse_price(price, symbol_info.digits as u8)
+                .map(Some)
+                .map_err(|e| ExecutionClientError::InvalidOrder(format!("{name}: {e}")))
+        }
+        (None, None) => Ok(None),
+    }
+}
+
+/// Normalizes `volume` to a multiple of the symbol's `volume_step`.
+///
+/// Volumes outside `[volume_min, volume_max]` are clamped when `clamp` is set,
+/// otherwise rejected. A volume that rounds to zero is always rejected.
+pub fn normalize_volume(
+    volume: f64,
+    symbol_info: &Mt5SymbolInfo,
+    rounding: VolumeRounding,
+    clamp: bool,
+) -> Result<f64, ExecutionClientError> {
+    if !volume.is_finite() || volume <= 0.0 {
+        return Err(ExecutionClientError::InvalidOrder(format!(
+            "volume must be positive, was {volume}"
+        )));
+    }
+
+    let step = symbol_info.volume_step;
+    let factor = 10f64.powi(step_decimals(step) as i32);
+    let round_to_step = |v: f64| {
+        // Epsilon guards against 0.3 / 0.1 = 2.9999999999999996
+        let steps = v / step + 1e-9;
+        let steps = match rounding {
+            VolumeRounding::Down => steps.floor(),
+            VolumeRounding::Nearest => steps.round(),
+        };
+        (steps * step * factor).round() / factor
+    };
+
+    let mut normalized = if step > 0.0 { round_to_step(volume) } else { volume };
+    if normalized <= 0.0 {
+        return Err(ExecutionClientError::InvalidOrder(format!(
+            "volume {volume} rounds to zero with volume_step {step}"
+        )));
+    }
+
+    if normalized > symbol_info.volume_max {
+        if !clamp {
+            return Err(ExecutionClientError::InvalidOrder(format!(
+                "volume {volume} exceeds volume_max {}",
+                symbol_info.volume_max
+            )));
+        }
+        normalized = symbol_info.volume_max;
+    } else if normalized < symbol_info.volume_min {
+        if !clamp {
+            return Err(ExecutionClientError::InvalidOrder(format!(
+                "volume {volume} is below volume_min {}",
+                symbol_info.volume_min
+            )));
+        }
+        normalized = symbol_info.volume_min;
+    }
+
+    if normalized != volume {
+        tracing::info!(
+            "Adjusted {} volume from {volume} to {normalized} (step {step}, min {}, max {})",
+            symbol_info.name,
+            symbol_info.volume_min,
+            symbol_info.volume_max
+        );
+    }
+
+    Ok(normalized)
+}
+
+/// Maps a pending order's time in force to MT5 `type_time` and optional `expiration`.
+///
+/// The terminal reads `expiration` as server-time epoch seconds, so the UTC
+/// expire time is shifted by `server_utc_offset_secs`.
+fn resolve_order_time(
+    request: &Mt5OrderRequest,
+    server_utc_offset_secs: i32,
+) -> Result<(i64, Option<u64>), ExecutionClientError> {
+    match request.time_in_force {
+        TimeInForce::Gtc => Ok((ORDER_TIME_GTC, None)),
+        TimeInForce::Day => Ok((ORDER_TIME_DAY, None)),
+        TimeInForce::Gtd => {
+            let expire_time = request.expire_time.ok_or_else(|| {
+                ExecutionClientError::InvalidOrder("GTD orders require an expire_time".to_string())
+            })?;
+            let expire_secs = expire_time.as_u64() / 1_000_000_000;
+            let expiration = expire_secs.saturating_add_signed(i64::from(server_utc_offset_secs));
+            Ok((ORDER_TIME_SPECIFIED, Some(expiration)))
+        }
+        other => Err(ExecutionClientError::InvalidOrder(format!(
+            "time in force {other} is not supported for pending orders"
+        ))),
+    }
+}
+
+/// Returns whether MT5 will store `comment` unchanged.
+///
+/// Comments are limited to [`MAX_COMMENT_LEN`] characters, and the trade server
+/// may replace or drop anything outside printable ASCII.
+pub fn is_valid_comment(comment: &str) -> bool {
+    comment.len() <= MAX_COMMENT_LEN && comment.chars().all(|c| c.is_ascii_graphic() || c == ' ')
+}
+
+/// The `comment` and `magic` that identify an order to MT5.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderCorrelation {
+    pub comment: String,
+    /// Set only when the comment had to be hashed.
+    pub magic: Option<u64>,
+}
+
+impl OrderCorrelation {
+    /// Returns whether `order` carries this comment or, when set, this `magic`.
+    pub fn matches(&self, order: &Mt5TradeOrder) -> bool {
+        order.comment == self.comment || self.magic.is_some_and(|magic| order.magic as u64 == magic)
+    }
+}
+
+/// Derives the correlation fields sent with `order_send` for `client_order_id`.
+///
+/// A `ClientOrderId` that passes [`is_valid_comment`] is sent verbatim as the
+/// comment. Otherwise MT5 would mangle it, so the id is hashed (FNV-1a, stable
+/// across restarts) and masked to 63 bits, since MT5 reports `magic` as a signed
+/// 64-bit integer: the hash goes in `magic` and a short `NT-<hex>` form in the
+/// comment. Trade transactions are then matched on either field.
+pub fn order_correlation(client_order_id: &ClientOrderId) -> OrderCorrelation {
+    let id = client_order_id.as_str();
+    if is_valid_comment(id) {
+        return OrderCorrelation {
+            comment: id.to_string(),
+            magic: None,
+        };
+    }
+
+    let hash = id.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    }) & i64::MAX as u64;
+    OrderCorrelation {
+        comment: format!("{HASHED_COMMENT_PREFIX}{hash:016x}"),
+        magic: Some(hash),
+    }
+}
+
+/// Checks `request` against the symbol's `trade_mode`, so restricted orders fail
+/// before reaching the broker.
+///
+/// Orders closing a `position` or marked `reduce_only` are allowed in every
+/// mode but `Disabled`. Opening orders must be on a side the mode permits.
+pub fn check_trade_mode(
+    request: &Mt5OrderRequest,
+    symbol_info: &Mt5SymbolInfo,
+) -> Result<(), ExecutionClientError> {
+    let mode = symbol_info.trade_mode;
+    let is_buy = request.order_type.is_buy();
+    let (allowed, action) = match request.position {
+        Some(ticket) => (mode.allows_close(), format!("closing position {ticket}")),
+        None if request.reduce_only => (mode.allows_close(), "reducing a position".to_string()),
+        None => (
+            mode.allows_open(is_buy),
+            format!("opening a {} position", if is_buy { "long" } else { "short" }),
+        ),
+    };
+
+    if allowed {
+        Ok(())
+    } else {
+        Err(ExecutionClientError::TradeModeRestricted {
+            symbol: request.symbol.clone(),
+            mode,
+            action,
+        })
+    }
+}
+
+/// How a market order is sent under a symbol's [`Mt5ExecutionMode`].
+///
+/// Every flow ends in a `TRADE_ACTION_DEAL`; they differ in where its price comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarketOrderFlow {
+    /// Request execution: fetch a fresh quote with `symbol_info_tick`, then deal at it.
+    QuoteThenDeal,
+    /// Instant execution: deal at the current price, within `deviation`.
+    PricedDeal,
+    /// Market and exchange execution: deal at the price the server fills.
+    Deal,
+}
+
+/// Returns how a market order on the symbol must be sent.
+///
+/// # Errors
+///
+/// Returns `ExecutionClientError::MarketOrderNotAllowed` if the symbol's trade
+/// mode is `Disabled` or its `order_mode` prohibits market orders (see
+/// [`Mt5SymbolInfo::is_market_order_allowed`]).
+pub fn check_market_order(symbol_info: &Mt5SymbolInfo) -> Result<MarketOrderFlow, ExecutionClientError> {
+    if !symbol_info.is_market_order_allowed() {
+        let reason = if symbol_info.trade_mode == Mt5TradeMode::Disabled {
+            "trading is disabled".to_string()
+        } else {
+            format!("order_mode {:#x} prohibits market orders", symbol_info.order_mode)
+        };
+        return Err(ExecutionClientError::MarketOrderNotAllowed {
+            symbol: symbol_info.name.clone(),
+            reason,
+        });
+    }
+    Ok(match symbol_info.trade_exemode {
+        Mt5ExecutionMode::Request => MarketOrderFlow::QuoteThenDeal,
+        Mt5ExecutionMode::Instant => MarketOrderFlow::PricedDeal,
+        Mt5ExecutionMode::Market | Mt5ExecutionMode::Exchange => MarketOrderFlow::Deal,
+    })
+}
+
+/// Checks the request's `type_filling`, if any, against the symbol's `filling_mode`.
+///
+/// FOK and IOC need their bit in the bitmask. RETURN has no bit and is allowed
+/// except for market orders on symbols in market execution mode.
+pub fn check_filling_mode(
+    request: &Mt5OrderRequest,
+    symbol_info: &Mt5SymbolInfo,
+) -> Result<(), ExecutionClientError> {
+    let Some(filling) = request.type_filling else {
+        return Ok(());
+    };
+    let allowed = match filling {
+        ORDER_FILLING_FOK => symbol_info.filling_mode & SYMBOL_FILLING_FOK != 0,
+        ORDER_FILLING_IOC => symbol_info.filling_mode & SYMBOL_FILLING_IOC != 0,
+        ORDER_FILLING_RETURN => {
+            request.order_type.is_pending()
+                || symbol_info.trade_exemode != Mt5ExecutionMode::Market
+        }
+        _ => false,
+    };
+    if allowed {
+        Ok(())
+    } else {
+        Err(ExecutionClientError::UnsupportedFilling {
+            symbol: request.symbol.clone(),
+            filling,
+        })
+    }
+}
+
+/// Resolves the `position` a `reduce_only` order must name under `margin_mode`.
+///
+/// On hedging accounts an opposite deal without a `position` opens a separate
+/// position, so the oldest opposite position in the symbol is named. On netting
+/// and exchange accounts the deal nets against the single position, so no
+/// ticket is needed, but the order must not exceed it or it would reverse.
+///
+/// # Errors
+///
+/// Returns `ExecutionClientError::InvalidOrder` if there is no opposite position
+/// or the order is larger than the position it reduces.
+pub fn resolve_reduce_position(
+    request: &Mt5OrderRequest,
+    margin_mode: Mt5MarginMode,
+    positions: &[Mt5TradePosition],
+) -> Result<Option<u64>, ExecutionClientError> {
+    let is_buy = request.order_type.is_buy();
+    // A buy reduces a short (`POSITION_TYPE_SELL`) position and a sell a long one
+    let opposite: Vec<&Mt5TradePosition> = positions
+        .iter()
+        .filter(|position| position.symbol == request.symbol && position.is_long() != is_buy)
+        .collect();
+    let side = if is_buy { "short" } else { "long" };
+    let oldest = opposite
+        .iter()
+        .min_by_key(|position| (position.time_msc, position.ticket))
+        .ok_or_else(|| {
+            ExecutionClientError::InvalidOrder(format!(
+                "reduce-only order has no {side} {} position to reduce",
+                request.symbol
+            ))
+        })?;
+
+    let (ticket, open_volume) = if margin_mode.is_hedging() {
+        (Some(oldest.ticket), oldest.volume)
+    } else {
+        (None, opposite.iter().map(|position| position.volume).sum())
+    };
+    if request.volume > open_volume + VOLUME_EPSILON {
+        return Err(ExecutionClientError::InvalidOrder(format!(
+            "reduce-only volume {} exceeds the {open_volume} {side} {} position",
+            request.volume, request.symbol
+        )));
+    }
+    Ok(ticket)
+}
+
+/// Converts a formatted decimal string into a JSON number.
+fn bridge_number(formatted: &str) -> Value {
+    formatted.parse::<f64>().map_or(Value::Null, |n| json!(n))
+}
+
+/// Builds the `order_send` request body for `request` against the symbol specification.
+///
+/// Market orders use the current ask (buy) or bid (sell) as the entry price for
+/// points-based SL/TP. Stop-limit orders send the trigger as `price` and the
+/// limit as `stoplimit`, and measure points-based SL/TP from the limit, where
+/// they fill. A GTD `expiration` is sent in server time, `server_utc_offset_secs`
+/// ahead of UTC.
+pub fn build_order_send_body(
+    request: &Mt5OrderRequest,
+    symbol_info: &Mt5SymbolInfo,
+    server_utc_offset_secs: i32,
+) -> Result<Value, ExecutionClientError> {
+    let is_buy = request.order_type.is_buy();
+    let is_pending = request.order_type.is_pending();
+
+    let entry = if is_pending {
+        request.price.ok_or_else(|| {
+            ExecutionClientError::InvalidOrder("pending orders require a price".to_string())
+        })?
+    } else if is_buy {
+        symbol_info.ask
+    } else {
+        symbol_info.bid
+    };
+    let stoplimit = if request.order_type.is_stop_limit() {
+        Some(request.stoplimit.ok_or_else(|| {
+            ExecutionClientError::InvalidOrder("stop-limit orders require a stoplimit price".to_string())
+        })?)
+    } else {
+        None
+    };
+    let fill_price = stoplimit.unwrap_or(entry);
+
+    let sl = resolve_protective_price("sl", request.sl, request.sl_points, fill_price, symbol_info, is_buy, true)?;
+    let tp = resolve_protective_price("tp", request.tp, request.tp_points, fill_price, symbol_info, is_buy, false)?;
+
+    // Round-trip through the formatted string so no extra decimals reach the bridge
+    let digits = symbol_info.digits;
+    let price_value = |price: f64| bridge_number(&format_price(price, digits));
+
+    let mut body = json!({
+        "action": if is_pending { TRADE_ACTION_PENDING } else { TRADE_ACTION_DEAL },
+        "symbol": request.symbol,
+        "volume": bridge_number(&format_volume(request.volume, symbol_info.volume_step)),
+        "type": request.order_type.as_i64(),
+        "price": price_value(entry),
+        "deviation": request.deviation,
+        "magic": request.magic,
+    });
+    if let Some(stoplimit) = stoplimit {
+        body["stoplimit"] = price_value(stoplimit);
+    }
+    if let Some(sl) = sl {
+        body["sl"] = price_value(sl);
+    }
+    if let Some(tp) = tp {
+        body["tp"] = price_value(tp);
+    }
+    if let Some(position) = request.position {
+        body["position"] = json!(position);
+    }
+    if let Some(type_filling) = request.type_filling {
+        body["type_filling"] = json!(type_filling);
+    }
+    if let Some(comment) = &request.comment {
+        if !is_valid_comment(comment) {
+            return Err(ExecutionClientError::InvalidOrder(format!(
+                "comment '{comment}' must be at most {MAX_COMMENT_LEN} printable ASCII characters"
+            )));
+        }
+        body["comment"] = json!(comment);
+    }
+    if is_pending {
+        let (type_time, expiration) = resolve_order_time(request, server_utc_offset_secs)?;
+        body["type_time"] = json!(type_time);
+        if let Some(expiration) = expiration {
+            body["expiration"] = json!(expiration);
+        }
+    }
+
+    Ok(body)
+}
+
+/// Returns the order filling modes to retry `request` with after `rejected` was refused.
+///
+/// Only modes [`check_filling_mode`] allows for the symbol are offered, FOK and
+/// IOC before RETURN.
+fn fallback_filling_modes(
+    request: &Mt5OrderRequest,
+    symbol_info: &Mt5SymbolInfo,
+    rejected: i64,
+) -> Vec<i64> {
+    let mut candidate = request.clone();
+    [ORDER_FILLING_FOK, ORDER_FILLING_IOC, ORDER_FILLING_RETURN]
+        .into_iter()
+        .filter(|mode| *mode != rejected)
+        .filter(|mode| {
+            candidate.type_filling = Some(*mode);
+            check_filling_mode(&candidate, symbol_info).is_ok()
+        })
+        .collect()
+}
+
+/// Builds the `TRADE_ACTION_SLTP` body moving `position`'s stops.
+///
+/// MT5 replaces both levels, so a `None` side keeps the position's current value
+/// (0 for no stop). Prices are rounded to `digits`.
+pub fn build_sltp_body(position: &Mt5TradePosition, sl: Option<f64>, tp: Option<f64>, digits: u32) -> Value {
+    let price_value = |price: f64| bridge_number(&format_price(price, digits));
+    json!({
+        "action": TRADE_ACTION_SLTP,
+        "symbol": position.symbol,
+        "position": position.ticket,
+        "sl": price_value(sl.unwrap_or(position.sl)),
+        "tp": price_value(tp.unwrap_or(position.tp)),
+        "magic": position.magic,
+    })
+}
+
+/// Fetches one `account_info` and `positions_get` snapshot.
+async fn poll_account_snapshot(
+    http_client: &Mt5HttpClient,
+) -> Result<(Mt5AccountInfo, Vec<Mt5TradePosition>), ExecutionClientError> {
+    let info = serde_json::from_value(unwrap_result(http_client.account_info().await?)?)
+        .map_err(|e| ExecutionClientError::ParseError(e.to_string()))?;
+    Ok((info, fetch_positions(http_client).await?))
+}
+
+async fn fetch_positions(http_client: &Mt5HttpClient) -> Result<Vec<Mt5TradePosition>, ExecutionClientError> {
+    let response = http_client.positions_get().await?;
+    Ok(http_client.parse_list(response, "positions_get")?)
+}
+
+async fn fetch_history_orders(
+    http_client: &Mt5HttpClient,
+    start: i64,
+    end: i64,
+) -> Result<Vec<Mt5TradeOrder>, ExecutionClientError> {
+    let response = http_client.history_orders_get(&json!([start, end])).await?;
+    Ok(http_client.parse_list(response, "history_orders_get")?)
+}
+
+/// Runs [`reconcile_lost_orders`] for a re-established session, logging rather
+/// than returning a failed lookup so the orders stay pending for the next one.
+async fn reconcile_after_reconnect(
+    http_client: &Mt5HttpClient,
+    pending_place_requests: &DashMap<ClientOrderId, PendingPlace>,
+    transactions: &Mt5TradeTransactionHandler,
+) -> Vec<OrderEventAny> {
+    match reconcile_lost_orders(http_client, pending_place_requests, transactions).await {
+        Ok(events) => events,
+        Err(e) => {
+            tracing::warn!("Could not reconcile orders with lost responses: {e}");
+            Vec::new()
+        }
+    }
+}
+
+/// See [`Mt5ExecutionClient::reconcile_pending_orders`].
+async fn reconcile_lost_orders(
+    http_client: &Mt5HttpClient,
+    pending_place_requests: &DashMap<ClientOrderId, PendingPlace>,
+    transactions: &Mt5TradeTransactionHandler,
+) -> Result<Vec<OrderEventAny>, ExecutionClientError> {
+    let account_id = transactions.account_id();
+    let mut unresolved: Vec<(ClientOrderId, PendingPlace)> = pending_place_requests
+        .iter()
+        .filter(|entry| entry.response_lost)
+        .map(|entry| (*entry.key(), entry.value().clone()))
+        .collect();
+    let Some(earliest) = unresolved.iter().map(|(_, pending)| pending.sent_at).min() else {
+        return Ok(Vec::new());
+    };
+
+    let mut events = Vec::with_capacity(unresolved.len());
+    for attempt in 1..=LOST_ORDER_LOOKUP_ATTEMPTS {
+        if attempt > 1 {
+            tokio::time::sleep(LOST_ORDER_RETRY_DELAY).await;
+        }
+        let ts_init = nautilus_core::time::get_atomic_clock_realtime().get_time_ns();
+        let now = (ts_init.as_u64() / 1_000_000_000) as i64;
+        let mut orders = http_client.orders_get_typed().await?;
+        orders.extend(
+            fetch_history_orders(
+                http_client,
+                earliest - HISTORY_SEARCH_MARGIN_SECS,
+                now + HISTORY_SEARCH_MARGIN_SECS,
+            )
+            .await?,
+        );
+
+        let mut missing = Vec::new();
+        for (client_order_id, pending) in unresolved {
+            let Some(order) = orders.iter().find(|order| pending.correlation.matches(order)) else {
+                missing.push((client_order_id, pending));
+                continue;
+            };
+            // Skip orders resolved concurrently, e.g. by a late response
+            if pending_place_requests.remove(&client_order_id).is_none() {
+                continue;
+            }
+            events.push(if order.state == Mt5OrderState::Rejected {
+                transactions.unregister(&client_order_id);
+                rejected_event(account_id, pending.ids, client_order_id, "rejected by MT5", ts_init)
+            } else {
+                tracing::info!("Order {client_order_id} found as ticket {} after reconnect", order.ticket);
+                transactions.bind_ticket(&client_order_id, order.ticket);
+                accepted_event(
+                    account_id,
+                    pending.ids,
+                    client_order_id,
+                    VenueOrderId::new(order.ticket.to_string()),
+                    ts_init,
+                )
+            });
+        }
+        unresolved = missing;
+        if unresolved.is_empty() {
+            break;
+        }
+    }
+
+    let ts_init = nautilus_core::time::get_atomic_clock_realtime().get_time_ns();
+    for (client_order_id, pending) in unresolved {
+        if pending_place_requests.remove(&client_order_id).is_none() {
+            continue;
+        }
+        tracing::warn!(
+            "Order {client_order_id} not found after {LOST_ORDER_LOOKUP_ATTEMPTS} lookups, rejecting"
+        );
+        transactions.unregister(&client_order_id);
+        events.push(rejected_event(
+            account_id,
+            pending.ids,
+            client_order_id,
+            "order_send response lost and no matching order found",
+            ts_init,
+        ));
+    }
+    Ok(events)
+}
+
+fn accepted_event(
+    account_id: AccountId,
+    (trader_id, strategy_id, instrument_id): PendingPlaceRequest,
+    client_order_id: ClientOrderId,
+    venue_order_id: VenueOrderId,
+    ts_init: UnixNanos,
+) -> OrderEventAny {
+    OrderEventAny::Accepted(OrderAccepted::new(
+        trader_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        venue_order_id,
+        account_id,
+        UUID4::new(),
+        ts_init,
+        ts_init,
+        false,
+    ))
+}
+
+fn rejected_event(
+    account_id: AccountId,
+    (trader_id, strategy_id, instrument_id): PendingPlaceRequest,
+    client_order_id: ClientOrderId,
+    reason: &str,
+    ts_init: UnixNanos,
+) -> OrderEventAny {
+    OrderEventAny::Rejected(OrderRejected::new(
+        trader_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        account_id,
+        ustr::Ustr::from(reason),
+        UUID4::new(),
+        ts_init,
+        ts_init,
+        false,
+        false,
+    ))
 }
 
 impl Mt5ExecutionClient {
@@ -84,83 +1098,2778 @@ impl Mt5ExecutionClient {
             base_url: config.base_url.clone(),
             http_timeout: config.http_timeout,
             proxy: None,
+            min_build: config.min_build,
+            strict_parsing: config.strict_parsing,
+            ..Default::default()
         };
         
         let http_client = Arc::new(Mt5HttpClient::new(
             http_config,
             config.base_url.clone(),
         ).map_err(|e| ExecutionClientError::ConnectionError(e.to_string()))?);
+        let account_id = AccountId::new(format!("MT5-{}", config.credential.login));
+        let symbol_mapper = config.symbol_mapper()?;
 
         Ok(Self {
             config,
             http_client,
+            connection: ConnectionStateTracker::new(),
+            connectivity_monitor: ConnectivityMonitor::default(),
+            keep_alive: KeepAlive::default(),
+            instruments: Arc::new(DashMap::new()),
+            symbol_mapper,
+            pending_place_requests: Arc::new(DashMap::new()),
+            order_events: broadcast::channel(ORDER_EVENT_CAPACITY).0,
+            transactions: Arc::new(Mt5TradeTransactionHandler::new(account_id)),
+            account_feed: Arc::new(std::sync::Mutex::new(None)),
+            margin_mode: Arc::new(OnceLock::new()),
+            environment: Arc::new(OnceLock::new()),
+            margin_cache: Arc::new(MarginCache::new(
+                config.margin_cache_max_entries,
+                Duration::from_millis(config.margin_cache_ttl_ms),
+            )),
+            runtime: None,
+            cancellation_token: Arc::new(std::sync::Mutex::new(CancellationToken::new())),
         })
     }
 
+    /// Spawns the client's background tasks (account polling, connectivity monitor
+    /// and keep-alive) on `runtime`, e.g. a dedicated runtime isolating the
+    /// adapter's I/O. Tasks already running stay where they are.
+    pub fn set_runtime(&mut self, runtime: tokio::runtime::Handle) {
+        self.runtime = Some(runtime);
+    }
+
+    /// Returns the account id events are tagged with, derived from the login.
+    pub fn account_id(&self) -> AccountId {
+        self.transactions.account_id()
+    }
+
+    /// Sends requests through `http_client`, e.g. one shared with another client of
+    /// the same bridge so that `Serialized` access holds across both. Its own
+    /// config (timeouts, retries) then applies instead of this client's.
+    pub fn set_http_client(&mut self, http_client: &Mt5HttpClient) {
+        self.http_client = Arc::new(http_client.clone());
+    }
+
+    /// Returns the HTTP client requests are sent through.
+    pub fn http_client(&self) -> Mt5HttpClient {
+        (*self.http_client).clone()
+    }
+
+    /// Shares `provider`'s instrument cache, so instruments it loads become visible here.
+    pub fn set_instrument_provider(&mut self, provider: &Mt5InstrumentProvider) {
+        self.instruments = provider.instruments_cache();
+    }
+
+    /// Returns a cached instrument, or `None` if no provider has loaded it.
+    pub fn instrument(&self, instrument_id: &InstrumentId) -> Option<InstrumentAny> {
+        self.instruments.get(instrument_id).map(|entry| entry.value().clone())
+    }
+
     /// Establishes a connection to the MT5 server.
     ///
     /// # Returns
     ///
     /// A result indicating success or failure.
+    ///
+    /// Also starts the terminal connectivity monitor unless `terminal_check_interval_ms` is `None`,
+    /// and the idle keep-alive when `keep_alive_interval_ms` is set.
+    ///
+    /// The account's environment is read from `account_info` after login, before the
+    /// session is reported `Connected`; when it differs from `require_environment`,
+    /// the bridge session is shut down and connecting fails with
+    /// `ExecutionClientError::EnvironmentMismatch`.
     pub async fn connect(&self) -> Result<(), ExecutionClientError> {
-        // Connect HTTP
-        let _response = self.http_client.login().await
+        open_session(&self.http_client, &self.connection, false)
+            .await
             .map_err(|e| ExecutionClientError::ConnectionError(e.to_string()))?;
+        if let Err(e) = self.check_environment().await {
+            abandon_session(&self.http_client, &self.connection).await;
+            return Err(e);
+        }
+        complete_session(&self.connection);
+        self.start_connectivity_monitor();
+        self.start_keep_alive();
 
         tracing::info!("MT5 execution client connected");
 
         Ok(())
     }
 
-    /// Disconnects from the MT5 server.
+    /// Reads the account's environment and checks it against `require_environment`.
+    ///
+    /// Without a requirement, an `account_info` that cannot be read or lacks
+    /// `trade_mode` only leaves [`Self::environment`] unset.
+    async fn check_environment(&self) -> Result<(), ExecutionClientError> {
+        let info = async {
+            let response = self.http_client.account_info().await?;
+            serde_json::from_value::<Mt5AccountInfo>(unwrap_result(response)?)
+                .map_err(|e| ExecutionClientError::ParseError(e.to_string()))
+        }
+        .await;
+        let actual = match info {
+            Ok(info) => info.trade_mode,
+            Err(e) if self.config.require_environment.is_some() => return Err(e),
+            Err(e) => {
+                tracing::warn!("Could not read the account environment: {e}");
+                None
+            }
+        };
+        if let Some(actual) = actual {
+            let _ = self.environment.set(actual);
+            tracing::info!("MT5 account environment is {actual}");
+        }
+
+        match (self.config.require_environment, actual) {
+            (Some(required), Some(actual)) if required != actual => {
+                Err(ExecutionClientError::EnvironmentMismatch { required, actual })
+            }
+            (Some(required), None) => Err(ExecutionClientError::ConnectionError(format!(
+                "account_info has no trade_mode, so the required {required} environment cannot be confirmed"
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns whether the account is demo, contest or real, as read on connect.
+    pub fn environment(&self) -> Option<Mt5Environment> {
+        self.environment.get().copied()
+    }
+
+    /// Re-establishes the bridge session after a fault.
+    ///
+    /// Orders whose `order_send` response was lost before the fault are then
+    /// resolved by [`Self::reconcile_pending_orders`], and the resulting events
+    /// returned. If that lookup fails, they stay pending for the next reconnect.
+    /// Sessions the connectivity monitor re-establishes on its own are reconciled
+    /// the same way, with the events sent to [`Self::subscribe_order_events`].
+    pub async fn reconnect(&self) -> Result<Vec<OrderEventAny>, ExecutionClientError> {
+        establish_session(&self.http_client, &self.connection, true)
+            .await
+            .map_err(|e| ExecutionClientError::ConnectionError(e.to_string()))?;
+        self.start_connectivity_monitor();
+        self.start_keep_alive();
+
+        tracing::info!("MT5 execution client reconnected");
+
+        Ok(reconcile_after_reconnect(&self.http_client, &self.pending_place_requests, &self.transactions).await)
+    }
+
+    /// Disconnects from the MT5 server, stopping account state polling.
     ///
     /// # Returns
     ///
     /// A result indicating success or failure.
     pub async fn disconnect(&self) -> Result<(), ExecutionClientError> {
+        self.cancel_subscriptions();
+        self.connectivity_monitor.stop();
+        self.keep_alive.stop();
+        self.connection.transition(ConnectionState::Disconnected);
         Ok(())
     }
 
-    /// Checks if the client is connected.
+    /// Returns a token for a new background task, cancelled by `disconnect`.
+    fn subscription_token(&self) -> CancellationToken {
+        self.cancellation_token.lock().unwrap_or_else(PoisonError::into_inner).child_token()
+    }
+
+    /// Cancels the account feed and background tasks, leaving a fresh token for later ones.
+    fn cancel_subscriptions(&self) {
+        let mut token = self.cancellation_token.lock().unwrap_or_else(PoisonError::into_inner);
+        std::mem::replace(&mut *token, CancellationToken::new()).cancel();
+    }
+
+    fn start_keep_alive(&self) {
+        if let Some(interval_ms) = self.config.keep_alive_interval_ms {
+            self.keep_alive.start(
+                Arc::clone(&self.http_client),
+                Duration::from_millis(interval_ms),
+                self.runtime.as_ref(),
+                self.subscription_token(),
+            );
+        }
+    }
+
+    fn start_connectivity_monitor(&self) {
+        if let Some(interval_ms) = self.config.terminal_check_interval_ms {
+            // Holds the parts reconciliation needs rather than a client clone, which
+            // would keep the client's background tasks alive past its last drop
+            let http_client = Arc::clone(&self.http_client);
+            let pending_place_requests = Arc::clone(&self.pending_place_requests);
+            let transactions = Arc::clone(&self.transactions);
+            let order_events = self.order_events.clone();
+            let on_reconnect: ReconnectHook = Arc::new(move || {
+                let http_client = Arc::clone(&http_client);
+                let pending_place_requests = Arc::clone(&pending_place_requests);
+                let transactions = Arc::clone(&transactions);
+                let order_events = order_events.clone();
+                Box::pin(async move {
+                    let events =
+                        reconcile_after_reconnect(&http_client, &pending_place_requests, &transactions).await;
+                    for event in events {
+                        // No receivers is fine; subscribing is optional
+                        let _ = order_events.send(event);
+                    }
+                })
+            });
+            self.connectivity_monitor.start(
+                Arc::clone(&self.http_client),
+                self.connection.clone(),
+                Duration::from_millis(interval_ms),
+                self.runtime.as_ref(),
+                self.subscription_token(),
+                Some(on_reconnect),
+            );
+        }
+    }
+
+    /// Subscribes to order events the client produces outside a call: orders
+    /// whose lost `order_send` responses are resolved after the connectivity
+    /// monitor re-establishes the session, and fills and cancels passed in through
+    /// [`Self::handle_trade_transaction`].
     ///
-    /// # Returns
+    /// Events resolved by an explicit [`Self::reconnect`] are returned by it instead.
+    pub fn subscribe_order_events(&self) -> broadcast::Receiver<OrderEventAny> {
+        self.order_events.subscribe()
+    }
+
+    /// Returns the current connection state.
+    pub fn connection_state(&self) -> ConnectionState {
+        self.connection.state()
+    }
+
+    /// Subscribes to connection state transitions.
+    pub fn subscribe_connection_state(&self) -> tokio::sync::broadcast::Receiver<ConnectionTransition> {
+        self.connection.subscribe()
+    }
+
+    /// Waits until the client is `Connected`, polling its connection state.
     ///
-    /// True if connected, false otherwise.
-    pub fn is_connected(&self) -> bool {
-        // For now, we'll assume the client is connected if it was created successfully
-        // In a real implementation, you would have a way to check the actual connection status
-        true
+    /// Gives startup code a deterministic point to continue from, e.g. while
+    /// `connect` runs on another task.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ExecutionClientError::ConnectionTimeout` with the last observed state if the
+    /// client is not connected within `timeout_secs`.
+    pub async fn wait_until_connected(&self, timeout_secs: f64) -> Result<(), ExecutionClientError> {
+        let timeout = Duration::try_from_secs_f64(timeout_secs).unwrap_or_default();
+        self.connection
+            .wait_until(ConnectionState::Connected, timeout)
+            .await
+            .map_err(|state| ExecutionClientError::ConnectionTimeout { timeout_secs, state })
     }
-}
 
-#[cfg(feature = "python-bindings")]
-#[pymethods]
-impl Mt5ExecutionClient {
-    #[new]
-    pub fn py_new(config: Mt5ExecutionClientConfig) -> Result<Self, PyErr> {
-        Self::new(config).map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))
+    /// Fetches `account_info` and converts it to an account balance in the account currency.
+    pub async fn account_balance(&self) -> Result<nautilus_model::types::AccountBalance, ExecutionClientError> {
+        let response = self.http_client.account_info().await?;
+        let info: Mt5AccountInfo = serde_json::from_value(unwrap_result(response)?)
+            .map_err(|e| ExecutionClientError::ParseError(e.to_string()))?;
+        parse_account_balance(&info).map_err(|e| ExecutionClientError::ParseError(e.to_string()))
     }
 
-    #[pyo3(name = "connect")]
-    pub fn py_connect<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        let client = self.clone();
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            client.connect().await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
-        })
+    /// Fetches deals between `start` and `end` (Unix seconds) and builds fill reports.
+    pub async fn fill_reports(&self, start: i64, end: i64) -> Result<Vec<FillReport>, ExecutionClientError> {
+        Ok(generate_fill_reports(&self.history_deals(start, end).await?))
     }
 
-    #[pyo3(name = "disconnect")]
-    pub fn py_disconnect<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        let client = self.clone();
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            client.disconnect().await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
-        })
+    /// Fetches deals between `start` and `end` (Unix seconds) and aggregates them per position.
+    ///
+    /// Positions opened before `start` only include the deals inside the window.
+    pub async fn position_pnl(&self, start: i64, end: i64) -> Result<Vec<Mt5PositionPnl>, ExecutionClientError> {
+        Ok(aggregate_position_pnl(&self.history_deals(start, end).await?))
     }
 
-    #[pyo3(name = "is_connected")]
-    pub fn py_is_connected(&self) -> bool {
-        Mt5ExecutionClient::is_connected(self)
+    /// Gathers orders, positions, fills over `lookback` and the account state in one call.
+    ///
+    /// A section that fails is left empty and its error recorded in
+    /// [`ReconciliationReport::errors`], so one bad endpoint does not block
+    /// reconciliation of the rest.
+    pub async fn reconcile(&self, lookback: Duration) -> ReconciliationReport {
+        let ts_init = nautilus_core::time::get_atomic_clock_realtime().get_time_ns();
+        let end = (ts_init.as_u64() / 1_000_000_000) as i64;
+        let start = end - lookback.as_secs() as i64;
+        let mut report = ReconciliationReport::default();
+
+        match self.http_client.orders_get_typed().await {
+            Ok(orders) => report.order_reports.extend(generate_order_status_reports(&orders)),
+            Err(e) => report.errors.push(format!("open orders: {e}")),
+        }
+        match self.history_orders(start, end).await {
+            Ok(orders) => report.order_reports.extend(generate_order_status_reports(&orders)),
+            Err(e) => report.errors.push(format!("order history: {e}")),
+        }
+        match self.fill_reports(start, end).await {
+            Ok(fills) => report.fill_reports = fills,
+            Err(e) => report.errors.push(format!("fills: {e}")),
+        }
+
+        let differ = AccountSnapshotDiffer::new(
+            self.account_id(),
+            Arc::clone(&self.instruments),
+            self.symbol_mapper.clone(),
+        );
+        match fetch_positions(&self.http_client).await {
+            Ok(positions) => report.position_reports = differ.position_reports(&positions, ts_init),
+            Err(e) => report.errors.push(format!("positions: {e}")),
+        }
+        match self.account_balance().await {
+            Ok(balance) => {
+                report.account_state = Some(account_state(self.account_id(), balance, ts_init));
+            }
+            Err(e) => report.errors.push(format!("account: {e}")),
+        }
+
+        report
+    }
+
+    /// Searches the order history over the last `lookback` for the order
+    /// submitted as `client_order_id`, with the deals that filled it.
+    ///
+    /// History is stamped in the trade server's time, so the window is widened
+    /// by a day on each side to cover any broker offset from UTC.
+    ///
+    /// Orders are matched on the comment and `magic` that [`order_correlation`]
+    /// derives from the id, so an order submitted with its own `comment` is not
+    /// found. If several orders match, the most recently placed one wins.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order or deal history cannot be fetched.
+    pub async fn find_order_by_client_id(
+        &self,
+        client_order_id: &ClientOrderId,
+        lookback: Duration,
+    ) -> Result<Option<OrderHistoryMatch>, ExecutionClientError> {
+        let ts_now = nautilus_core::time::get_atomic_clock_realtime().get_time_ns();
+        let now = (ts_now.as_u64() / 1_000_000_000) as i64;
+        let start = now - lookback.as_secs() as i64 - HISTORY_SEARCH_MARGIN_SECS;
+        let end = now + HISTORY_SEARCH_MARGIN_SECS;
+        let correlation = order_correlation(client_order_id);
+
+        let Some(order) = self
+            .history_orders(start, end)
+            .await?
+            .into_iter()
+            .filter(|order| correlation.matches(order))
+            .max_by_key(|order| (order.time_setup, order.time_setup_msc))
+        else {
+            return Ok(None);
+        };
+        let deals = self
+            .history_deals(start, end)
+            .await?
+            .into_iter()
+            .filter(|deal| deal.order == order.ticket)
+            .collect();
+        Ok(Some(OrderHistoryMatch { order, deals }))
+    }
+
+    async fn history_orders(&self, start: i64, end: i64) -> Result<Vec<Mt5TradeOrder>, ExecutionClientError> {
+        fetch_history_orders(&self.http_client, start, end).await
+    }
+
+    async fn history_deals(&self, start: i64, end: i64) -> Result<Vec<Mt5Deal>, ExecutionClientError> {
+        let response = self.http_client.history_deals_get(&json!([start, end])).await?;
+        Ok(self.http_client.parse_list(response, "history_deals_get")?)
+    }
+
+    /// Subscribes to account and position updates, polled every `interval`.
+    ///
+    /// Each poll fetches `account_info` and `positions_get`, and only changes
+    /// since the previous poll are emitted (see [`AccountSnapshotDiffer`]). All
+    /// subscribers share one polling task, started by the first subscriber with
+    /// its `interval`; it stops once every receiver has been dropped, or on
+    /// [`Self::unsubscribe_account_state`] or `disconnect`.
+    pub fn subscribe_account_state(&self, interval: Duration) -> broadcast::Receiver<AccountUpdate> {
+        let mut feed = self.account_feed.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(feed) = feed.as_ref().filter(|feed| !feed.task.is_finished()) {
+            return feed.sender.subscribe();
+        }
+
+        let (sender, receiver) = broadcast::channel(ACCOUNT_UPDATE_CAPACITY);
+        let http_client = Arc::clone(&self.http_client);
+        let mut differ = AccountSnapshotDiffer::new(
+            self.account_id(),
+            Arc::clone(&self.instruments),
+            self.symbol_mapper.clone(),
+        );
+        let task_sender = sender.clone();
+        let token = self.subscription_token();
+
+        let task = spawn_cancellable(self.runtime.as_ref(), token.clone(), async move {
+            while task_sender.receiver_count() > 0 {
+                match poll_account_snapshot(&http_client).await {
+                    Ok((info, positions)) => {
+                        let ts_init = nautilus_core::time::get_atomic_clock_realtime().get_time_ns();
+                        for update in differ.diff(&info, &positions, ts_init) {
+                            let _ = task_sender.send(update);
+                        }
+                    }
+                    Err(e) => tracing::warn!("Account state poll failed: {e}"),
+                }
+                tokio::time::sleep(interval).await;
+            }
+        });
+
+        *feed = Some(AccountStateFeed { sender, task, token });
+        receiver
+    }
+
+    /// Stops the account state polling task; existing receivers see the channel close.
+    pub fn unsubscribe_account_state(&self) {
+        if let Some(feed) = self.account_feed.lock().unwrap_or_else(PoisonError::into_inner).take() {
+            feed.token.cancel();
+        }
+    }
+
+    /// Fetches the symbol specification for `symbol`.
+    pub async fn symbol_info(&self, symbol: &str) -> Result<Mt5SymbolInfo, ExecutionClientError> {
+        let response = self.http_client.symbol_info(&json!([symbol])).await?;
+
+        // The bridge may wrap the named tuple in a single-element list
+        let result = match unwrap_result(response)? {
+            Value::Array(items) => items.into_iter().next().unwrap_or(Value::Null),
+            value => value,
+        };
+        if result.is_null() {
+            return Err(ExecutionClientError::SymbolNotFound(symbol.to_string()));
+        }
+        serde_json::from_value(result).map_err(|e| ExecutionClientError::ParseError(e.to_string()))
+    }
+
+    /// Returns the account's margin mode, fetched from `account_info` once.
+    async fn margin_mode(&self) -> Result<Mt5MarginMode, ExecutionClientError> {
+        if let Some(mode) = self.margin_mode.get() {
+            return Ok(*mode);
+        }
+        let info: Mt5AccountInfo = serde_json::from_value(unwrap_result(self.http_client.account_info().await?)?)
+            .map_err(|e| ExecutionClientError::ParseError(e.to_string()))?;
+        Ok(*self.margin_mode.get_or_init(|| info.margin_mode))
+    }
+
+    /// Submits an order via `order_send`.
+    ///
+    /// A `reduce_only` order without a `position` is resolved against the open
+    /// positions first (see [`resolve_reduce_position`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns the first failing check of [`Self::validate_order`],
+    /// `ExecutionClientError::InvalidOrder` if the request is otherwise
+    /// inconsistent, and `ExecutionClientError::OrderRejected` if MT5 does not accept it.
+    ///
+    /// Market orders follow the symbol's execution mode (see [`MarketOrderFlow`]):
+    /// under request execution the quote is refreshed just before the deal.
+    ///
+    /// If MT5 refuses the filling mode (`TRADE_RETCODE_INVALID_FILL`), the order is
+    /// resent with each other filling mode the symbol allows until one is accepted.
+    pub async fn submit_order(
+        &self,
+        request: &Mt5OrderRequest,
+    ) -> Result<Mt5OrderSendResult, ExecutionClientError> {
+        let (mut request, mut symbol_info) = self.validate_order(request).await?;
+        if request.reduce_only && request.position.is_none() {
+            let margin_mode = self.margin_mode().await?;
+            let positions = self.http_client.positions_get_for_symbol(&request.symbol).await?;
+            request.position = resolve_reduce_position(&request, margin_mode, &positions)?;
+        }
+        if !request.order_type.is_pending()
+            && check_market_order(&symbol_info)? == MarketOrderFlow::QuoteThenDeal
+        {
+            self.refresh_quote(&mut symbol_info).await?;
+        }
+        let mut body =
+            build_order_send_body(&request, &symbol_info, self.config.server_utc_offset_secs)?;
+
+        if self.config.precheck_margin {
+            self.check_margin(&body).await?;
+        }
+
+        let mut result = self.order_send(&body).await?;
+        if result.retcode == TRADE_RETCODE_INVALID_FILL {
+            let mut rejected = body["type_filling"].as_i64().unwrap_or(ORDER_FILLING_FOK);
+            for filling in fallback_filling_modes(&request, &symbol_info, rejected) {
+                tracing::warn!(
+                    "{} rejected filling mode {rejected}, retrying with {filling}",
+                    request.symbol
+                );
+                body["type_filling"] = json!(filling);
+                result = self.order_send(&body).await?;
+                if result.retcode != TRADE_RETCODE_INVALID_FILL {
+                    break;
+                }
+                rejected = filling;
+            }
+        }
+
+        if result.retcode != TRADE_RETCODE_DONE && result.retcode != TRADE_RETCODE_PLACED {
+            return Err(ExecutionClientError::OrderRejected {
+                retcode: result.retcode,
+                comment: result.comment,
+            });
+        }
+
+        Ok(result)
+    }
+
+    /// Runs the pre-submit checks for `request`, returning the first that fails.
+    ///
+    /// In order: the symbol exists, it is selected in Market Watch (selecting it
+    /// when `auto_select_symbols` is set), its trade mode allows the order (see
+    /// [`check_trade_mode`]), a market order is allowed at all (see
+    /// [`check_market_order`]), the volume fits the symbol's limits and step (see
+    /// [`normalize_volume`]) and the filling mode is supported (see
+    /// [`check_filling_mode`]). Returns the request with its normalized volume
+    /// and the symbol specification it was checked against.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SymbolNotFound`, `SymbolNotSelected`, `TradeModeRestricted`,
+    /// `MarketOrderNotAllowed`, `InvalidOrder` for the volume or
+    /// `UnsupportedFilling`, and propagates
+    /// bridge errors.
+    pub async fn validate_order(
+        &self,
+        request: &Mt5OrderRequest,
+    ) -> Result<(Mt5OrderRequest, Mt5SymbolInfo), ExecutionClientError> {
+        let symbol_info = self.symbol_info(&request.symbol).await?;
+        if !symbol_info.select {
+            if !self.config.auto_select_symbols {
+                return Err(ExecutionClientError::SymbolNotSelected(request.symbol.clone()));
+            }
+            self.http_client.symbol_select_checked(&request.symbol).await?;
+        }
+        check_trade_mode(request, &symbol_info)?;
+        if !request.order_type.is_pending() {
+            check_market_order(&symbol_info)?;
+        }
+
+        let mut request = request.clone();
+        request.volume = normalize_volume(
+            request.volume,
+            &symbol_info,
+            self.config.volume_rounding,
+            self.config.clamp_volume,
+        )?;
+        check_filling_mode(&request, &symbol_info)?;
+        Ok((request, symbol_info))
+    }
+
+    /// Replaces the bid and ask in `symbol_info` with the latest `symbol_info_tick`.
+    ///
+    /// Symbols in request execution deal at a price the dealer has just quoted,
+    /// so the quote read with the symbol specification may already be stale.
+    async fn refresh_quote(&self, symbol_info: &mut Mt5SymbolInfo) -> Result<(), ExecutionClientError> {
+        let tick = unwrap_result(
+            self.http_client
+                .symbol_info_tick(&json!([symbol_info.name]))
+                .await?,
+        )?;
+        let price = |field: &str| {
+            tick.get(field).and_then(Value::as_f64).filter(|price| *price > 0.0).ok_or_else(|| {
+                ExecutionClientError::ParseError(format!(
+                    "No {field} quoted for {}",
+                    symbol_info.name
+                ))
+            })
+        };
+        let (bid, ask) = (price("bid")?, price("ask")?);
+        symbol_info.bid = bid;
+        symbol_info.ask = ask;
+        Ok(())
+    }
+
+    /// Sends `body` to `order_send`.
+    ///
+    /// A request that fails in transit may still have reached the terminal, so
+    /// network errors and timeouts become `ExecutionClientError::ResponseLost`.
+    async fn order_send(&self, body: &Value) -> Result<Mt5OrderSendResult, ExecutionClientError> {
+        let response = self.http_client.order_send(body).await.map_err(|e| match e {
+            HttpClientError::NetworkError(_) | HttpClientError::TimeoutError(_) => {
+                ExecutionClientError::ResponseLost(e.to_string())
+            }
+            e => e.into(),
+        })?;
+        serde_json::from_value(unwrap_result(response)?)
+            .map_err(|e| ExecutionClientError::ParseError(e.to_string()))
+    }
+
+    /// Moves the stop-loss and take-profit of the open position `ticket`, e.g. to trail a stop.
+    ///
+    /// The position is read first for the fields `TRADE_ACTION_SLTP` requires;
+    /// a `None` level is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ExecutionClientError::InvalidOrder` if `ticket` is a pending order
+    /// or no open position, and `ExecutionClientError::OrderRejected` if MT5 does
+    /// not accept the new levels.
+    pub async fn update_stops(
+        &self,
+        ticket: u64,
+        sl: Option<f64>,
+        tp: Option<f64>,
+    ) -> Result<Mt5OrderSendResult, ExecutionClientError> {
+        let positions = self.http_client.positions_get_for_ticket(ticket).await?;
+        let Some(position) = positions.into_iter().find(|position| position.ticket == ticket) else {
+            let orders = self.http_client.orders_get_for_ticket(ticket).await?;
+            let reason = if orders.iter().any(|order| order.ticket == ticket) {
+                "is a pending order, not a position"
+            } else {
+                "is not an open position"
+            };
+            return Err(ExecutionClientError::InvalidOrder(format!("ticket {ticket} {reason}")));
+        };
+
+        let symbol_info = self.symbol_info(&position.symbol).await?;
+        let body = build_sltp_body(&position, sl, tp, symbol_info.digits);
+        let response = self.http_client.order_send(&body).await?;
+        let result: Mt5OrderSendResult = serde_json::from_value(unwrap_result(response)?)
+            .map_err(|e| ExecutionClientError::ParseError(e.to_string()))?;
+
+        if result.retcode != TRADE_RETCODE_DONE {
+            return Err(ExecutionClientError::OrderRejected {
+                retcode: result.retcode,
+                comment: result.comment,
+            });
+        }
+        Ok(result)
+    }
+
+    /// Submits an order on behalf of a strategy and returns the resulting order event.
+    ///
+    /// The ids are held in the pending-request map while `order_send` is in flight,
+    /// and the response becomes an `OrderAccepted` or `OrderRejected` tagged with
+    /// them. Unless the request sets its own `comment`, the comment and `magic`
+    /// come from [`order_correlation`] so trade transactions for the order can be
+    /// correlated too.
+    ///
+    /// Returns `None` if the response was lost in transit: the order may have
+    /// reached the terminal, so it stays pending until
+    /// [`Self::reconcile_pending_orders`] finds out.
+    pub async fn submit_order_for(
+        &self,
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        request: &Mt5OrderRequest,
+    ) -> Option<OrderEventAny> {
+        let mut request = request.clone();
+        let caller_comment = request.comment.clone();
+        let correlation = match &caller_comment {
+            Some(comment) => OrderCorrelation {
+                comment: comment.clone(),
+                magic: None,
+            },
+            None => {
+                let correlation = order_correlation(&client_order_id);
+                request.comment = Some(correlation.comment.clone());
+                if let (Some(magic), 0) = (correlation.magic, request.magic) {
+                    request.magic = magic;
+                }
+                correlation
+            }
+        };
+        self.track_transactions_for(
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            &request,
+            caller_comment,
+        );
+        let ts_sent = nautilus_core::time::get_atomic_clock_realtime().get_time_ns();
+        self.pending_place_requests.insert(
+            client_order_id,
+            PendingPlace {
+                ids: (trader_id, strategy_id, instrument_id),
+                correlation,
+                sent_at: (ts_sent.as_u64() / 1_000_000_000) as i64,
+                response_lost: false,
+            },
+        );
+
+        let result = self.submit_order(&request).await;
+
+        let ts_init = nautilus_core::time::get_atomic_clock_realtime().get_time_ns();
+        self.handle_order_send_response(&client_order_id, result, ts_init)
+    }
+
+    /// Registers a submitted order with the trade transaction handler so
+    /// [`Self::handle_trade_transaction`] can attribute its fills and cancels.
+    fn track_transactions_for(
+        &self,
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        request: &Mt5OrderRequest,
+        comment: Option<String>,
+    ) {
+        let (Some(instrument), Some(order_type)) =
+            (self.instrument(&instrument_id), request.order_type.to_order_type())
+        else {
+            tracing::debug!("Not tracking trade transactions for {client_order_id}: {instrument_id} not cached");
+            return;
+        };
+        self.transactions.register(PendingOrder {
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            order_type,
+            quote_currency: instrument.quote_currency(),
+            price_precision: instrument.price_precision(),
+            size_precision: instrument.size_precision(),
+            magic: (request.magic != 0).then_some(request.magic),
+            comment,
+        });
+    }
+
+    /// Publishes the fill or cancel a trade transaction reports for a submitted
+    /// order to [`Self::subscribe_order_events`].
+    ///
+    /// Acceptance is already reported by `order_send` or reconciliation, so
+    /// accepted events derived from the transaction are dropped.
+    pub fn handle_trade_transaction(&self, transaction: &Mt5TradeTransaction) {
+        let ts_init = nautilus_core::time::get_atomic_clock_realtime().get_time_ns();
+        match self.transactions.handle(transaction, ts_init) {
+            None | Some(OrderEventAny::Accepted(_)) => {}
+            Some(event) => {
+                // No receivers is fine; subscribing is optional
+                let _ = self.order_events.send(event);
+            }
+        }
+    }
+
+    /// Converts an `order_send` outcome into an event for a pending order.
+    ///
+    /// Returns `None` if `client_order_id` has no pending place request, e.g. when
+    /// the response was already handled, or if the outcome is
+    /// `ExecutionClientError::ResponseLost`, which leaves the order pending for
+    /// [`Self::reconcile_pending_orders`].
+    pub fn handle_order_send_response(
+        &self,
+        client_order_id: &ClientOrderId,
+        result: Result<Mt5OrderSendResult, ExecutionClientError>,
+        ts_init: UnixNanos,
+    ) -> Option<OrderEventAny> {
+        if let Err(ExecutionClientError::ResponseLost(e)) = &result {
+            let mut pending = self.pending_place_requests.get_mut(client_order_id)?;
+            tracing::warn!("Order {client_order_id} outcome unknown, reconciling on reconnect: {e}");
+            pending.response_lost = true;
+            return None;
+        }
+        let (_, pending) = self.pending_place_requests.remove(client_order_id)?;
+        Some(match result {
+            Ok(result) => {
+                self.transactions.bind_ticket(client_order_id, result.order);
+                accepted_event(
+                    self.account_id(),
+                    pending.ids,
+                    *client_order_id,
+                    VenueOrderId::new(result.order.to_string()),
+                    ts_init,
+                )
+            }
+            Err(e) => {
+                tracing::warn!("Order {client_order_id} rejected: {e}");
+                self.transactions.unregister(client_order_id);
+                rejected_event(self.account_id(), pending.ids, *client_order_id, &e.to_string(), ts_init)
+            }
+        })
+    }
+
+    /// Resolves orders whose `order_send` response was lost, e.g. to a dropped connection.
+    ///
+    /// Open orders and the order history around each submission are searched for
+    /// the order's correlation (see [`OrderCorrelation::matches`]). A match becomes
+    /// an `OrderAccepted` with the order's ticket, or an `OrderRejected` if MT5
+    /// rejected it. An order found in neither is looked up once more after a short
+    /// pause, since the terminal may not list it yet, and only then rejected as
+    /// never placed. [`Self::reconnect`] runs this once the session is back.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the orders cannot be fetched; the orders then stay pending.
+    pub async fn reconcile_pending_orders(&self) -> Result<Vec<OrderEventAny>, ExecutionClientError> {
+        reconcile_lost_orders(&self.http_client, &self.pending_place_requests, &self.transactions).await
+    }
+
+    /// Deletes the pending order `ticket` via `TRADE_ACTION_REMOVE`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ExecutionClientError::OrderRejected` if MT5 does not remove the order.
+    pub async fn cancel_order(&self, ticket: u64) -> Result<Mt5OrderSendResult, ExecutionClientError> {
+        let body = json!({
+            "action": TRADE_ACTION_REMOVE,
+            "order": ticket,
+        });
+        let result = self.order_send(&body).await?;
+        if result.retcode != TRADE_RETCODE_DONE {
+            return Err(ExecutionClientError::OrderRejected {
+                retcode: result.retcode,
+                comment: result.comment,
+            });
+        }
+        Ok(result)
+    }
+
+    /// Cancels an order on behalf of a strategy and returns the resulting order event.
+    ///
+    /// `venue_order_id` is the MT5 ticket reported in the order's `OrderAccepted`.
+    /// The outcome becomes an `OrderCanceled`, or an `OrderCancelRejected`
+    /// carrying the reason, so the engine's order state follows without a poll.
+    pub async fn cancel_order_for(
+        &self,
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+    ) -> OrderEventAny {
+        let result = match venue_order_id.as_str().parse::<u64>() {
+            Ok(ticket) => self.cancel_order(ticket).await,
+            Err(_) => Err(ExecutionClientError::InvalidOrder(format!(
+                "venue order id {venue_order_id} is not an MT5 ticket"
+            ))),
+        };
+
+        let ts_init = nautilus_core::time::get_atomic_clock_realtime().get_time_ns();
+        match result {
+            Ok(_) => OrderEventAny::Canceled(OrderCanceled::new(
+                trader_id,
+                strategy_id,
+                instrument_id,
+                client_order_id,
+                UUID4::new(),
+                ts_init,
+                ts_init,
+                false,
+                Some(venue_order_id),
+                Some(self.account_id()),
+            )),
+            Err(e) => {
+                tracing::warn!("Cancel of order {client_order_id} rejected: {e}");
+                OrderEventAny::CancelRejected(OrderCancelRejected::new(
+                    trader_id,
+                    strategy_id,
+                    instrument_id,
+                    client_order_id,
+                    ustr::Ustr::from(&e.to_string()),
+                    UUID4::new(),
+                    ts_init,
+                    ts_init,
+                    false,
+                    Some(venue_order_id),
+                    Some(self.account_id()),
+                ))
+            }
+        }
+    }
+
+    /// Sends the closing deal for `ticket` and returns the position it closed.
+    ///
+    /// The deal keeps the position's `magic`; `correlation` overrides it and sets
+    /// the comment so the closing deal's trade transactions can be matched.
+    async fn close_position_with(
+        &self,
+        ticket: u64,
+        correlation: Option<OrderCorrelation>,
+    ) -> Result<(Mt5TradePosition, Mt5OrderSendResult), ExecutionClientError> {
+        let positions = self.http_client.positions_get_for_ticket(ticket).await?;
+        let position = positions
+            .into_iter()
+            .find(|position| position.ticket == ticket)
+            .ok_or_else(|| {
+                ExecutionClientError::InvalidOrder(format!("ticket {ticket} is not an open position"))
+            })?;
+
+        let mut builder = Mt5OrderRequest::builder();
+        builder
+            .symbol(position.symbol.clone())
+            .order_type(if position.is_long() {
+                Mt5TradeType::Sell
+            } else {
+                Mt5TradeType::Buy
+            })
+            .volume(position.volume)
+            .position(ticket)
+            .magic(position.magic);
+        if let Some(correlation) = correlation {
+            builder.comment(correlation.comment);
+            if let Some(magic) = correlation.magic {
+                builder.magic(magic);
+            }
+        }
+        let request = builder
+            .build()
+            .map_err(|e| ExecutionClientError::InvalidOrder(e.to_string()))?;
+
+        let result = self.submit_order(&request).await?;
+        Ok((position, result))
+    }
+
+    /// Closes the open position `ticket` with an opposite market deal for its full volume.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ExecutionClientError::InvalidOrder` if `ticket` is not an open
+    /// position, and the errors of [`Self::submit_order`] for the closing deal.
+    pub async fn close_position(&self, ticket: u64) -> Result<Mt5OrderSendResult, ExecutionClientError> {
+        Ok(self.close_position_with(ticket, None).await?.1)
+    }
+
+    /// Closes a position on behalf of a strategy and returns its updated status.
+    ///
+    /// The closing deal is tagged with the [`order_correlation`] of
+    /// `client_order_id`. On success the report is flat, or carries the
+    /// remaining volume if the deal filled only part of the position.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ExecutionClientError::InvalidOrder` if `position_id` is not an open
+    /// MT5 position, the errors of [`Self::submit_order`] for the closing deal, and
+    /// `ExecutionClientError::ParseError` if the position's volume is corrupt.
+    pub async fn close_position_for(
+        &self,
+        client_order_id: ClientOrderId,
+        position_id: PositionId,
+    ) -> Result<PositionStatusReport, ExecutionClientError> {
+        let ticket = position_id.as_str().parse::<u64>().map_err(|_| {
+            ExecutionClientError::InvalidOrder(format!("position id {position_id} is not an MT5 ticket"))
+        })?;
+        let (position, result) = self
+            .close_position_with(ticket, Some(order_correlation(&client_order_id)))
+            .await?;
+
+        // Bridges that omit the filled volume report 0; the deal was for the whole position
+        let closed_volume = if result.volume > 0.0 { result.volume } else { position.volume };
+        let ts_init = nautilus_core::time::get_atomic_clock_realtime().get_time_ns();
+        closed_position_report(
+            self.account_id(),
+            &self.instruments,
+            &self.symbol_mapper,
+            &position,
+            closed_volume,
+            ts_init,
+        )
+        .map_err(|e| ExecutionClientError::ParseError(e.to_string()))
+    }
+
+    /// Returns whether `client_order_id` is still awaiting its `order_send` response.
+    pub fn is_pending(&self, client_order_id: &ClientOrderId) -> bool {
+        self.pending_place_requests.contains_key(client_order_id)
+    }
+
+    /// Returns the margin, in account currency, required to open `volume` lots of
+    /// `symbol` at `price` with a market order of `order_type`'s side.
+    ///
+    /// The per-lot margin from `order_calc_margin` is cached per symbol, side and
+    /// price rounded to three significant digits for `margin_cache_ttl_ms`, so
+    /// sizing many similar orders costs one bridge call. Set `bypass_cache` for
+    /// checks that need the terminal's current figure; its result still
+    /// refreshes the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ExecutionClientError::InvalidOrder` for a non-positive volume and
+    /// `ExecutionClientError::ParseError` if the bridge returns no margin.
+    pub async fn order_margin(
+        &self,
+        order_type: Mt5TradeType,
+        symbol: &str,
+        volume: f64,
+        price: f64,
+        bypass_cache: bool,
+    ) -> Result<f64, ExecutionClientError> {
+        if !volume.is_finite() || volume <= 0.0 {
+            return Err(ExecutionClientError::InvalidOrder(format!(
+                "margin volume must be positive, was {volume}"
+            )));
+        }
+        let key = MarginCacheKey::new(symbol, order_type.is_buy(), price);
+        if !bypass_cache {
+            if let Some(per_lot) = self.margin_cache.get(&key) {
+                return Ok(per_lot * volume);
+            }
+        }
+
+        let action = if order_type.is_buy() {
+            Mt5TradeType::Buy
+        } else {
+            Mt5TradeType::Sell
+        };
+        let body = json!([action.as_i64(), symbol, volume, price]);
+        let response = self.http_client.order_calc_margin(&body).await?;
+        let result = unwrap_result(response)?;
+        let margin = result.as_f64().ok_or_else(|| {
+            ExecutionClientError::ParseError(format!("order_calc_margin: expected a number, got {result}"))
+        })?;
+
+        self.margin_cache.insert(key, margin / volume);
+        Ok(margin)
+    }
+
+    /// Runs `order_check` for `body`, failing with `Mt5HttpError::InsufficientMargin`
+    /// if the order would leave negative free margin.
+    async fn check_margin(
+        &self,
+        body: &Value,
+    ) -> Result<Mt5OrderCheckResult, ExecutionClientError> {
+        let response = self.http_client.order_check(body).await?;
+        let check: Mt5OrderCheckResult = serde_json::from_value(unwrap_result(response)?)
+            .map_err(|e| ExecutionClientError::ParseError(e.to_string()))?;
+
+        if check.margin_free < 0.0 || check.retcode == TRADE_RETCODE_NO_MONEY {
+            return Err(HttpClientError::InsufficientMargin(format!(
+                "{} {}: margin {} would leave margin_free {}",
+                body["symbol"], body["volume"], check.margin, check.margin_free
+            ))
+            .into());
+        }
+        Ok(check)
+    }
+
+    /// Checks if the client is connected.
+    ///
+    /// # Returns
+    ///
+    /// True if connected, false otherwise.
+    pub fn is_connected(&self) -> bool {
+        self.connection.state() == ConnectionState::Connected
+    }
+}
+
+#[cfg(feature = "python-bindings")]
+#[pymethods]
+impl Mt5ExecutionClient {
+    #[new]
+    pub fn py_new(config: Mt5ExecutionClientConfig) -> Result<Self, PyErr> {
+        Self::new(config).map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))
+    }
+
+    #[pyo3(name = "connect")]
+    pub fn py_connect<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            client.connect().await
+                .map_err(to_pyerr)
+        })
+    }
+
+    #[pyo3(name = "disconnect")]
+    pub fn py_disconnect<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            client.disconnect().await
+                .map_err(to_pyerr)
+        })
+    }
+
+    /// Reconnects and returns the events of orders resolved by reconciliation.
+    #[pyo3(name = "reconnect")]
+    pub fn py_reconnect<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let events = client.reconnect().await
+                .map_err(to_pyerr)?;
+            Python::attach(|py| {
+                events
+                    .into_iter()
+                    .map(|event| nautilus_model::python::events::order::order_event_to_pyobject(py, event))
+                    .collect::<PyResult<Vec<_>>>()
+            })
+        })
+    }
+
+    #[getter]
+    #[pyo3(name = "connection_state")]
+    pub fn py_connection_state(&self) -> ConnectionState {
+        self.connection_state()
+    }
+
+    #[getter]
+    #[pyo3(name = "environment")]
+    pub fn py_environment(&self) -> Option<Mt5Environment> {
+        self.environment()
+    }
+
+    #[pyo3(name = "set_http_client")]
+    pub fn py_set_http_client(&mut self, http_client: PyRef<'_, Mt5HttpClient>) {
+        self.set_http_client(&http_client);
+    }
+
+    #[pyo3(name = "set_instrument_provider")]
+    pub fn py_set_instrument_provider(&mut self, provider: PyRef<'_, Mt5InstrumentProvider>) {
+        self.set_instrument_provider(&provider);
+    }
+
+    /// Connects on `async with` entry and returns the client itself.
+    fn __aenter__<'py>(slf: PyRef<'py, Self>, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = (*slf).clone();
+        let this: Py<Self> = slf.into();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            client.connect().await
+                .map_err(to_pyerr)?;
+            Ok(this)
+        })
+    }
+
+    /// Disconnects on `async with` exit; exceptions raised in the block are not suppressed.
+    #[pyo3(signature = (_exc_type=None, _exc_value=None, _traceback=None))]
+    fn __aexit__<'py>(
+        &self,
+        py: Python<'py>,
+        _exc_type: Option<Bound<'py, PyAny>>,
+        _exc_value: Option<Bound<'py, PyAny>>,
+        _traceback: Option<Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            client.disconnect().await
+                .map_err(to_pyerr)?;
+            Ok(false)
+        })
+    }
+
+    #[pyo3(name = "is_connected")]
+    pub fn py_is_connected(&self) -> bool {
+        Mt5ExecutionClient::is_connected(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nautilus_model::{
+        enums::{OrderSide, OrderType, PositionSideSpecified},
+        events::OrderFilled,
+        identifiers::TradeId,
+        instruments::Instrument,
+        types::{Price, Quantity},
+    };
+    use crate::common::testing::{
+        load_deals_fixture, load_index_symbol_info_fixture, load_mt5_fixture,
+        load_order_send_fixture, load_positions_fixture, load_scale_in_deals_fixture,
+        load_symbol_info_fixture,
+        mock_bridge::{mock_exec_client, MockMt5Bridge},
+    };
+
+    fn order(order_type: Mt5TradeType) -> Mt5OrderRequestBuilder {
+        let mut builder = Mt5OrderRequest::builder();
+        builder.symbol("EURUSD").order_type(order_type).volume(0.1);
+        builder
+    }
+
+    #[test]
+    fn test_buy_points_convert_to_prices() {
+        let info = load_symbol_info_fixture();
+        let request = order(Mt5TradeType::Buy).sl_points(200.0).tp_points(400.0).build().unwrap();
+
+        let body = build_order_send_body(&request, &info, 0).unwrap();
+
+        // Entry at ask 1.10419
+        assert_eq!(body["sl"], json!(1.10219));
+        assert_eq!(body["tp"], json!(1.10819));
+        assert_eq!(body["action"], json!(TRADE_ACTION_DEAL));
+    }
+
+    #[test]
+    fn test_sell_points_convert_to_prices() {
+        let info = load_symbol_info_fixture();
+        let request = order(Mt5TradeType::Sell).sl_points(200.0).tp_points(400.0).build().unwrap();
+
+        let body = build_order_send_body(&request, &info, 0).unwrap();
+
+        // Entry at bid 1.10412
+        assert_eq!(body["sl"], json!(1.10612));
+        assert_eq!(body["tp"], json!(1.10012));
+    }
+
+    #[test]
+    fn test_pending_points_use_order_price() {
+        let info = load_symbol_info_fixture();
+        let request = order(Mt5TradeType::SellLimit)
+            .price(1.11)
+            .sl_points(100.0)
+            .tp(1.1)
+            .build()
+            .unwrap();
+
+        let body = build_order_send_body(&request, &info, 0).unwrap();
+
+        assert_eq!(body["action"], json!(TRADE_ACTION_PENDING));
+        assert_eq!(body["sl"], json!(1.111));
+        assert_eq!(body["tp"], json!(1.1));
+    }
+
+    #[test]
+    fn test_price_form_passes_through() {
+        let info = load_symbol_info_fixture();
+        let request = order(Mt5TradeType::Buy).sl(1.1).tp(1.2).build().unwrap();
+
+        let body = build_order_send_body(&request, &info, 0).unwrap();
+
+        assert_eq!(body["sl"], json!(1.1));
+        assert_eq!(body["tp"], json!(1.2));
+    }
+
+    #[test]
+    fn test_body_prices_and_volume_use_symbol_precision() {
+        let info = load_symbol_info_fixture();
+        let request = order(Mt5TradeType::Buy).volume(0.1000000001).sl(1.100000000001).build().unwrap();
+
+        let body = build_order_send_body(&request, &info, 0).unwrap();
+
+        assert_eq!(body["volume"], json!(0.1));
+        assert_eq!(body["sl"], json!(1.1));
+        assert_eq!(body["price"], json!(1.10419));
+    }
+
+    #[test]
+    fn test_buy_stop_limit_sends_trigger_as_price_and_limit_as_stoplimit() {
+        let info = load_symbol_info_fixture();
+        let order_type = Mt5TradeType::from_nautilus(OrderType::StopLimit, OrderSide::Buy).unwrap();
+        // Buy once the ask rises to the trigger, paying at most the limit below it
+        let request = order(order_type).stop_limit(1.1060, 1.1050).build().unwrap();
+
+        let body = build_order_send_body(&request, &info, 0).unwrap();
+
+        assert_eq!(body["type"], json!(6));
+        assert_eq!(body["action"], json!(TRADE_ACTION_PENDING));
+        assert_eq!(body["price"], json!(1.106));
+        assert_eq!(body["stoplimit"], json!(1.105));
+    }
+
+    #[test]
+    fn test_sell_stop_limit_sends_trigger_as_price_and_limit_as_stoplimit() {
+        let info = load_symbol_info_fixture();
+        let order_type = Mt5TradeType::from_nautilus(OrderType::StopLimit, OrderSide::Sell).unwrap();
+        let request = order(order_type).stop_limit(1.0940, 1.0950).sl_points(100.0).build().unwrap();
+
+        let body = build_order_send_body(&request, &info, 0).unwrap();
+
+        assert_eq!(body["type"], json!(7));
+        assert_eq!(body["price"], json!(1.094));
+        assert_eq!(body["stoplimit"], json!(1.095));
+        // Points are measured from the limit, where the order fills
+        assert_eq!(body["sl"], json!(1.096));
+    }
+
+    #[test]
+    fn test_stop_limit_without_limit_price_rejected() {
+        let info = load_symbol_info_fixture();
+        let request = order(Mt5TradeType::BuyStopLimit).price(1.106).build().unwrap();
+
+        assert!(matches!(
+            build_order_send_body(&request, &info, 0),
+            Err(ExecutionClientError::InvalidOrder(_))
+        ));
+    }
+
+    fn pending(time_in_force: TimeInForce) -> Mt5OrderRequestBuilder {
+        let mut builder = order(Mt5TradeType::BuyLimit);
+        builder.price(1.1).time_in_force(time_in_force);
+        builder
+    }
+
+    #[test]
+    fn test_time_in_force_maps_to_type_time() {
+        let info = load_symbol_info_fixture();
+
+        let gtc = build_order_send_body(&pending(TimeInForce::Gtc).build().unwrap(), &info, 0).unwrap();
+        assert_eq!(gtc["type_time"], json!(ORDER_TIME_GTC));
+        assert!(gtc.get("expiration").is_none());
+
+        let day = build_order_send_body(&pending(TimeInForce::Day).build().unwrap(), &info, 0).unwrap();
+        assert_eq!(day["type_time"], json!(ORDER_TIME_DAY));
+        assert!(day.get("expiration").is_none());
+
+        let gtd_request = pending(TimeInForce::Gtd)
+            .expire_time(Some(UnixNanos::from(1_704_117_600_000_000_000)))
+            .build()
+            .unwrap();
+        let gtd = build_order_send_body(&gtd_request, &info, 0).unwrap();
+        assert_eq!(gtd["type_time"], json!(ORDER_TIME_SPECIFIED));
+        assert_eq!(gtd["expiration"], json!(1_704_117_600));
+    }
+
+    #[test]
+    fn test_gtd_expiration_shifted_to_server_time() {
+        let info = load_symbol_info_fixture();
+        let request = pending(TimeInForce::Gtd)
+            .expire_time(Some(UnixNanos::from(1_704_117_600_000_000_000)))
+            .build()
+            .unwrap();
+
+        // A UTC+2 server expects 14:00 UTC as 16:00 server time
+        let body = build_order_send_body(&request, &info, 7200).unwrap();
+        assert_eq!(body["expiration"], json!(1_704_117_600 + 7200));
+
+        let body = build_order_send_body(&request, &info, -3600).unwrap();
+        assert_eq!(body["expiration"], json!(1_704_117_600 - 3600));
+    }
+
+    #[test]
+    fn test_time_in_force_rejections() {
+        let info = load_symbol_info_fixture();
+
+        let gtd = pending(TimeInForce::Gtd).build().unwrap();
+        assert!(matches!(build_order_send_body(&gtd, &info, 0), Err(ExecutionClientError::InvalidOrder(_))));
+
+        let ioc = pending(TimeInForce::Ioc).build().unwrap();
+        assert!(matches!(build_order_send_body(&ioc, &info, 0), Err(ExecutionClientError::InvalidOrder(_))));
+    }
+
+    #[test]
+    fn test_market_orders_omit_type_time() {
+        let info = load_symbol_info_fixture();
+        let request = order(Mt5TradeType::Buy).time_in_force(TimeInForce::Day).build().unwrap();
+
+        let body = build_order_send_body(&request, &info, 0).unwrap();
+
+        assert!(body.get("type_time").is_none());
+    }
+
+    #[test]
+    fn test_both_forms_rejected() {
+        let info = load_symbol_info_fixture();
+        let request = order(Mt5TradeType::Buy).sl(1.1).sl_points(100.0).build().unwrap();
+
+        let result = build_order_send_body(&request, &info, 0);
+
+        assert!(matches!(result, Err(ExecutionClientError::InvalidOrder(_))));
+    }
+
+    #[test]
+    fn test_normalize_volume_rounding_modes() {
+        let info = load_symbol_info_fixture(); // step 0.01, min 0.01, max 500
+
+        assert_eq!(normalize_volume(0.015, &info, VolumeRounding::Down, true).unwrap(), 0.01);
+        assert_eq!(normalize_volume(0.016, &info, VolumeRounding::Nearest, true).unwrap(), 0.02);
+        assert_eq!(normalize_volume(0.3, &info, VolumeRounding::Down, true).unwrap(), 0.3);
+        assert_eq!(normalize_volume(1.0, &info, VolumeRounding::Nearest, true).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_normalize_volume_clamps_to_range() {
+        let info = load_index_symbol_info_fixture(); // step 0.1, min 0.1, max 100
+
+        assert_eq!(normalize_volume(250.0, &info, VolumeRounding::Nearest, true).unwrap(), 100.0);
+        assert_eq!(normalize_volume(0.06, &info, VolumeRounding::Nearest, true).unwrap(), 0.1);
+    }
+
+    #[test]
+    fn test_normalize_volume_rejects_without_clamp() {
+        let info = load_index_symbol_info_fixture();
+
+        assert!(normalize_volume(250.0, &info, VolumeRounding::Nearest, false).is_err());
+    }
+
+    #[test]
+    fn test_normalize_volume_rejects_zero() {
+        let info = load_index_symbol_info_fixture();
+
+        assert!(normalize_volume(0.04, &info, VolumeRounding::Down, true).is_err());
+        assert!(normalize_volume(0.0, &info, VolumeRounding::Nearest, true).is_err());
+        assert!(normalize_volume(f64::NAN, &info, VolumeRounding::Nearest, true).is_err());
+    }
+
+    #[test]
+    fn test_normalize_volume_with_coarse_min() {
+        let mut info = load_symbol_info_fixture();
+        info.volume_min = 1.0;
+        info.volume_step = 0.5;
+
+        assert_eq!(normalize_volume(1.74, &info, VolumeRounding::Down, true).unwrap(), 1.5);
+        assert!(normalize_volume(0.5, &info, VolumeRounding::Nearest, false).is_err());
+    }
+
+    #[test]
+    fn test_pending_without_price_rejected() {
+        let info = load_symbol_info_fixture();
+        let request = order(Mt5TradeType::BuyStop).build().unwrap();
+
+        assert!(build_order_send_body(&request, &info, 0).is_err());
+    }
+
+    #[test]
+    fn test_trade_mode_restrictions() {
+        let mut symbol_info = load_symbol_info_fixture();
+        assert_eq!(symbol_info.trade_mode, Mt5TradeMode::Full);
+        let buy = order(Mt5TradeType::Buy).build().unwrap();
+        let sell_limit = order(Mt5TradeType::SellLimit).price(1.2).build().unwrap();
+        let close = order(Mt5TradeType::Sell).position(Some(50012345)).build().unwrap();
+
+        // (mode, buy allowed, sell allowed, close allowed)
+        let cases = [
+            (Mt5TradeMode::Full, true, true, true),
+            (Mt5TradeMode::LongOnly, true, false, true),
+            (Mt5TradeMode::ShortOnly, false, true, true),
+            (Mt5TradeMode::CloseOnly, false, false, true),
+            (Mt5TradeMode::Disabled, false, false, false),
+        ];
+        for (mode, buy_ok, sell_ok, close_ok) in cases {
+            symbol_info.trade_mode = mode;
+            let expectations = [(&buy, buy_ok), (&sell_limit, sell_ok), (&close, close_ok)];
+            for (request, expected) in expectations {
+                let result = check_trade_mode(request, &symbol_info);
+                assert_eq!(result.is_ok(), expected, "{mode} {:?}", request.order_type);
+                if !expected {
+                    assert!(matches!(
+                        result,
+                        Err(ExecutionClientError::TradeModeRestricted { mode: m, .. }) if m == mode
+                    ));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_trade_mode_error_message() {
+        let mut symbol_info = load_symbol_info_fixture();
+        symbol_info.trade_mode = Mt5TradeMode::LongOnly;
+        let sell = order(Mt5TradeType::Sell).build().unwrap();
+
+        let error = check_trade_mode(&sell, &symbol_info).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "EURUSD trade mode LONGONLY does not allow opening a short position"
+        );
+    }
+
+    #[test]
+    fn test_execution_mode_selects_market_order_flow() {
+        let mut symbol_info = load_symbol_info_fixture();
+        let cases = [
+            (Mt5ExecutionMode::Request, MarketOrderFlow::QuoteThenDeal),
+            (Mt5ExecutionMode::Instant, MarketOrderFlow::PricedDeal),
+            (Mt5ExecutionMode::Market, MarketOrderFlow::Deal),
+            (Mt5ExecutionMode::Exchange, MarketOrderFlow::Deal),
+        ];
+        for (mode, flow) in cases {
+            symbol_info.trade_exemode = mode;
+            assert_eq!(check_market_order(&symbol_info).unwrap(), flow, "{mode}");
+            let body = build_order_send_body(&order(Mt5TradeType::Buy).build().unwrap(), &symbol_info, 0).unwrap();
+            assert_eq!(body["action"], json!(TRADE_ACTION_DEAL), "{mode}");
+        }
+    }
+
+    #[test]
+    fn test_disabled_and_prohibited_market_orders_rejected() {
+        let mut disabled = load_symbol_info_fixture();
+        disabled.trade_mode = Mt5TradeMode::Disabled;
+        let mut prohibited = load_symbol_info_fixture();
+        // Limit, stop and stop-limit orders only
+        prohibited.order_mode = 0b1110;
+        let mut unreported = load_symbol_info_fixture();
+        unreported.order_mode = 0;
+
+        let error = check_market_order(&disabled).unwrap_err();
+        assert_eq!(error.to_string(), "EURUSD does not accept market orders (trading is disabled)");
+        assert!(matches!(
+            check_market_order(&prohibited),
+            Err(ExecutionClientError::MarketOrderNotAllowed { reason, .. }) if reason.contains("order_mode")
+        ));
+        assert!(check_market_order(&unreported).is_ok());
+    }
+
+    fn eurusd_longs() -> Vec<Mt5TradePosition> {
+        let mut positions = load_positions_fixture();
+        let mut later = positions[0].clone();
+        later.ticket = 50012400;
+        later.time_msc += 60_000;
+        later.volume = 0.3;
+        positions.insert(0, later);
+        positions
+    }
+
+    #[test]
+    fn test_reduce_only_names_oldest_position_on_hedging_account() {
+        let sell = order(Mt5TradeType::Sell).reduce_only(true).build().unwrap();
+
+        let position = resolve_reduce_position(&sell, Mt5MarginMode::RetailHedging, &eurusd_longs()).unwrap();
+
+        assert_eq!(position, Some(50012345));
+    }
+
+    #[test]
+    fn test_reduce_only_nets_without_ticket_on_netting_account() {
+        // Netting accounts hold one position per symbol, so 0.3 of the 0.4 is reduced
+        let sell = order(Mt5TradeType::Sell).volume(0.3).reduce_only(true).build().unwrap();
+
+        for mode in [Mt5MarginMode::RetailNetting, Mt5MarginMode::Exchange] {
+            assert_eq!(resolve_reduce_position(&sell, mode, &eurusd_longs()).unwrap(), None);
+        }
+        // On a hedging account 0.3 exceeds the oldest position
+        assert!(resolve_reduce_position(&sell, Mt5MarginMode::RetailHedging, &eurusd_longs()).is_err());
+    }
+
+    #[test]
+    fn test_reduce_only_rejected_without_opposite_position() {
+        let buy = order(Mt5TradeType::Buy).reduce_only(true).build().unwrap();
+        let reversing = order(Mt5TradeType::Sell).volume(0.5).reduce_only(true).build().unwrap();
+
+        for mode in [Mt5MarginMode::RetailNetting, Mt5MarginMode::RetailHedging] {
+            assert!(matches!(
+                resolve_reduce_position(&buy, mode, &eurusd_longs()),
+                Err(ExecutionClientError::InvalidOrder(_))
+            ));
+            assert!(matches!(
+                resolve_reduce_position(&reversing, mode, &eurusd_longs()),
+                Err(ExecutionClientError::InvalidOrder(_))
+            ));
+        }
+    }
+
+    async fn submit_reduce_only(margin_mode: i64) -> Value {
+        let bridge = MockMt5Bridge::new();
+        let mut account_info = load_mt5_fixture("account_info");
+        account_info["result"]["margin_mode"] = json!(margin_mode);
+        bridge.respond("account_info", account_info);
+        bridge.respond("positions_get", load_mt5_fixture("positions_get"));
+        bridge.respond("symbol_info", load_mt5_fixture("symbol_info_eurusd"));
+        bridge.respond("order_send", load_mt5_fixture("order_send_success"));
+        let (client, handle) = mock_exec_client(&bridge, Mt5ExecutionClientConfig::default()).await;
+
+        let sell = order(Mt5TradeType::Sell).reduce_only(true).build().unwrap();
+        client.submit_order(&sell).await.unwrap();
+
+        assert_eq!(bridge.requests_for("positions_get"), vec![json!({"symbol": "EURUSD"})]);
+        handle.abort();
+        bridge.requests_for("order_send").remove(0)
+    }
+
+    #[tokio::test]
+    async fn test_reduce_only_close_differs_between_hedging_and_netting() {
+        let hedging = submit_reduce_only(2).await;
+        let netting = submit_reduce_only(0).await;
+
+        assert_eq!(hedging["position"], json!(50012345));
+        assert!(netting.get("position").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_find_order_by_client_id_in_history() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("history_orders_get", load_mt5_fixture("history_orders_audit"));
+        bridge.respond("history_deals_get", load_mt5_fixture("history_deals_audit"));
+        let (client, handle) = mock_exec_client(&bridge, Mt5ExecutionClientConfig::default()).await;
+        let lookback = Duration::from_secs(86_400);
+
+        // Sent verbatim as the comment, filled in two deals
+        let found = client
+            .find_order_by_client_id(&ClientOrderId::from("O-20240101-002"), lookback)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.order.ticket, 60012345);
+        assert_eq!(found.deals.iter().map(|deal| deal.ticket).collect::<Vec<_>>(), vec![40012345, 40012346]);
+
+        // Hashed into the comment and magic
+        let hashed = ClientOrderId::from("O-20240101-000000-001-001-AUDIT-3");
+        assert_eq!(order_correlation(&hashed).magic, Some(1857041881361714106));
+        let found = client.find_order_by_client_id(&hashed, lookback).await.unwrap().unwrap();
+        assert_eq!(found.order.ticket, 60012346);
+        assert_eq!(found.deals.len(), 1);
+        assert_eq!(found.deals[0].ticket, 40012347);
+
+        let missing = client
+            .find_order_by_client_id(&ClientOrderId::from("O-20240101-999"), lookback)
+            .await
+            .unwrap();
+        assert!(missing.is_none());
+
+        let windows = bridge.requests_for("history_orders_get");
+        assert_eq!(windows.len(), 3);
+        assert_eq!(
+            windows[0][1].as_i64().unwrap() - windows[0][0].as_i64().unwrap(),
+            86_400 + 2 * HISTORY_SEARCH_MARGIN_SECS
+        );
+        assert_eq!(bridge.requests_for("history_deals_get").len(), 2);
+        handle.abort();
+    }
+
+    fn reconcile_bridge() -> MockMt5Bridge {
+        let bridge = MockMt5Bridge::new();
+        let mut history = load_mt5_fixture("orders_get");
+        history["result"][0]["state"] = json!(4);
+        bridge.respond("orders_get", load_mt5_fixture("orders_get"));
+        bridge.respond("history_orders_get", history);
+        bridge.respond("history_deals_get", load_mt5_fixture("history_deals_round_trip"));
+        bridge.respond("positions_get", load_mt5_fixture("positions_get"));
+        bridge.respond("account_info", load_mt5_fixture("account_info"));
+        bridge
+    }
+
+    async fn reconcile_with(bridge: &MockMt5Bridge) -> ReconciliationReport {
+        let (client, handle) = mock_exec_client(bridge, Mt5ExecutionClientConfig::default()).await;
+
+        let report = client.reconcile(Duration::from_secs(86_400)).await;
+        handle.abort();
+        report
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_populates_every_section() {
+        let report = reconcile_with(&reconcile_bridge()).await;
+
+        assert!(report.errors.is_empty(), "{:?}", report.errors);
+        let statuses: Vec<_> = report.order_reports.iter().map(|r| r.status).collect();
+        assert_eq!(statuses, vec![Some(OrderStatus::Accepted), Some(OrderStatus::Filled)]);
+        assert_eq!(report.position_reports.len(), load_positions_fixture().len());
+        assert_eq!(report.fill_reports.len(), generate_fill_reports(&load_deals_fixture()).len());
+        assert!(report.account_state.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_collects_section_failures() {
+        let bridge = reconcile_bridge();
+        bridge.fail("history_deals_get", 500, "terminal busy");
+
+        let report = reconcile_with(&bridge).await;
+
+        assert_eq!(report.errors.len(), 1);
+        assert!(report.errors[0].starts_with("fills:"));
+        assert!(report.fill_reports.is_empty());
+        assert_eq!(report.order_reports.len(), 2);
+        assert!(report.account_state.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_filling_retried_with_next_allowed_mode() {
+        let bridge = MockMt5Bridge::new();
+        let mut invalid_fill = load_mt5_fixture("order_send_rejected");
+        invalid_fill["result"]["retcode"] = json!(TRADE_RETCODE_INVALID_FILL);
+        bridge.respond("symbol_info", load_mt5_fixture("symbol_info_eurusd"));
+        bridge.respond_once("order_send", invalid_fill);
+        bridge.respond("order_send", load_mt5_fixture("order_send_success"));
+        let (client, handle) = mock_exec_client(&bridge, Mt5ExecutionClientConfig::default()).await;
+
+        let result = client.submit_order(&order(Mt5TradeType::Buy).build().unwrap()).await.unwrap();
+
+        assert_eq!(result.retcode, TRADE_RETCODE_DONE);
+        let sends = bridge.requests_for("order_send");
+        assert_eq!(sends.len(), 2);
+        assert!(sends[0].get("type_filling").is_none());
+        assert_eq!(sends[1]["type_filling"], json!(ORDER_FILLING_IOC));
+        handle.abort();
+    }
+
+    #[test]
+    fn test_fallback_filling_modes_skip_return_for_market_execution() {
+        let mut symbol_info = load_symbol_info_fixture();
+        symbol_info.filling_mode = SYMBOL_FILLING_FOK | SYMBOL_FILLING_IOC;
+        symbol_info.trade_exemode = Mt5ExecutionMode::Market;
+        let market = order(Mt5TradeType::Buy).build().unwrap();
+        let pending = order(Mt5TradeType::BuyLimit).price(1.1).build().unwrap();
+
+        assert_eq!(
+            fallback_filling_modes(&market, &symbol_info, ORDER_FILLING_FOK),
+            vec![ORDER_FILLING_IOC]
+        );
+        assert_eq!(
+            fallback_filling_modes(&pending, &symbol_info, ORDER_FILLING_FOK),
+            vec![ORDER_FILLING_IOC, ORDER_FILLING_RETURN]
+        );
+        symbol_info.trade_exemode = Mt5ExecutionMode::Instant;
+        assert_eq!(
+            fallback_filling_modes(&market, &symbol_info, ORDER_FILLING_IOC),
+            vec![ORDER_FILLING_FOK, ORDER_FILLING_RETURN]
+        );
+    }
+
+    async fn validate_with(
+        symbol_info: Value,
+        config: Mt5ExecutionClientConfig,
+        request: Mt5OrderRequest,
+    ) -> Result<Mt5OrderRequest, ExecutionClientError> {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_info", json!({ "result": symbol_info }));
+        bridge.respond("symbol_select", json!({"result": true}));
+        let (client, handle) = mock_exec_client(&bridge, config).await;
+
+        let result = client.validate_order(&request).await.map(|(request, _)| request);
+        handle.abort();
+        result
+    }
+
+    #[tokio::test]
+    async fn test_validate_order_reports_each_failing_guard() {
+        let eurusd = load_mt5_fixture("symbol_info_eurusd")["result"].clone();
+        let buy = || order(Mt5TradeType::Buy).build().unwrap();
+        let with = |field: &str, value: Value| {
+            let mut info = eurusd.clone();
+            info[field] = value;
+            info
+        };
+        let strict = || Mt5ExecutionClientConfig {
+            auto_select_symbols: false,
+            clamp_volume: false,
+            ..Default::default()
+        };
+
+        assert!(validate_with(eurusd.clone(), strict(), buy()).await.is_ok());
+        assert!(matches!(
+            validate_with(Value::Null, strict(), buy()).await,
+            Err(ExecutionClientError::SymbolNotFound(symbol)) if symbol == "EURUSD"
+        ));
+        assert!(matches!(
+            validate_with(with("select", json!(false)), strict(), buy()).await,
+            Err(ExecutionClientError::SymbolNotSelected(_))
+        ));
+        // With auto-select on, the symbol is selected instead
+        assert!(validate_with(with("select", json!(false)), Default::default(), buy())
+            .await
+            .is_ok());
+        assert!(matches!(
+            validate_with(with("trade_mode", json!(3)), strict(), buy()).await,
+            Err(ExecutionClientError::TradeModeRestricted { .. })
+        ));
+        let oversized = order(Mt5TradeType::Buy).volume(501.0).build().unwrap();
+        assert!(matches!(
+            validate_with(eurusd.clone(), strict(), oversized).await,
+            Err(ExecutionClientError::InvalidOrder(message)) if message.contains("volume_max")
+        ));
+        let ioc = order(Mt5TradeType::Buy).type_filling(ORDER_FILLING_IOC).build().unwrap();
+        assert!(matches!(
+            validate_with(with("filling_mode", json!(SYMBOL_FILLING_FOK)), strict(), ioc).await,
+            Err(ExecutionClientError::UnsupportedFilling { filling: ORDER_FILLING_IOC, .. })
+        ));
+        let market_return = order(Mt5TradeType::Buy).type_filling(ORDER_FILLING_RETURN).build().unwrap();
+        assert!(matches!(
+            validate_with(eurusd.clone(), strict(), market_return).await,
+            Err(ExecutionClientError::UnsupportedFilling { .. })
+        ));
+    }
+
+    async fn stops_client(positions: Value) -> (Mt5ExecutionClient, MockMt5Bridge, tokio::task::JoinHandle<()>) {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("positions_get", positions);
+        bridge.respond("orders_get", load_mt5_fixture("orders_get"));
+        bridge.respond("symbol_info", load_mt5_fixture("symbol_info_eurusd"));
+        bridge.respond("order_send", load_mt5_fixture("order_send_success"));
+        let (client, handle) = mock_exec_client(&bridge, Mt5ExecutionClientConfig::default()).await;
+        (client, bridge, handle)
+    }
+
+    #[tokio::test]
+    async fn test_update_stops_trails_position_stop_loss() {
+        let (client, bridge, handle) = stops_client(load_mt5_fixture("positions_get")).await;
+
+        let result = client.update_stops(50012345, Some(1.102345678), None).await.unwrap();
+
+        assert_eq!(result.retcode, TRADE_RETCODE_DONE);
+        assert_eq!(bridge.requests_for("positions_get"), vec![json!({"ticket": 50012345})]);
+        assert_eq!(
+            bridge.requests_for("order_send"),
+            vec![json!({
+                "action": TRADE_ACTION_SLTP,
+                "symbol": "EURUSD",
+                "position": 50012345,
+                "sl": 1.10235,
+                "tp": 1.10785,
+                "magic": 0,
+            })]
+        );
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_update_stops_rejects_pending_order_ticket() {
+        let (client, bridge, handle) = stops_client(json!({"result": null})).await;
+
+        let result = client.update_stops(50012401, Some(1.095), None).await;
+
+        assert!(matches!(
+            result,
+            Err(ExecutionClientError::InvalidOrder(message)) if message.contains("pending order")
+        ));
+        assert!(bridge.requests_for("order_send").is_empty());
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_close_only_symbol_rejected_before_order_send() {
+        let bridge = MockMt5Bridge::new();
+        let mut symbol_info = load_mt5_fixture("symbol_info_eurusd");
+        symbol_info["result"]["trade_mode"] = json!(3);
+        bridge.respond("symbol_info", symbol_info);
+        bridge.respond("order_send", load_mt5_fixture("order_send_success"));
+        let (client, handle) = mock_exec_client(&bridge, Mt5ExecutionClientConfig::default()).await;
+
+        let result = client.submit_order(&order(Mt5TradeType::Buy).build().unwrap()).await;
+
+        assert!(matches!(result, Err(ExecutionClientError::TradeModeRestricted { .. })));
+        assert!(bridge.requests_for("order_send").is_empty());
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_request_execution_deals_at_refreshed_quote() {
+        let bridge = MockMt5Bridge::new();
+        let mut symbol_info = load_mt5_fixture("symbol_info_eurusd");
+        symbol_info["result"]["trade_exemode"] = json!(0);
+        bridge.respond("symbol_info", symbol_info);
+        bridge.respond("symbol_info_tick", json!({"result": {"bid": 1.10501, "ask": 1.10508}}));
+        bridge.respond("order_send", load_mt5_fixture("order_send_success"));
+        let (client, handle) = mock_exec_client(&bridge, Mt5ExecutionClientConfig::default()).await;
+
+        client.submit_order(&order(Mt5TradeType::Buy).build().unwrap()).await.unwrap();
+        // Pending orders carry their own price, so no quote is needed
+        client
+            .submit_order(&order(Mt5TradeType::BuyLimit).price(1.1).build().unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(bridge.requests_for("symbol_info_tick"), vec![json!(["EURUSD"])]);
+        let sends = bridge.requests_for("order_send");
+        assert_eq!(sends[0]["action"], json!(TRADE_ACTION_DEAL));
+        assert_eq!(sends[0]["price"], json!(1.10508));
+        assert_eq!(sends[1]["action"], json!(TRADE_ACTION_PENDING));
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_prohibited_market_order_rejected_before_order_send() {
+        let bridge = MockMt5Bridge::new();
+        let mut symbol_info = load_mt5_fixture("symbol_info_eurusd");
+        symbol_info["result"]["order_mode"] = json!(0b1110);
+        bridge.respond("symbol_info", symbol_info);
+        bridge.respond("order_send", load_mt5_fixture("order_send_success"));
+        let (client, handle) = mock_exec_client(&bridge, Mt5ExecutionClientConfig::default()).await;
+
+        let result = client.submit_order(&order(Mt5TradeType::Buy).build().unwrap()).await;
+
+        assert!(matches!(result, Err(ExecutionClientError::MarketOrderNotAllowed { .. })));
+        assert!(bridge.requests_for("order_send").is_empty());
+        handle.abort();
+    }
+
+    #[test]
+    fn test_fill_reports_round_trip_tagged_by_entry() {
+        let reports = generate_fill_reports(&load_deals_fixture());
+
+        // The deposit is not a fill
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].entry, Mt5DealEntry::In);
+        assert_eq!(reports[1].entry, Mt5DealEntry::Out);
+        assert!(reports.iter().all(|r| r.position_id == 50012345));
+        assert_eq!(reports[0].fill_quantity, reports[1].fill_quantity);
+        assert_eq!(reports[1].fill_id, "40012399");
+    }
+
+    #[test]
+    fn test_order_states_map_to_order_status() {
+        let cases = [
+            (0, Some(OrderStatus::Submitted)),
+            (1, Some(OrderStatus::Accepted)),
+            (2, Some(OrderStatus::Canceled)),
+            (3, Some(OrderStatus::PartiallyFilled)),
+            (4, Some(OrderStatus::Filled)),
+            (5, Some(OrderStatus::Rejected)),
+            (6, Some(OrderStatus::Expired)),
+            (7, Some(OrderStatus::Submitted)),
+            (8, Some(OrderStatus::PendingUpdate)),
+            (9, Some(OrderStatus::PendingCancel)),
+            (42, None),
+        ];
+
+        for (raw, expected) in cases {
+            let state = Mt5OrderState::from(raw);
+            assert_eq!(state.to_order_status(), expected, "state {raw}");
+            assert_eq!(i64::from(state), raw);
+        }
+        assert_eq!(Mt5OrderState::from(42), Mt5OrderState::Other(42));
+    }
+
+    #[test]
+    fn test_order_status_reports_from_orders_get() {
+        let mut orders: Vec<Mt5TradeOrder> =
+            serde_json::from_value(load_mt5_fixture("orders_get")["result"].clone()).unwrap();
+        let mut partial = orders[0].clone();
+        partial.ticket += 1;
+        partial.state = Mt5OrderState::Partial;
+        partial.volume_current = 0.05;
+        let mut unknown = orders[0].clone();
+        unknown.ticket += 2;
+        unknown.state = Mt5OrderState::Other(42);
+        orders.extend([partial, unknown]);
+
+        let reports = generate_order_status_reports(&orders);
+
+        assert_eq!(reports.len(), 3);
+        assert_eq!(reports[0].order_id, "50012401");
+        assert_eq!(reports[0].status, Some(OrderStatus::Accepted));
+        assert_eq!(reports[0].filled_qty, 0.0);
+        assert_eq!(reports[1].status, Some(OrderStatus::PartiallyFilled));
+        assert!((reports[1].filled_qty - 0.15).abs() < 1e-9);
+        assert_eq!(reports[2].state, Mt5OrderState::Other(42));
+        assert_eq!(reports[2].status, None);
+    }
+
+    #[test]
+    fn test_position_pnl_sums_scale_in_deals() {
+        let pnl = aggregate_position_pnl(&load_scale_in_deals_fixture());
+
+        assert_eq!(pnl.len(), 1);
+        let position = &pnl[0];
+        assert_eq!(position.position_id, 50020001);
+        assert_eq!(position.symbol, "EURUSD");
+        assert_eq!(position.deal_count, 3);
+        assert!((position.commission - -2.10).abs() < 1e-9);
+        assert!((position.swap - -0.45).abs() < 1e-9);
+        assert!((position.profit - 70.0).abs() < 1e-9);
+        assert!((position.net_profit - 67.45).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_position_pnl_from_bridge() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("history_deals_get", load_mt5_fixture("history_deals_scale_in"));
+        let (client, handle) = mock_exec_client(&bridge, Mt5ExecutionClientConfig::default()).await;
+
+        let pnl = client.position_pnl(1704067200, 1704240000).await.unwrap();
+
+        assert_eq!(pnl, aggregate_position_pnl(&load_scale_in_deals_fixture()));
+        handle.abort();
+    }
+
+    async fn precheck_client(
+        check_fixture: &str,
+    ) -> (Mt5ExecutionClient, MockMt5Bridge, tokio::task::JoinHandle<()>) {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_info", load_mt5_fixture("symbol_info_eurusd"));
+        bridge.respond("order_check", load_mt5_fixture(check_fixture));
+        bridge.respond("order_send", load_mt5_fixture("order_send_success"));
+        let (client, handle) = mock_exec_client(
+            &bridge,
+            Mt5ExecutionClientConfig {
+                precheck_margin: true,
+                ..Default::default()
+            },
+        )
+        .await;
+        (client, bridge, handle)
+    }
+
+    #[tokio::test]
+    async fn test_margin_precheck_passes_through() {
+        let (client, bridge, handle) = precheck_client("order_check_sufficient").await;
+        let request = order(Mt5TradeType::Buy).build().unwrap();
+
+        let result = client.submit_order(&request).await.unwrap();
+
+        assert_eq!(result.retcode, TRADE_RETCODE_DONE);
+        assert_eq!(bridge.requests_for("order_check").len(), 1);
+        assert_eq!(bridge.requests_for("order_send").len(), 1);
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_margin_precheck_aborts_before_order_send() {
+        let (client, bridge, handle) = precheck_client("order_check_insufficient").await;
+        let request = order(Mt5TradeType::Buy).volume(100.0).build().unwrap();
+
+        let result = client.submit_order(&request).await;
+
+        assert!(matches!(
+            result,
+            Err(ExecutionClientError::HttpClient(HttpClientError::InsufficientMargin(_)))
+        ));
+        assert!(bridge.requests_for("order_send").is_empty());
+        handle.abort();
+    }
+
+    async fn submit_for(fixture: &str) -> (OrderEventAny, Vec<Value>, bool) {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_info", load_mt5_fixture("symbol_info_eurusd"));
+        bridge.respond("order_send", load_mt5_fixture(fixture));
+        let (client, handle) = mock_exec_client(&bridge, Mt5ExecutionClientConfig::default()).await;
+        let client_order_id = ClientOrderId::from("O-20240101-0001");
+
+        let event = client
+            .submit_order_for(
+                TraderId::from("TRADER-001"),
+                StrategyId::from("S-001"),
+                InstrumentId::from("EURUSD.MT5"),
+                client_order_id,
+                &order(Mt5TradeType::Buy).build().unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let pending = client.is_pending(&client_order_id);
+        handle.abort();
+        (event, bridge.requests_for("order_send"), pending)
+    }
+
+    #[tokio::test]
+    async fn test_submit_order_for_emits_accepted() {
+        let (event, sent, pending) = submit_for("order_send_success").await;
+
+        let OrderEventAny::Accepted(accepted) = event else {
+            panic!("expected OrderAccepted, was {event:?}");
+        };
+        assert_eq!(accepted.client_order_id, ClientOrderId::from("O-20240101-0001"));
+        assert_eq!(accepted.strategy_id, StrategyId::from("S-001"));
+        assert_eq!(accepted.instrument_id, InstrumentId::from("EURUSD.MT5"));
+        assert_eq!(accepted.venue_order_id, VenueOrderId::from("50012345"));
+        assert_eq!(accepted.account_id, AccountId::from("MT5-demo"));
+        assert_eq!(sent[0]["comment"], "O-20240101-0001");
+        assert!(!pending);
+    }
+
+    #[tokio::test]
+    async fn test_submit_order_for_emits_rejected() {
+        let (event, _, pending) = submit_for("order_send_rejected").await;
+
+        let OrderEventAny::Rejected(rejected) = event else {
+            panic!("expected OrderRejected, was {event:?}");
+        };
+        assert_eq!(rejected.client_order_id, ClientOrderId::from("O-20240101-0001"));
+        assert_eq!(rejected.trader_id, TraderId::from("TRADER-001"));
+        assert!(rejected.reason.as_str().contains("retcode 10014"));
+        assert!(!pending);
+    }
+
+    async fn cancel_for(fixture: &str) -> (OrderEventAny, Vec<Value>) {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("order_send", load_mt5_fixture(fixture));
+        let (client, handle) = mock_exec_client(&bridge, Mt5ExecutionClientConfig::default()).await;
+
+        let event = client
+            .cancel_order_for(
+                TraderId::from("TRADER-001"),
+                StrategyId::from("S-001"),
+                InstrumentId::from("EURUSD.MT5"),
+                ClientOrderId::from("O-20240101-0001"),
+                VenueOrderId::from("50012401"),
+            )
+            .await;
+
+        handle.abort();
+        (event, bridge.requests_for("order_send"))
+    }
+
+    #[tokio::test]
+    async fn test_cancel_order_for_emits_canceled() {
+        let (event, sent) = cancel_for("order_send_success").await;
+
+        let OrderEventAny::Canceled(canceled) = event else {
+            panic!("expected OrderCanceled, was {event:?}");
+        };
+        assert_eq!(canceled.client_order_id, ClientOrderId::from("O-20240101-0001"));
+        assert_eq!(canceled.venue_order_id, Some(VenueOrderId::from("50012401")));
+        assert_eq!(canceled.account_id, Some(AccountId::from("MT5-demo")));
+        assert_eq!(sent, vec![json!({"action": TRADE_ACTION_REMOVE, "order": 50012401})]);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_order_for_emits_cancel_rejected() {
+        let (event, _) = cancel_for("order_send_rejected").await;
+
+        let OrderEventAny::CancelRejected(rejected) = event else {
+            panic!("expected OrderCancelRejected, was {event:?}");
+        };
+        assert_eq!(rejected.client_order_id, ClientOrderId::from("O-20240101-0001"));
+        assert_eq!(rejected.strategy_id, StrategyId::from("S-001"));
+        assert!(rejected.reason.as_str().contains("retcode 10014"));
+    }
+
+    #[tokio::test]
+    async fn test_close_position_for_reports_flat_position() {
+        let (client, bridge, handle) = stops_client(load_mt5_fixture("positions_get")).await;
+
+        let report = client
+            .close_position_for(ClientOrderId::from("O-20240101-0002"), PositionId::from("50012345"))
+            .await
+            .unwrap();
+
+        assert_eq!(report.position_side, PositionSideSpecified::Flat);
+        assert_eq!(report.venue_position_id, Some(PositionId::from("50012345")));
+        let sent = bridge.requests_for("order_send");
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0]["position"], 50012345);
+        assert_eq!(sent[0]["type"], Mt5TradeType::Sell.as_i64());
+        assert_eq!(sent[0]["volume"], 0.1);
+        assert_eq!(sent[0]["comment"], "O-20240101-0002");
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_close_position_for_unknown_ticket_sends_nothing() {
+        let (client, bridge, handle) = stops_client(json!({"result": null})).await;
+
+        let result = client
+            .close_position_for(ClientOrderId::from("O-20240101-0002"), PositionId::from("50012345"))
+            .await;
+
+        assert!(matches!(result, Err(ExecutionClientError::InvalidOrder(_))));
+        assert!(bridge.requests_for("order_send").is_empty());
+        handle.abort();
+    }
+
+    async fn margin_client(ttl_ms: u64) -> (Mt5ExecutionClient, MockMt5Bridge, tokio::task::JoinHandle<()>) {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("order_calc_margin", json!({"result": 110.42}));
+        let (client, handle) = mock_exec_client(
+            &bridge,
+            Mt5ExecutionClientConfig {
+                margin_cache_ttl_ms: ttl_ms,
+                ..Default::default()
+            },
+        )
+        .await;
+        (client, bridge, handle)
+    }
+
+    #[tokio::test]
+    async fn test_order_margin_served_from_cache_for_nearby_price() {
+        let (client, bridge, handle) = margin_client(60_000).await;
+
+        let first = client.order_margin(Mt5TradeType::Buy, "EURUSD", 0.1, 1.10419, false).await.unwrap();
+        let second = client.order_margin(Mt5TradeType::BuyLimit, "EURUSD", 0.3, 1.10385, false).await.unwrap();
+        client.order_margin(Mt5TradeType::Buy, "EURUSD", 0.1, 1.10419, true).await.unwrap();
+
+        assert!((first - 110.42).abs() < 1e-9);
+        assert!((second - 331.26).abs() < 1e-9);
+        assert_eq!(
+            bridge.requests_for("order_calc_margin"),
+            vec![json!([0, "EURUSD", 0.1, 1.10419]), json!([0, "EURUSD", 0.1, 1.10419])]
+        );
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_order_margin_refetched_after_ttl() {
+        let (client, bridge, handle) = margin_client(0).await;
+
+        client.order_margin(Mt5TradeType::Sell, "EURUSD", 0.1, 1.10412, false).await.unwrap();
+        client.order_margin(Mt5TradeType::Sell, "EURUSD", 0.1, 1.10412, false).await.unwrap();
+
+        assert_eq!(bridge.requests_for("order_calc_margin").len(), 2);
+        handle.abort();
+    }
+
+    async fn connect_requiring(
+        trade_mode: i64,
+        required: Option<Mt5Environment>,
+    ) -> (Mt5ExecutionClient, Result<(), ExecutionClientError>) {
+        let (client, result, _, _) = connect_requiring_with_bridge(trade_mode, required).await;
+        (client, result)
+    }
+
+    /// Like `connect_requiring`, also returning the bridge and every state the client passed through.
+    async fn connect_requiring_with_bridge(
+        trade_mode: i64,
+        required: Option<Mt5Environment>,
+    ) -> (
+        Mt5ExecutionClient,
+        Result<(), ExecutionClientError>,
+        MockMt5Bridge,
+        Vec<ConnectionState>,
+    ) {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("initialize", json!({"result": true}));
+        bridge.respond("login", json!({"result": true}));
+        bridge.respond("shutdown", json!({"result": true}));
+        let mut account_info = load_mt5_fixture("account_info");
+        account_info["result"]["trade_mode"] = json!(trade_mode);
+        bridge.respond("account_info", account_info);
+        let (client, handle) = mock_exec_client(
+            &bridge,
+            Mt5ExecutionClientConfig {
+                terminal_check_interval_ms: None,
+                require_environment: required,
+                ..Default::default()
+            },
+        )
+        .await;
+        let mut transitions = client.subscribe_connection_state();
+
+        let result = client.connect().await;
+        handle.abort();
+        let mut states = Vec::new();
+        while let Ok(transition) = transitions.try_recv() {
+            states.push(transition.to);
+        }
+        (client, result, bridge, states)
+    }
+
+    #[tokio::test]
+    async fn test_demo_requirement_refuses_real_account() {
+        let (client, result, bridge, states) =
+            connect_requiring_with_bridge(2, Some(Mt5Environment::Demo)).await;
+
+        assert!(matches!(
+            result,
+            Err(ExecutionClientError::EnvironmentMismatch {
+                required: Mt5Environment::Demo,
+                actual: Mt5Environment::Real,
+            })
+        ));
+        assert_eq!(client.connection_state(), ConnectionState::Disconnected);
+        // The wrong account is never reported usable, and its session is closed
+        assert!(!states.contains(&ConnectionState::Connected));
+        assert_eq!(bridge.requests_for("shutdown").len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_matching_environment_connects() {
+        let (client, result) = connect_requiring(0, Some(Mt5Environment::Demo)).await;
+
+        result.unwrap();
+        assert_eq!(client.environment(), Some(Mt5Environment::Demo));
+        assert_eq!(client.connection_state(), ConnectionState::Connected);
+
+        let (client, result) = connect_requiring(2, None).await;
+
+        result.unwrap();
+        assert_eq!(client.environment(), Some(Mt5Environment::Real));
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_connected_times_out_against_dead_bridge() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+        let client = Mt5ExecutionClient::new(Mt5ExecutionClientConfig {
+            base_url: format!("http://{addr}"),
+            ..Default::default()
+        })
+        .unwrap();
+
+        assert!(client.connect().await.is_err());
+        let result = client.wait_until_connected(0.05).await;
+
+        assert!(matches!(
+            result,
+            Err(ExecutionClientError::ConnectionTimeout { state: ConnectionState::Faulted, .. })
+        ));
+    }
+
+    #[test]
+    fn test_unknown_order_send_response_is_ignored() {
+        let client = Mt5ExecutionClient::new(Mt5ExecutionClientConfig::default()).unwrap();
+
+        let event = client.handle_order_send_response(
+            &ClientOrderId::from("O-UNKNOWN"),
+            Ok(load_order_send_fixture("order_send_success")),
+            UnixNanos::from(1),
+        );
+
+        assert!(event.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_orders_with_lost_responses_resolved_on_reconnect() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("initialize", json!({"result": true}));
+        bridge.respond("login", json!({"result": true}));
+        bridge.respond("symbol_info", load_mt5_fixture("symbol_info_eurusd"));
+        bridge.delay("order_send", Duration::from_secs(2));
+        let mut orders = load_mt5_fixture("orders_get");
+        orders["result"][0]["comment"] = json!("O-20240101-LOST-1");
+        bridge.respond("orders_get", orders);
+        bridge.respond("history_orders_get", json!({"result": []}));
+        let (client, handle) = mock_exec_client(
+            &bridge,
+            Mt5ExecutionClientConfig {
+                http_timeout: 1,
+                ..Default::default()
+            },
+        )
+        .await;
+        client.connect().await.unwrap();
+        let submit = |client_order_id: &str| {
+            client.submit_order_for(
+                TraderId::from("TRADER-001"),
+                StrategyId::from("S-001"),
+                InstrumentId::from("EURUSD.MT5"),
+                ClientOrderId::from(client_order_id),
+                &order(Mt5TradeType::Buy).build().unwrap(),
+            )
+        };
+
+        let (placed, lost) = tokio::join!(submit("O-20240101-LOST-1"), submit("O-20240101-LOST-2"));
+        assert!(placed.is_none() && lost.is_none());
+        assert!(client.is_pending(&ClientOrderId::from("O-20240101-LOST-1")));
+        client.disconnect().await.unwrap();
+        let mut events = client.reconnect().await.unwrap();
+        events.sort_by_key(|event| event.client_order_id().to_string());
+
+        assert_eq!(events.len(), 2);
+        let OrderEventAny::Accepted(accepted) = &events[0] else {
+            panic!("expected OrderAccepted, was {:?}", events[0]);
+        };
+        assert_eq!(accepted.client_order_id, ClientOrderId::from("O-20240101-LOST-1"));
+        assert_eq!(accepted.venue_order_id, VenueOrderId::from("50012401"));
+        let OrderEventAny::Rejected(rejected) = &events[1] else {
+            panic!("expected OrderRejected, was {:?}", events[1]);
+        };
+        assert_eq!(rejected.client_order_id, ClientOrderId::from("O-20240101-LOST-2"));
+        assert!(!client.is_pending(&ClientOrderId::from("O-20240101-LOST-2")));
+        // The missing order was looked up again before being rejected
+        assert_eq!(bridge.requests_for("orders_get").len(), LOST_ORDER_LOOKUP_ATTEMPTS as usize);
+        assert!(client.reconnect().await.unwrap().is_empty());
+        handle.abort();
+    }
+
+    /// Registers `client_order_id` as sent with its `order_send` response lost.
+    fn mark_response_lost(client: &Mt5ExecutionClient, client_order_id: &str) {
+        let client_order_id = ClientOrderId::from(client_order_id);
+        client.pending_place_requests.insert(
+            client_order_id,
+            PendingPlace {
+                ids: (
+                    TraderId::from("TRADER-001"),
+                    StrategyId::from("S-001"),
+                    InstrumentId::from("EURUSD.MT5"),
+                ),
+                correlation: order_correlation(&client_order_id),
+                sent_at: 1704103200,
+                response_lost: true,
+            },
+        );
+    }
+
+    #[tokio::test]
+    async fn test_lost_order_listed_late_is_accepted_on_retry() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("history_orders_get", json!({"result": []}));
+        // The terminal lists the order only from the second lookup on
+        bridge.respond_once("orders_get", json!({"result": []}));
+        let mut orders = load_mt5_fixture("orders_get");
+        orders["result"][0]["comment"] = json!("O-20240101-LATE-1");
+        bridge.respond("orders_get", orders);
+        let (client, handle) = mock_exec_client(&bridge, Mt5ExecutionClientConfig::default()).await;
+        mark_response_lost(&client, "O-20240101-LATE-1");
+
+        let events = client.reconcile_pending_orders().await.unwrap();
+
+        assert_eq!(events.len(), 1);
+        let OrderEventAny::Accepted(accepted) = &events[0] else {
+            panic!("expected OrderAccepted, was {:?}", events[0]);
+        };
+        assert_eq!(accepted.venue_order_id, VenueOrderId::from("50012401"));
+        assert_eq!(bridge.requests_for("orders_get").len(), 2);
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_monitor_reconnect_reconciles_lost_orders() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("initialize", json!({"result": true}));
+        bridge.respond("login", json!({"result": true}));
+        bridge.respond("account_info", load_mt5_fixture("account_info"));
+        bridge.respond_once("terminal_info", json!({"result": {"connected": false}}));
+        bridge.respond("terminal_info", json!({"result": {"connected": true}}));
+        let mut orders = load_mt5_fixture("orders_get");
+        orders["result"][0]["comment"] = json!("O-20240101-LOST-1");
+        bridge.respond("orders_get", orders);
+        bridge.respond("history_orders_get", json!({"result": []}));
+        let (client, handle) = mock_exec_client(
+            &bridge,
+            Mt5ExecutionClientConfig {
+                terminal_check_interval_ms: Some(10),
+                ..Default::default()
+            },
+        )
+        .await;
+        let mut events = client.subscribe_order_events();
+        mark_response_lost(&client, "O-20240101-LOST-1");
+
+        // The monitor sees the terminal drop and come back, then reconciles on its own
+        client.connect().await.unwrap();
+        let event = tokio::time::timeout(Duration::from_secs(5), events.recv())
+            .await
+            .unwrap()
+            .unwrap();
+
+        let OrderEventAny::Accepted(accepted) = event else {
+            panic!("expected OrderAccepted, was {event:?}");
+        };
+        assert_eq!(accepted.client_order_id, ClientOrderId::from("O-20240101-LOST-1"));
+        assert!(!client.is_pending(&ClientOrderId::from("O-20240101-LOST-1")));
+        assert_eq!(bridge.requests_for("initialize").len(), 2);
+        client.disconnect().await.unwrap();
+        handle.abort();
+    }
+
+    #[test]
+    fn test_short_client_order_id_is_sent_as_comment() {
+        let correlation = order_correlation(&ClientOrderId::from("O-20240101-000000-001-001-1"));
+
+        assert_eq!(correlation.comment, "O-20240101-000000-001-001-1");
+        assert_eq!(correlation.magic, None);
+    }
+
+    #[test]
+    fn test_printable_client_order_id_is_sent_as_comment() {
+        let correlation = order_correlation(&ClientOrderId::from("O-20240101-003@ftmo #2"));
+
+        assert_eq!(correlation.comment, "O-20240101-003@ftmo #2");
+        assert_eq!(correlation.magic, None);
+    }
+
+    #[test]
+    fn test_hashed_magic_fits_signed_mt5_magic() {
+        // FNV-1a of this id has the top bit set, which MT5's signed magic cannot hold
+        let client_order_id = ClientOrderId::from("O-20240101-000000-001-001-AUDIT-3");
+
+        let magic = order_correlation(&client_order_id).magic.unwrap();
+
+        assert_eq!(magic, 1857041881361714106);
+        let order: Mt5TradeOrder = serde_json::from_value(json!({
+            "ticket": 60012346,
+            "time_setup": 1704106800,
+            "type": 1,
+            "state": 4,
+            "magic": magic,
+            "volume_initial": 0.1,
+            "volume_current": 0.0,
+            "price_open": 1.10402,
+            "symbol": "EURUSD",
+            "comment": "",
+        }))
+        .unwrap();
+        assert!(order_correlation(&client_order_id).matches(&order));
+    }
+
+    #[test]
+    fn test_long_client_order_id_falls_back_to_magic() {
+        let client_order_id = ClientOrderId::from("O-20240101-000000-001-001-EXTENDED-1");
+
+        let correlation = order_correlation(&client_order_id);
+
+        assert!(is_valid_comment(&correlation.comment));
+        assert!(correlation.comment.starts_with(HASHED_COMMENT_PREFIX));
+        let magic = correlation.magic.unwrap();
+        assert_eq!(correlation.comment, format!("NT-{magic:016x}"));
+        assert_eq!(order_correlation(&client_order_id), correlation);
+        assert_ne!(
+            order_correlation(&ClientOrderId::from("O-20240101-000000-001-001-EXTENDED-2")),
+            correlation
+        );
+    }
+
+    #[test]
+    fn test_invalid_explicit_comment_rejected() {
+        let long = order(Mt5TradeType::Buy)
+            .comment("x".repeat(MAX_COMMENT_LEN + 1))
+            .build()
+            .unwrap();
+        let accented = order(Mt5TradeType::Buy)
+            .comment("stratégie".to_string())
+            .build()
+            .unwrap();
+
+        for request in [long, accented] {
+            assert!(matches!(
+                build_order_send_body(&request, &load_symbol_info_fixture(), 0),
+                Err(ExecutionClientError::InvalidOrder(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_long_client_order_id_submitted_with_hashed_comment() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_info", load_mt5_fixture("symbol_info_eurusd"));
+        bridge.respond("order_send", load_mt5_fixture("order_send_success"));
+        let (client, handle) = mock_exec_client(&bridge, Mt5ExecutionClientConfig::default()).await;
+        let client_order_id = ClientOrderId::from("O-20240101-000000-001-001-EXTENDED-1");
+
+        let event = client
+            .submit_order_for(
+                TraderId::from("TRADER-001"),
+                StrategyId::from("S-001"),
+                InstrumentId::from("EURUSD.MT5"),
+                client_order_id,
+                &order(Mt5TradeType::Buy).build().unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let correlation = order_correlation(&client_order_id);
+        let sent = bridge.requests_for("order_send");
+        assert_eq!(sent[0]["comment"], correlation.comment.as_str());
+        assert_eq!(sent[0]["magic"], correlation.magic.unwrap());
+        assert_eq!(event.client_order_id(), client_order_id);
+        handle.abort();
+    }
+
+    fn account_feed_finished(client: &Mt5ExecutionClient) -> bool {
+        client
+            .account_feed
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map_or(true, |feed| feed.task.is_finished())
+    }
+
+    async fn wait_account_feed_finished(client: &Mt5ExecutionClient) {
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while !account_feed_finished(client) {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("account polling did not stop");
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_and_unsubscribe_stop_account_polling() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("account_info", load_mt5_fixture("account_info"));
+        // Polls stay in flight, which must not hold up shutdown
+        bridge.delay("positions_get", Duration::from_secs(30));
+        let (client, handle) = mock_exec_client(&bridge, Mt5ExecutionClientConfig::default()).await;
+
+        let mut receivers: Vec<_> = (0..3).map(|_| client.subscribe_account_state(Duration::from_millis(5))).collect();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!account_feed_finished(&client));
+
+        client.disconnect().await.unwrap();
+        wait_account_feed_finished(&client).await;
+
+        // A new subscription after disconnect starts a fresh feed
+        receivers.push(client.subscribe_account_state(Duration::from_millis(5)));
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!account_feed_finished(&client));
+
+        // The channel closes once the cancelled task has dropped its sender
+        client.unsubscribe_account_state();
+        let closed = tokio::time::timeout(Duration::from_secs(1), receivers.last_mut().unwrap().recv()).await;
+        assert!(matches!(closed, Ok(Err(broadcast::error::RecvError::Closed))));
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_account_state_emits_once_per_balance_change() {
+        let bridge = MockMt5Bridge::new();
+        let mut account = load_mt5_fixture("account_info");
+        bridge.respond("account_info", account.clone());
+        bridge.respond("positions_get", load_mt5_fixture("positions_get"));
+        let (client, handle) = mock_exec_client(&bridge, Mt5ExecutionClientConfig::default()).await;
+        let interval = Duration::from_millis(5);
+        let mut first = client.subscribe_account_state(interval);
+        let mut second = client.subscribe_account_state(interval);
+
+        // Initial snapshot: the account and both open positions
+        for _ in 0..3 {
+            first.recv().await.unwrap();
+            second.recv().await.unwrap();
+        }
+        tokio::time::sleep(interval * 4).await;
+        assert!(first.try_recv().is_err());
+
+        account["result"]["balance"] = json!(10100.0);
+        account["result"]["equity"] = json!(10087.5);
+        account["result"]["margin_free"] = json!(9871.1);
+        bridge.respond("account_info", account);
+        tokio::time::sleep(interval * 6).await;
+
+        for receiver in [&mut first, &mut second] {
+            let AccountUpdate::Account(state) = receiver.try_recv().unwrap() else {
+                panic!("expected AccountState");
+            };
+            assert_eq!(state.balances[0].free.as_f64(), 9871.1);
+            assert!(receiver.try_recv().is_err());
+        }
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_instruments_loaded_by_provider_are_visible_to_execution_client() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond(
+            "symbols_get",
+            json!([{
+                "symbol": "EURUSD",
+                "digits": 5,
+                "point_size": 0.00001,
+                "volume_min": 0.01,
+                "volume_max": 100.0,
+                "volume_step": 0.01,
+                "contract_size": 100000.0,
+                "margin_initial": null,
+                "margin_maintenance": null,
+                "type": "forex"
+            }]),
+        );
+        let (addr, handle) = bridge.start().await;
+        let provider = Mt5InstrumentProvider::new(crate::config::Mt5InstrumentProviderConfig {
+            base_url: format!("http://{addr}"),
+            ..Default::default()
+        })
+        .unwrap();
+        let mut client = Mt5ExecutionClient::new(Mt5ExecutionClientConfig {
+            base_url: format!("http://{addr}"),
+            ..Default::default()
+        })
+        .unwrap();
+        client.set_instrument_provider(&provider);
+        let instrument_id = InstrumentId::from("EURUSD.MT5");
+        assert!(client.instrument(&instrument_id).is_none());
+
+        provider.load_all_async(None).await.unwrap();
+
+        let instrument = client.instrument(&instrument_id).unwrap();
+        assert_eq!(instrument.id(), instrument_id);
+        assert_eq!(instrument.price_precision(), 5);
+        handle.abort();
+    }
+
+    /// The `ORDER_ADD`, `DEAL_ADD` and `HISTORY_ADD` transactions the bridge
+    /// streams for a deal sent as `sent` and answered with `result`.
+    fn deal_transactions(sent: &Value, result: &Value, deal_type: i64) -> Vec<Mt5TradeTransaction> {
+        let common = json!({
+            "order": result["order"],
+            "symbol": sent["symbol"],
+            "order_type": sent["type"],
+            "price": result["price"],
+            "volume": result["volume"],
+        });
+        let with = |extra: Value| {
+            let mut transaction = common.clone();
+            transaction.as_object_mut().unwrap().extend(extra.as_object().unwrap().clone());
+            serde_json::from_value(transaction).unwrap()
+        };
+        vec![
+            // TRADE_TRANSACTION_ORDER_ADD
+            with(json!({"type": 0, "comment": sent["comment"], "magic": sent["magic"]})),
+            // TRADE_TRANSACTION_DEAL_ADD
+            with(json!({"type": 6, "deal": result["deal"], "deal_type": deal_type})),
+            // TRADE_TRANSACTION_HISTORY_ADD, ORDER_STATE_FILLED
+            with(json!({"type": 3, "order_state": 4})),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_order_lifecycle_from_submit_to_close() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("initialize", json!({"result": true}));
+        bridge.respond("login", json!({"result": true}));
+        bridge.respond("account_info", load_mt5_fixture("account_info"));
+        bridge.respond(
+            "symbols_get",
+            json!([{
+                "symbol": "EURUSD",
+                "digits": 5,
+                "point_size": 0.00001,
+                "volume_min": 0.01,
+                "volume_max": 100.0,
+                "volume_step": 0.01,
+                "contract_size": 100000.0,
+                "margin_initial": null,
+                "margin_maintenance": null,
+                "type": "forex"
+            }]),
+        );
+        bridge.respond("symbol_info", load_mt5_fixture("symbol_info_eurusd"));
+        bridge.respond("positions_get", load_mt5_fixture("positions_get"));
+        let open = load_mt5_fixture("order_send_success");
+        let mut close = open.clone();
+        close["result"]["deal"] = json!(40012399);
+        close["result"]["order"] = json!(50012399);
+        close["result"]["price"] = json!(1.10412);
+        bridge.respond_once("order_send", open);
+        bridge.respond_once("order_send", close);
+        let (addr, handle) = bridge.start().await;
+        let provider = Mt5InstrumentProvider::new(crate::config::Mt5InstrumentProviderConfig {
+            base_url: format!("http://{addr}"),
+            ..Default::default()
+        })
+        .unwrap();
+        let mut client = Mt5ExecutionClient::new(Mt5ExecutionClientConfig {
+            base_url: format!("http://{addr}"),
+            terminal_check_interval_ms: None,
+            ..Default::default()
+        })
+        .unwrap();
+        client.set_instrument_provider(&provider);
+
+        client.connect().await.unwrap();
+        provider.load_all_async(None).await.unwrap();
+        let instrument_id = InstrumentId::from("EURUSD.MT5");
+        let mut events = client.subscribe_order_events();
+        let mut fills = |sent: &Value, result: &Value, deal_type: i64| -> Vec<OrderFilled> {
+            for transaction in deal_transactions(sent, result, deal_type) {
+                client.handle_trade_transaction(&transaction);
+            }
+            std::iter::from_fn(|| events.try_recv().ok())
+                .map(|event| match event {
+                    OrderEventAny::Filled(filled) => filled,
+                    event => panic!("expected OrderFilled, was {event:?}"),
+                })
+                .collect()
+        };
+
+        // Open with a market buy
+        let open_id = ClientOrderId::from("O-20240101-0001");
+        let event = client
+            .submit_order_for(
+                TraderId::from("TRADER-001"),
+                StrategyId::from("S-001"),
+                instrument_id,
+                open_id,
+                &order(Mt5TradeType::Buy).build().unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let OrderEventAny::Accepted(accepted) = event else {
+            panic!("expected OrderAccepted, was {event:?}");
+        };
+        assert_eq!(accepted.client_order_id, open_id);
+        assert_eq!(accepted.venue_order_id, VenueOrderId::from("50012345"));
+        let sent = bridge.requests_for("order_send");
+        let filled = fills(&sent[0], &load_mt5_fixture("order_send_success")["result"], 0);
+        assert_eq!(filled.len(), 1);
+        assert_eq!(filled[0].client_order_id, open_id);
+        assert_eq!(filled[0].trade_id, TradeId::from("40012345"));
+        assert_eq!(filled[0].order_side, OrderSide::Buy);
+        assert_eq!(filled[0].last_qty, Quantity::from("0.10"));
+        assert_eq!(filled[0].last_px, Price::from("1.10419"));
+
+        // Close the resulting position with an opposite order against its ticket
+        let close_id = ClientOrderId::from("O-20240101-0002");
+        let event = client
+            .submit_order_for(
+                TraderId::from("TRADER-001"),
+                StrategyId::from("S-001"),
+                instrument_id,
+                close_id,
+                &order(Mt5TradeType::Sell).position(50012345).build().unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(event, OrderEventAny::Accepted(_)));
+        let sent = bridge.requests_for("order_send");
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[1]["position"], 50012345);
+        assert_eq!(sent[1]["type"], Mt5TradeType::Sell.as_i64());
+        let mut close_result = load_mt5_fixture("order_send_success")["result"].clone();
+        close_result["deal"] = json!(40012399);
+        close_result["order"] = json!(50012399);
+        close_result["price"] = json!(1.10412);
+        let filled = fills(&sent[1], &close_result, 1);
+        assert_eq!(filled.len(), 1);
+        assert_eq!(filled[0].client_order_id, close_id);
+        assert_eq!(filled[0].trade_id, TradeId::from("40012399"));
+        assert_eq!(filled[0].order_side, OrderSide::Sell);
+        assert_eq!(filled[0].last_px, Price::from("1.10412"));
+        assert_eq!(client.transactions.tracked_counts(), (0, 0));
+        assert!(!client.is_pending(&open_id));
+        handle.abort();
     }
 }
@@ -0,0 +1,172 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Blocking facade over [`Mt5HttpClient`] for synchronous scripts.
+//!
+//! [`Mt5HttpClientSync`] owns a single-threaded Tokio runtime and drives the
+//! async client on it, so callers never manage an event loop. Its methods must
+//! not be called from within an async context, where blocking on the runtime
+//! panics; async code should use [`Mt5HttpClient`] directly.
+
+#[cfg(feature = "python-bindings")]
+use pyo3::prelude::*;
+use tokio::runtime::{Builder, Runtime};
+
+use super::{
+    client::{parse_model, unwrap_result, Mt5HttpClient},
+    error::Mt5HttpError,
+    models::{Mt5TradeOrder, Mt5TradePosition},
+};
+use crate::{
+    common::models::{Mt5AccountInfo, Mt5Version},
+    config::Mt5Config,
+};
+#[cfg(feature = "python-bindings")]
+use crate::common::error::to_pyerr;
+
+/// Blocking MT5 HTTP client.
+#[derive(Debug)]
+#[cfg_attr(feature = "python-bindings", pyclass)]
+pub struct Mt5HttpClientSync {
+    client: Mt5HttpClient,
+    runtime: Runtime,
+}
+
+impl Mt5HttpClientSync {
+    /// Creates a client for the bridge at `base_url` with its own runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Mt5HttpError::ConnectionError` if the runtime or HTTP client cannot be built.
+    pub fn new(config: Mt5Config, base_url: String) -> Result<Self, Mt5HttpError> {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| Mt5HttpError::ConnectionError(e.to_string()))?;
+        // The HTTP client may bind to the runtime it is created on
+        let client = runtime.block_on(async { Mt5HttpClient::new(config, base_url) })?;
+        Ok(Self { client, runtime })
+    }
+
+    /// Returns the wrapped async client, for calls the facade does not cover.
+    pub fn client(&self) -> &Mt5HttpClient {
+        &self.client
+    }
+
+    /// Fetches `account_info`.
+    pub fn account_info_blocking(&self) -> Result<Mt5AccountInfo, Mt5HttpError> {
+        self.runtime.block_on(async {
+            let info = unwrap_result(self.client.account_info().await?)?;
+            parse_model(&info, "account_info")
+        })
+    }
+
+    /// Fetches the terminal version.
+    pub fn terminal_version_blocking(&self) -> Result<Mt5Version, Mt5HttpError> {
+        self.runtime.block_on(self.client.terminal_version())
+    }
+
+    /// Fetches all open positions.
+    pub fn positions_get_blocking(&self) -> Result<Vec<Mt5TradePosition>, Mt5HttpError> {
+        self.runtime
+            .block_on(async { self.client.parse_list(self.client.positions_get().await?, "positions_get") })
+    }
+
+    /// Fetches all active orders.
+    pub fn orders_get_blocking(&self) -> Result<Vec<Mt5TradeOrder>, Mt5HttpError> {
+        self.runtime.block_on(self.client.orders_get_typed())
+    }
+
+    /// Adds `symbol` to Market Watch; see [`Mt5HttpClient::symbol_select_checked`].
+    pub fn symbol_select_blocking(&self, symbol: &str) -> Result<(), Mt5HttpError> {
+        self.runtime.block_on(self.client.symbol_select_checked(symbol))
+    }
+}
+
+#[cfg(feature = "python-bindings")]
+fn to_json_string<T: serde::Serialize>(value: &T) -> PyResult<String> {
+    serde_json::to_string(value).map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+}
+
+#[cfg(feature = "python-bindings")]
+#[pymethods]
+impl Mt5HttpClientSync {
+    /// Create a new Mt5HttpClientSync from Python
+    #[new]
+    fn py_new(config: Mt5Config, base_url: String) -> PyResult<Self> {
+        Self::new(config, base_url).map_err(to_pyerr)
+    }
+
+    // Each call releases the GIL while blocking, so Python threads (such as an
+    // in-process bridge) keep running until the response arrives.
+
+    #[pyo3(name = "account_info")]
+    fn py_account_info(&self, py: Python<'_>) -> PyResult<String> {
+        let info = py.allow_threads(|| self.account_info_blocking()).map_err(to_pyerr)?;
+        to_json_string(&info)
+    }
+
+    #[pyo3(name = "terminal_version")]
+    fn py_terminal_version(&self, py: Python<'_>) -> PyResult<String> {
+        let version = py.allow_threads(|| self.terminal_version_blocking()).map_err(to_pyerr)?;
+        to_json_string(&version)
+    }
+
+    #[pyo3(name = "positions_get")]
+    fn py_positions_get(&self, py: Python<'_>) -> PyResult<String> {
+        let positions = py.allow_threads(|| self.positions_get_blocking()).map_err(to_pyerr)?;
+        to_json_string(&positions)
+    }
+
+    #[pyo3(name = "orders_get")]
+    fn py_orders_get(&self, py: Python<'_>) -> PyResult<String> {
+        let orders = py.allow_threads(|| self.orders_get_blocking()).map_err(to_pyerr)?;
+        to_json_string(&orders)
+    }
+
+    #[pyo3(name = "symbol_select")]
+    fn py_symbol_select(&self, py: Python<'_>, symbol: String) -> PyResult<()> {
+        py.allow_threads(|| self.symbol_select_blocking(&symbol)).map_err(to_pyerr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::common::testing::{load_mt5_fixture, mock_bridge::MockMt5Bridge};
+
+    #[test]
+    fn test_blocking_calls_against_mock_bridge() {
+        // The mock serves from its own runtime, as a real bridge would be out of process
+        let server = Runtime::new().unwrap();
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("account_info", load_mt5_fixture("account_info"));
+        bridge.respond("positions_get", load_mt5_fixture("positions_get"));
+        bridge.respond("orders_get", json!({"result": null}));
+        bridge.respond("version", json!({"result": [500, 4755, "13 Feb 2025"]}));
+        let (addr, handle) = server.block_on(bridge.start());
+        let base_url = format!("http://{addr}");
+        let client = Mt5HttpClientSync::new(Mt5Config::with_base_url(base_url.clone()), base_url).unwrap();
+
+        assert_eq!(client.account_info_blocking().unwrap().currency, "USD");
+        assert_eq!(client.positions_get_blocking().unwrap().len(), 2);
+        assert!(client.orders_get_blocking().unwrap().is_empty());
+        assert_eq!(client.terminal_version_blocking().unwrap().build, 4755);
+        assert_eq!(bridge.requests_for("account_info").len(), 1);
+        handle.abort();
+    }
+}
@@ -22,17 +22,39 @@
 //! - Success: `{"result": <data>}`
 //! - Error: `{"error": "error message"}`
 
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::borrow::Cow;
+use std::collections::{HashMap, VecDeque};
+use std::io::Read;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
 
 use nautilus_network::http::HttpClient;
+use serde::{de::DeserializeOwned, Deserialize};
 use serde_json::Value;
+use tokio::sync::Semaphore;
+use tracing::Instrument;
 
+use crate::common::enums::BridgeAccessMode;
+use crate::common::models::Mt5Version;
+use crate::common::parse::{parse_version, quote_non_finite_json};
 use crate::config::Mt5Config;
 use crate::http::error::Mt5HttpError;
+use crate::http::models::{Mt5Symbol, Mt5SymbolInfo, Mt5TradeOrder, Mt5TradePosition};
 
 #[cfg(feature = "python-bindings")]
 use pyo3::prelude::*;
+#[cfg(feature = "python-bindings")]
+use crate::common::error::to_pyerr;
+
+/// Longest excerpt of an offending body quoted in decode and parse errors.
+const ERROR_SNIPPET_LEN: usize = 256;
+/// Number of `ping` samples kept for the rolling average latency.
+const LATENCY_WINDOW: usize = 20;
+/// A ping slower than this multiple of the rolling average is logged as a spike.
+const LATENCY_SPIKE_FACTOR: u32 = 3;
+/// Header carrying the adapter's request id, for bridges that log it.
+pub const REQUEST_ID_HEADER: &str = "X-Request-Id";
 
 /// Inner MT5 HTTP client implementation
 #[derive(Debug)]
@@ -40,6 +62,20 @@ pub struct Mt5HttpInnerClient {
     base_url: String,
     #[allow(dead_code)]
     client: HttpClient,
+    max_retries: u32,
+    retry_delay_ms: u64,
+    retry_delay_max_ms: u64,
+    min_build: Option<u32>,
+    /// Most recent `ping` round-trips, oldest first.
+    latencies: Mutex<VecDeque<Duration>>,
+    /// Single permit held per request in `Serialized` mode; `None` when concurrent.
+    bridge_permit: Option<Arc<Semaphore>>,
+    /// When the bridge last answered a request.
+    last_activity: Mutex<Instant>,
+    /// Id of the most recently issued request; ids start at 1.
+    last_request_id: AtomicU64,
+    strict_parsing: bool,
+    warn_on_empty: bool,
 }
 
 /// MT5 HTTP client (clonable wrapper)
@@ -54,7 +90,14 @@ impl Mt5HttpInnerClient {
         let mut headers = HashMap::new();
         headers.insert("User-Agent".to_string(), "nautilus-mt5-adapter".to_string());
         headers.insert("Content-Type".to_string(), "application/json".to_string());
+        if config.accept_compression {
+            headers.insert("Accept-Encoding".to_string(), "gzip, deflate".to_string());
+        }
 
+        let bridge_permit = match config.bridge_access {
+            BridgeAccessMode::Serialized => Some(Arc::new(Semaphore::new(1))),
+            BridgeAccessMode::Concurrent => None,
+        };
         let client = HttpClient::new(
             headers,
             Vec::new(),
@@ -63,7 +106,27 @@ impl Mt5HttpInnerClient {
             Some(config.http_timeout as u64),
         );
 
-        Ok(Self { base_url, client })
+        Ok(Self {
+            base_url,
+            client,
+            max_retries: config.max_retries,
+            retry_delay_ms: config.retry_delay_ms,
+            retry_delay_max_ms: config.retry_delay_max_ms,
+            min_build: config.min_build,
+            latencies: Mutex::new(VecDeque::with_capacity(LATENCY_WINDOW)),
+            bridge_permit,
+            last_activity: Mutex::new(Instant::now()),
+            last_request_id: AtomicU64::new(0),
+            strict_parsing: config.strict_parsing,
+            warn_on_empty: config.warn_on_empty,
+        })
+    }
+
+    /// Returns the backoff before retry `attempt` (0-based): `retry_delay_ms`
+    /// doubled per attempt, capped at `retry_delay_max_ms`.
+    fn retry_delay(&self, attempt: u32) -> Duration {
+        let backoff = self.retry_delay_ms.saturating_mul(1 << attempt.min(16));
+        Duration::from_millis(backoff.min(self.retry_delay_max_ms))
     }
 
     #[allow(dead_code)]
@@ -90,37 +153,105 @@ impl Mt5HttpInnerClient {
             return Err(Mt5HttpError::from_http_status(status, text));
         }
 
-        serde_json::from_str(&text)
-            .map_err(|e| Mt5HttpError::JsonDecodeError(format!("Invalid JSON response: {}", e)))
+        serde_json::from_str(&quote_non_finite_json(&text)).map_err(|e| decode_error(path, e, &text))
     }
 
+    /// Posts `body` to `path`, retrying HTTP 429 responses.
+    ///
+    /// A `Retry-After` header (seconds or HTTP-date) is honored; otherwise the
+    /// delay backs off exponentially from `retry_delay_ms`. Each delay is capped
+    /// at `retry_delay_max_ms`.
+    ///
+    /// Each call gets the next request id, sent as [`REQUEST_ID_HEADER`],
+    /// recorded on the `mt5_request` tracing span and appended to any error;
+    /// retries reuse it.
     async fn post_request(&self, path: &str, body: &Value) -> Result<Value, Mt5HttpError> {
+        self.post_request_with_timeout(path, body, None).await
+    }
+
+    /// Posts like [`Self::post_request`], overriding the client's `http_timeout`
+    /// with `timeout_secs` for this request only.
+    async fn post_request_with_timeout(
+        &self,
+        path: &str,
+        body: &Value,
+        timeout_secs: Option<u64>,
+    ) -> Result<Value, Mt5HttpError> {
+        let request_id = self.last_request_id.fetch_add(1, Ordering::Relaxed) + 1;
+        let span = tracing::debug_span!("mt5_request", request_id, path);
+        let result = self
+            .send_post(path, body, request_id, timeout_secs)
+            .instrument(span.clone())
+            .await
+            .map_err(|e| e.with_request_id(request_id));
+        if let Err(e) = &result {
+            span.in_scope(|| tracing::debug!("MT5 request to {path} failed: {e}"));
+        }
+        result
+    }
+
+    async fn send_post(
+        &self,
+        path: &str,
+        body: &Value,
+        request_id: u64,
+        timeout_secs: Option<u64>,
+    ) -> Result<Value, Mt5HttpError> {
         let url = format!("{}{}", self.base_url, path);
         let body_bytes =
             serde_json::to_vec(body).map_err(|e| Mt5HttpError::JsonDecodeError(e.to_string()))?;
-
-        let resp = self
-            .client
-            .request(
-                reqwest::Method::POST,
-                url,
-                None,
-                Some(body_bytes),
-                None,
-                None,
-            )
-            .await
-            .map_err(|e| Mt5HttpError::NetworkError(e.to_string()))?;
-
-        let status = resp.status.as_u16();
-        let text = String::from_utf8_lossy(&resp.body).to_string();
-
-        if status != 200 {
-            return Err(Mt5HttpError::from_http_status(status, text));
+        let mut attempt = 0;
+
+        loop {
+            // Held for the request only, so rate-limit backoff doesn't block other callers
+            let permit = match &self.bridge_permit {
+                Some(semaphore) => Some(
+                    semaphore
+                        .acquire()
+                        .await
+                        .map_err(|e| Mt5HttpError::ConnectionError(e.to_string()))?,
+                ),
+                None => None,
+            };
+            let resp = self
+                .client
+                .request(
+                    reqwest::Method::POST,
+                    url.clone(),
+                    Some(HashMap::from([(
+                        REQUEST_ID_HEADER.to_string(),
+                        request_id.to_string(),
+                    )])),
+                    Some(body_bytes.clone()),
+                    timeout_secs,
+                    None,
+                )
+                .await
+                .map_err(|e| Mt5HttpError::NetworkError(e.to_string()))?;
+            drop(permit);
+            *self.last_activity.lock().unwrap_or_else(PoisonError::into_inner) = Instant::now();
+
+            let status = resp.status.as_u16();
+            let body = decompress_body(&resp.headers, &resp.body)?;
+            let text = String::from_utf8_lossy(&body).to_string();
+
+            if status == 429 && attempt < self.max_retries {
+                let delay = match parse_retry_after(&resp.headers) {
+                    Some(delay) => delay.min(Duration::from_millis(self.retry_delay_max_ms)),
+                    None => self.retry_delay(attempt),
+                };
+                attempt += 1;
+                tracing::warn!("Rate limited on {path}, retrying in {delay:?} ({attempt}/{})", self.max_retries);
+                tokio::time::sleep(delay).await;
+                continue;
+            }
+
+            if status != 200 {
+                return Err(Mt5HttpError::from_http_status(status, text));
+            }
+
+            return serde_json::from_str(&quote_non_finite_json(&text)).map_err(|e| decode_error(path, e, &text));
         }
-
-        serde_json::from_str(&text)
-            .map_err(|e| Mt5HttpError::JsonDecodeError(format!("Invalid JSON response: {}", e)))
     }
 
     // ========================================================================
@@ -151,6 +282,14 @@ impl Mt5HttpInnerClient {
         self.post_request("/api/symbols_get", body).await
     }
 
+    pub async fn http_symbols_get_with_timeout(
+        &self,
+        body: &Value,
+        timeout_secs: Option<u64>,
+    ) -> Result<Value, Mt5HttpError> {
+        self.post_request_with_timeout("/api/symbols_get", body, timeout_secs).await
+    }
+
     pub async fn http_symbol_info(&self, body: &Value) -> Result<Value, Mt5HttpError> {
         self.post_request("/api/symbol_info", body).await
     }
@@ -183,6 +322,10 @@ impl Mt5HttpInnerClient {
         self.post_request("/api/copy_rates_range", body).await
     }
 
+    pub async fn http_copy_rates_from_pos(&self, body: &Value) -> Result<Value, Mt5HttpError> {
+        self.post_request("/api/copy_rates_from_pos", body).await
+    }
+
     // ========================================================================
     // ORDERS AND POSITIONS (POST - middleware uses POST for all endpoints)
     // ========================================================================
@@ -195,6 +338,10 @@ impl Mt5HttpInnerClient {
         self.post_request("/api/orders_get", &serde_json::json!({})).await
     }
 
+    pub async fn http_orders_get_filtered(&self, body: &Value) -> Result<Value, Mt5HttpError> {
+        self.post_request("/api/orders_get", body).await
+    }
+
     pub async fn http_positions_total(&self) -> Result<Value, Mt5HttpError> {
         self.post_request("/api/positions_total", &serde_json::json!({})).await
     }
@@ -203,6 +350,10 @@ impl Mt5HttpInnerClient {
         self.post_request("/api/positions_get", &serde_json::json!({})).await
     }
 
+    pub async fn http_positions_get_filtered(&self, body: &Value) -> Result<Value, Mt5HttpError> {
+        self.post_request("/api/positions_get", body).await
+    }
+
     // ========================================================================
     // HISTORY DATA
     // ========================================================================
@@ -309,6 +460,128 @@ impl Mt5HttpClient {
         self.inner.http_terminal_info().await
     }
 
+    /// Returns the terminal version as a typed, comparable value.
+    pub async fn terminal_version(&self) -> Result<Mt5Version, Mt5HttpError> {
+        let version = unwrap_result(self.version().await?)?;
+        parse_version(&version).map_err(|e| Mt5HttpError::ParseError(e.to_string()))
+    }
+
+    /// Returns how long ago the bridge last answered a request.
+    pub fn idle_time(&self) -> Duration {
+        self.inner.last_activity.lock().unwrap_or_else(PoisonError::into_inner).elapsed()
+    }
+
+    /// Returns the id of the most recently issued request, or 0 before the first.
+    ///
+    /// Ids increase by one per request and are sent as [`REQUEST_ID_HEADER`], so
+    /// the id logged with a failure can be matched against the bridge's logs.
+    pub fn last_request_id(&self) -> u64 {
+        self.inner.last_request_id.load(Ordering::Relaxed)
+    }
+
+    /// Returns the backoff before retry `attempt` (0-based), as used for rate
+    /// limiting, so callers retrying on their own back off the same way.
+    pub fn retry_delay(&self, attempt: u32) -> Duration {
+        self.inner.retry_delay(attempt)
+    }
+
+    /// Parses the rows of a list response from `endpoint`.
+    ///
+    /// A malformed row fails the whole response when `strict_parsing` is set
+    /// and is otherwise skipped with a warning. A `null` result and an empty
+    /// array both yield an empty `Vec`; with `warn_on_empty` set either is logged.
+    pub fn parse_list<T: DeserializeOwned>(&self, response: Value, endpoint: &str) -> Result<Vec<T>, Mt5HttpError> {
+        let rows = unwrap_result(response)?;
+        let null = rows.is_null();
+        let items = parse_rows_with(rows, endpoint, self.inner.strict_parsing)?;
+        if items.is_empty() {
+            self.warn_empty(endpoint, null);
+        }
+        Ok(items)
+    }
+
+    fn warn_empty(&self, endpoint: &str, null: bool) {
+        if self.inner.warn_on_empty {
+            let shape = if null { "null" } else { "[]" };
+            tracing::warn!("{endpoint} returned no rows (result: {shape})");
+        }
+    }
+
+    /// Calls a bridge endpoint the typed API does not cover yet.
+    ///
+    /// `path` is the full endpoint path, e.g. `/api/market_book_get`. The request
+    /// goes through the same retry, rate-limit and request-id handling as the
+    /// typed methods, and the `result` of the response is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Mt5HttpError::InvalidRequestError` if `path` is not a
+    /// `/api/<endpoint>` path, `Mt5HttpError::RequestError` if the bridge answers
+    /// with an `error`, and any transport error.
+    pub async fn call(&self, path: &str, body: Value) -> Result<Value, Mt5HttpError> {
+        let endpoint = path.strip_prefix("/api/").unwrap_or_default();
+        if endpoint.is_empty()
+            || !endpoint
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '/'))
+        {
+            return Err(Mt5HttpError::InvalidRequestError(format!(
+                "bridge path must look like /api/<endpoint>, was '{path}'"
+            )));
+        }
+        unwrap_result(self.inner.post_request(path, &body).await?)
+    }
+
+    /// Times a `version` round-trip to the bridge and records it in the rolling average.
+    ///
+    /// Pings well above the average are logged at `warn` level, so a degrading
+    /// bridge shows up before requests start failing.
+    pub async fn ping(&self) -> Result<Duration, Mt5HttpError> {
+        let started = Instant::now();
+        self.version().await?;
+        let latency = started.elapsed();
+
+        let average = self.average_latency();
+        {
+            let mut latencies = self.inner.latencies.lock().unwrap_or_else(PoisonError::into_inner);
+            if latencies.len() == LATENCY_WINDOW {
+                latencies.pop_front();
+            }
+            latencies.push_back(latency);
+        }
+
+        match average {
+            Some(average) if latency > average * LATENCY_SPIKE_FACTOR => {
+                tracing::warn!("MT5 bridge latency spike: {latency:?} (average {average:?})");
+            }
+            _ => tracing::debug!("MT5 bridge latency: {latency:?}"),
+        }
+        Ok(latency)
+    }
+
+    /// Returns the mean of the recent `ping` latencies, or `None` before the first ping.
+    pub fn average_latency(&self) -> Option<Duration> {
+        let latencies = self.inner.latencies.lock().unwrap_or_else(PoisonError::into_inner);
+        let count = u32::try_from(latencies.len()).ok().filter(|&n| n > 0)?;
+        Some(latencies.iter().sum::<Duration>() / count)
+    }
+
+    /// Returns the minimum terminal build configured for this client, if any.
+    pub fn min_build(&self) -> Option<u32> {
+        self.inner.min_build
+    }
+
+    /// Fails with `Mt5HttpError::UnsupportedVersion` if the terminal build is below `min_build`.
+    pub async fn require_min_build(&self, min_build: u32) -> Result<Mt5Version, Mt5HttpError> {
+        let version = self.terminal_version().await?;
+        if version.build < min_build {
+            return Err(Mt5HttpError::UnsupportedVersion(format!(
+                "terminal {version} is older than the minimum build {min_build}"
+            )));
+        }
+        Ok(version)
+    }
+
     pub async fn account_info(&self) -> Result<Value, Mt5HttpError> {
         self.inner.http_account_info().await
     }
@@ -330,10 +603,34 @@ impl Mt5HttpClient {
         self.inner.http_symbol_info_tick(body).await
     }
 
+    /// Returns the typed specification of `symbol`.
+    pub async fn symbol_info_typed(&self, symbol: &str) -> Result<Mt5SymbolInfo, Mt5HttpError> {
+        // The bridge may wrap the named tuple in a single-element list
+        let result = match unwrap_result(self.symbol_info(&serde_json::json!([symbol])).await?)? {
+            Value::Array(items) => items.into_iter().next().unwrap_or(Value::Null),
+            value => value,
+        };
+        parse_model(&result, "symbol_info")
+    }
+
     pub async fn symbol_select(&self, body: &Value) -> Result<Value, Mt5HttpError> {
         self.inner.http_symbol_select(body).await
     }
 
+    /// Adds `symbol` to Market Watch.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Mt5HttpError::SelectFailed` if the terminal reports `false`, e.g.
+    /// for a symbol the broker does not offer.
+    pub async fn symbol_select_checked(&self, symbol: &str) -> Result<(), Mt5HttpError> {
+        let response = self.symbol_select(&serde_json::json!([symbol, true])).await?;
+        match unwrap_result(response)? {
+            Value::Bool(false) => Err(Mt5HttpError::SelectFailed(symbol.to_string())),
+            _ => Ok(()),
+        }
+    }
+
     // Market Data
     pub async fn copy_ticks_from(&self, body: &Value) -> Result<Value, Mt5HttpError> {
         self.inner.http_copy_ticks_from(body).await
@@ -351,6 +648,12 @@ impl Mt5HttpClient {
         self.inner.http_copy_rates_range(body).await
     }
 
+    /// Fetches `[symbol, timeframe, start_pos, count]` bars counting back from the
+    /// current one at position 0, oldest first.
+    pub async fn copy_rates_from_pos(&self, body: &Value) -> Result<Value, Mt5HttpError> {
+        self.inner.http_copy_rates_from_pos(body).await
+    }
+
     // Orders and Positions
     pub async fn orders_total(&self) -> Result<Value, Mt5HttpError> {
         self.inner.http_orders_total().await
@@ -368,6 +671,79 @@ impl Mt5HttpClient {
         self.inner.http_positions_get().await
     }
 
+    /// Returns the open positions in `symbol`, filtered by the terminal.
+    pub async fn positions_get_for_symbol(&self, symbol: &str) -> Result<Vec<Mt5TradePosition>, Mt5HttpError> {
+        let body = serde_json::json!({ "symbol": symbol });
+        self.parse_list(self.inner.http_positions_get_filtered(&body).await?, "positions_get")
+    }
+
+    /// Returns the open positions whose symbol matches `group` (MT5 syntax, e.g. `"*USD*"`).
+    pub async fn positions_get_for_group(&self, group: &str) -> Result<Vec<Mt5TradePosition>, Mt5HttpError> {
+        let body = serde_json::json!({ "group": group });
+        self.parse_list(self.inner.http_positions_get_filtered(&body).await?, "positions_get")
+    }
+
+    /// Returns the open position with `ticket`, as a list of at most one.
+    pub async fn positions_get_for_ticket(&self, ticket: u64) -> Result<Vec<Mt5TradePosition>, Mt5HttpError> {
+        let body = serde_json::json!({ "ticket": ticket });
+        self.parse_list(self.inner.http_positions_get_filtered(&body).await?, "positions_get")
+    }
+
+    /// Returns the active order with `ticket`, as a list of at most one.
+    pub async fn orders_get_for_ticket(&self, ticket: u64) -> Result<Vec<Mt5TradeOrder>, Mt5HttpError> {
+        let body = serde_json::json!({ "ticket": ticket });
+        self.parse_list(self.inner.http_orders_get_filtered(&body).await?, "orders_get")
+    }
+
+    /// Returns all active orders as typed rows.
+    pub async fn orders_get_typed(&self) -> Result<Vec<Mt5TradeOrder>, Mt5HttpError> {
+        self.parse_list(self.inner.http_orders_get().await?, "orders_get")
+    }
+
+    /// Returns the active orders in `symbol`, filtered by the terminal.
+    pub async fn orders_get_for_symbol(&self, symbol: &str) -> Result<Vec<Mt5TradeOrder>, Mt5HttpError> {
+        let body = serde_json::json!({ "symbol": symbol });
+        self.parse_list(self.inner.http_orders_get_filtered(&body).await?, "orders_get")
+    }
+
+    /// Returns the active orders whose symbol matches `group` (MT5 syntax, e.g. `"*USD*"`).
+    pub async fn orders_get_for_group(&self, group: &str) -> Result<Vec<Mt5TradeOrder>, Mt5HttpError> {
+        let body = serde_json::json!({ "group": group });
+        self.parse_list(self.inner.http_orders_get_filtered(&body).await?, "orders_get")
+    }
+
+    /// Returns the number of open positions as a typed count.
+    pub async fn positions_total_count(&self) -> Result<u64, Mt5HttpError> {
+        parse_count(self.positions_total().await?, "positions_total")
+    }
+
+    /// Returns the number of active orders as a typed count.
+    pub async fn orders_total_count(&self) -> Result<u64, Mt5HttpError> {
+        parse_count(self.orders_total().await?, "orders_total")
+    }
+
+    /// Returns the symbols matching `group` (MT5 syntax, e.g. `"*USD*"`), or all symbols.
+    ///
+    /// Rows are parsed like [`Self::parse_list`].
+    pub async fn symbols_get_typed(&self, group: Option<&str>) -> Result<Vec<Mt5Symbol>, Mt5HttpError> {
+        self.symbols_get_typed_with_timeout(group, None).await
+    }
+
+    /// Lists symbols like [`Self::symbols_get_typed`], with `timeout_secs` replacing
+    /// `http_timeout` for the listing only, which is slow on large ECN accounts.
+    pub async fn symbols_get_typed_with_timeout(
+        &self,
+        group: Option<&str>,
+        timeout_secs: Option<u64>,
+    ) -> Result<Vec<Mt5Symbol>, Mt5HttpError> {
+        let body = match group {
+            Some(group) => serde_json::json!({ "group": group }),
+            None => serde_json::json!({}),
+        };
+        let response = self.inner.http_symbols_get_with_timeout(&body, timeout_secs).await?;
+        self.parse_list(response, "symbols_get")
+    }
+
     // History Data
     pub async fn history_orders_total(&self, body: &Value) -> Result<Value, Mt5HttpError> {
         self.inner.http_history_orders_total(body).await
@@ -422,12 +798,53 @@ impl Mt5HttpClient {
     }
 
     // Session Management
-    pub async fn initialize(&self) -> Result<Value, Mt5HttpError> {
-        self.inner.http_initialize().await
+
+    /// Initializes the terminal behind the bridge.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Mt5HttpError::ConnectionError` with the terminal's `last_error`
+    /// when the bridge reports `false`, and propagates bridge errors.
+    pub async fn initialize(&self) -> Result<(), Mt5HttpError> {
+        match unwrap_result(self.inner.http_initialize().await?)? {
+            Value::Bool(false) => Err(Mt5HttpError::ConnectionError(format!(
+                "initialize failed: {}",
+                self.last_error_details().await
+            ))),
+            _ => Ok(()),
+        }
     }
 
-    pub async fn login(&self) -> Result<Value, Mt5HttpError> {
-        self.inner.http_login().await
+    /// Logs the terminal in to its trade account.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Mt5HttpError::AuthenticationError` with the terminal's
+    /// `last_error` when the bridge reports `false`, and propagates bridge errors.
+    pub async fn login(&self) -> Result<(), Mt5HttpError> {
+        match unwrap_result(self.inner.http_login().await?)? {
+            Value::Bool(false) => Err(Mt5HttpError::AuthenticationError(format!(
+                "login failed: {}",
+                self.last_error_details().await
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Describes the terminal's `last_error` (`[code, message]`) for an error message.
+    async fn last_error_details(&self) -> String {
+        match self.last_error().await.and_then(unwrap_result) {
+            Ok(Value::Array(error)) => {
+                let code = error.first().and_then(Value::as_i64);
+                let message = error.get(1).and_then(Value::as_str);
+                match (code, message) {
+                    (Some(code), Some(message)) => format!("{message} ({code})"),
+                    _ => Value::Array(error.clone()).to_string(),
+                }
+            }
+            Ok(other) => other.to_string(),
+            Err(e) => format!("last_error unavailable: {e}"),
+        }
     }
 
     pub async fn shutdown(&self) -> Result<Value, Mt5HttpError> {
@@ -435,6 +852,155 @@ impl Mt5HttpClient {
     }
 }
 
+/// Parses a `Retry-After` header given as delay-seconds or an HTTP-date.
+/// Decodes a `gzip` or `deflate` body as announced by its `Content-Encoding` header.
+///
+/// Bodies without the header are returned as-is, e.g. when the HTTP stack has
+/// already decompressed them.
+fn decompress_body<'a>(
+    headers: &HashMap<String, String>,
+    body: &'a [u8],
+) -> Result<Cow<'a, [u8]>, Mt5HttpError> {
+    let Some(encoding) = headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-encoding"))
+        .map(|(_, value)| value.trim().to_ascii_lowercase())
+    else {
+        return Ok(Cow::Borrowed(body));
+    };
+
+    let mut decoded = Vec::new();
+    let result = match encoding.as_str() {
+        "identity" => return Ok(Cow::Borrowed(body)),
+        "gzip" | "x-gzip" => flate2::read::GzDecoder::new(body).read_to_end(&mut decoded),
+        // HTTP deflate is zlib-wrapped, but some servers send raw deflate
+        "deflate" => flate2::read::ZlibDecoder::new(body)
+            .read_to_end(&mut decoded)
+            .or_else(|_| {
+                decoded.clear();
+                flate2::read::DeflateDecoder::new(body).read_to_end(&mut decoded)
+            }),
+        other => {
+            return Err(Mt5HttpError::JsonDecodeError(format!(
+                "unsupported Content-Encoding '{other}'"
+            )))
+        }
+    };
+    result.map_err(|e| Mt5HttpError::JsonDecodeError(format!("failed to decode {encoding} body: {e}")))?;
+    Ok(Cow::Owned(decoded))
+}
+
+fn parse_retry_after(headers: &HashMap<String, String>) -> Option<Duration> {
+    let value = headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))
+        .map(|(_, value)| value.trim())?;
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let at = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let wait = at.with_timezone(&chrono::Utc) - chrono::Utc::now();
+    Some(wait.to_std().unwrap_or(Duration::ZERO))
+}
+
+/// Extracts the payload of a bridge response.
+///
+/// Returns the `result` of `{"result": <data>}`, the `error` of
+/// `{"error": <message>}` as `Mt5HttpError::RequestError`, and any other value
+/// unchanged, since some bridges send bare payloads. Apply this before
+/// deserializing a response into a model.
+pub fn unwrap_result(value: Value) -> Result<Value, Mt5HttpError> {
+    match value {
+        Value::Object(mut response) => {
+            if let Some(error) = response.remove("error") {
+                let message = match error {
+                    Value::String(message) => message,
+                    other => other.to_string(),
+                };
+                return Err(Mt5HttpError::RequestError(message));
+            }
+            match response.remove("result") {
+                Some(result) => Ok(result),
+                None => Ok(Value::Object(response)),
+            }
+        }
+        other => Ok(other),
+    }
+}
+
+/// Returns `body` cut to `ERROR_SNIPPET_LEN` characters, noting its full length when cut.
+fn body_snippet(body: &str) -> String {
+    match body.char_indices().nth(ERROR_SNIPPET_LEN) {
+        Some((end, _)) => format!("{}... ({} bytes)", &body[..end], body.len()),
+        None => body.to_string(),
+    }
+}
+
+/// Reports a response from `endpoint` that is not valid JSON, quoting the body.
+fn decode_error(endpoint: &str, error: serde_json::Error, body: &str) -> Mt5HttpError {
+    Mt5HttpError::JsonDecodeError(format!(
+        "{endpoint}: {error}; body: {}",
+        body_snippet(body)
+    ))
+}
+
+/// Deserializes `value` from `endpoint` into a model, quoting the value on failure.
+pub(crate) fn parse_model<T: DeserializeOwned>(value: &Value, endpoint: &str) -> Result<T, Mt5HttpError> {
+    T::deserialize(value).map_err(|e| {
+        Mt5HttpError::ParseError(format!(
+            "{endpoint}: {e}; body: {}",
+            body_snippet(&value.to_string())
+        ))
+    })
+}
+
+/// Deserializes the rows of a list response; MT5 returns `None` when nothing matches.
+pub(crate) fn parse_rows<T: DeserializeOwned>(response: Value, endpoint: &str) -> Result<Vec<T>, Mt5HttpError> {
+    match unwrap_result(response)? {
+        Value::Null => Ok(Vec::new()),
+        rows => parse_model(&rows, endpoint),
+    }
+}
+
+/// Deserializes the rows of a list response like [`parse_rows`], except that
+/// unless `strict` a row that does not match the model is skipped with a warning.
+pub(crate) fn parse_rows_with<T: DeserializeOwned>(
+    response: Value,
+    endpoint: &str,
+    strict: bool,
+) -> Result<Vec<T>, Mt5HttpError> {
+    if strict {
+        return parse_rows(response, endpoint);
+    }
+    match unwrap_result(response)? {
+        Value::Null => Ok(Vec::new()),
+        Value::Array(rows) => Ok(rows
+            .into_iter()
+            .filter_map(|row| match T::deserialize(&row) {
+                Ok(item) => Some(item),
+                Err(e) => {
+                    tracing::warn!(
+                        "Skipping malformed {endpoint} row: {e}; row: {}",
+                        body_snippet(&row.to_string())
+                    );
+                    None
+                }
+            })
+            .collect()),
+        other => parse_model(&other, endpoint),
+    }
+}
+
+/// Extracts a non-negative integer from a `{"result": <int>}` response.
+fn parse_count(response: Value, endpoint: &str) -> Result<u64, Mt5HttpError> {
+    let result = unwrap_result(response)
+        .map_err(|e| Mt5HttpError::RequestError(format!("{endpoint}: {e}")))?;
+    result
+        .as_u64()
+        .ok_or_else(|| Mt5HttpError::ParseError(format!("{endpoint}: expected integer result, got {result}")))
+}
+
 impl Clone for Mt5HttpClient {
     fn clone(&self) -> Self {
         Self {
@@ -470,7 +1036,7 @@ impl Mt5HttpClient {
     #[new]
     fn py_new(config: Mt5Config, base_url: String) -> PyResult<Self> {
         Self::new(config, base_url)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+            .map_err(to_pyerr)
     }
 
     #[pyo3(name = "version")]
@@ -478,17 +1044,35 @@ impl Mt5HttpClient {
         let client = self.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let result = client.version().await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
 
+    /// Times a `version` round-trip and returns it in seconds.
+    #[pyo3(name = "ping")]
+    fn py_ping<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let latency = client.ping().await
+                .map_err(to_pyerr)?;
+            Ok(latency.as_secs_f64())
+        })
+    }
+
+    /// Mean of the recent ping latencies in seconds, or `None` before the first ping.
+    #[getter]
+    #[pyo3(name = "average_latency")]
+    fn py_average_latency(&self) -> Option<f64> {
+        self.average_latency().map(|latency| latency.as_secs_f64())
+    }
+
     #[pyo3(name = "terminal_info")]
     fn py_terminal_info<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let result = client.terminal_info().await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -498,7 +1082,7 @@ impl Mt5HttpClient {
         let client = self.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let result = client.account_info().await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -508,7 +1092,7 @@ impl Mt5HttpClient {
         let client = self.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let result = client.symbols_total().await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -519,7 +1103,7 @@ impl Mt5HttpClient {
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let body_value = json_string_to_value(&body)?;
             let result = client.symbols_get(&body_value).await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -530,7 +1114,7 @@ impl Mt5HttpClient {
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let body_value = json_string_to_value(&body)?;
             let result = client.symbol_info(&body_value).await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -541,7 +1125,7 @@ impl Mt5HttpClient {
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let body_value = json_string_to_value(&body)?;
             let result = client.symbol_info_tick(&body_value).await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -552,7 +1136,7 @@ impl Mt5HttpClient {
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let body_value = json_string_to_value(&body)?;
             let result = client.symbol_select(&body_value).await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -563,7 +1147,7 @@ impl Mt5HttpClient {
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let body_value = json_string_to_value(&body)?;
             let result = client.copy_ticks_from(&body_value).await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -574,7 +1158,7 @@ impl Mt5HttpClient {
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let body_value = json_string_to_value(&body)?;
             let result = client.copy_ticks_range(&body_value).await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -585,7 +1169,7 @@ impl Mt5HttpClient {
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let body_value = json_string_to_value(&body)?;
             let result = client.copy_rates_from(&body_value).await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -596,7 +1180,18 @@ impl Mt5HttpClient {
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let body_value = json_string_to_value(&body)?;
             let result = client.copy_rates_range(&body_value).await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
+            value_to_json_string(result)
+        })
+    }
+
+    #[pyo3(name = "copy_rates_from_pos")]
+    fn py_copy_rates_from_pos<'py>(&self, py: Python<'py>, body: String) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let body_value = json_string_to_value(&body)?;
+            let result = client.copy_rates_from_pos(&body_value).await
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -606,7 +1201,7 @@ impl Mt5HttpClient {
         let client = self.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let result = client.orders_total().await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -616,7 +1211,7 @@ impl Mt5HttpClient {
         let client = self.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let result = client.orders_get().await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -626,7 +1221,7 @@ impl Mt5HttpClient {
         let client = self.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let result = client.positions_total().await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -636,7 +1231,7 @@ impl Mt5HttpClient {
         let client = self.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let result = client.positions_get().await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -647,7 +1242,7 @@ impl Mt5HttpClient {
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let body_value = json_string_to_value(&body)?;
             let result = client.history_orders_total(&body_value).await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -658,7 +1253,7 @@ impl Mt5HttpClient {
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let body_value = json_string_to_value(&body)?;
             let result = client.history_orders_get(&body_value).await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -669,7 +1264,7 @@ impl Mt5HttpClient {
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let body_value = json_string_to_value(&body)?;
             let result = client.history_deals_total(&body_value).await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -680,7 +1275,7 @@ impl Mt5HttpClient {
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let body_value = json_string_to_value(&body)?;
             let result = client.history_deals_get(&body_value).await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -691,7 +1286,7 @@ impl Mt5HttpClient {
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let body_value = json_string_to_value(&body)?;
             let result = client.order_calc_margin(&body_value).await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -702,7 +1297,7 @@ impl Mt5HttpClient {
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let body_value = json_string_to_value(&body)?;
             let result = client.order_calc_profit(&body_value).await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -713,7 +1308,7 @@ impl Mt5HttpClient {
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let body_value = json_string_to_value(&body)?;
             let result = client.order_check(&body_value).await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -724,7 +1319,7 @@ impl Mt5HttpClient {
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let body_value = json_string_to_value(&body)?;
             let result = client.order_send(&body_value).await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -735,7 +1330,7 @@ impl Mt5HttpClient {
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let body_value = json_string_to_value(&body)?;
             let result = client.market_book_add(&body_value).await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -746,7 +1341,7 @@ impl Mt5HttpClient {
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let body_value = json_string_to_value(&body)?;
             let result = client.market_book_get(&body_value).await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -757,7 +1352,7 @@ impl Mt5HttpClient {
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let body_value = json_string_to_value(&body)?;
             let result = client.market_book_release(&body_value).await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -767,7 +1362,7 @@ impl Mt5HttpClient {
         let client = self.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let result = client.last_error().await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
@@ -776,9 +1371,9 @@ impl Mt5HttpClient {
     fn py_initialize<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let result = client.initialize().await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
-            value_to_json_string(result)
+            client.initialize().await
+                .map_err(to_pyerr)?;
+            value_to_json_string(Value::Bool(true))
         })
     }
 
@@ -786,9 +1381,9 @@ impl Mt5HttpClient {
     fn py_login<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
         let client = self.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let result = client.login().await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
-            value_to_json_string(result)
+            client.login().await
+                .map_err(to_pyerr)?;
+            value_to_json_string(Value::Bool(true))
         })
     }
 
@@ -797,8 +1392,580 @@ impl Mt5HttpClient {
         let client = self.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let result = client.shutdown().await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             value_to_json_string(result)
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{
+        enums::{Mt5OrderState, Mt5OrderType},
+        testing::{load_mt5_fixture, mock_bridge::MockMt5Bridge},
+    };
+    use serde_json::json;
+    use tracing_test::traced_test;
+
+    #[tokio::test]
+    async fn test_total_counts() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("positions_total", json!({"result": 3}));
+        bridge.respond("orders_total", json!({"result": 0}));
+        let (addr, handle) = bridge.start().await;
+        let base_url = format!("http://{addr}");
+        let client = Mt5HttpClient::new(Mt5Config::with_base_url(base_url.clone()), base_url).unwrap();
+
+        assert_eq!(client.positions_total_count().await.unwrap(), 3);
+        assert_eq!(client.orders_total_count().await.unwrap(), 0);
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_ping_measures_bridge_round_trip() {
+        let bridge = MockMt5Bridge::new();
+        bridge.delay("version", Duration::from_millis(50));
+        let (addr, handle) = bridge.start().await;
+        let base_url = format!("http://{addr}");
+        let client = Mt5HttpClient::new(Mt5Config::with_base_url(base_url.clone()), base_url).unwrap();
+        assert_eq!(client.average_latency(), None);
+
+        let first = client.ping().await.unwrap();
+        let second = client.ping().await.unwrap();
+
+        assert!(first >= Duration::from_millis(50));
+        assert!(second >= Duration::from_millis(50));
+        assert_eq!(client.average_latency(), Some((first + second) / 2));
+        handle.abort();
+    }
+
+    fn symbol_row(index: usize) -> Value {
+        json!({
+            "symbol": format!("SYM{index:05}"),
+            "digits": 5,
+            "point_size": 0.00001,
+            "volume_min": 0.01,
+            "volume_max": 100.0,
+            "volume_step": 0.01,
+            "contract_size": 100000.0,
+            "margin_initial": null,
+            "margin_maintenance": null,
+            "type": "forex"
+        })
+    }
+
+    #[tokio::test]
+    async fn test_symbols_get_typed_parses_large_symbol_set() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbols_get", Value::Array((0..20_000).map(symbol_row).collect()));
+        let (addr, handle) = bridge.start().await;
+        let base_url = format!("http://{addr}");
+        let client = Mt5HttpClient::new(Mt5Config::with_base_url(base_url.clone()), base_url).unwrap();
+
+        let symbols = client.symbols_get_typed(Some("SYM*")).await.unwrap();
+
+        assert_eq!(symbols.len(), 20_000);
+        for (i, symbol) in symbols.iter().enumerate() {
+            assert_eq!(symbol.symbol, format!("SYM{i:05}"));
+        }
+        assert_eq!(bridge.requests_for("symbols_get"), vec![json!({"group": "SYM*"})]);
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_symbols_get_typed_skips_bad_rows_unless_strict() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbols_get", json!({"result": [symbol_row(0), {"symbol": "BROKEN"}]}));
+        let (addr, handle) = bridge.start().await;
+        let base_url = format!("http://{addr}");
+        let client = Mt5HttpClient::new(Mt5Config::with_base_url(base_url.clone()), base_url.clone()).unwrap();
+        let strict = Mt5HttpClient::new(
+            Mt5Config {
+                strict_parsing: true,
+                ..Mt5Config::with_base_url(base_url.clone())
+            },
+            base_url,
+        )
+        .unwrap();
+
+        let symbols = client.symbols_get_typed(None).await.unwrap();
+
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].symbol, "SYM00000");
+        assert!(matches!(strict.symbols_get_typed(None).await, Err(Mt5HttpError::ParseError(_))));
+        assert_eq!(bridge.requests_for("symbols_get"), vec![json!({}); 2]);
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_request_honors_retry_after() {
+        use wiremock::{matchers, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(matchers::method("POST"))
+            .and(matchers::path("/api/version"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "1"))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(matchers::method("POST"))
+            .and(matchers::path("/api/version"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"result": [500, 4000]})))
+            .mount(&server)
+            .await;
+        let client = Mt5HttpClient::new(Mt5Config::with_base_url(server.uri()), server.uri()).unwrap();
+
+        let started = std::time::Instant::now();
+        let response = client.version().await.unwrap();
+
+        assert!(started.elapsed() >= Duration::from_secs(1));
+        assert_eq!(response["result"][0], 500);
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_rate_limit_maps_to_rate_limited() {
+        let bridge = MockMt5Bridge::new();
+        bridge.fail("version", 429, "slow down");
+        let (addr, handle) = bridge.start().await;
+        let base_url = format!("http://{addr}");
+        let config = Mt5Config {
+            max_retries: 1,
+            retry_delay_ms: 1,
+            ..Mt5Config::with_base_url(base_url.clone())
+        };
+        let client = Mt5HttpClient::new(config, base_url).unwrap();
+
+        assert!(matches!(client.version().await, Err(Mt5HttpError::RateLimited(_))));
+        assert_eq!(bridge.requests_for("version").len(), 2);
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_request_ids_unique_and_monotonic_across_concurrent_requests() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("version", json!({"result": [500, 4000, "01 Jan 2024"]}));
+        let (addr, handle) = bridge.start().await;
+        let base_url = format!("http://{addr}");
+        let client = Mt5HttpClient::new(Mt5Config::with_base_url(base_url.clone()), base_url).unwrap();
+        assert_eq!(client.last_request_id(), 0);
+
+        let results = futures_util::future::join_all((0..20).map(|_| client.version())).await;
+        assert!(results.iter().all(Result::is_ok));
+
+        let mut ids = bridge.request_ids();
+        ids.sort_unstable();
+        assert_eq!(ids, (1..=20).collect::<Vec<u64>>());
+        assert_eq!(client.last_request_id(), 20);
+
+        client.version().await.unwrap();
+        assert_eq!(bridge.request_ids().last(), Some(&21));
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_failed_request_error_carries_request_id() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("version", json!({"result": [500, 4000, "01 Jan 2024"]}));
+        bridge.fail("terminal_info", 503, "terminal busy");
+        let (addr, handle) = bridge.start().await;
+        let base_url = format!("http://{addr}");
+        let client = Mt5HttpClient::new(Mt5Config::with_base_url(base_url.clone()), base_url).unwrap();
+        client.version().await.unwrap();
+
+        let error = client.terminal_info().await.unwrap_err();
+
+        assert!(matches!(&error, Mt5HttpError::ServerError(message) if message.ends_with("(request 2)")), "{error}");
+        assert_eq!(bridge.request_ids().last(), Some(&2));
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_compressed_responses_are_decoded() {
+        let bridge = MockMt5Bridge::new();
+        let symbol = json!({
+            "symbol": "EURUSD",
+            "digits": 5,
+            "point_size": 0.00001,
+            "volume_min": 0.01,
+            "volume_max": 100.0,
+            "volume_step": 0.01,
+            "contract_size": 100000.0,
+            "margin_initial": null,
+            "margin_maintenance": null,
+            "type": "forex"
+        });
+        bridge.respond_compressed("symbols_get", json!({"result": [symbol]}), "gzip");
+        let ticks = load_mt5_fixture("copy_ticks_range_eurusd_trades");
+        bridge.respond_compressed("copy_ticks_range", ticks.clone(), "deflate");
+        let (addr, handle) = bridge.start().await;
+        let base_url = format!("http://{addr}");
+        let client =
+            Mt5HttpClient::new(Mt5Config::with_base_url(base_url.clone()), base_url.clone()).unwrap();
+
+        let symbols = client.symbols_get_typed(None).await.unwrap();
+        let decoded = client.copy_ticks_range(&json!(["EURUSD", 0, 3600, 2])).await.unwrap();
+
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].symbol, "EURUSD");
+        assert_eq!(decoded, ticks);
+        assert_eq!(bridge.accept_encodings(), vec!["gzip, deflate"; 2]);
+
+        let config = Mt5Config {
+            accept_compression: false,
+            ..Mt5Config::with_base_url(base_url.clone())
+        };
+        Mt5HttpClient::new(config, base_url).unwrap().symbols_get_typed(None).await.unwrap();
+        assert_eq!(bridge.accept_encodings().last().unwrap(), "");
+        handle.abort();
+    }
+
+    async fn max_overlap(mode: BridgeAccessMode) -> usize {
+        let bridge = MockMt5Bridge::new();
+        bridge.delay("copy_rates_range", Duration::from_millis(200));
+        bridge.respond("order_send", json!({"result": {"retcode": 10009}}));
+        let (addr, handle) = bridge.start().await;
+        let base_url = format!("http://{addr}");
+        let config = Mt5Config {
+            bridge_access: mode,
+            ..Mt5Config::with_base_url(base_url.clone())
+        };
+        let client = Mt5HttpClient::new(config, base_url).unwrap();
+
+        let rates = client.copy_rates_range(&json!(["EURUSD", 1, 0, 3600]));
+        let order = async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            client.order_send(&json!({"symbol": "EURUSD"})).await
+        };
+        let (rates, order) = tokio::join!(rates, order);
+        rates.unwrap();
+        order.unwrap();
+        handle.abort();
+        bridge.max_in_flight()
+    }
+
+    #[tokio::test]
+    async fn test_serialized_mode_requests_do_not_overlap() {
+        assert_eq!(max_overlap(BridgeAccessMode::Serialized).await, 1);
+        assert_eq!(max_overlap(BridgeAccessMode::Concurrent).await, 2);
+    }
+
+    async fn overlap_requests(first: &Mt5HttpClient, second: &Mt5HttpClient) {
+        let rates = first.copy_rates_range(&json!(["EURUSD", 1, 0, 3600]));
+        let order = async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            second.order_send(&json!({"symbol": "EURUSD"})).await
+        };
+        let (rates, order) = tokio::join!(rates, order);
+        rates.unwrap();
+        order.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_serialized_permit_is_shared_by_clones_only() {
+        let bridge = MockMt5Bridge::new();
+        bridge.delay("copy_rates_range", Duration::from_millis(200));
+        bridge.respond("order_send", json!({"result": {"retcode": 10009}}));
+        let (addr, handle) = bridge.start().await;
+        let base_url = format!("http://{addr}");
+        let client = Mt5HttpClient::new(Mt5Config::with_base_url(base_url.clone()), base_url.clone()).unwrap();
+
+        overlap_requests(&client, &client.clone()).await;
+        assert_eq!(bridge.max_in_flight(), 1);
+
+        // Separately built clients keep their own permits
+        let other = Mt5HttpClient::new(Mt5Config::with_base_url(base_url.clone()), base_url).unwrap();
+        overlap_requests(&client, &other).await;
+        assert_eq!(bridge.max_in_flight(), 2);
+        handle.abort();
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        let seconds = HashMap::from([("retry-after".to_string(), "3".to_string())]);
+        assert_eq!(parse_retry_after(&seconds), Some(Duration::from_secs(3)));
+
+        let past = HashMap::from([("Retry-After".to_string(), "Sun, 06 Nov 1994 08:49:37 GMT".to_string())]);
+        assert_eq!(parse_retry_after(&past), Some(Duration::ZERO));
+
+        assert_eq!(parse_retry_after(&HashMap::new()), None);
+    }
+
+    #[test]
+    fn test_parse_count_malformed() {
+        assert!(matches!(
+            parse_count(json!({"result": [1, 2]}), "positions_total"),
+            Err(Mt5HttpError::ParseError(_))
+        ));
+        assert!(matches!(
+            parse_count(json!({"result": -1}), "orders_total"),
+            Err(Mt5HttpError::ParseError(_))
+        ));
+        assert!(matches!(
+            parse_count(json!({"error": "not initialized"}), "orders_total"),
+            Err(Mt5HttpError::RequestError(_))
+        ));
+    }
+
+    #[test]
+    fn test_unwrap_result_extracts_result() {
+        assert_eq!(unwrap_result(json!({"result": [1, 2]})).unwrap(), json!([1, 2]));
+        assert_eq!(unwrap_result(json!({"result": null})).unwrap(), Value::Null);
+    }
+
+    #[test]
+    fn test_unwrap_result_maps_error() {
+        let Err(Mt5HttpError::RequestError(message)) = unwrap_result(json!({"error": "Symbol not found"})) else {
+            panic!("expected RequestError");
+        };
+        assert_eq!(message, "Symbol not found");
+        assert!(matches!(
+            unwrap_result(json!({"error": {"code": -1}, "result": true})),
+            Err(Mt5HttpError::RequestError(message)) if message == r#"{"code":-1}"#
+        ));
+    }
+
+    #[test]
+    fn test_unwrap_result_passes_bare_values_through() {
+        assert_eq!(unwrap_result(json!([1, 2])).unwrap(), json!([1, 2]));
+        assert_eq!(unwrap_result(json!({"bid": 1.1})).unwrap(), json!({"bid": 1.1}));
+        assert_eq!(unwrap_result(json!(true)).unwrap(), json!(true));
+    }
+
+    #[tokio::test]
+    async fn test_positions_and_orders_filtered_server_side() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("positions_get", load_mt5_fixture("positions_get"));
+        bridge.respond("orders_get", load_mt5_fixture("orders_get"));
+        let (addr, handle) = bridge.start().await;
+        let base_url = format!("http://{addr}");
+        let client = Mt5HttpClient::new(Mt5Config::with_base_url(base_url.clone()), base_url).unwrap();
+
+        let positions = client.positions_get_for_symbol("EURUSD").await.unwrap();
+        client.positions_get_for_group("*USD*").await.unwrap();
+        let orders = client.orders_get_for_symbol("EURUSD").await.unwrap();
+        client.orders_get_for_group("*USD*").await.unwrap();
+
+        assert_eq!(
+            bridge.requests_for("positions_get"),
+            vec![json!({"symbol": "EURUSD"}), json!({"group": "*USD*"})]
+        );
+        assert_eq!(
+            bridge.requests_for("orders_get"),
+            vec![json!({"symbol": "EURUSD"}), json!({"group": "*USD*"})]
+        );
+        assert_eq!(positions[0].ticket, 50012345);
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].order_type, Mt5OrderType::BuyLimit);
+        assert_eq!(orders[0].price_open, 1.0985);
+        assert_eq!(orders[0].state, Mt5OrderState::Placed);
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_filtered_positions_without_matches_are_empty() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("positions_get", json!({"result": null}));
+        let (addr, handle) = bridge.start().await;
+        let base_url = format!("http://{addr}");
+        let client = Mt5HttpClient::new(Mt5Config::with_base_url(base_url.clone()), base_url).unwrap();
+
+        assert!(client.positions_get_for_symbol("GBPJPY").await.unwrap().is_empty());
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_invalid_json_error_names_endpoint_and_quotes_body() {
+        use wiremock::{matchers, Mock, MockServer, ResponseTemplate};
+
+        let body = format!("<html>Bad gateway{}</html>", "x".repeat(1_000));
+        let server = MockServer::start().await;
+        Mock::given(matchers::method("POST"))
+            .and(matchers::path("/api/account_info"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(body))
+            .mount(&server)
+            .await;
+        let client = Mt5HttpClient::new(Mt5Config::with_base_url(server.uri()), server.uri()).unwrap();
+
+        let Err(Mt5HttpError::JsonDecodeError(message)) = client.account_info().await else {
+            panic!("expected JsonDecodeError");
+        };
+
+        assert!(message.starts_with("/api/account_info: "));
+        assert!(message.contains("body: <html>Bad gateway"));
+        assert!(message.ends_with("... (1024 bytes) (request 1)"));
+        assert!(message.len() < ERROR_SNIPPET_LEN + 200);
+    }
+
+    #[test]
+    fn test_model_error_quotes_offending_row() {
+        let response = json!({"result": [{"ticket": 1, "symbol": "EURUSD"}]});
+
+        let Err(Mt5HttpError::ParseError(message)) = parse_rows::<Mt5TradePosition>(response, "positions_get") else {
+            panic!("expected ParseError");
+        };
+
+        assert!(message.starts_with("positions_get: missing field"));
+        assert!(message.contains(r#"body: [{"#));
+        assert!(message.contains(r#""ticket":1"#));
+    }
+
+    #[tokio::test]
+    async fn test_call_reaches_untyped_endpoint() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("market_book_get", json!({"result": [{"type": 1, "price": 1.1042}]}));
+        bridge.respond("order_calc_profit", json!({"error": "symbol not found"}));
+        let (addr, handle) = bridge.start().await;
+        let base_url = format!("http://{addr}");
+        let client = Mt5HttpClient::new(Mt5Config::with_base_url(base_url.clone()), base_url).unwrap();
+
+        let result = client.call("/api/market_book_get", json!(["EURUSD"])).await.unwrap();
+        let error = client.call("/api/order_calc_profit", json!([0, "XXX", 1.0, 1.0, 1.1])).await;
+
+        assert_eq!(result, json!([{"type": 1, "price": 1.1042}]));
+        assert_eq!(bridge.requests_for("market_book_get"), vec![json!(["EURUSD"])]);
+        assert!(matches!(error, Err(Mt5HttpError::RequestError(message)) if message == "symbol not found"));
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_call_rejects_paths_outside_api() {
+        let client = Mt5HttpClient::new(Mt5Config::default(), "http://localhost:5000".to_string()).unwrap();
+
+        for path in ["market_book_get", "/health", "/api/", "/api/../admin", "http://evil/api/x"] {
+            assert!(
+                matches!(client.call(path, json!({})).await, Err(Mt5HttpError::InvalidRequestError(_))),
+                "{path} accepted"
+            );
+        }
+    }
+
+    #[test]
+    fn test_malformed_row_skipped_unless_strict() {
+        let mut response = load_mt5_fixture("positions_get");
+        let total = response["result"].as_array().unwrap().len();
+        response["result"][0]["volume"] = json!("abc");
+        let lenient = Mt5HttpClient::new(Mt5Config::default(), "http://localhost:5000".to_string()).unwrap();
+        let strict = Mt5HttpClient::new(
+            Mt5Config {
+                strict_parsing: true,
+                ..Default::default()
+            },
+            "http://localhost:5000".to_string(),
+        )
+        .unwrap();
+
+        let positions: Vec<Mt5TradePosition> = lenient.parse_list(response.clone(), "positions_get").unwrap();
+        let result = strict.parse_list::<Mt5TradePosition>(response, "positions_get");
+
+        assert_eq!(positions.len(), total - 1);
+        assert!(!positions.is_empty());
+        assert!(matches!(result, Err(Mt5HttpError::ParseError(_))));
+    }
+
+    #[test]
+    #[traced_test]
+    fn test_empty_and_null_results_parse_to_empty_list() {
+        let fixture = load_mt5_fixture("positions_get");
+        let total = fixture["result"].as_array().unwrap().len();
+        let client = Mt5HttpClient::new(
+            Mt5Config {
+                warn_on_empty: true,
+                ..Default::default()
+            },
+            "http://localhost:5000".to_string(),
+        )
+        .unwrap();
+
+        let populated: Vec<Mt5TradePosition> = client.parse_list(fixture, "positions_get").unwrap();
+        assert_eq!(populated.len(), total);
+        assert!(!logs_contain("returned no rows"));
+
+        let empty: Vec<Mt5TradePosition> = client.parse_list(json!({"result": []}), "positions_get").unwrap();
+        assert!(empty.is_empty());
+        assert!(logs_contain("positions_get returned no rows (result: [])"));
+
+        let null: Vec<Mt5TradePosition> = client.parse_list(json!({"result": null}), "positions_get").unwrap();
+        assert!(null.is_empty());
+        assert!(logs_contain("positions_get returned no rows (result: null)"));
+
+        let failure = client.parse_list::<Mt5TradePosition>(json!({"error": [-1, "Terminal: Call failed"]}), "positions_get");
+        assert!(failure.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_symbols_get_typed_null_result_is_empty() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbols_get", json!({"result": null}));
+        let (addr, handle) = bridge.start().await;
+        let base_url = format!("http://{addr}");
+        let client = Mt5HttpClient::new(Mt5Config::with_base_url(base_url.clone()), base_url).unwrap();
+
+        let symbols = client.symbols_get_typed(None).await.unwrap();
+
+        assert!(symbols.is_empty());
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_login_result_shapes() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("login", json!({"result": true}));
+        bridge.respond("last_error", json!({"result": [-6, "Terminal: Authorization failed"]}));
+        let (addr, handle) = bridge.start().await;
+        let base_url = format!("http://{addr}");
+        let client = Mt5HttpClient::new(Mt5Config::with_base_url(base_url.clone()), base_url).unwrap();
+
+        client.login().await.unwrap();
+        assert!(bridge.requests_for("last_error").is_empty());
+
+        bridge.respond("login", json!({"result": false}));
+        let err = client.login().await.unwrap_err();
+        assert!(matches!(err, Mt5HttpError::AuthenticationError(_)));
+        assert!(err.to_string().contains("Terminal: Authorization failed (-6)"), "{err}");
+
+        bridge.respond("login", json!({"error": "terminal not initialized"}));
+        let err = client.login().await.unwrap_err();
+        assert!(matches!(err, Mt5HttpError::RequestError(_)));
+        assert!(err.to_string().contains("terminal not initialized"), "{err}");
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_initialize_false_fails_with_last_error() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("initialize", json!({"result": false}));
+        bridge.respond("last_error", json!({"result": [-10003, "IPC initialize failed"]}));
+        let (addr, handle) = bridge.start().await;
+        let base_url = format!("http://{addr}");
+        let client = Mt5HttpClient::new(Mt5Config::with_base_url(base_url.clone()), base_url).unwrap();
+
+        let err = client.initialize().await.unwrap_err();
+
+        assert!(matches!(err, Mt5HttpError::ConnectionError(_)));
+        assert!(err.to_string().contains("IPC initialize failed (-10003)"), "{err}");
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_symbol_select_checked() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbol_select", json!({"result": true}));
+        let (addr, handle) = bridge.start().await;
+        let base_url = format!("http://{addr}");
+        let client = Mt5HttpClient::new(Mt5Config::with_base_url(base_url.clone()), base_url).unwrap();
+
+        client.symbol_select_checked("EURUSD").await.unwrap();
+
+        bridge.respond("symbol_select", json!({"result": false}));
+        let result = client.symbol_select_checked("NOTASYMBOL").await;
+
+        assert!(matches!(&result, Err(Mt5HttpError::SelectFailed(symbol)) if symbol == "NOTASYMBOL"));
+        assert!(result.unwrap_err().is_non_retryable());
+        assert_eq!(
+            bridge.requests_for("symbol_select"),
+            vec![json!(["EURUSD", true]), json!(["NOTASYMBOL", true])]
+        );
+        handle.abort();
+    }
+}
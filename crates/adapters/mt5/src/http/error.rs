@@ -18,8 +18,6 @@
 pub use nautilus_network::http::HttpClientError;
 use thiserror::Error;
 
-use crate::error::Mt5Error;
-
 #[derive(Error, Debug)]
 pub enum Mt5HttpError {
     #[error("Connection error: {0}")]
@@ -38,7 +36,7 @@ pub enum Mt5HttpError {
     AuthorizationError(String),
 
     #[error("Rate limit exceeded: {0}")]
-    RateLimitError(String),
+    RateLimited(String),
 
     #[error("Invalid request: {0}")]
     InvalidRequestError(String),
@@ -60,6 +58,15 @@ pub enum Mt5HttpError {
 
     #[error("Network error: {0}")]
     NetworkError(String),
+
+    #[error("Insufficient margin: {0}")]
+    InsufficientMargin(String),
+
+    #[error("Unsupported terminal version: {0}")]
+    UnsupportedVersion(String),
+
+    #[error("symbol_select failed for {0}; check the symbol exists on the broker's server")]
+    SelectFailed(String),
 }
 
 impl Mt5HttpError {
@@ -71,7 +78,7 @@ impl Mt5HttpError {
                 | Mt5HttpError::RequestError(_)
                 | Mt5HttpError::ServerError(_)
                 | Mt5HttpError::TimeoutError(_)
-                | Mt5HttpError::RateLimitError(_)
+                | Mt5HttpError::RateLimited(_)
                 | Mt5HttpError::NetworkError(_)
         )
     }
@@ -86,6 +93,9 @@ impl Mt5HttpError {
                 | Mt5HttpError::NotFoundError(_)
                 | Mt5HttpError::JsonDecodeError(_)
                 | Mt5HttpError::ParseError(_)
+                | Mt5HttpError::InsufficientMargin(_)
+                | Mt5HttpError::UnsupportedVersion(_)
+                | Mt5HttpError::SelectFailed(_)
         )
     }
 
@@ -97,6 +107,30 @@ impl Mt5HttpError {
         )
     }
 
+    /// Appends the id of the bridge request that failed to the error message, so
+    /// it can be matched with the `X-Request-Id` the bridge logged.
+    pub fn with_request_id(self, request_id: u64) -> Self {
+        let tag = |message: String| format!("{message} (request {request_id})");
+        match self {
+            Mt5HttpError::ConnectionError(m) => Mt5HttpError::ConnectionError(tag(m)),
+            Mt5HttpError::RequestError(m) => Mt5HttpError::RequestError(tag(m)),
+            Mt5HttpError::HttpError(status, m) => Mt5HttpError::HttpError(status, tag(m)),
+            Mt5HttpError::AuthenticationError(m) => Mt5HttpError::AuthenticationError(tag(m)),
+            Mt5HttpError::AuthorizationError(m) => Mt5HttpError::AuthorizationError(tag(m)),
+            Mt5HttpError::RateLimited(m) => Mt5HttpError::RateLimited(tag(m)),
+            Mt5HttpError::InvalidRequestError(m) => Mt5HttpError::InvalidRequestError(tag(m)),
+            Mt5HttpError::NotFoundError(m) => Mt5HttpError::NotFoundError(tag(m)),
+            Mt5HttpError::JsonDecodeError(m) => Mt5HttpError::JsonDecodeError(tag(m)),
+            Mt5HttpError::ParseError(m) => Mt5HttpError::ParseError(tag(m)),
+            Mt5HttpError::ServerError(m) => Mt5HttpError::ServerError(tag(m)),
+            Mt5HttpError::TimeoutError(m) => Mt5HttpError::TimeoutError(tag(m)),
+            Mt5HttpError::NetworkError(m) => Mt5HttpError::NetworkError(tag(m)),
+            Mt5HttpError::InsufficientMargin(m) => Mt5HttpError::InsufficientMargin(tag(m)),
+            Mt5HttpError::UnsupportedVersion(m) => Mt5HttpError::UnsupportedVersion(tag(m)),
+            Mt5HttpError::SelectFailed(symbol) => Mt5HttpError::SelectFailed(symbol),
+        }
+    }
+
     /// Maps HTTP status codes to appropriate error variants
     pub fn from_http_status(status: u16, message: String) -> Self {
         match status {
@@ -104,28 +138,13 @@ impl Mt5HttpError {
             401 => Mt5HttpError::AuthenticationError(message),
             403 => Mt5HttpError::AuthorizationError(message),
             404 => Mt5HttpError::NotFoundError(message),
-            429 => Mt5HttpError::RateLimitError(message),
+            429 => Mt5HttpError::RateLimited(message),
             500..=599 => Mt5HttpError::ServerError(message),
             _ => Mt5HttpError::HttpError(status, message),
         }
     }
 }
 
-impl From<Mt5HttpError> for Mt5Error {
-    fn from(err: Mt5HttpError) -> Self {
-        match err {
-            Mt5HttpError::AuthenticationError(msg) => Mt5Error::AuthenticationError(msg),
-            Mt5HttpError::AuthorizationError(msg) => Mt5Error::AuthenticationError(msg),
-            Mt5HttpError::RateLimitError(_) => Mt5Error::RateLimitError,
-            Mt5HttpError::NotFoundError(msg) => Mt5Error::SymbolNotFound(msg),
-            Mt5HttpError::JsonDecodeError(_) | Mt5HttpError::ParseError(_) => {
-                Mt5Error::SerializationError(err.to_string())
-            }
-            _ => Mt5Error::HttpError(err.to_string()),
-        }
-    }
-}
-
 // We don't need direct conversion between Mt5HttpError and HttpClientError
 // since HttpClientError is from nautilus_network and has different variants
 // Instead, we'll use the error handling through the ? operator where appropriate
\ No newline at end of file
@@ -3,10 +3,12 @@
 //! This module provides a simple HTTP client for interacting with the MT5 REST server,
 //! which exposes all MT5 Python API functions via HTTP endpoints.
 
+pub mod blocking;
 pub mod client;
 pub mod error;
 pub mod models;
 
+pub use blocking::Mt5HttpClientSync;
 pub use client::Mt5HttpClient;
 pub use error::*;
 pub use models::*;
\ No newline at end of file
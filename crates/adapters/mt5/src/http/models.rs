@@ -22,7 +22,18 @@
 //! Most responses are handled as raw `serde_json::Value` to maintain
 //! flexibility with the MT5 Python API format.
 
-use serde::{Deserialize, Serialize};
+use chrono::{Datelike, NaiveDate, Weekday};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
+
+use crate::common::{
+    enums::{
+        Mt5DealEntry, Mt5DealType, Mt5ExecutionMode, Mt5OrderState, Mt5OrderType, Mt5SwapMode,
+        Mt5SwapUnit, Mt5TradeMode, VolumeSource,
+    },
+    parse::{
+        de_f64_flexible, de_i64_flexible, de_opt_f64_flexible, de_u32_flexible, de_u64_flexible,
+    },
+};
 
 #[cfg(feature = "python-bindings")]
 use pyo3::prelude::*;
@@ -32,16 +43,590 @@ use pyo3::prelude::*;
 #[cfg_attr(feature = "python-bindings", pyclass(get_all, set_all))]
 pub struct Mt5Symbol {
     pub symbol: String,
-    pub digits: u32,
+    /// Omitted by some bridges for synthetic or custom symbols, in which case the
+    /// instrument provider infers the price precision.
+    #[serde(default)]
+    pub digits: Option<u32>,
+    #[serde(default)]
     pub point_size: f64,
     pub volume_min: f64,
     pub volume_max: f64,
     pub volume_step: f64,
     pub contract_size: f64,
+    #[serde(default, deserialize_with = "de_opt_f64_flexible")]
     pub margin_initial: Option<f64>,
+    #[serde(default, deserialize_with = "de_opt_f64_flexible")]
     pub margin_maintenance: Option<f64>,
     #[serde(rename = "type")]
     pub symbol_type: String,
+    /// Position in the terminal's symbol tree, e.g. `Forex\Majors\EURUSD`.
+    #[serde(default)]
+    pub path: String,
+    /// Bridges that omit the flag only list Market Watch symbols, so it defaults to `true`.
+    #[serde(default = "default_visible")]
+    pub visible: bool,
+    #[serde(default)]
+    pub trade_mode: Mt5TradeMode,
+}
+
+fn default_visible() -> bool {
+    true
+}
+
+impl Mt5Symbol {
+    /// Returns whether the symbol is visible and its trade mode is not `Disabled`.
+    pub fn is_tradable(&self) -> bool {
+        self.visible && self.trade_mode != Mt5TradeMode::Disabled
+    }
+
+    /// Returns whether the symbol name matches the MT5 `group` pattern.
+    ///
+    /// See [`crate::common::symbol::matches_group`] for the syntax.
+    pub fn matches_group(&self, group: &str) -> bool {
+        crate::common::symbol::matches_group(&self.symbol, group)
+    }
+}
+
+/// Sorts symbols by tree `path`, then by name, for a deterministic instrument order.
+pub fn sort_symbols(symbols: &mut [Mt5Symbol]) {
+    symbols.sort_by(|a, b| a.path.cmp(&b.path).then_with(|| a.symbol.cmp(&b.symbol)));
+}
+
+/// `SYMBOL_ORDER_MARKET` bit of a symbol's `order_mode`.
+pub const SYMBOL_ORDER_MARKET: i64 = 1;
+
+/// Symbol specification as returned by the MT5 `symbol_info` endpoint.
+///
+/// Mirrors the fields of the MT5 Python `SymbolInfo` named tuple; fields that
+/// not every broker populates default to zero/empty.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Mt5SymbolInfo {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub path: String,
+    #[serde(default)]
+    pub currency_base: String,
+    #[serde(default)]
+    pub currency_profit: String,
+    #[serde(default)]
+    pub currency_margin: String,
+    #[serde(deserialize_with = "de_u32_flexible")]
+    pub digits: u32,
+    #[serde(deserialize_with = "de_f64_flexible")]
+    pub point: f64,
+    /// Current spread in points (zero for some floating-spread symbols).
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub spread: i64,
+    #[serde(default)]
+    pub spread_float: bool,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub bid: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub ask: f64,
+    #[serde(default)]
+    pub select: bool,
+    #[serde(default)]
+    pub visible: bool,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub trade_contract_size: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub trade_tick_size: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub trade_tick_value: f64,
+    #[serde(default)]
+    pub trade_mode: Mt5TradeMode,
+    #[serde(default)]
+    pub trade_exemode: Mt5ExecutionMode,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub trade_stops_level: i64,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub trade_freeze_level: i64,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub filling_mode: i64,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub expiration_mode: i64,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub order_mode: i64,
+    #[serde(deserialize_with = "de_f64_flexible")]
+    pub volume_min: f64,
+    #[serde(deserialize_with = "de_f64_flexible")]
+    pub volume_max: f64,
+    #[serde(deserialize_with = "de_f64_flexible")]
+    pub volume_step: f64,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub swap_mode: i64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub swap_long: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub swap_short: f64,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub swap_rollover3days: i64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub margin_initial: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub margin_maintenance: f64,
+}
+
+impl Mt5SymbolInfo {
+    /// Returns whether the symbol accepts market orders at all.
+    ///
+    /// A `Disabled` trade mode forbids every order, and an `order_mode` without
+    /// the `SYMBOL_ORDER_MARKET` bit prohibits market orders; an `order_mode` of
+    /// zero means the bridge did not report it.
+    pub fn is_market_order_allowed(&self) -> bool {
+        self.trade_mode != Mt5TradeMode::Disabled
+            && (self.order_mode == 0 || self.order_mode & SYMBOL_ORDER_MARKET != 0)
+    }
+
+    /// Returns the current spread as an absolute price distance.
+    ///
+    /// Uses `spread * point` when the bridge reports a spread in points. Some
+    /// floating-spread symbols report `spread == 0`, in which case the spread is
+    /// taken from the quoted `ask - bid` instead.
+    pub fn spread_price(&self) -> f64 {
+        if self.spread > 0 {
+            self.spread as f64 * self.point
+        } else if self.bid > 0.0 && self.ask > 0.0 {
+            (self.ask - self.bid).max(0.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the spread in basis points of the mid price, or `None` without a valid quote.
+    pub fn spread_bps(&self) -> Option<f64> {
+        let mid = (self.bid + self.ask) / 2.0;
+        if self.bid <= 0.0 || self.ask <= 0.0 || mid <= 0.0 {
+            return None;
+        }
+        Some(self.spread_price() / mid * 10_000.0)
+    }
+
+    /// Returns the symbol's swap rates and rollover schedule.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `swap_mode` or `swap_rollover3days` is out of range.
+    pub fn swap_info(&self) -> Result<Mt5SwapInfo, String> {
+        let mode = Mt5SwapMode::try_from(self.swap_mode)?;
+        // `ENUM_DAY_OF_WEEK` counts from Sunday
+        let triple_day = match self.swap_rollover3days {
+            0 => Weekday::Sun,
+            1 => Weekday::Mon,
+            2 => Weekday::Tue,
+            3 => Weekday::Wed,
+            4 => Weekday::Thu,
+            5 => Weekday::Fri,
+            6 => Weekday::Sat,
+            value => return Err(format!("unknown swap_rollover3days value {value}")),
+        };
+        let currency = match mode {
+            Mt5SwapMode::CurrencySymbol => Some(self.currency_base.clone()),
+            Mt5SwapMode::CurrencyMargin => Some(self.currency_margin.clone()),
+            Mt5SwapMode::CurrencyProfit => Some(self.currency_profit.clone()),
+            _ => None,
+        };
+        Ok(Mt5SwapInfo {
+            symbol: self.name.clone(),
+            mode,
+            long: self.swap_long,
+            short: self.swap_short,
+            triple_day,
+            currency,
+        })
+    }
+}
+
+/// Swap (rollover) terms of a symbol, taken from `symbol_info`.
+///
+/// `long` and `short` are per-lot rates for one night in the unit given by
+/// [`Mt5SwapInfo::unit`]; positive rates are credited, negative rates charged.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mt5SwapInfo {
+    pub symbol: String,
+    pub mode: Mt5SwapMode,
+    pub long: f64,
+    pub short: f64,
+    /// Day whose rollover is charged three times to cover the weekend.
+    pub triple_day: Weekday,
+    /// Currency of money swaps; `None` for other units and for
+    /// [`Mt5SwapMode::CurrencyDeposit`], which uses the account currency.
+    pub currency: Option<String>,
+}
+
+impl Mt5SwapInfo {
+    /// Returns the unit of `long` and `short`.
+    pub fn unit(&self) -> Mt5SwapUnit {
+        self.mode.unit()
+    }
+
+    /// Returns the rate for a long (`is_long`) or short position.
+    pub fn rate(&self, is_long: bool) -> f64 {
+        if is_long {
+            self.long
+        } else {
+            self.short
+        }
+    }
+
+    /// Returns how many nights of swap the rollover on `day` charges.
+    ///
+    /// Weekday rollovers charge one night, the triple day three, and weekends none.
+    pub fn rollover_nights(&self, day: Weekday) -> u32 {
+        if day == self.triple_day {
+            3
+        } else if matches!(day, Weekday::Sat | Weekday::Sun) {
+            0
+        } else {
+            1
+        }
+    }
+
+    /// Returns the rollover applied on `date`, or `None` when no swap is charged.
+    pub fn rollover(&self, date: NaiveDate) -> Option<Mt5Rollover> {
+        let nights = self.rollover_nights(date.weekday());
+        if nights == 0 || self.mode == Mt5SwapMode::Disabled {
+            return None;
+        }
+        Some(Mt5Rollover {
+            symbol: self.symbol.clone(),
+            date,
+            nights,
+            unit: self.unit(),
+            long: self.long * f64::from(nights),
+            short: self.short * f64::from(nights),
+            currency: self.currency.clone(),
+        })
+    }
+}
+
+/// Swap applied to open positions at one daily rollover.
+///
+/// `long` and `short` are per-lot amounts already multiplied by `nights`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mt5Rollover {
+    pub symbol: String,
+    /// Trade server date of the rollover.
+    pub date: NaiveDate,
+    pub nights: u32,
+    pub unit: Mt5SwapUnit,
+    pub long: f64,
+    pub short: f64,
+    pub currency: Option<String>,
+}
+
+/// Open position as returned by the MT5 `positions_get` endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Mt5TradePosition {
+    #[serde(deserialize_with = "de_u64_flexible")]
+    pub ticket: u64,
+    #[serde(deserialize_with = "de_i64_flexible")]
+    pub time: i64,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub time_msc: i64,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub time_update: i64,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub time_update_msc: i64,
+    /// `POSITION_TYPE_BUY` (0) or `POSITION_TYPE_SELL` (1).
+    #[serde(rename = "type")]
+    #[serde(deserialize_with = "de_i64_flexible")]
+    pub position_type: i64,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub magic: i64,
+    #[serde(default, deserialize_with = "de_u64_flexible")]
+    pub identifier: u64,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub reason: i64,
+    #[serde(deserialize_with = "de_f64_flexible")]
+    pub volume: f64,
+    #[serde(deserialize_with = "de_f64_flexible")]
+    pub price_open: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub sl: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub tp: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub price_current: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub swap: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub profit: f64,
+    pub symbol: String,
+    #[serde(default)]
+    pub comment: String,
+    #[serde(default)]
+    pub external_id: String,
+}
+
+impl Mt5TradePosition {
+    /// Returns whether this is a buy (`POSITION_TYPE_BUY`) position.
+    pub fn is_long(&self) -> bool {
+        self.position_type == 0
+    }
+}
+
+/// `orders_get` only returns working orders, so a missing state means placed.
+fn default_order_state() -> Mt5OrderState {
+    Mt5OrderState::Placed
+}
+
+/// Active order as returned by the MT5 `orders_get` endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Mt5TradeOrder {
+    #[serde(deserialize_with = "de_u64_flexible")]
+    pub ticket: u64,
+    #[serde(deserialize_with = "de_i64_flexible")]
+    pub time_setup: i64,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub time_setup_msc: i64,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub time_expiration: i64,
+    #[serde(rename = "type")]
+    pub order_type: Mt5OrderType,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub type_time: i64,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub type_filling: i64,
+    #[serde(default = "default_order_state")]
+    pub state: Mt5OrderState,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub magic: i64,
+    #[serde(default, deserialize_with = "de_u64_flexible")]
+    pub position_id: u64,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub reason: i64,
+    #[serde(deserialize_with = "de_f64_flexible")]
+    pub volume_initial: f64,
+    #[serde(deserialize_with = "de_f64_flexible")]
+    pub volume_current: f64,
+    #[serde(deserialize_with = "de_f64_flexible")]
+    pub price_open: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub sl: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub tp: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub price_current: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub price_stoplimit: f64,
+    pub symbol: String,
+    #[serde(default)]
+    pub comment: String,
+    #[serde(default)]
+    pub external_id: String,
+}
+
+/// Executed deal as returned by the MT5 `history_deals_get` endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Mt5Deal {
+    #[serde(deserialize_with = "de_u64_flexible")]
+    pub ticket: u64,
+    #[serde(default, deserialize_with = "de_u64_flexible")]
+    pub order: u64,
+    #[serde(deserialize_with = "de_i64_flexible")]
+    pub time: i64,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub time_msc: i64,
+    #[serde(rename = "type")]
+    pub deal_type: Mt5DealType,
+    pub entry: Mt5DealEntry,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub magic: i64,
+    #[serde(default, deserialize_with = "de_u64_flexible")]
+    pub position_id: u64,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub reason: i64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub volume: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub price: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub commission: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub swap: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub profit: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub fee: f64,
+    #[serde(default)]
+    pub symbol: String,
+    #[serde(default)]
+    pub comment: String,
+    #[serde(default)]
+    pub external_id: String,
+}
+
+impl Mt5Deal {
+    /// Returns whether this is a trade (buy/sell) rather than a balance, credit or similar operation.
+    pub fn is_trade(&self) -> bool {
+        self.deal_type.is_trade()
+    }
+}
+
+/// One bar as returned by the MT5 `copy_rates_*` endpoints.
+///
+/// Depending on the MetaTrader5 Python package version, bridges send each rate
+/// either as a named object or as a positional array
+/// `[time, open, high, low, close, tick_volume, spread, real_volume]`; both decode here.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Mt5Rate {
+    /// Bar open time in Unix seconds
+    pub time: i64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub tick_volume: f64,
+    pub spread: i64,
+    pub real_volume: f64,
+}
+
+impl Mt5Rate {
+    /// Returns the bar volume selected by `source`.
+    pub fn volume(&self, source: VolumeSource) -> f64 {
+        match source {
+            VolumeSource::Tick => self.tick_volume,
+            VolumeSource::Real => self.real_volume,
+            VolumeSource::Auto if self.real_volume > 0.0 => self.real_volume,
+            VolumeSource::Auto => self.tick_volume,
+        }
+    }
+}
+
+/// Named encoding of [`Mt5Rate`].
+#[derive(Deserialize)]
+struct Mt5RateObject {
+    #[serde(deserialize_with = "de_i64_flexible")]
+    time: i64,
+    #[serde(deserialize_with = "de_f64_flexible")]
+    open: f64,
+    #[serde(deserialize_with = "de_f64_flexible")]
+    high: f64,
+    #[serde(deserialize_with = "de_f64_flexible")]
+    low: f64,
+    #[serde(deserialize_with = "de_f64_flexible")]
+    close: f64,
+    #[serde(deserialize_with = "de_f64_flexible")]
+    tick_volume: f64,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    spread: i64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    real_volume: f64,
+}
+
+impl<'de> Deserialize<'de> for Mt5Rate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let rate = match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::Array(fields) => {
+                if fields.len() < 6 {
+                    return Err(D::Error::invalid_length(fields.len(), &"at least 6 rate fields"));
+                }
+                let field = |index: usize| fields.get(index).cloned().unwrap_or_default();
+                let optional = |value: serde_json::Value| (!value.is_null()).then_some(value);
+                Mt5RateObject {
+                    time: de_i64_flexible(field(0)).map_err(D::Error::custom)?,
+                    open: de_f64_flexible(field(1)).map_err(D::Error::custom)?,
+                    high: de_f64_flexible(field(2)).map_err(D::Error::custom)?,
+                    low: de_f64_flexible(field(3)).map_err(D::Error::custom)?,
+                    close: de_f64_flexible(field(4)).map_err(D::Error::custom)?,
+                    tick_volume: de_f64_flexible(field(5)).map_err(D::Error::custom)?,
+                    spread: optional(field(6))
+                        .map_or(Ok(0), de_i64_flexible)
+                        .map_err(D::Error::custom)?,
+                    real_volume: optional(field(7))
+                        .map_or(Ok(0.0), de_f64_flexible)
+                        .map_err(D::Error::custom)?,
+                }
+            }
+            value => Mt5RateObject::deserialize(value).map_err(D::Error::custom)?,
+        };
+        Ok(Self {
+            time: rate.time,
+            open: rate.open,
+            high: rate.high,
+            low: rate.low,
+            close: rate.close,
+            tick_volume: rate.tick_volume,
+            spread: rate.spread,
+            real_volume: rate.real_volume,
+        })
+    }
+}
+
+/// `BOOK_TYPE_SELL`: an ask level.
+pub const BOOK_TYPE_SELL: i64 = 1;
+/// `BOOK_TYPE_BUY`: a bid level.
+pub const BOOK_TYPE_BUY: i64 = 2;
+
+/// Depth of market level as returned by the MT5 `market_book_get` endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Mt5BookEntry {
+    /// `BOOK_TYPE_*`: 1 = sell, 2 = buy, 3/4 = sell/buy by market.
+    #[serde(rename = "type")]
+    #[serde(deserialize_with = "de_i64_flexible")]
+    pub book_type: i64,
+    #[serde(deserialize_with = "de_f64_flexible")]
+    pub price: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub volume: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub volume_dbl: f64,
+}
+
+/// Result of the MT5 `order_check` endpoint: the account as it would be after the order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Mt5OrderCheckResult {
+    /// `0` when the check passed, otherwise a `TRADE_RETCODE_*`.
+    #[serde(deserialize_with = "de_u32_flexible")]
+    pub retcode: u32,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub balance: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub equity: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub profit: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub margin: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub margin_free: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub margin_level: f64,
+    #[serde(default)]
+    pub comment: String,
+    #[serde(default)]
+    pub request: serde_json::Value,
+}
+
+/// Result of the MT5 `order_send` endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Mt5OrderSendResult {
+    /// Trade server return code (`TRADE_RETCODE_*`).
+    #[serde(deserialize_with = "de_u32_flexible")]
+    pub retcode: u32,
+    #[serde(default, deserialize_with = "de_u64_flexible")]
+    pub deal: u64,
+    #[serde(default, deserialize_with = "de_u64_flexible")]
+    pub order: u64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub volume: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub price: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub bid: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub ask: f64,
+    #[serde(default)]
+    pub comment: String,
+    #[serde(default, deserialize_with = "de_u64_flexible")]
+    pub request_id: u64,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub retcode_external: i64,
+    /// Echo of the submitted trade request.
+    #[serde(default)]
+    pub request: serde_json::Value,
 }
 
 /// Standard MT5 REST API response wrapper
@@ -60,3 +645,154 @@ impl<T> Mt5Response<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::testing::{
+        load_index_symbol_info_fixture, load_mt5_fixture, load_symbol_info_fixture,
+    };
+    use serde::de::DeserializeOwned;
+    use serde_json::Value;
+
+    /// Re-encodes every top-level numeric field of each record as a string.
+    fn stringify_numbers(value: &Value) -> Value {
+        match value {
+            Value::Array(items) => Value::Array(items.iter().map(stringify_numbers).collect()),
+            Value::Object(fields) => Value::Object(
+                fields
+                    .iter()
+                    .map(|(k, v)| match v {
+                        Value::Number(n) => (k.clone(), Value::String(n.to_string())),
+                        other => (k.clone(), other.clone()),
+                    })
+                    .collect(),
+            ),
+            other => other.clone(),
+        }
+    }
+
+    fn assert_parses_both_shapes<T: DeserializeOwned + PartialEq + std::fmt::Debug>(fixture: &str) {
+        let result = load_mt5_fixture(fixture)["result"].clone();
+        let from_numbers: T = serde_json::from_value(result.clone()).unwrap();
+        let from_strings: T = serde_json::from_value(stringify_numbers(&result)).unwrap();
+        assert_eq!(from_numbers, from_strings, "{fixture}");
+    }
+
+    #[test]
+    fn test_models_accept_numbers_as_strings() {
+        assert_parses_both_shapes::<Mt5SymbolInfo>("symbol_info_eurusd");
+        assert_parses_both_shapes::<Mt5SymbolInfo>("symbol_info_us30");
+        assert_parses_both_shapes::<Vec<Mt5TradePosition>>("positions_get");
+        assert_parses_both_shapes::<Mt5OrderSendResult>("order_send_success");
+        assert_parses_both_shapes::<Mt5OrderCheckResult>("order_check_insufficient");
+        assert_parses_both_shapes::<Vec<Mt5Deal>>("history_deals_round_trip");
+        assert_parses_both_shapes::<crate::common::models::Mt5AccountInfo>("account_info");
+    }
+
+    #[test]
+    fn test_spread_fixed_symbol() {
+        let us30 = load_index_symbol_info_fixture();
+
+        assert!((us30.spread_price() - 2.0).abs() < 1e-9);
+        let bps = us30.spread_bps().unwrap();
+        assert!((bps - 2.0 / 37716.4 * 10_000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_spread_floating_symbol_without_points_uses_quotes() {
+        let mut eurusd = load_symbol_info_fixture();
+        eurusd.spread = 0;
+
+        assert!((eurusd.spread_price() - 0.00007).abs() < 1e-9);
+        assert!(eurusd.spread_bps().unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_spread_without_quote() {
+        let mut eurusd = load_symbol_info_fixture();
+        eurusd.spread = 0;
+        eurusd.bid = 0.0;
+        eurusd.ask = 0.0;
+
+        assert_eq!(eurusd.spread_price(), 0.0);
+        assert_eq!(eurusd.spread_bps(), None);
+    }
+
+    #[test]
+    fn test_swap_info_in_points() {
+        let swap = load_symbol_info_fixture().swap_info().unwrap();
+
+        assert_eq!(swap.mode, Mt5SwapMode::Points);
+        assert_eq!(swap.unit(), Mt5SwapUnit::Points);
+        assert_eq!(swap.rate(true), -7.32);
+        assert_eq!(swap.rate(false), 2.18);
+        assert_eq!(swap.triple_day, Weekday::Wed);
+        assert_eq!(swap.currency, None);
+    }
+
+    #[test]
+    fn test_swap_info_in_money_uses_mode_currency() {
+        let swap = load_index_symbol_info_fixture().swap_info().unwrap();
+
+        assert_eq!(swap.mode, Mt5SwapMode::CurrencySymbol);
+        assert_eq!(swap.unit(), Mt5SwapUnit::Money);
+        assert_eq!(swap.currency.as_deref(), Some("USD"));
+        assert_eq!(swap.triple_day, Weekday::Fri);
+
+        let mut eurusd = load_symbol_info_fixture();
+        eurusd.swap_mode = 3;
+        assert_eq!(eurusd.swap_info().unwrap().currency.as_deref(), Some("EUR"));
+        eurusd.swap_mode = 4;
+        assert_eq!(eurusd.swap_info().unwrap().currency, None);
+    }
+
+    #[test]
+    fn test_swap_info_in_percent() {
+        let mut eurusd = load_symbol_info_fixture();
+        eurusd.swap_mode = 6;
+
+        let swap = eurusd.swap_info().unwrap();
+
+        assert_eq!(swap.mode, Mt5SwapMode::InterestOpen);
+        assert_eq!(swap.unit(), Mt5SwapUnit::Percent);
+        assert_eq!(swap.currency, None);
+    }
+
+    #[test]
+    fn test_swap_info_in_profit_currency() {
+        let mut eurusd = load_symbol_info_fixture();
+        eurusd.swap_mode = 9;
+
+        let swap = eurusd.swap_info().unwrap();
+
+        assert_eq!(swap.mode, Mt5SwapMode::CurrencyProfit);
+        assert_eq!(swap.unit(), Mt5SwapUnit::Money);
+        assert_eq!(swap.currency, Some(eurusd.currency_profit.clone()));
+    }
+
+    #[test]
+    fn test_swap_info_rejects_unknown_mode() {
+        let mut eurusd = load_symbol_info_fixture();
+        eurusd.swap_mode = 10;
+
+        assert!(eurusd.swap_info().is_err());
+    }
+
+    #[test]
+    fn test_rollover_triples_on_triple_day() {
+        let swap = load_symbol_info_fixture().swap_info().unwrap();
+        // 2024-01-02 is a Tuesday
+        let tuesday = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+
+        let rollover = swap.rollover(tuesday).unwrap();
+        assert_eq!(rollover.nights, 1);
+        assert_eq!(rollover.long, -7.32);
+
+        let rollover = swap.rollover(tuesday.succ_opt().unwrap()).unwrap();
+        assert_eq!(rollover.nights, 3);
+        assert!((rollover.short - 6.54).abs() < 1e-9);
+
+        assert!(swap.rollover(NaiveDate::from_ymd_opt(2024, 1, 6).unwrap()).is_none());
+    }
+}
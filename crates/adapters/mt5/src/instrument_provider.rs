@@ -19,13 +19,38 @@
 //! following the specifications in the adapter documentation.
 
 use crate::config::{Mt5Config, Mt5InstrumentProviderConfig};
-use crate::http::client::Mt5HttpClient;
+use crate::http::client::{unwrap_result, Mt5HttpClient};
+use crate::http::models::{sort_symbols, Mt5Symbol};
 use crate::http::error::{Mt5HttpError};
 use crate::common::parse::InstrumentMetadata;
 use crate::common::parse::InstrumentType;
+use crate::common::parse::step_decimals;
+use crate::common::symbol::SymbolMapper;
+use dashmap::DashMap;
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    identifiers::{InstrumentId, Symbol, Venue},
+    instruments::{CurrencyPair, Instrument, InstrumentAny},
+    types::{Currency, Price, Quantity},
+};
+use std::collections::HashMap;
+use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
-use tokio::sync::RwLock;
+use tokio::sync::{broadcast, RwLock};
+
+/// Instrument updates buffered per subscriber before the oldest are dropped.
+const INSTRUMENT_UPDATE_CAPACITY: usize = 256;
+
+/// Progress of an instrument load, reported after each chunk of symbols.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoadProgress {
+    /// Symbols processed so far.
+    pub loaded: usize,
+    /// Symbols to process in this load.
+    pub total: usize,
+}
 
 // Filter types for instrument loading
 #[derive(Debug, Clone, PartialEq)]
@@ -59,6 +84,10 @@ impl From<String> for InstrumentProviderError {
 
 #[cfg(feature = "python-bindings")]
 use pyo3::prelude::*;
+#[cfg(feature = "python-bindings")]
+use crate::common::error::to_pyerr;
+#[cfg(feature = "python-bindings")]
+use nautilus_model::python::instruments::instrument_any_to_pyobject;
 
 #[cfg(feature = "python-bindings")]
 #[derive(Clone, Debug)]
@@ -68,23 +97,33 @@ pub struct Mt5InstrumentProvider {
     config: Mt5InstrumentProviderConfig,
     http_client: Arc<Mt5HttpClient>,
     cache: Arc<RwLock<Vec<InstrumentMetadata>>>,
+    instruments: Arc<DashMap<InstrumentId, InstrumentAny>>,
+    updates: broadcast::Sender<InstrumentAny>,
+    symbol_mapper: SymbolMapper,
 }
 
 #[cfg(not(feature = "python-bindings"))]
+#[derive(Clone, Debug)]
 pub struct Mt5InstrumentProvider {
     pub config: Mt5InstrumentProviderConfig,
     http_client: Arc<Mt5HttpClient>,
     cache: Arc<RwLock<Vec<InstrumentMetadata>>>,
+    instruments: Arc<DashMap<InstrumentId, InstrumentAny>>,
+    updates: broadcast::Sender<InstrumentAny>,
+    symbol_mapper: SymbolMapper,
 }
 
 impl Mt5InstrumentProvider {
     pub fn new(config: Mt5InstrumentProviderConfig) -> Result<Self, InstrumentProviderError> {
+        let symbol_mapper = config.symbol_mapper().map_err(InstrumentProviderError::ConfigError)?;
         // Build the HTTP config for the client
         let base_url = config.base_url.clone();
         let http_config = Mt5Config {
             base_url: base_url.clone(),
+            // The listing overrides this with `market_data_timeout_secs`, see `list_symbols`
             http_timeout: config.http_timeout.unwrap_or(30),
             proxy: None,
+            ..Default::default()
         };
 
         let http_client_result = Mt5HttpClient::new(http_config, base_url);
@@ -98,9 +137,66 @@ impl Mt5InstrumentProvider {
             config,
             http_client,
             cache: Arc::new(RwLock::new(Vec::new())),
+            instruments: Arc::new(DashMap::new()),
+            updates: broadcast::channel(INSTRUMENT_UPDATE_CAPACITY).0,
+            symbol_mapper,
+        })
+    }
+
+    /// Returns the mapper between Nautilus and MT5 symbol names.
+    pub fn symbol_mapper(&self) -> &SymbolMapper {
+        &self.symbol_mapper
+    }
+
+    /// Returns the instrument cache shared with clients via `set_instrument_provider`.
+    pub fn instruments_cache(&self) -> Arc<DashMap<InstrumentId, InstrumentAny>> {
+        Arc::clone(&self.instruments)
+    }
+
+    /// Returns a loaded instrument, or `None` if it has not been loaded.
+    pub fn instrument(&self, instrument_id: &InstrumentId) -> Option<InstrumentAny> {
+        self.instruments.get(instrument_id).map(|entry| entry.value().clone())
+    }
+
+    /// Subscribes to instruments whose contract specs changed on a reload.
+    ///
+    /// Clients holding their own copies should re-cache each instrument
+    /// received. Instruments loaded for the first time are not sent.
+    pub fn instrument_updates(&self) -> broadcast::Receiver<InstrumentAny> {
+        self.updates.subscribe()
+    }
+
+    /// Returns the cached instrument, loading it from the bridge if needed.
+    pub async fn get_or_load(
+        &self,
+        instrument_id: &InstrumentId,
+    ) -> Result<InstrumentAny, InstrumentProviderError> {
+        if let Some(instrument) = self.instrument(instrument_id) {
+            return Ok(instrument);
+        }
+        let symbol = self.symbol_mapper.nautilus_to_mt5(instrument_id.symbol.as_str());
+        self.load_ids_async(vec![symbol], None)
+            .await?;
+        self.instrument(instrument_id).ok_or_else(|| {
+            InstrumentProviderError::ParseError(format!("Instrument {instrument_id} not found"))
         })
     }
 
+    /// Converts `metadata` and adds the supported instruments to the shared cache.
+    ///
+    /// Instruments replacing a cached one with different specs are published
+    /// to [`Self::instrument_updates`] subscribers.
+    fn cache_instruments(&self, metadata: &[InstrumentMetadata]) {
+        for instrument in metadata_to_instruments(metadata, &self.symbol_mapper) {
+            let previous = self.instruments.insert(instrument.id(), instrument.clone());
+            if previous.is_some_and(|previous| specs_changed(&previous, &instrument)) {
+                tracing::info!("Contract specs changed for {}", instrument.id());
+                // No subscribers is fine; updates are optional
+                let _ = self.updates.send(instrument);
+            }
+        }
+    }
+
     /// Loads all instruments asynchronously, optionally applying filters.
     ///
     /// # Arguments
@@ -109,17 +205,28 @@ impl Mt5InstrumentProvider {
     /// # Returns
     /// A `Result` containing a `Vec` of loaded instruments or an `InstrumentProviderError`
     pub async fn load_all_async(&self, _filters: Option<Vec<InstrumentFilter>>) -> Result<Vec<InstrumentMetadata>, InstrumentProviderError> {
-        let instruments = self.discover_instruments_metadata().await?;
-        
+        self.load_all_with_progress(_filters, &|_| {}).await
+    }
+
+    /// Loads all instruments like [`Self::load_all_async`], calling
+    /// `on_progress` after each `load_chunk_size` symbols.
+    pub async fn load_all_with_progress(
+        &self,
+        filters: Option<Vec<InstrumentFilter>>,
+        on_progress: &(dyn Fn(LoadProgress) + Send + Sync),
+    ) -> Result<Vec<InstrumentMetadata>, InstrumentProviderError> {
+        let instruments = self.discover_instruments_metadata(on_progress).await?;
+
         // Apply filters if provided
-        let filtered_instruments = if let Some(filters) = _filters {
+        let filtered_instruments = if let Some(filters) = filters {
             instruments.into_iter()
                 .filter(|instrument| self.matches_filters_metadata(instrument, &filters))
                 .collect()
         } else {
             instruments
         };
-        
+        self.cache_instruments(&filtered_instruments);
+
         Ok(filtered_instruments)
     }
 
@@ -132,7 +239,7 @@ impl Mt5InstrumentProvider {
     /// # Returns
     /// A `Result` containing a `Vec` of loaded instruments or an `InstrumentProviderError`
     pub async fn load_ids_async(&self, _instrument_ids: Vec<String>, _filters: Option<Vec<InstrumentFilter>>) -> Result<Vec<InstrumentMetadata>, InstrumentProviderError> {
-        let all_instruments = self.discover_instruments_metadata().await?;
+        let all_instruments = self.discover_instruments_metadata(&|_| {}).await?;
         
         // Filter by instrument IDs
         let mut filtered_instruments: Vec<InstrumentMetadata> = all_instruments.into_iter()
@@ -143,6 +250,7 @@ impl Mt5InstrumentProvider {
         if let Some(filters) = _filters {
             filtered_instruments.retain(|instrument| self.matches_filters_metadata(instrument, &filters));
         }
+        self.cache_instruments(&filtered_instruments);
         
         Ok(filtered_instruments)
     }
@@ -196,35 +304,62 @@ impl Mt5InstrumentProvider {
 
     /// Discovers all instruments from the MT5 server.
     ///
+    /// Symbols are converted in chunks of `load_chunk_size`, reporting each
+    /// chunk to `on_progress` and yielding to other tasks in between. The
+    /// bridge lookups for symbols sent without `digits` are issued together
+    /// per chunk.
+    ///
     /// # Returns
     /// A `Result` containing a `Vec` of discovered instruments or an `InstrumentProviderError`
-    async fn discover_instruments_metadata(&self) -> Result<Vec<InstrumentMetadata>, InstrumentProviderError> {
-        // Get all symbols from MT5
-        let body = serde_json::json!({});
-        let response = self.http_client.symbols_get(&body).await
-            .map_err(|e| InstrumentProviderError::ConnectionError(e.to_string()))?;
-        let symbols: Vec<crate::http::models::Mt5Symbol> = serde_json::from_value(response)
-            .map_err(|e| InstrumentProviderError::ParseError(e.to_string()))?;
-
-        let mut instruments = Vec::new();
-
-        for symbol in symbols {
-            // Parse instrument type from symbol name
-            let instrument_type = crate::common::parse::parse_instrument_symbol(&symbol.symbol)
-                .unwrap_or_else(|_| InstrumentType::Cfd { symbol: symbol.symbol.clone() });
-            
-            let metadata = InstrumentMetadata {
-                symbol: symbol.symbol.clone(),
-                digits: symbol.digits as u8,
-                point_size: symbol.point_size,
-                volume_min: symbol.volume_min,
-                volume_max: symbol.volume_max,
-                volume_step: symbol.volume_step,
-                contract_size: symbol.contract_size,
-                instrument_type,
-            };
-            instruments.push(metadata);
+    async fn discover_instruments_metadata(
+        &self,
+        on_progress: &(dyn Fn(LoadProgress) + Send + Sync),
+    ) -> Result<Vec<InstrumentMetadata>, InstrumentProviderError> {
+        if let Some(max_symbols) = self.config.max_symbols {
+            self.check_symbols_total(max_symbols).await?;
+        }
+
+        // The group is also applied client-side since not every bridge filters on it
+        let group = self.config.symbol_group.as_deref();
+        let mut symbols = self.list_symbols(group).await?;
+        symbols.retain(|symbol| {
+            symbol.is_tradable() && group.map_or(true, |group| symbol.matches_group(group))
+        });
+        sort_symbols(&mut symbols);
+
+        let total = symbols.len();
+        let mut instruments = Vec::with_capacity(total);
+
+        for chunk in symbols.chunks(self.config.load_chunk_size.max(1)) {
+            let precisions = futures_util::future::try_join_all(chunk.iter().map(|symbol| async move {
+                match symbol.digits {
+                    Some(digits) => Ok((digits as u8, symbol.point_size)),
+                    None => self.infer_price_precision(symbol).await,
+                }
+            }))
+            .await?;
+            for (symbol, (digits, point_size)) in chunk.iter().zip(precisions) {
+                // Parse instrument type from the Nautilus name, which has no broker suffix
+                let name = self.symbol_mapper.mt5_to_nautilus(&symbol.symbol);
+                let instrument_type = crate::common::parse::parse_instrument_symbol(&name)
+                    .unwrap_or_else(|_| InstrumentType::Cfd { symbol: symbol.symbol.clone() });
+
+                let metadata = InstrumentMetadata {
+                    symbol: symbol.symbol.clone(),
+                    digits,
+                    point_size,
+                    volume_min: symbol.volume_min,
+                    volume_max: symbol.volume_max,
+                    volume_step: symbol.volume_step,
+                    contract_size: symbol.contract_size,
+                    instrument_type,
+                };
+                instruments.push(metadata);
+            }
+            on_progress(LoadProgress { loaded: instruments.len(), total });
+            tokio::task::yield_now().await;
         }
+        let instruments = self.resolve_instrument_id_collisions(instruments)?;
 
         // Cache the instruments
         {
@@ -235,12 +370,139 @@ impl Mt5InstrumentProvider {
         Ok(instruments)
     }
 
+    /// Infers the price precision and point of a symbol the bridge sent without
+    /// `digits`, from its `point` or else from the decimals of a sample tick.
+    ///
+    /// A tick price with trailing zeros understates the precision, so the
+    /// most precise of the tick's prices is used.
+    async fn infer_price_precision(&self, symbol: &Mt5Symbol) -> Result<(u8, f64), InstrumentProviderError> {
+        if symbol.point_size > 0.0 {
+            let digits = precision_from_increment(symbol.point_size);
+            tracing::info!(
+                "{} has no digits; inferred price precision {digits} from point {}",
+                symbol.symbol,
+                symbol.point_size
+            );
+            return Ok((digits, symbol.point_size));
+        }
+
+        let response = self
+            .http_client
+            .symbol_info_tick(&serde_json::json!([symbol.symbol]))
+            .await?;
+        let tick = unwrap_result(response)?;
+        let digits = ["bid", "ask", "last"]
+            .iter()
+            .filter_map(|field| tick.get(field).and_then(serde_json::Value::as_f64))
+            .filter(|price| *price > 0.0)
+            .map(precision_from_increment)
+            .max()
+            .ok_or_else(|| {
+                InstrumentProviderError::ParseError(format!(
+                    "{} has no digits, point or priced tick to infer price precision from",
+                    symbol.symbol
+                ))
+            })?;
+        tracing::info!("{} has no digits; inferred price precision {digits} from a sample tick", symbol.symbol);
+        Ok((digits, 10f64.powi(-i32::from(digits))))
+    }
+
+    /// Resolves MT5 symbols that map to the same Nautilus symbol, e.g. `EURUSD.pro`
+    /// through `symbol_suffix` and `EURUSD.raw` through `symbol_map`.
+    ///
+    /// With `strict_instrument_ids` a collision fails the load. Otherwise the
+    /// symbol that orders for the instrument are routed to is kept, or failing
+    /// that the first loaded, and the others are dropped with a warning.
+    fn resolve_instrument_id_collisions(
+        &self,
+        instruments: Vec<InstrumentMetadata>,
+    ) -> Result<Vec<InstrumentMetadata>, InstrumentProviderError> {
+        let mut resolved: Vec<InstrumentMetadata> = Vec::with_capacity(instruments.len());
+        let mut index_by_name = HashMap::with_capacity(instruments.len());
+        for metadata in instruments {
+            let name = self.symbol_mapper.mt5_to_nautilus(&metadata.symbol);
+            let Some(&index) = index_by_name.get(&name) else {
+                index_by_name.insert(name, resolved.len());
+                resolved.push(metadata);
+                continue;
+            };
+
+            let instrument_id = format!("{name}.{}", crate::common::consts::MT5_NAME);
+            let existing = &resolved[index].symbol;
+            if self.config.strict_instrument_ids {
+                return Err(InstrumentProviderError::ConfigError(format!(
+                    "MT5 symbols '{existing}' and '{}' both map to {instrument_id}; \
+                     adjust symbol_suffix or symbol_map",
+                    metadata.symbol
+                )));
+            }
+            let (kept, dropped) = if metadata.symbol == self.symbol_mapper.nautilus_to_mt5(&name) {
+                (metadata.symbol.clone(), existing.clone())
+            } else {
+                (existing.clone(), metadata.symbol.clone())
+            };
+            tracing::warn!("MT5 symbols '{kept}' and '{dropped}' both map to {instrument_id}; skipping '{dropped}'");
+            if kept == metadata.symbol {
+                resolved[index] = metadata;
+            }
+        }
+        Ok(resolved)
+    }
+
+    /// Fails if the terminal offers more than `max_symbols` symbols.
+    async fn check_symbols_total(&self, max_symbols: usize) -> Result<(), InstrumentProviderError> {
+        let response = self.http_client.symbols_total().await?;
+        let total = unwrap_result(response)?.as_u64().ok_or_else(|| {
+            InstrumentProviderError::ParseError("symbols_total: expected a count".to_string())
+        })?;
+        if total > max_symbols as u64 {
+            return Err(InstrumentProviderError::ConfigError(format!(
+                "Terminal offers {total} symbols, more than max_symbols {max_symbols}; \
+                 narrow symbol_group or raise max_symbols"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Lists the symbols in `group`, applying the market-data timeout and retrying
+    /// timeouts and retryable errors up to `market_data_retries` times.
+    ///
+    /// Only this request uses `market_data_timeout_secs`; everything else keeps
+    /// `http_timeout`.
+    async fn list_symbols(&self, group: Option<&str>) -> Result<Vec<Mt5Symbol>, InstrumentProviderError> {
+        let timeout_secs = self.config.market_data_timeout_secs;
+        let timeout = Duration::from_secs(timeout_secs);
+        let mut attempt = 0;
+        loop {
+            let listing = self.http_client.symbols_get_typed_with_timeout(group, Some(timeout_secs));
+            let result = tokio::time::timeout(timeout, listing)
+                .await
+                .unwrap_or_else(|_| {
+                    Err(Mt5HttpError::TimeoutError(format!("symbols_get exceeded {timeout:?}")))
+                });
+            match result {
+                Ok(symbols) => return Ok(symbols),
+                Err(e) if e.is_retryable() && attempt < self.config.market_data_retries => {
+                    attempt += 1;
+                    tracing::warn!(
+                        "Symbol listing failed, retrying ({attempt}/{}): {e}",
+                        self.config.market_data_retries
+                    );
+                }
+                Err(e @ Mt5HttpError::ParseError(_)) => {
+                    return Err(InstrumentProviderError::ParseError(e.to_string()))
+                }
+                Err(e) => return Err(InstrumentProviderError::ConnectionError(e.to_string())),
+            }
+        }
+    }
+
     /// Loads all instruments (legacy method for backward compatibility).
     ///
     /// # Returns
     /// A `Result` containing a unit type or an `InstrumentProviderError`
     pub async fn load_instruments(&self) -> Result<(), InstrumentProviderError> {
-        let instruments = self.discover_instruments_metadata().await?;
+        let instruments = self.discover_instruments_metadata(&|_| {}).await?;
         
         for instrument in instruments {
             tracing::info!("Loaded instrument: {}", instrument.symbol);
@@ -249,9 +511,108 @@ impl Mt5InstrumentProvider {
         Ok(())
     }
 
+    /// Returns the cached metadata for `symbol`, if it has been loaded.
+    pub async fn get_cached(&self, symbol: &str) -> Option<InstrumentMetadata> {
+        let cache = self.cache.read().await;
+        cache.iter().find(|instrument| instrument.symbol == symbol).cloned()
+    }
+
     // Remove the create_instrument method as it's not needed with the simplified approach
 }
 
+/// Returns the number of decimal places implied by an increment such as `volume_step`.
+fn precision_from_increment(increment: f64) -> u8 {
+    u8::try_from(step_decimals(increment)).unwrap_or(u8::MAX)
+}
+
+/// Converts instrument metadata into a Nautilus `InstrumentAny`.
+///
+/// The instrument's symbol is the Nautilus name `symbol_mapper` gives the MT5
+/// symbol. Only currency pairs are currently supported; other MT5 symbol types return
+/// `InstrumentProviderError::ParseError`.
+pub fn metadata_to_instrument(
+    metadata: &InstrumentMetadata,
+    symbol_mapper: &SymbolMapper,
+    ts_init: UnixNanos,
+) -> Result<InstrumentAny, InstrumentProviderError> {
+    let InstrumentType::CurrencyPair { base_currency, quote_currency } = &metadata.instrument_type else {
+        return Err(InstrumentProviderError::ParseError(format!(
+            "Unsupported instrument type for {}",
+            metadata.symbol
+        )));
+    };
+
+    let parse_currency = |code: &str| {
+        Currency::from_str(code).map_err(|e| InstrumentProviderError::ParseError(e.to_string()))
+    };
+    let to_parse_error = |e: anyhow::Error| InstrumentProviderError::ParseError(e.to_string());
+
+    let size_precision = precision_from_increment(metadata.volume_step);
+    let symbol = Symbol::new(symbol_mapper.mt5_to_nautilus(&metadata.symbol));
+    let instrument_id = InstrumentId::new(symbol, Venue::new(crate::common::consts::MT5_NAME));
+
+    let instrument = CurrencyPair::new(
+        instrument_id,
+        symbol,
+        parse_currency(base_currency)?,
+        parse_currency(quote_currency)?,
+        metadata.digits,
+        size_precision,
+        Price::from_f64(metadata.point_size, metadata.digits).map_err(to_parse_error)?,
+        Quantity::from_f64(metadata.volume_step, size_precision).map_err(to_parse_error)?,
+        None,
+        Some(Quantity::from_f64(metadata.volume_step, size_precision).map_err(to_parse_error)?),
+        Some(Quantity::from_f64(metadata.volume_max, size_precision).map_err(to_parse_error)?),
+        Some(Quantity::from_f64(metadata.volume_min, size_precision).map_err(to_parse_error)?),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        ts_init,
+        ts_init,
+    );
+
+    Ok(InstrumentAny::CurrencyPair(instrument))
+}
+
+/// Returns whether two versions of an instrument differ in any contract spec.
+fn specs_changed(old: &InstrumentAny, new: &InstrumentAny) -> bool {
+    old.price_precision() != new.price_precision()
+        || old.size_precision() != new.size_precision()
+        || old.price_increment() != new.price_increment()
+        || old.size_increment() != new.size_increment()
+        || old.multiplier() != new.multiplier()
+        || old.lot_size() != new.lot_size()
+        || old.max_quantity() != new.max_quantity()
+        || old.min_quantity() != new.min_quantity()
+        || old.margin_init() != new.margin_init()
+        || old.margin_maint() != new.margin_maint()
+        || old.maker_fee() != new.maker_fee()
+        || old.taker_fee() != new.taker_fee()
+}
+
+/// Converts metadata into instruments, skipping (and logging) unsupported symbols.
+fn metadata_to_instruments(
+    metadata: &[InstrumentMetadata],
+    symbol_mapper: &SymbolMapper,
+) -> Vec<InstrumentAny> {
+    let ts_init = nautilus_core::time::get_atomic_clock_realtime().get_time_ns();
+    metadata
+        .iter()
+        .filter_map(|m| match metadata_to_instrument(m, symbol_mapper, ts_init) {
+            Ok(instrument) => Some(instrument),
+            Err(e) => {
+                tracing::warn!("Skipping instrument {}: {}", m.symbol, e);
+                None
+            }
+        })
+        .collect()
+}
+
 #[cfg(feature = "python-bindings")]
 #[pymethods]
 impl Mt5InstrumentProvider {
@@ -266,7 +627,7 @@ impl Mt5InstrumentProvider {
         let provider = self.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let result = provider.load_all_async(None).await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             // Serialize to JSON string
             serde_json::to_string(&result)
                 .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
@@ -279,7 +640,7 @@ impl Mt5InstrumentProvider {
         let provider = self.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let result = provider.load_ids_async(instrument_ids, None).await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             serde_json::to_string(&result)
                 .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
         })
@@ -291,7 +652,7 @@ impl Mt5InstrumentProvider {
         let provider = self.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let result = provider.load_async(instrument_id, None).await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             serde_json::to_string(&result)
                 .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
         })
@@ -302,21 +663,401 @@ impl Mt5InstrumentProvider {
     pub fn py_discover_instruments<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
         let provider = self.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let result = provider.discover_instruments_metadata().await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+            let result = provider.discover_instruments_metadata(&|_| {}).await
+                .map_err(to_pyerr)?;
             serde_json::to_string(&result)
                 .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
         })
     }
 
+    /// Loads all instruments and returns them as Nautilus instrument objects.
+    #[pyo3(name = "load_all")]
+    pub fn py_load_all<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let provider = self.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let metadata = provider.load_all_async(None).await
+                .map_err(to_pyerr)?;
+            let instruments = metadata_to_instruments(&metadata, &provider.symbol_mapper);
+
+            Python::attach(|py| {
+                instruments
+                    .into_iter()
+                    .map(|instrument| instrument_any_to_pyobject(py, instrument))
+                    .collect::<PyResult<Vec<_>>>()
+            })
+        })
+    }
+
+    /// Loads the given instruments and returns them as Nautilus instrument objects.
+    #[pyo3(name = "load_ids")]
+    pub fn py_load_ids<'py>(&self, py: Python<'py>, instrument_ids: Vec<InstrumentId>) -> PyResult<Bound<'py, PyAny>> {
+        let provider = self.clone();
+        let symbols = instrument_ids
+            .iter()
+            .map(|id| self.symbol_mapper.nautilus_to_mt5(id.symbol.as_str()))
+            .collect();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let metadata = provider.load_ids_async(symbols, None).await
+                .map_err(to_pyerr)?;
+            let instruments = metadata_to_instruments(&metadata, &provider.symbol_mapper);
+
+            Python::attach(|py| {
+                instruments
+                    .into_iter()
+                    .map(|instrument| instrument_any_to_pyobject(py, instrument))
+                    .collect::<PyResult<Vec<_>>>()
+            })
+        })
+    }
+
+    /// Returns a previously loaded instrument, or `None` if it is not cached.
+    #[pyo3(name = "get")]
+    pub fn py_get<'py>(&self, py: Python<'py>, instrument_id: InstrumentId) -> PyResult<Bound<'py, PyAny>> {
+        let provider = self.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let symbol = provider.symbol_mapper.nautilus_to_mt5(instrument_id.symbol.as_str());
+            let Some(metadata) = provider.get_cached(&symbol).await else {
+                return Python::attach(|py| Ok(py.None()));
+            };
+            let ts_init = nautilus_core::time::get_atomic_clock_realtime().get_time_ns();
+            let instrument = metadata_to_instrument(&metadata, &provider.symbol_mapper, ts_init)
+                .map_err(to_pyerr)?;
+
+            Python::attach(|py| instrument_any_to_pyobject(py, instrument))
+        })
+    }
+
     /// Loads all instruments (returns empty string on success).
     #[pyo3(name = "load_instruments")]
     pub fn py_load_instruments<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
         let provider = self.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             provider.load_instruments().await
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+                .map_err(to_pyerr)?;
             Ok::<String, PyErr>("".to_string())
         })
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::testing::mock_bridge::MockMt5Bridge;
+
+    fn eurusd_metadata() -> InstrumentMetadata {
+        InstrumentMetadata {
+            symbol: "EURUSD".to_string(),
+            digits: 5,
+            point_size: 0.00001,
+            volume_min: 0.01,
+            volume_max: 100.0,
+            volume_step: 0.01,
+            contract_size: 100000.0,
+            instrument_type: InstrumentType::CurrencyPair {
+                base_currency: "EUR".to_string(),
+                quote_currency: "USD".to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_precision_from_increment() {
+        assert_eq!(precision_from_increment(0.01), 2);
+        assert_eq!(precision_from_increment(0.1), 1);
+        assert_eq!(precision_from_increment(1.0), 0);
+    }
+
+    #[test]
+    fn test_metadata_to_currency_pair() {
+        let instrument =
+            metadata_to_instrument(&eurusd_metadata(), &SymbolMapper::default(), UnixNanos::default())
+                .unwrap();
+
+        assert_eq!(instrument.id().to_string(), "EURUSD.MT5");
+        assert_eq!(instrument.price_precision(), 5);
+        assert_eq!(instrument.size_precision(), 2);
+    }
+
+    #[test]
+    fn test_metadata_to_instrument_uses_nautilus_symbol() {
+        let mut metadata = eurusd_metadata();
+        metadata.symbol = "EURUSD.m".to_string();
+        let mapper = SymbolMapper::new(&Default::default(), Some(".m")).unwrap();
+
+        let instrument = metadata_to_instrument(&metadata, &mapper, UnixNanos::default()).unwrap();
+
+        assert_eq!(instrument.id().to_string(), "EURUSD.MT5");
+    }
+
+    #[test]
+    fn test_conflicting_symbol_map_rejected_at_load() {
+        let config = Mt5InstrumentProviderConfig {
+            symbol_map: [("XAUUSD", "GOLD"), ("XAUUSD.spot", "GOLD")]
+                .into_iter()
+                .map(|(nautilus, mt5)| (nautilus.to_string(), mt5.to_string()))
+                .collect(),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            Mt5InstrumentProvider::new(config),
+            Err(InstrumentProviderError::ConfigError(_))
+        ));
+    }
+
+    fn symbols_response(volume_step: f64) -> serde_json::Value {
+        serde_json::json!([{
+            "symbol": "EURUSD",
+            "digits": 5,
+            "point_size": 0.00001,
+            "volume_min": 0.01,
+            "volume_max": 100.0,
+            "volume_step": volume_step,
+            "contract_size": 100000.0,
+            "type": "forex"
+        }])
+    }
+
+    #[tokio::test]
+    async fn test_changed_specs_on_reload_emit_update() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbols_get", symbols_response(0.01));
+        let (addr, handle) = bridge.start().await;
+        let provider = Mt5InstrumentProvider::new(Mt5InstrumentProviderConfig {
+            base_url: format!("http://{addr}"),
+            ..Default::default()
+        })
+        .unwrap();
+        let mut updates = provider.instrument_updates();
+        let instrument_id = InstrumentId::from("EURUSD.MT5");
+
+        // First load and an unchanged reload are not updates
+        provider.get_or_load(&instrument_id).await.unwrap();
+        provider.load_ids_async(vec!["EURUSD".to_string()], None).await.unwrap();
+        assert!(updates.try_recv().is_err());
+
+        bridge.respond("symbols_get", symbols_response(0.1));
+        provider.load_ids_async(vec!["EURUSD".to_string()], None).await.unwrap();
+
+        let update = updates.try_recv().unwrap();
+        assert_eq!(update.id(), instrument_id);
+        assert_eq!(update.size_increment(), Quantity::from("0.1"));
+        assert_eq!(
+            provider.get_or_load(&instrument_id).await.unwrap().size_increment(),
+            Quantity::from("0.1")
+        );
+        handle.abort();
+    }
+
+    fn synthetic_provider(addr: std::net::SocketAddr) -> Mt5InstrumentProvider {
+        Mt5InstrumentProvider::new(Mt5InstrumentProviderConfig {
+            base_url: format!("http://{addr}"),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_missing_digits_inferred_from_point() {
+        let bridge = MockMt5Bridge::new();
+        let mut symbols = symbols_response(0.01);
+        symbols[0].as_object_mut().unwrap().remove("digits");
+        symbols[0]["point_size"] = serde_json::json!(0.001);
+        bridge.respond("symbols_get", symbols);
+        let (addr, handle) = bridge.start().await;
+        let provider = synthetic_provider(addr);
+
+        let instrument = provider.get_or_load(&InstrumentId::from("EURUSD.MT5")).await.unwrap();
+
+        assert_eq!(instrument.price_precision(), 3);
+        assert_eq!(instrument.price_increment(), Price::from("0.001"));
+        assert!(bridge.requests_for("symbol_info_tick").is_empty());
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_missing_digits_and_point_inferred_from_tick() {
+        let bridge = MockMt5Bridge::new();
+        let mut symbols = symbols_response(0.01);
+        let row = symbols[0].as_object_mut().unwrap();
+        row.remove("digits");
+        row.remove("point_size");
+        bridge.respond("symbols_get", symbols);
+        bridge.respond("symbol_info_tick", serde_json::json!({"result": {"bid": 1.1041, "ask": 1.10412, "last": 0.0}}));
+        let (addr, handle) = bridge.start().await;
+        let provider = synthetic_provider(addr);
+
+        let instrument = provider.get_or_load(&InstrumentId::from("EURUSD.MT5")).await.unwrap();
+
+        assert_eq!(instrument.price_precision(), 5);
+        assert_eq!(instrument.price_increment(), Price::from("0.00001"));
+        assert_eq!(bridge.requests_for("symbol_info_tick"), vec![serde_json::json!(["EURUSD"])]);
+        handle.abort();
+    }
+
+    /// `EURUSD.pro` via `symbol_suffix` and `EURUSD.raw` via `symbol_map` both map to `EURUSD`.
+    async fn colliding_load(strict_instrument_ids: bool) -> Result<Vec<InstrumentMetadata>, InstrumentProviderError> {
+        let mut pro = symbols_response(0.01);
+        pro[0]["symbol"] = serde_json::json!("EURUSD.pro");
+        let mut raw = symbols_response(0.1);
+        raw[0]["symbol"] = serde_json::json!("EURUSD.raw");
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbols_get", serde_json::json!([pro[0], raw[0]]));
+        let (addr, handle) = bridge.start().await;
+        let provider = Mt5InstrumentProvider::new(Mt5InstrumentProviderConfig {
+            base_url: format!("http://{addr}"),
+            symbol_map: [("EURUSD".to_string(), "EURUSD.raw".to_string())].into_iter().collect(),
+            symbol_suffix: Some(".pro".to_string()),
+            strict_instrument_ids,
+            ..Default::default()
+        })
+        .unwrap();
+
+        let result = provider.load_all_async(None).await;
+        if result.is_ok() {
+            let instrument = provider.instrument(&InstrumentId::from("EURUSD.MT5")).unwrap();
+            assert_eq!(instrument.size_increment(), Quantity::from("0.1"));
+        }
+        handle.abort();
+        result
+    }
+
+    #[tokio::test]
+    async fn test_colliding_instrument_ids_keep_routed_symbol() {
+        let metadata = colliding_load(false).await.unwrap();
+
+        // Orders for EURUSD.MT5 go to EURUSD.raw, so it wins over the first-listed EURUSD.pro
+        let symbols: Vec<_> = metadata.iter().map(|metadata| metadata.symbol.as_str()).collect();
+        assert_eq!(symbols, vec!["EURUSD.raw"]);
+    }
+
+    #[tokio::test]
+    async fn test_colliding_instrument_ids_rejected_when_strict() {
+        let Err(InstrumentProviderError::ConfigError(message)) = colliding_load(true).await else {
+            panic!("expected a collision error");
+        };
+
+        assert!(message.contains("'EURUSD.pro' and 'EURUSD.raw' both map to EURUSD.MT5"));
+    }
+
+    fn symbol_rows(count: usize) -> serde_json::Value {
+        (0..count)
+            .map(|index| {
+                serde_json::json!({
+                    "symbol": format!("SYM{index:05}"),
+                    "digits": 5,
+                    "point_size": 0.00001,
+                    "volume_min": 0.01,
+                    "volume_max": 100.0,
+                    "volume_step": 0.01,
+                    "contract_size": 100000.0,
+                    "type": "forex"
+                })
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_large_symbol_set_loads_in_chunks_with_progress() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbols_total", serde_json::json!({"result": 12_000}));
+        bridge.respond("symbols_get", symbol_rows(12_000));
+        let (addr, handle) = bridge.start().await;
+        let provider = Mt5InstrumentProvider::new(Mt5InstrumentProviderConfig {
+            base_url: format!("http://{addr}"),
+            load_chunk_size: 5_000,
+            max_symbols: Some(20_000),
+            ..Default::default()
+        })
+        .unwrap();
+        let progress = std::sync::Mutex::new(Vec::new());
+
+        let metadata = provider
+            .load_all_with_progress(None, &|update| progress.lock().unwrap().push(update))
+            .await
+            .unwrap();
+
+        assert_eq!(metadata.len(), 12_000);
+        let milestones: Vec<_> = progress
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .map(|update| (update.loaded, update.total))
+            .collect();
+        assert_eq!(milestones, vec![(5_000, 12_000), (10_000, 12_000), (12_000, 12_000)]);
+        assert_eq!(bridge.requests_for("symbols_get").len(), 1);
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_max_symbols_guard_refuses_load() {
+        let bridge = MockMt5Bridge::new();
+        bridge.respond("symbols_total", serde_json::json!({"result": 12_000}));
+        bridge.respond("symbols_get", symbol_rows(1));
+        let (addr, handle) = bridge.start().await;
+        let provider = Mt5InstrumentProvider::new(Mt5InstrumentProviderConfig {
+            base_url: format!("http://{addr}"),
+            max_symbols: Some(10_000),
+            ..Default::default()
+        })
+        .unwrap();
+
+        let result = provider.load_all_async(None).await;
+
+        assert!(matches!(result, Err(InstrumentProviderError::ConfigError(_))), "{result:?}");
+        assert!(bridge.requests_for("symbols_get").is_empty());
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_failed_symbol_listing_is_retried() {
+        let bridge = MockMt5Bridge::new();
+        bridge.fail("symbols_get", 503, "terminal busy");
+        let (addr, handle) = bridge.start().await;
+        let provider = Mt5InstrumentProvider::new(Mt5InstrumentProviderConfig {
+            base_url: format!("http://{addr}"),
+            market_data_retries: 2,
+            ..Default::default()
+        })
+        .unwrap();
+
+        let result = provider.load_all_async(None).await;
+
+        assert!(matches!(result, Err(InstrumentProviderError::ConnectionError(_))), "{result:?}");
+        assert_eq!(bridge.requests_for("symbols_get").len(), 3);
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_symbol_listing_outlasts_http_timeout() {
+        let bridge = MockMt5Bridge::new();
+        bridge.delay("symbols_get", Duration::from_millis(1_500));
+        let (addr, handle) = bridge.start().await;
+        let provider = Mt5InstrumentProvider::new(Mt5InstrumentProviderConfig {
+            base_url: format!("http://{addr}"),
+            http_timeout: Some(1),
+            market_data_timeout_secs: 5,
+            market_data_retries: 0,
+            ..Default::default()
+        })
+        .unwrap();
+
+        let metadata = provider.load_all_async(None).await.unwrap();
+
+        assert!(metadata.is_empty());
+        assert_eq!(bridge.requests_for("symbols_get").len(), 1);
+        handle.abort();
+    }
+
+    #[test]
+    fn test_metadata_to_instrument_rejects_cfd() {
+        let mut metadata = eurusd_metadata();
+        metadata.symbol = "US30".to_string();
+        metadata.instrument_type = InstrumentType::Cfd { symbol: "US30".to_string() };
+
+        assert!(
+            metadata_to_instrument(&metadata, &SymbolMapper::default(), UnixNanos::default())
+                .is_err()
+        );
+    }
+}
@@ -24,13 +24,17 @@
 
 pub mod common;
 pub mod config;
-pub mod error;
 pub mod http;
+pub mod websocket;
 
 // MT5 client modules
+pub mod account_state;
+pub mod bar_cache;
 pub mod data_client;
 pub mod execution_client;
 pub mod instrument_provider;
+pub mod margin_cache;
+pub mod trade_transactions;
 
 #[cfg(feature = "python-bindings")]
 pub mod python;
@@ -40,5 +44,4 @@ pub use python::nautilus_mt5;
 
 pub use common::*;
 pub use config::*;
-pub use error::*;
 pub use http::Mt5HttpClient;
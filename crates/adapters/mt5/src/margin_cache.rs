@@ -0,0 +1,168 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Caching of `order_calc_margin` results.
+//!
+//! Strategies sizing many hypothetical orders ask for the margin of nearly
+//! identical orders over and over. Margin per lot barely moves intraday, so
+//! [`Mt5ExecutionClient::order_margin`](crate::execution_client::Mt5ExecutionClient::order_margin)
+//! keeps the per-lot margin of recent requests in a [`MarginCache`], keyed on
+//! the symbol, side and price rounded to a few significant digits.
+
+use std::{
+    collections::HashMap,
+    sync::{Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+/// Significant digits a price keeps in a [`MarginCacheKey`].
+const PRICE_SIGNIFICANT_DIGITS: i32 = 3;
+
+/// Identifies a cached per-lot margin.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MarginCacheKey {
+    pub symbol: String,
+    pub is_buy: bool,
+    /// Bits of the price rounded to [`PRICE_SIGNIFICANT_DIGITS`] significant digits.
+    price_bucket: u64,
+}
+
+impl MarginCacheKey {
+    pub fn new(symbol: &str, is_buy: bool, price: f64) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            is_buy,
+            price_bucket: round_significant(price).to_bits(),
+        }
+    }
+}
+
+/// Rounds `price` to [`PRICE_SIGNIFICANT_DIGITS`] significant digits.
+fn round_significant(price: f64) -> f64 {
+    if price == 0.0 || !price.is_finite() {
+        return price;
+    }
+    let magnitude = price.abs().log10().floor() as i32;
+    let scale = 10_f64.powi(PRICE_SIGNIFICANT_DIGITS - 1 - magnitude);
+    (price * scale).round() / scale
+}
+
+#[derive(Debug)]
+struct CachedMargin {
+    inserted: Instant,
+    /// Access order, which decides eviction.
+    last_used: u64,
+    per_lot: f64,
+}
+
+#[derive(Debug, Default)]
+struct MarginCacheState {
+    entries: HashMap<MarginCacheKey, CachedMargin>,
+    clock: u64,
+}
+
+/// Least-recently-used cache of margin per lot, bounded by entry count and age.
+#[derive(Debug)]
+pub struct MarginCache {
+    max_entries: usize,
+    ttl: Duration,
+    state: Mutex<MarginCacheState>,
+}
+
+impl MarginCache {
+    pub fn new(max_entries: usize, ttl: Duration) -> Self {
+        Self {
+            max_entries,
+            ttl,
+            state: Mutex::new(MarginCacheState::default()),
+        }
+    }
+
+    /// Returns the margin per lot cached under `key`, unless expired.
+    pub fn get(&self, key: &MarginCacheKey) -> Option<f64> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.clock += 1;
+        let clock = state.clock;
+        let entry = state.entries.get_mut(key)?;
+        if entry.inserted.elapsed() >= self.ttl {
+            state.entries.remove(key);
+            return None;
+        }
+        entry.last_used = clock;
+        Some(entry.per_lot)
+    }
+
+    /// Stores `per_lot` under `key`, evicting the least recently used entry when full.
+    pub fn insert(&self, key: MarginCacheKey, per_lot: f64) {
+        if self.max_entries == 0 {
+            return;
+        }
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.clock += 1;
+        let clock = state.clock;
+        while state.entries.len() >= self.max_entries && !state.entries.contains_key(&key) {
+            let Some(oldest) = state
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            state.entries.remove(&oldest);
+        }
+        let entry = CachedMargin {
+            inserted: Instant::now(),
+            last_used: clock,
+            per_lot,
+        };
+        state.entries.insert(key, entry);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nearby_prices_share_a_bucket() {
+        assert_eq!(
+            MarginCacheKey::new("EURUSD", true, 1.10412),
+            MarginCacheKey::new("EURUSD", true, 1.10385)
+        );
+        assert_ne!(
+            MarginCacheKey::new("EURUSD", true, 1.10412),
+            MarginCacheKey::new("EURUSD", false, 1.10412)
+        );
+        assert_ne!(
+            MarginCacheKey::new("US30", true, 37_730.2),
+            MarginCacheKey::new("US30", true, 38_150.0)
+        );
+    }
+
+    #[test]
+    fn test_least_recently_used_entry_evicted() {
+        let cache = MarginCache::new(2, Duration::from_secs(60));
+        let key = |price| MarginCacheKey::new("EURUSD", true, price);
+        cache.insert(key(1.0), 1.0);
+        cache.insert(key(2.0), 2.0);
+        cache.get(&key(1.0));
+        cache.insert(key(3.0), 3.0);
+
+        assert_eq!(cache.get(&key(1.0)), Some(1.0));
+        assert_eq!(cache.get(&key(2.0)), None);
+        assert_eq!(cache.get(&key(3.0)), Some(3.0));
+    }
+}
@@ -33,12 +33,29 @@ pub fn nautilus_mt5(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::config::instrument_provider::Mt5InstrumentProviderConfig>()?;
     m.add_class::<crate::config::data_client::Mt5DataClientConfig>()?;
     m.add_class::<crate::config::execution_client::Mt5ExecutionClientConfig>()?;
+    m.add_class::<crate::config::websocket::Mt5WebSocketConfig>()?;
 
     // Add common types
     m.add_class::<crate::common::credential::Mt5Credential>()?;
+    m.add_class::<crate::common::enums::VolumeRounding>()?;
+    m.add_class::<crate::common::enums::VolumeSource>()?;
+    m.add_class::<crate::common::enums::QuoteSizeSource>()?;
+    m.add_class::<crate::common::enums::ConnectionState>()?;
+    m.add_class::<crate::common::enums::BridgeAccessMode>()?;
+    m.add_class::<crate::common::enums::BackpressurePolicy>()?;
+    m.add_class::<crate::common::enums::TickKind>()?;
+    m.add_class::<crate::common::enums::Mt5DealEntry>()?;
+    m.add_class::<crate::common::enums::Mt5OrderType>()?;
+    m.add_class::<crate::common::enums::Mt5TradeMode>()?;
+    m.add_class::<crate::common::enums::Mt5ExecutionMode>()?;
+    m.add_class::<crate::common::enums::Mt5MarginMode>()?;
+    m.add_class::<crate::common::enums::Mt5StopOutMode>()?;
+    m.add_class::<crate::common::enums::Mt5Environment>()?;
+    m.add_class::<crate::common::enums::Mt5SwapMode>()?;
 
     // Add HTTP-related types
     m.add_class::<crate::http::client::Mt5HttpClient>()?;
+    m.add_class::<crate::http::blocking::Mt5HttpClientSync>()?;
     m.add_class::<crate::http::models::Mt5Symbol>()?;
 
     // Add the main client classes
@@ -0,0 +1,512 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Conversion of MT5 trade transactions into Nautilus order events.
+//!
+//! A bridge that streams `OnTradeTransaction` records lets the execution client
+//! react to acceptances, fills and cancels as they happen instead of polling
+//! `orders_get`. The handler here is transport-agnostic: it is fed one record
+//! at a time and correlates each back to the submitting order through the
+//! `comment` or `magic` sent with `order_send` (see [`order_correlation`]).
+
+use std::{
+    collections::HashMap,
+    sync::{Mutex, PoisonError},
+};
+
+use nautilus_core::{nanos::UnixNanos, UUID4};
+use nautilus_model::{
+    enums::{LiquiditySide, OrderType},
+    events::{OrderAccepted, OrderCanceled, OrderEventAny, OrderFilled},
+    identifiers::{
+        AccountId, ClientOrderId, InstrumentId, StrategyId, TradeId, TraderId, VenueOrderId,
+    },
+    types::{Currency, Price, Quantity},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common::{
+        enums::{Mt5DealType, Mt5OrderType},
+        parse::{de_f64_flexible, de_i64_flexible, de_u64_flexible},
+    },
+    execution_client::order_correlation,
+};
+
+/// `TRADE_TRANSACTION_ORDER_ADD`: a new order was placed.
+const TRADE_TRANSACTION_ORDER_ADD: i64 = 0;
+/// `TRADE_TRANSACTION_ORDER_DELETE`: an order left the active list.
+const TRADE_TRANSACTION_ORDER_DELETE: i64 = 2;
+/// `TRADE_TRANSACTION_HISTORY_ADD`: an order was moved to the history.
+const TRADE_TRANSACTION_HISTORY_ADD: i64 = 3;
+/// `TRADE_TRANSACTION_DEAL_ADD`: a deal was executed.
+const TRADE_TRANSACTION_DEAL_ADD: i64 = 6;
+/// `ORDER_STATE_CANCELED`.
+const ORDER_STATE_CANCELED: i64 = 2;
+
+/// A single `MqlTradeTransaction` record as streamed by the bridge.
+///
+/// `comment` and `magic` are not part of `MqlTradeTransaction` itself; the
+/// bridge copies them from the order so the record can be correlated.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Mt5TradeTransaction {
+    /// `TRADE_TRANSACTION_*` type.
+    #[serde(rename = "type")]
+    #[serde(deserialize_with = "de_i64_flexible")]
+    pub transaction_type: i64,
+    #[serde(default, deserialize_with = "de_u64_flexible")]
+    pub deal: u64,
+    #[serde(default, deserialize_with = "de_u64_flexible")]
+    pub order: u64,
+    #[serde(default)]
+    pub symbol: String,
+    #[serde(default)]
+    pub order_type: Mt5OrderType,
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub order_state: i64,
+    #[serde(default)]
+    pub deal_type: Mt5DealType,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub price: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub volume: f64,
+    #[serde(default, deserialize_with = "de_u64_flexible")]
+    pub position: u64,
+    #[serde(default)]
+    pub comment: String,
+    #[serde(default, deserialize_with = "de_u64_flexible")]
+    pub magic: u64,
+    /// Server time of the transaction in milliseconds.
+    #[serde(default, deserialize_with = "de_u64_flexible")]
+    pub time_msc: u64,
+}
+
+/// Identity of a submitted order, registered so its transactions can be attributed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingOrder {
+    pub trader_id: TraderId,
+    pub strategy_id: StrategyId,
+    pub instrument_id: InstrumentId,
+    pub client_order_id: ClientOrderId,
+    pub order_type: OrderType,
+    pub quote_currency: Currency,
+    pub price_precision: u8,
+    pub size_precision: u8,
+    /// `magic` sent with the order, used when the bridge omits the comment.
+    pub magic: Option<u64>,
+    /// Comment sent with the order, if the caller chose it rather than
+    /// [`order_correlation`] deriving it from the `ClientOrderId`.
+    pub comment: Option<String>,
+}
+
+/// Turns trade transactions into `OrderAccepted`, `OrderFilled` and `OrderCanceled` events.
+///
+/// Orders are registered at submit time under their `ClientOrderId`. The
+/// `ORDER_ADD` transaction, or [`Self::bind_ticket`] once `order_send` returns,
+/// then binds the MT5 order ticket to that order, and later deal and delete
+/// transactions are matched by ticket. An order is forgotten once it leaves the
+/// active list (`ORDER_DELETE` or `HISTORY_ADD`), or through [`Self::unregister`]
+/// if it never reached the terminal.
+#[derive(Debug)]
+pub struct Mt5TradeTransactionHandler {
+    account_id: AccountId,
+    pending_by_comment: Mutex<HashMap<String, PendingOrder>>,
+    pending_by_magic: Mutex<HashMap<u64, String>>,
+    orders_by_ticket: Mutex<HashMap<u64, PendingOrder>>,
+}
+
+impl Mt5TradeTransactionHandler {
+    pub fn new(account_id: AccountId) -> Self {
+        Self {
+            account_id,
+            pending_by_comment: Mutex::new(HashMap::new()),
+            pending_by_magic: Mutex::new(HashMap::new()),
+            orders_by_ticket: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the account id events are tagged with.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Registers a submitted order under its comment and `magic`, derived from its
+    /// `ClientOrderId` by [`order_correlation`] unless the order carries its own.
+    pub fn register(&self, order: PendingOrder) {
+        let (comment, magic) = correlation_keys(&order);
+        if let Some(magic) = magic {
+            self.pending_by_magic
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .insert(magic, comment.clone());
+        }
+        self.pending_by_comment
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(comment, order);
+    }
+
+    /// Binds `ticket` to the registered order `client_order_id`, e.g. from its
+    /// `order_send` response, in case its `ORDER_ADD` carries neither comment nor
+    /// `magic`. Does nothing if the order is not awaiting its ticket.
+    pub fn bind_ticket(&self, client_order_id: &ClientOrderId, ticket: u64) {
+        if let Some(order) = self.remove_pending_for(client_order_id) {
+            self.orders_by_ticket.lock().unwrap_or_else(PoisonError::into_inner).insert(ticket, order);
+        }
+    }
+
+    /// Forgets the registered order `client_order_id`, e.g. once `order_send` rejected it.
+    pub fn unregister(&self, client_order_id: &ClientOrderId) {
+        self.remove_pending_for(client_order_id);
+        self.orders_by_ticket
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|_, order| order.client_order_id != *client_order_id);
+    }
+
+    /// Returns the number of orders awaiting a ticket and of orders bound to one.
+    pub fn tracked_counts(&self) -> (usize, usize) {
+        (
+            self.pending_by_comment.lock().unwrap_or_else(PoisonError::into_inner).len(),
+            self.orders_by_ticket.lock().unwrap_or_else(PoisonError::into_inner).len(),
+        )
+    }
+
+    /// Converts `transaction` into an order event, if it concerns a registered order.
+    ///
+    /// A deal whose price or volume cannot be represented is logged and skipped.
+    pub fn handle(
+        &self,
+        transaction: &Mt5TradeTransaction,
+        ts_init: UnixNanos,
+    ) -> Option<OrderEventAny> {
+        let ts_event = if transaction.time_msc > 0 {
+            UnixNanos::from(transaction.time_msc * 1_000_000)
+        } else {
+            ts_init
+        };
+
+        match transaction.transaction_type {
+            TRADE_TRANSACTION_ORDER_ADD => {
+                let order = self.take_pending(transaction)?;
+                self.orders_by_ticket
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .insert(transaction.order, order.clone());
+                Some(OrderEventAny::Accepted(OrderAccepted::new(
+                    order.trader_id,
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    VenueOrderId::new(transaction.order.to_string()),
+                    self.account_id,
+                    UUID4::new(),
+                    ts_event,
+                    ts_init,
+                    false,
+                )))
+            }
+            TRADE_TRANSACTION_DEAL_ADD => {
+                let order = self
+                    .orders_by_ticket
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .get(&transaction.order)
+                    .cloned()?;
+                let order_side = transaction.deal_type.order_side()?;
+                let last_qty = Quantity::new_checked(transaction.volume, order.size_precision);
+                let last_px = Price::new_checked(transaction.price, order.price_precision);
+                let (last_qty, last_px) = match (last_qty, last_px) {
+                    (Ok(last_qty), Ok(last_px)) => (last_qty, last_px),
+                    (Err(e), _) | (_, Err(e)) => {
+                        tracing::warn!(
+                            "Skipping deal {} for {}: invalid volume {} or price {}: {e}",
+                            transaction.deal,
+                            order.client_order_id,
+                            transaction.volume,
+                            transaction.price,
+                        );
+                        return None;
+                    }
+                };
+                Some(OrderEventAny::Filled(OrderFilled::new(
+                    order.trader_id,
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    VenueOrderId::new(transaction.order.to_string()),
+                    self.account_id,
+                    TradeId::new(transaction.deal.to_string()),
+                    order_side,
+                    order.order_type,
+                    last_qty,
+                    last_px,
+                    order.quote_currency,
+                    LiquiditySide::Taker,
+                    UUID4::new(),
+                    ts_event,
+                    ts_init,
+                    false,
+                    None,
+                    None,
+                )))
+            }
+            TRADE_TRANSACTION_ORDER_DELETE | TRADE_TRANSACTION_HISTORY_ADD => {
+                // The order is done either way; only a cancel needs an event
+                let order = self
+                    .orders_by_ticket
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .remove(&transaction.order)?;
+                if transaction.order_state != ORDER_STATE_CANCELED {
+                    return None;
+                }
+                Some(OrderEventAny::Canceled(OrderCanceled::new(
+                    order.trader_id,
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    UUID4::new(),
+                    ts_event,
+                    ts_init,
+                    false,
+                    Some(VenueOrderId::new(transaction.order.to_string())),
+                    Some(self.account_id),
+                )))
+            }
+            _ => None,
+        }
+    }
+
+    fn take_pending(&self, transaction: &Mt5TradeTransaction) -> Option<PendingOrder> {
+        let comment = if transaction.comment.is_empty() {
+            self.pending_by_magic
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .get(&transaction.magic)?
+                .clone()
+        } else {
+            transaction.comment.clone()
+        };
+        self.remove_pending(&comment)
+    }
+
+    /// Removes the pending order `client_order_id`, whatever comment it is registered under.
+    fn remove_pending_for(&self, client_order_id: &ClientOrderId) -> Option<PendingOrder> {
+        let comment = self
+            .pending_by_comment
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .find(|(_, order)| order.client_order_id == *client_order_id)
+            .map(|(comment, _)| comment.clone())?;
+        self.remove_pending(&comment)
+    }
+
+    /// Removes the order registered under `comment` along with its `magic` entry.
+    fn remove_pending(&self, comment: &str) -> Option<PendingOrder> {
+        let order = self.pending_by_comment.lock().unwrap_or_else(PoisonError::into_inner).remove(comment)?;
+        if let (_, Some(magic)) = correlation_keys(&order) {
+            self.pending_by_magic.lock().unwrap_or_else(PoisonError::into_inner).remove(&magic);
+        }
+        Some(order)
+    }
+}
+
+/// Returns the comment and `magic` `order` is registered under.
+fn correlation_keys(order: &PendingOrder) -> (String, Option<u64>) {
+    if let Some(comment) = &order.comment {
+        return (comment.clone(), order.magic);
+    }
+    let correlation = order_correlation(&order.client_order_id);
+    (correlation.comment, order.magic.or(correlation.magic))
+}
+
+#[cfg(test)]
+mod tests {
+    use nautilus_model::enums::OrderSide;
+
+    use super::*;
+    use crate::common::testing::load_mt5_fixture_result;
+
+    fn pending_order(client_order_id: &str, magic: Option<u64>) -> PendingOrder {
+        PendingOrder {
+            trader_id: TraderId::from("TRADER-001"),
+            strategy_id: StrategyId::from("S-001"),
+            instrument_id: InstrumentId::from("EURUSD.MT5"),
+            client_order_id: ClientOrderId::from(client_order_id),
+            order_type: OrderType::Limit,
+            quote_currency: Currency::USD(),
+            price_precision: 5,
+            size_precision: 2,
+            magic,
+            comment: None,
+        }
+    }
+
+    fn handler_with_order(magic: Option<u64>) -> Mt5TradeTransactionHandler {
+        let handler = Mt5TradeTransactionHandler::new(AccountId::from("MT5-001"));
+        handler.register(pending_order("O-20240101-0001", magic));
+        handler
+    }
+
+    fn replay(handler: &Mt5TradeTransactionHandler, fixture: &str) -> Vec<OrderEventAny> {
+        let transactions: Vec<Mt5TradeTransaction> = load_mt5_fixture_result(fixture);
+        transactions
+            .iter()
+            .filter_map(|t| handler.handle(t, UnixNanos::from(1)))
+            .collect()
+    }
+
+    #[test]
+    fn test_fill_transactions_emit_accepted_then_filled() {
+        let handler = handler_with_order(None);
+        let events = replay(&handler, "trade_transactions_fill");
+
+        assert_eq!(events.len(), 2);
+        let OrderEventAny::Accepted(accepted) = &events[0] else {
+            panic!("expected OrderAccepted, was {:?}", events[0]);
+        };
+        assert_eq!(accepted.venue_order_id, VenueOrderId::from("60012345"));
+        let OrderEventAny::Filled(filled) = &events[1] else {
+            panic!("expected OrderFilled, was {:?}", events[1]);
+        };
+        assert_eq!(
+            filled.client_order_id,
+            ClientOrderId::from("O-20240101-0001")
+        );
+        assert_eq!(filled.trade_id, TradeId::from("40012345"));
+        assert_eq!(filled.order_side, OrderSide::Buy);
+        assert_eq!(filled.last_qty, Quantity::from("0.10"));
+        assert_eq!(filled.last_px, Price::from("1.10385"));
+        // The ORDER_DELETE that follows the fill retires the order
+        assert_eq!(handler.tracked_counts(), (0, 0));
+    }
+
+    #[test]
+    fn test_deal_with_invalid_values_is_skipped() {
+        let mut transactions: Vec<Mt5TradeTransaction> =
+            load_mt5_fixture_result("trade_transactions_fill");
+
+        for (volume, price) in [(-0.1, 1.10385), (0.1, f64::NAN)] {
+            let handler = handler_with_order(None);
+            transactions[1].volume = volume;
+            transactions[1].price = price;
+
+            let events: Vec<_> = transactions
+                .iter()
+                .filter_map(|t| handler.handle(t, UnixNanos::from(1)))
+                .collect();
+
+            assert_eq!(events.len(), 1);
+            assert!(matches!(events[0], OrderEventAny::Accepted(_)));
+        }
+    }
+
+    #[test]
+    fn test_bound_and_unregistered_orders_are_forgotten() {
+        let handler = handler_with_order(Some(777));
+        handler.register(pending_order("O-20240101-0002", None));
+
+        handler.bind_ticket(&ClientOrderId::from("O-20240101-0001"), 60012345);
+        assert_eq!(handler.tracked_counts(), (1, 1));
+        // Already bound, so its ORDER_ADD has nothing left to accept
+        let transactions: Vec<Mt5TradeTransaction> =
+            load_mt5_fixture_result("trade_transactions_fill");
+        assert!(handler.handle(&transactions[0], UnixNanos::from(1)).is_none());
+
+        handler.unregister(&ClientOrderId::from("O-20240101-0001"));
+        handler.unregister(&ClientOrderId::from("O-20240101-0002"));
+        assert_eq!(handler.tracked_counts(), (0, 0));
+        assert!(handler.pending_by_magic.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_cancel_transactions_correlated_by_magic() {
+        let handler = handler_with_order(Some(777));
+        let events = replay(&handler, "trade_transactions_cancel");
+
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], OrderEventAny::Accepted(_)));
+        let OrderEventAny::Canceled(canceled) = &events[1] else {
+            panic!("expected OrderCanceled, was {:?}", events[1]);
+        };
+        assert_eq!(
+            canceled.client_order_id,
+            ClientOrderId::from("O-20240101-0001")
+        );
+        assert_eq!(handler.tracked_counts(), (0, 0));
+        assert!(handler.pending_by_magic.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_caller_comment_correlates_and_unregisters() {
+        let mut transactions: Vec<Mt5TradeTransaction> =
+            load_mt5_fixture_result("trade_transactions_fill");
+        transactions[0].comment = "grid-leg-3".to_string();
+        let handler = Mt5TradeTransactionHandler::new(AccountId::from("MT5-001"));
+        let order = PendingOrder {
+            comment: Some("grid-leg-3".to_string()),
+            ..pending_order("O-20240101-0001", None)
+        };
+        handler.register(order.clone());
+
+        let events: Vec<_> = transactions
+            .iter()
+            .filter_map(|t| handler.handle(t, UnixNanos::from(1)))
+            .collect();
+        assert_eq!(events.len(), 2);
+
+        handler.register(order);
+        handler.unregister(&ClientOrderId::from("O-20240101-0001"));
+        assert_eq!(handler.tracked_counts(), (0, 0));
+    }
+
+    #[test]
+    fn test_long_client_order_id_correlated_by_hashed_comment_or_magic() {
+        const LONG_ID: &str = "O-20240101-000000-001-001-EXTENDED-1";
+        let correlation = order_correlation(&ClientOrderId::from(LONG_ID));
+        let mut transactions: Vec<Mt5TradeTransaction> =
+            load_mt5_fixture_result("trade_transactions_fill");
+
+        // The bridge relays the hashed comment, or drops it but keeps the magic
+        for (comment, magic) in [
+            (correlation.comment.clone(), 0),
+            (String::new(), correlation.magic.unwrap()),
+        ] {
+            let handler = Mt5TradeTransactionHandler::new(AccountId::from("MT5-001"));
+            handler.register(pending_order(LONG_ID, None));
+            transactions[0].comment = comment;
+            transactions[0].magic = magic;
+
+            let events: Vec<_> = transactions
+                .iter()
+                .filter_map(|t| handler.handle(t, UnixNanos::from(1)))
+                .collect();
+
+            assert_eq!(events.len(), 2);
+            assert!(events
+                .iter()
+                .all(|e| e.client_order_id() == ClientOrderId::from(LONG_ID)));
+        }
+    }
+
+    #[test]
+    fn test_unregistered_order_is_ignored() {
+        let handler = Mt5TradeTransactionHandler::new(AccountId::from("MT5-001"));
+
+        assert!(replay(&handler, "trade_transactions_fill").is_empty());
+    }
+}
@@ -0,0 +1,695 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! WebSocket client for the bridge event stream.
+//!
+//! `connect` hands the socket to a background task that classifies frames,
+//! parses ticks and bars into Nautilus data, and reconnects with every
+//! subscription restored when the connection drops. Its output is read through
+//! [`Mt5WebSocketClient::stream`], which buffers up to [`OUTPUT_CAPACITY`] items.
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+    time::{Duration, Instant},
+};
+
+use dashmap::DashMap;
+use futures_util::{SinkExt, Stream, StreamExt};
+use nautilus_core::{
+    time::{get_atomic_clock_realtime, AtomicTime},
+    UnixNanos,
+};
+use nautilus_model::{
+    data::{BarType, Data},
+    identifiers::InstrumentId,
+    instruments::{Instrument, InstrumentAny},
+};
+use nautilus_network::backoff::ExponentialBackoff;
+use tokio::{net::TcpStream, runtime::Handle, sync::mpsc, task::JoinHandle};
+use tokio_tungstenite::{
+    connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream,
+};
+
+use super::{
+    messages::{Mt5WsError, Mt5WsMessage, Mt5WsOutput},
+    parse::{classify_mt5_message, parse_ws_bar, parse_ws_quote},
+};
+use crate::{
+    common::{error::Mt5Error, parse::quote_non_finite_json, runtime::spawn_on, symbol::SymbolMapper},
+    config::websocket::Mt5WebSocketConfig,
+    data_client::{bar_type_seconds, mt5_timeframe},
+};
+
+type Mt5WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Output items buffered for [`Mt5WebSocketClient::stream`]; further items are
+/// dropped until it catches up, or for good if the stream is never taken.
+pub const OUTPUT_CAPACITY: usize = 10_000;
+
+/// Subscriptions shared with the handler task, which replays them on reconnect.
+#[derive(Debug, Default)]
+struct Subscriptions {
+    /// Subscribe frames sent so far, replayed after a reconnect.
+    frames: Mutex<BTreeSet<String>>,
+    /// Symbols whose ticks are emitted as quotes.
+    quotes: Mutex<HashMap<String, InstrumentId>>,
+    /// Bar types keyed by MT5 symbol and `TIMEFRAME_*` value.
+    bars: Mutex<HashMap<(String, i64), BarType>>,
+}
+
+/// Client for the bridge WebSocket event stream.
+#[derive(Debug)]
+pub struct Mt5WebSocketClient {
+    config: Mt5WebSocketConfig,
+    connected: Arc<AtomicBool>,
+    subscriptions: Arc<Subscriptions>,
+    instruments: Arc<DashMap<InstrumentId, InstrumentAny>>,
+    cmd_tx: Option<mpsc::UnboundedSender<Message>>,
+    out_tx: mpsc::Sender<Mt5WsOutput>,
+    out_rx: Option<mpsc::Receiver<Mt5WsOutput>>,
+    task: Option<JoinHandle<()>>,
+    /// Runtime the socket and its handler task run on; the current runtime if `None`.
+    runtime: Option<Handle>,
+    clock: &'static AtomicTime,
+    symbol_mapper: SymbolMapper,
+}
+
+impl Mt5WebSocketClient {
+    pub fn new(config: Mt5WebSocketConfig) -> Self {
+        let (out_tx, out_rx) = mpsc::channel(OUTPUT_CAPACITY);
+        Self {
+            config,
+            connected: Arc::new(AtomicBool::new(false)),
+            subscriptions: Arc::new(Subscriptions::default()),
+            instruments: Arc::new(DashMap::new()),
+            cmd_tx: None,
+            out_tx,
+            out_rx: Some(out_rx),
+            task: None,
+            runtime: None,
+            clock: get_atomic_clock_realtime(),
+            symbol_mapper: SymbolMapper::default(),
+        }
+    }
+
+    /// Opens the socket and runs its handler task on `runtime` from the next
+    /// `connect`, e.g. a dedicated runtime isolating the adapter's I/O.
+    pub fn set_runtime(&mut self, runtime: Handle) {
+        self.runtime = Some(runtime);
+    }
+
+    /// Replaces the clock stamping `ts_init` on parsed data from the next
+    /// `connect`, e.g. with a static clock so tests see fixed timestamps.
+    pub fn set_clock(&mut self, clock: &'static AtomicTime) {
+        self.clock = clock;
+    }
+
+    /// Replaces the mapper translating instrument symbols to MT5 names, e.g. the
+    /// data client's, so subscriptions reach broker-suffixed or renamed symbols.
+    pub fn set_symbol_mapper(&mut self, symbol_mapper: SymbolMapper) {
+        self.symbol_mapper = symbol_mapper;
+    }
+
+    /// Returns whether a connection is open.
+    pub fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::Relaxed)
+    }
+
+    /// Caches `instrument`, whose precisions are needed to parse its ticks and bars.
+    pub fn cache_instrument(&self, instrument: InstrumentAny) {
+        self.instruments.insert(instrument.id(), instrument);
+    }
+
+    /// Connects to the bridge, retrying failed attempts with exponential backoff.
+    ///
+    /// Gives up after `reconnect_max_attempts` attempts.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Mt5Error::ConfigError` if the backoff settings are invalid and
+    /// `Mt5Error::WebSocketError` once every attempt has failed.
+    pub async fn connect(&mut self) -> Result<(), Mt5Error> {
+        // The socket is bound to the runtime it is opened on, so open it where it will be polled
+        let socket = match &self.runtime {
+            Some(runtime) => {
+                let config = self.config.clone();
+                runtime
+                    .spawn(async move { connect_with_backoff(&config).await })
+                    .await
+                    .map_err(|e| Mt5Error::WebSocketError(e.to_string()))??
+            }
+            None => connect_with_backoff(&self.config).await?,
+        };
+        let (cmd_tx, cmd_rx) = mpsc::unbounded_channel();
+        self.connected.store(true, Ordering::Relaxed);
+        self.cmd_tx = Some(cmd_tx);
+        self.task = Some(spawn_on(self.runtime.as_ref(), run_handler(
+            self.config.clone(),
+            socket,
+            cmd_rx,
+            self.out_tx.clone(),
+            Arc::clone(&self.connected),
+            Arc::clone(&self.subscriptions),
+            Arc::clone(&self.instruments),
+            self.clock,
+        )));
+        Ok(())
+    }
+
+    /// Returns the stream of parsed bridge output.
+    ///
+    /// The stream can be taken once, before or after `connect`; subscribing
+    /// keeps working after it has been taken. Output arriving while
+    /// [`OUTPUT_CAPACITY`] items are unread is dropped with a warning.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the stream has already been taken.
+    pub fn stream(&mut self) -> impl Stream<Item = Mt5WsOutput> + Send + 'static {
+        let rx = self.out_rx.take().expect("stream() can only be called once");
+        futures_util::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|output| (output, rx))
+        })
+    }
+
+    /// Subscribes to ticks for `instrument_id`, emitted as quotes.
+    ///
+    /// The bridge is subscribed to the instrument's MT5 name from the symbol
+    /// mapper; ticks for that name are emitted under `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Mt5Error::WebSocketError` if the client is not connected.
+    pub fn subscribe_quotes(&self, instrument_id: InstrumentId) -> Result<(), Mt5Error> {
+        let cmd_tx = self.command_sender()?;
+        let symbol = self.symbol_mapper.nautilus_to_mt5(instrument_id.symbol.as_str());
+        self.subscriptions
+            .quotes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(symbol.clone(), instrument_id);
+        self.subscribe(cmd_tx, serde_json::json!({"channel": "ticks", "symbol": symbol}))
+    }
+
+    /// Subscribes to bar updates for `bar_type`, under its instrument's MT5 name
+    /// from the symbol mapper.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Mt5Error::WebSocketError` if the client is not connected.
+    pub fn subscribe_bars(&self, bar_type: BarType) -> Result<(), Mt5Error> {
+        let cmd_tx = self.command_sender()?;
+        let symbol = self.symbol_mapper.nautilus_to_mt5(bar_type.instrument_id().symbol.as_str());
+        let timeframe = i64::from(mt5_timeframe(bar_type_seconds(&bar_type)));
+        self.subscriptions
+            .bars
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert((symbol.clone(), timeframe), bar_type);
+        self.subscribe(cmd_tx, serde_json::json!({
+            "channel": "bars",
+            "symbol": symbol,
+            "timeframe": timeframe,
+        }))
+    }
+
+    fn command_sender(&self) -> Result<&mpsc::UnboundedSender<Message>, Mt5Error> {
+        self.cmd_tx
+            .as_ref()
+            .filter(|_| self.is_connected())
+            .ok_or_else(|| Mt5Error::WebSocketError("not connected".to_string()))
+    }
+
+    fn subscribe(
+        &self,
+        cmd_tx: &mpsc::UnboundedSender<Message>,
+        data: serde_json::Value,
+    ) -> Result<(), Mt5Error> {
+        let frame = serde_json::json!({"type": "subscribe", "data": data}).to_string();
+        self.subscriptions.frames.lock().unwrap_or_else(PoisonError::into_inner).insert(frame.clone());
+        cmd_tx
+            .send(Message::Text(frame.into()))
+            .map_err(|_| Mt5Error::WebSocketError("connection handler has stopped".to_string()))
+    }
+
+    /// Closes the connection, if open.
+    pub async fn disconnect(&mut self) {
+        if let Some(cmd_tx) = self.cmd_tx.take() {
+            let _ = cmd_tx.send(Message::Close(None));
+        }
+        if let Some(task) = self.task.take() {
+            let _ = task.await;
+        }
+        self.connected.store(false, Ordering::Relaxed);
+    }
+}
+
+async fn connect_with_backoff(config: &Mt5WebSocketConfig) -> Result<Mt5WsStream, Mt5Error> {
+    let mut backoff = ExponentialBackoff::new(
+        Duration::from_millis(config.reconnect_delay_initial_ms),
+        Duration::from_millis(config.reconnect_delay_max_ms),
+        config.reconnect_backoff_factor,
+        config.reconnect_jitter_ms,
+        false,
+    )
+    .map_err(|e| Mt5Error::ConfigError(e.to_string()))?;
+    let max_attempts = config.reconnect_max_attempts.max(1);
+    let started = Instant::now();
+
+    let mut attempt = 1;
+    loop {
+        match connect_async(config.url.as_str()).await {
+            Ok((stream, _)) => {
+                tracing::info!("Connected to {} (attempt {attempt})", config.url);
+                return Ok(stream);
+            }
+            Err(e) if attempt < max_attempts => {
+                let delay = backoff.next_duration();
+                tracing::warn!(
+                    "Connecting to {} failed (attempt {attempt}/{max_attempts}): {e}, retrying in {delay:?}",
+                    config.url
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            Err(e) => {
+                return Err(Mt5Error::WebSocketError(format!(
+                    "could not connect to {} after {attempt} attempts in {:?}: {e}; \
+                     check that the bridge host resolves and its port is reachable",
+                    config.url,
+                    started.elapsed()
+                )))
+            }
+        }
+    }
+}
+
+/// Owns the socket: forwards commands to it and parsed frames to `out_tx`.
+async fn run_handler(
+    config: Mt5WebSocketConfig,
+    mut socket: Mt5WsStream,
+    mut cmd_rx: mpsc::UnboundedReceiver<Message>,
+    out_tx: mpsc::Sender<Mt5WsOutput>,
+    connected: Arc<AtomicBool>,
+    subscriptions: Arc<Subscriptions>,
+    instruments: Arc<DashMap<InstrumentId, InstrumentAny>>,
+    clock: &'static AtomicTime,
+) {
+    loop {
+        tokio::select! {
+            command = cmd_rx.recv() => match command {
+                Some(Message::Close(frame)) | None => {
+                    let _ = socket.close(frame).await;
+                    break;
+                }
+                Some(message) => {
+                    if let Err(e) = socket.send(message).await {
+                        tracing::warn!("Sending to {} failed: {e}", config.url);
+                    }
+                }
+            },
+            frame = socket.next() => match frame {
+                Some(Ok(Message::Text(text))) => {
+                    match serde_json::from_str(&quote_non_finite_json(text.as_str())) {
+                        Ok(value) => {
+                            let output = classify_mt5_message(&value)
+                                .and_then(|message| {
+                                    let ts_init = clock.get_time_ns();
+                                    to_output(message, &config, &subscriptions, &instruments, ts_init)
+                                });
+                            if let Some(output) = output {
+                                emit(&out_tx, output);
+                            }
+                        }
+                        Err(e) => tracing::warn!("Invalid JSON frame from bridge: {e}"),
+                    }
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => {
+                    tracing::warn!("Connection to {} lost, reconnecting", config.url);
+                    match reconnect(&config, &subscriptions).await {
+                        Ok(new_socket) => {
+                            socket = new_socket;
+                            emit(&out_tx, Mt5WsOutput::Reconnected);
+                        }
+                        Err(e) => {
+                            emit(&out_tx, Mt5WsOutput::Error(Mt5WsError {
+                                code: 0,
+                                message: e.to_string(),
+                            }));
+                            break;
+                        }
+                    }
+                }
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+    connected.store(false, Ordering::Relaxed);
+}
+
+/// Queues `output` for the stream, dropping it if the buffer is full.
+fn emit(out_tx: &mpsc::Sender<Mt5WsOutput>, output: Mt5WsOutput) {
+    match out_tx.try_send(output) {
+        Ok(()) => {}
+        Err(mpsc::error::TrySendError::Full(_)) => {
+            tracing::warn!("Stream buffer full ({OUTPUT_CAPACITY} items unread), dropping output");
+        }
+        // The receiver was dropped along with the stream
+        Err(mpsc::error::TrySendError::Closed(_)) => {}
+    }
+}
+
+/// Reconnects and replays every subscribe frame sent so far.
+async fn reconnect(
+    config: &Mt5WebSocketConfig,
+    subscriptions: &Subscriptions,
+) -> Result<Mt5WsStream, Mt5Error> {
+    let mut socket = connect_with_backoff(config).await?;
+    let frames: Vec<String> = subscriptions.frames.lock().unwrap_or_else(PoisonError::into_inner).iter().cloned().collect();
+    for frame in frames {
+        socket
+            .send(Message::Text(frame.into()))
+            .await
+            .map_err(|e| Mt5Error::WebSocketError(e.to_string()))?;
+    }
+    Ok(socket)
+}
+
+/// Maps a classified frame to stream output; ticks and bars need a subscription
+/// and a cached instrument.
+///
+/// The subscription maps key by MT5 name, so looking a frame's symbol up in them
+/// maps it back to the subscribed instrument.
+fn to_output(
+    message: Mt5WsMessage,
+    config: &Mt5WebSocketConfig,
+    subscriptions: &Subscriptions,
+    instruments: &DashMap<InstrumentId, InstrumentAny>,
+    ts_init: UnixNanos,
+) -> Option<Mt5WsOutput> {
+    let precisions = |instrument_id: &InstrumentId| {
+        let precisions = instruments
+            .get(instrument_id)
+            .map(|instrument| (instrument.price_precision(), instrument.size_precision()));
+        if precisions.is_none() {
+            tracing::warn!("Dropping update for {instrument_id}, instrument not cached");
+        }
+        precisions
+    };
+
+    match message {
+        Mt5WsMessage::Tick(tick) => {
+            let instrument_id = *subscriptions.quotes.lock().unwrap_or_else(PoisonError::into_inner).get(&tick.symbol)?;
+            let (price_prec, size_prec) = precisions(&instrument_id)?;
+            let (volume_min, point) = instruments
+                .get(&instrument_id)
+                .map(|instrument| {
+                    let volume_min = instrument.min_quantity().map(|quantity| quantity.as_f64());
+                    (volume_min, instrument.price_increment().as_f64())
+                })?;
+            if let Some(points) = config.max_spread_points_for(&tick.symbol) {
+                // Tolerates float error when the spread sits exactly on the limit
+                if tick.ask - tick.bid > points * point + 1e-12 {
+                    tracing::debug!("Dropping tick for {} with excessive spread", tick.symbol);
+                    return None;
+                }
+            }
+            let quote = parse_ws_quote(
+                &tick,
+                instrument_id,
+                price_prec,
+                size_prec,
+                config.quote_size_source,
+                volume_min,
+                ts_init,
+            )?;
+            Some(Mt5WsOutput::Data(vec![Data::Quote(quote)]))
+        }
+        Mt5WsMessage::Bar(bar) => {
+            let bar_type = subscriptions
+                .bars
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .get(&(bar.symbol.clone(), bar.timeframe))
+                .cloned()?;
+            let (price_prec, size_prec) = precisions(&bar_type.instrument_id())?;
+            let tf_seconds = bar_type_seconds(&bar_type);
+            let bar = parse_ws_bar(&bar, bar_type, config.volume_source, tf_seconds, price_prec, size_prec, ts_init)?;
+            Some(Mt5WsOutput::Data(vec![Data::Bar(bar)]))
+        }
+        Mt5WsMessage::TradeTransaction(transaction) => {
+            Some(Mt5WsOutput::TradeTransaction(transaction))
+        }
+        Mt5WsMessage::AccountUpdate(account) => Some(Mt5WsOutput::AccountUpdate(account)),
+        Mt5WsMessage::Error(error) => Some(Mt5WsOutput::Error(error)),
+        Mt5WsMessage::Pong => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    };
+
+    use super::*;
+    use crate::{
+        common::{
+            parse::{InstrumentMetadata, InstrumentType},
+            symbol::SymbolMapper,
+            testing::load_ws_fixture,
+        },
+        instrument_provider::metadata_to_instrument,
+    };
+
+    fn eurusd() -> InstrumentAny {
+        eurusd_mapped(&SymbolMapper::default())
+    }
+
+    fn eurusd_mapped(symbol_mapper: &SymbolMapper) -> InstrumentAny {
+        let metadata = InstrumentMetadata {
+            symbol: "EURUSD".to_string(),
+            digits: 5,
+            point_size: 0.00001,
+            volume_min: 0.01,
+            volume_max: 100.0,
+            volume_step: 0.01,
+            contract_size: 100000.0,
+            instrument_type: InstrumentType::CurrencyPair {
+                base_currency: "EUR".to_string(),
+                quote_currency: "USD".to_string(),
+            },
+        };
+        metadata_to_instrument(&metadata, symbol_mapper, Default::default()).unwrap()
+    }
+
+    /// Serves one connection, answering every subscribe frame with the tick
+    /// fixture and passing the frame's `data` to the returned receiver.
+    async fn tick_server() -> (
+        std::net::SocketAddr,
+        mpsc::UnboundedReceiver<serde_json::Value>,
+        JoinHandle<()>,
+    ) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (frames_tx, frames_rx) = mpsc::unbounded_channel();
+        let server = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            while let Some(Ok(Message::Text(text))) = ws.next().await {
+                let frame: serde_json::Value = serde_json::from_str(text.as_str()).unwrap();
+                if frame["type"] == "subscribe" {
+                    let _ = frames_tx.send(frame["data"].clone());
+                    let tick = load_ws_fixture("tick").to_string();
+                    ws.send(Message::Text(tick.into())).await.unwrap();
+                }
+            }
+        });
+        (addr, frames_rx, server)
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_after_taking_stream_delivers_quotes() {
+        let (addr, _frames, server) = tick_server().await;
+        let mut client = Mt5WebSocketClient::new(Mt5WebSocketConfig {
+            url: format!("ws://{addr}/ws"),
+            ..Default::default()
+        });
+        client.connect().await.unwrap();
+        client.cache_instrument(eurusd());
+
+        let mut stream = std::pin::pin!(client.stream());
+        client.subscribe_quotes(InstrumentId::from("EURUSD.MT5")).unwrap();
+        let output = tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .unwrap()
+            .unwrap();
+
+        let Mt5WsOutput::Data(data) = output else {
+            panic!("expected Data, was {output:?}");
+        };
+        let [Data::Quote(quote)] = data.as_slice() else {
+            panic!("expected one quote, was {data:?}");
+        };
+        assert_eq!(quote.instrument_id, InstrumentId::from("EURUSD.MT5"));
+        assert_eq!(quote.bid_price.as_f64(), 1.10412);
+        client.disconnect().await;
+        assert!(!client.is_connected());
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn test_quotes_map_symbols_and_use_client_clock() {
+        let (addr, mut frames, server) = tick_server().await;
+        let symbol_mapper = SymbolMapper::new(
+            &HashMap::from([("FX-EURUSD".to_string(), "EURUSD".to_string())]),
+            None,
+        )
+        .unwrap();
+        let ts_init = UnixNanos::from(1_700_000_000_000_000_000);
+        let mut client = Mt5WebSocketClient::new(Mt5WebSocketConfig {
+            url: format!("ws://{addr}/ws"),
+            ..Default::default()
+        });
+        client.set_symbol_mapper(symbol_mapper.clone());
+        client.set_clock(Box::leak(Box::new(AtomicTime::new(false, ts_init))));
+        client.connect().await.unwrap();
+        client.cache_instrument(eurusd_mapped(&symbol_mapper));
+
+        let mut stream = std::pin::pin!(client.stream());
+        let instrument_id = InstrumentId::from("FX-EURUSD.MT5");
+        client.subscribe_quotes(instrument_id).unwrap();
+        let output = tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(frames.recv().await.unwrap()["symbol"], "EURUSD");
+        let Mt5WsOutput::Data(data) = output else {
+            panic!("expected Data, was {output:?}");
+        };
+        let [Data::Quote(quote)] = data.as_slice() else {
+            panic!("expected one quote, was {data:?}");
+        };
+        assert_eq!(quote.instrument_id, instrument_id);
+        assert_eq!(quote.ts_init, ts_init);
+        client.disconnect().await;
+        server.abort();
+    }
+
+    #[test]
+    fn test_ticks_wider_than_max_spread_are_dropped() {
+        let subscriptions = Subscriptions::default();
+        let instrument_id = InstrumentId::from("EURUSD.MT5");
+        subscriptions
+            .quotes
+            .lock()
+            .unwrap()
+            .insert("EURUSD".to_string(), instrument_id);
+        let instruments = DashMap::new();
+        instruments.insert(instrument_id, eurusd());
+        let tick = |config: &Mt5WebSocketConfig| {
+            let message = classify_mt5_message(&load_ws_fixture("tick")).unwrap();
+            to_output(message, config, &subscriptions, &instruments, UnixNanos::from(1))
+        };
+
+        // The fixture's spread is 7 points
+        let narrow = Mt5WebSocketConfig {
+            max_spread_points: Some(5.0),
+            ..Default::default()
+        };
+        let wide = Mt5WebSocketConfig {
+            max_spread_points_by_symbol: HashMap::from([("EURUSD".to_string(), 7.0)]),
+            ..narrow.clone()
+        };
+
+        assert!(tick(&narrow).is_none());
+        assert!(matches!(tick(&wide), Some(Mt5WsOutput::Data(_))));
+    }
+
+    #[test]
+    #[should_panic(expected = "stream() can only be called once")]
+    fn test_stream_can_only_be_taken_once() {
+        let mut client = Mt5WebSocketClient::new(Mt5WebSocketConfig::default());
+        let _stream = client.stream();
+        let _again = client.stream();
+    }
+
+    #[test]
+    fn test_subscribe_requires_connection() {
+        let client = Mt5WebSocketClient::new(Mt5WebSocketConfig::default());
+
+        assert!(client.subscribe_quotes(InstrumentId::from("EURUSD.MT5")).is_err());
+        assert!(client.subscriptions.quotes.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_connect_gives_up_after_max_attempts_with_backoff() {
+        // Accepts TCP connections and drops them, so every handshake fails
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let attempts = Arc::new(AtomicU32::new(0));
+        let counter = Arc::clone(&attempts);
+        let handle = tokio::spawn(async move {
+            while let Ok((socket, _)) = listener.accept().await {
+                counter.fetch_add(1, Ordering::SeqCst);
+                drop(socket);
+            }
+        });
+        let config = Mt5WebSocketConfig {
+            url: format!("ws://{addr}/ws"),
+            reconnect_delay_initial_ms: 20,
+            reconnect_delay_max_ms: 1_000,
+            reconnect_backoff_factor: 2.0,
+            reconnect_jitter_ms: 0,
+            reconnect_max_attempts: 3,
+            ..Default::default()
+        };
+        let mut client = Mt5WebSocketClient::new(config);
+
+        let started = Instant::now();
+        let err = client.connect().await.unwrap_err();
+
+        // Delays of 20ms then 40ms between the three attempts
+        assert!(started.elapsed() >= Duration::from_millis(60));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+        assert!(matches!(&err, Mt5Error::WebSocketError(message) if message.contains("after 3 attempts")));
+        assert!(!client.is_connected());
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_connect_to_unreachable_port_reports_network_hint() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+        let config = Mt5WebSocketConfig {
+            url: format!("ws://{addr}/ws"),
+            reconnect_delay_initial_ms: 1,
+            reconnect_jitter_ms: 0,
+            reconnect_max_attempts: 2,
+            ..Default::default()
+        };
+
+        let err = Mt5WebSocketClient::new(config).connect().await.unwrap_err();
+
+        assert!(err.to_string().contains("port is reachable"));
+    }
+}
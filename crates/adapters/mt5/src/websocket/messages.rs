@@ -0,0 +1,97 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Typed WebSocket frames streamed by the MT5 bridge.
+//!
+//! Every frame is an envelope `{"type": "<kind>", "data": {...}}`; `pong`
+//! frames carry no data.
+
+use nautilus_model::data::Data;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common::{
+        models::Mt5AccountInfo,
+        parse::{de_f64_flexible, de_i64_flexible, de_u64_flexible},
+    },
+    http::models::Mt5Rate,
+    trade_transactions::Mt5TradeTransaction,
+};
+
+/// A classified bridge frame.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mt5WsMessage {
+    Tick(Mt5WsTick),
+    Bar(Mt5WsBar),
+    TradeTransaction(Mt5TradeTransaction),
+    AccountUpdate(Mt5AccountInfo),
+    Error(Mt5WsError),
+    Pong,
+}
+
+/// An item yielded by [`crate::websocket::Mt5WebSocketClient::stream`].
+#[derive(Debug, Clone)]
+pub enum Mt5WsOutput {
+    /// Quotes and bars for subscribed, cached instruments.
+    Data(Vec<Data>),
+    TradeTransaction(Mt5TradeTransaction),
+    AccountUpdate(Mt5AccountInfo),
+    /// The connection dropped and was re-established with every subscription restored.
+    Reconnected,
+    Error(Mt5WsError),
+}
+
+/// A quote or trade tick (`MqlTick`) for one symbol.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Mt5WsTick {
+    pub symbol: String,
+    /// Tick time in Unix seconds.
+    #[serde(deserialize_with = "de_i64_flexible")]
+    pub time: i64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub bid: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub ask: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub last: f64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub volume: f64,
+    /// Tick time in milliseconds.
+    #[serde(default, deserialize_with = "de_u64_flexible")]
+    pub time_msc: u64,
+    /// `TICK_FLAG_*` bits saying which fields changed.
+    #[serde(default, deserialize_with = "de_u64_flexible")]
+    pub flags: u64,
+    #[serde(default, deserialize_with = "de_f64_flexible")]
+    pub volume_real: f64,
+}
+
+/// A bar update for one symbol and timeframe.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Mt5WsBar {
+    pub symbol: String,
+    /// MT5 `TIMEFRAME_*` value.
+    #[serde(deserialize_with = "de_i64_flexible")]
+    pub timeframe: i64,
+    pub rate: Mt5Rate,
+}
+
+/// An error reported by the bridge over the stream.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Mt5WsError {
+    #[serde(default, deserialize_with = "de_i64_flexible")]
+    pub code: i64,
+    pub message: String,
+}
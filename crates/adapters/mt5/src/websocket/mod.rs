@@ -0,0 +1,13 @@
+//! WebSocket support for bridges that stream MT5 events.
+//!
+//! This module contains the typed frames a streaming bridge sends and the pure
+//! classification of raw frames into them, so routing can be tested without a
+//! live connection, plus the client that reads the stream.
+
+pub mod client;
+pub mod messages;
+pub mod parse;
+
+pub use client::Mt5WebSocketClient;
+pub use messages::*;
+pub use parse::classify_mt5_message;
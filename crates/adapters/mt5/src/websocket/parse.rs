@@ -0,0 +1,276 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Classification of raw MT5 bridge WebSocket frames.
+
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    data::{Bar, BarType, QuoteTick},
+    identifiers::InstrumentId,
+    types::{Price, Quantity},
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use super::messages::{Mt5WsBar, Mt5WsError, Mt5WsMessage, Mt5WsTick};
+use crate::common::{
+    enums::{QuoteSizeSource, VolumeSource},
+    parse::quote_size,
+};
+
+/// Routes a raw bridge frame to its typed [`Mt5WsMessage`].
+///
+/// Frames are `{"type": ..., "data": ...}` envelopes. A bare `{"error": ...}`,
+/// as the HTTP endpoints return, is classified as an error too. Returns `None`
+/// for unknown kinds and for payloads that do not match their kind.
+pub fn classify_mt5_message(value: &Value) -> Option<Mt5WsMessage> {
+    let Some(kind) = value.get("type").and_then(Value::as_str) else {
+        let message = value.get("error")?;
+        return Some(Mt5WsMessage::Error(Mt5WsError {
+            code: 0,
+            message: message.as_str().map_or_else(|| message.to_string(), str::to_string),
+        }));
+    };
+
+    match kind {
+        "tick" => data(value).map(Mt5WsMessage::Tick),
+        "bar" => data(value).map(Mt5WsMessage::Bar),
+        "trade_transaction" => data(value).map(Mt5WsMessage::TradeTransaction),
+        "account" => data(value).map(Mt5WsMessage::AccountUpdate),
+        "error" => data(value).map(Mt5WsMessage::Error),
+        "pong" => Some(Mt5WsMessage::Pong),
+        _ => {
+            tracing::debug!("Unhandled MT5 bridge frame type '{kind}'");
+            None
+        }
+    }
+}
+
+/// Parses a streamed tick into a `QuoteTick`.
+///
+/// MT5 does not report top-of-book sizes, so both sides carry the size from
+/// `size_source` (see [`quote_size`]). Returns `None` for one-sided ticks and
+/// prices that cannot be represented.
+pub fn parse_ws_quote(
+    tick: &Mt5WsTick,
+    instrument_id: InstrumentId,
+    price_prec: u8,
+    size_prec: u8,
+    size_source: QuoteSizeSource,
+    volume_min: Option<f64>,
+    ts_init: UnixNanos,
+) -> Option<QuoteTick> {
+    if tick.bid <= 0.0 || tick.ask <= 0.0 {
+        return None;
+    }
+    let ts_event = if tick.time_msc > 0 {
+        UnixNanos::from(tick.time_msc * 1_000_000)
+    } else {
+        UnixNanos::from(u64::try_from(tick.time).ok()? * 1_000_000_000)
+    };
+    let tick_volume = if tick.volume_real > 0.0 { tick.volume_real } else { tick.volume };
+    let size = quote_size(size_source, tick_volume, volume_min, size_prec)?;
+
+    Some(QuoteTick::new(
+        instrument_id,
+        Price::from_f64(tick.bid, price_prec).ok()?,
+        Price::from_f64(tick.ask, price_prec).ok()?,
+        size,
+        size,
+        ts_event,
+        ts_init,
+    ))
+}
+
+/// Parses a streamed bar update into a `Bar` of `bar_type`, stamped at the bar close.
+pub fn parse_ws_bar(
+    bar: &Mt5WsBar,
+    bar_type: BarType,
+    volume_source: VolumeSource,
+    tf_seconds: u64,
+    price_prec: u8,
+    size_prec: u8,
+    ts_init: UnixNanos,
+) -> Option<Bar> {
+    let rate = &bar.rate;
+    let ts_open = u64::try_from(rate.time).ok()?;
+
+    Some(Bar::new(
+        bar_type,
+        Price::from_f64(rate.open, price_prec).ok()?,
+        Price::from_f64(rate.high, price_prec).ok()?,
+        Price::from_f64(rate.low, price_prec).ok()?,
+        Price::from_f64(rate.close, price_prec).ok()?,
+        Quantity::from_f64(rate.volume(volume_source), size_prec).ok()?,
+        UnixNanos::from((ts_open + tf_seconds) * 1_000_000_000),
+        ts_init,
+    ))
+}
+
+fn data<T: DeserializeOwned>(value: &Value) -> Option<T> {
+    let data = value.get("data")?;
+    serde_json::from_value(data.clone())
+        .map_err(|e| tracing::warn!("Malformed MT5 bridge frame {value}: {e}"))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::common::testing::{load_ws_fixture, ws_fixture_dir};
+
+    #[test]
+    fn test_classify_tick() {
+        let Some(Mt5WsMessage::Tick(tick)) = classify_mt5_message(&load_ws_fixture("tick")) else {
+            panic!("expected Tick");
+        };
+        assert_eq!(tick.symbol, "EURUSD");
+        assert_eq!(tick.bid, 1.10412);
+        assert_eq!(tick.ask, 1.10419);
+        assert_eq!(tick.time_msc, 1704103200123);
+    }
+
+    #[test]
+    fn test_classify_bar() {
+        let Some(Mt5WsMessage::Bar(bar)) = classify_mt5_message(&load_ws_fixture("bar")) else {
+            panic!("expected Bar");
+        };
+        assert_eq!(bar.symbol, "EURUSD");
+        assert_eq!(bar.timeframe, 1);
+        assert_eq!(bar.rate.time, 1704880800);
+        assert_eq!(bar.rate.close, 1.09726);
+    }
+
+    #[test]
+    fn test_classify_trade_transaction() {
+        let message = classify_mt5_message(&load_ws_fixture("trade_transaction"));
+
+        let Some(Mt5WsMessage::TradeTransaction(transaction)) = message else {
+            panic!("expected TradeTransaction, was {message:?}");
+        };
+        assert_eq!(transaction.transaction_type, 6);
+        assert_eq!(transaction.deal, 40012345);
+        assert_eq!(transaction.order, 60012345);
+    }
+
+    #[test]
+    fn test_classify_account_update() {
+        let message = classify_mt5_message(&load_ws_fixture("account"));
+
+        let Some(Mt5WsMessage::AccountUpdate(account)) = message else {
+            panic!("expected AccountUpdate, was {message:?}");
+        };
+        assert_eq!(account.login, 51234567);
+        assert_eq!(account.balance, 10100.0);
+    }
+
+    #[test]
+    fn test_classify_error() {
+        let message = classify_mt5_message(&load_ws_fixture("error"));
+
+        assert_eq!(
+            message,
+            Some(Mt5WsMessage::Error(Mt5WsError {
+                code: -10004,
+                message: "No IPC connection".to_string(),
+            }))
+        );
+        assert_eq!(
+            classify_mt5_message(&json!({"error": "terminal not initialized"})),
+            Some(Mt5WsMessage::Error(Mt5WsError {
+                code: 0,
+                message: "terminal not initialized".to_string(),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_ws_quote() {
+        let Some(Mt5WsMessage::Tick(tick)) = classify_mt5_message(&load_ws_fixture("tick")) else {
+            panic!("expected Tick");
+        };
+
+        let quote = parse_ws_quote(
+            &tick,
+            InstrumentId::from("EURUSD.MT5"),
+            5,
+            2,
+            QuoteSizeSource::MinVolume,
+            Some(0.01),
+            UnixNanos::from(1),
+        )
+        .unwrap();
+
+        assert_eq!(quote.bid_price, Price::from("1.10412"));
+        assert_eq!(quote.ask_price, Price::from("1.10419"));
+        assert_eq!(quote.bid_size, Quantity::from("0.01"));
+        assert_eq!(quote.ask_size, Quantity::from("0.01"));
+        assert_eq!(quote.ts_event, UnixNanos::from(1_704_103_200_123_000_000));
+    }
+
+    #[test]
+    fn test_parse_ws_bar_stamped_at_close() {
+        let Some(Mt5WsMessage::Bar(bar)) = classify_mt5_message(&load_ws_fixture("bar")) else {
+            panic!("expected Bar");
+        };
+        let bar_type = BarType::from("EURUSD.MT5-1-MINUTE-BID-EXTERNAL");
+
+        let bar = parse_ws_bar(&bar, bar_type, VolumeSource::default(), 60, 5, 0, UnixNanos::from(1)).unwrap();
+
+        assert_eq!(bar.close, Price::from("1.09726"));
+        assert_eq!(bar.ts_event, UnixNanos::from(1_704_880_860_000_000_000));
+    }
+
+    #[test]
+    fn test_parse_ws_bar_uses_volume_source() {
+        let Some(Mt5WsMessage::Bar(bar)) = classify_mt5_message(&load_ws_fixture("bar")) else {
+            panic!("expected Bar");
+        };
+        let bar_type = BarType::from("EURUSD.MT5-1-MINUTE-BID-EXTERNAL");
+        let parse = |source| parse_ws_bar(&bar, bar_type, source, 60, 5, 0, UnixNanos::from(1)).unwrap();
+
+        // The fixture has no real volume, so only `Real` departs from the tick volume
+        assert_eq!(parse(VolumeSource::Auto).volume, Quantity::from("85"));
+        assert_eq!(parse(VolumeSource::Tick).volume, Quantity::from("85"));
+        assert_eq!(parse(VolumeSource::Real).volume, Quantity::from("0"));
+    }
+
+    #[test]
+    fn test_classify_pong() {
+        assert_eq!(classify_mt5_message(&load_ws_fixture("pong")), Some(Mt5WsMessage::Pong));
+    }
+
+    #[test]
+    fn test_unknown_and_malformed_frames_are_unclassified() {
+        assert_eq!(classify_mt5_message(&json!({"type": "heartbeat"})), None);
+        assert_eq!(classify_mt5_message(&json!({"type": "tick", "data": {"bid": 1.1}})), None);
+        assert_eq!(classify_mt5_message(&json!({"type": "bar"})), None);
+        assert_eq!(classify_mt5_message(&json!([1, 2, 3])), None);
+    }
+
+    #[test]
+    fn test_every_ws_fixture_is_classified() {
+        for entry in std::fs::read_dir(ws_fixture_dir()).unwrap() {
+            let path = entry.unwrap().path();
+            let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+            assert!(
+                classify_mt5_message(&load_ws_fixture(&name)).is_some(),
+                "fixture {name} was not classified"
+            );
+        }
+    }
+}
@@ -1,5 +1,6 @@
 """Type stubs for nautilus_mt5 Rust bindings."""
 
+from datetime import datetime
 from typing import Optional
 
 __version__: str
@@ -11,6 +12,10 @@ class Mt5Config:
     base_url: str
     http_timeout: int
     proxy: str | None
+    max_retries: int
+    retry_delay_ms: int
+    retry_delay_max_ms: int
+    min_build: int | None
 
     def __init__(
         self,
@@ -32,6 +37,9 @@ class Mt5HttpClient:
     async def login(self, params: str) -> str: ...
     async def shutdown(self) -> str: ...
     async def version(self) -> str: ...
+    async def ping(self) -> float: ...
+    @property
+    def average_latency(self) -> float | None: ...
     async def last_error(self) -> str: ...
 
     # Account methods
@@ -48,6 +56,7 @@ class Mt5HttpClient:
     # Market data methods
     async def copy_rates_from(self, params: str) -> str: ...
     async def copy_rates_range(self, params: str) -> str: ...
+    async def copy_rates_from_pos(self, params: str) -> str: ...
     async def copy_ticks_from(self, params: str) -> str: ...
     async def copy_ticks_range(self, params: str) -> str: ...
 
@@ -72,6 +81,15 @@ class Mt5HttpClient:
     async def market_book_get(self, params: str) -> str: ...
     async def market_book_release(self, params: str) -> str: ...
 
+class Mt5HttpClientSync:
+    """Blocking HTTP client for synchronous scripts; must not be used inside a running event loop."""
+    def __init__(self, config: Mt5Config, base_url: str) -> None: ...
+    def account_info(self) -> str: ...
+    def terminal_version(self) -> str: ...
+    def positions_get(self) -> str: ...
+    def orders_get(self) -> str: ...
+    def symbol_select(self, symbol: str) -> None: ...
+
 class Mt5Symbol:
     """MT5 symbol information."""
 
@@ -111,22 +129,99 @@ class Mt5InstrumentProviderConfig:
         load_symbols: Optional[list[str]] = None,
     ) -> None: ...
 
+class ConnectionState:
+    """Lifecycle state of a client's session with the MT5 bridge."""
+
+    Disconnected: ConnectionState
+    Initializing: ConnectionState
+    LoggingIn: ConnectionState
+    Connected: ConnectionState
+    Reconnecting: ConnectionState
+    Faulted: ConnectionState
+
+class TickKind:
+    """Which ticks an MT5 tick copy returns (`COPY_TICKS_*`)."""
+
+    All: TickKind
+    Info: TickKind
+    Trade: TickKind
+
 class Mt5DataClient:
     """MT5 data client for NautilusTrader."""
 
     def __init__(self, config: Mt5DataClientConfig) -> None: ...
+    async def __aenter__(self) -> Mt5DataClient: ...
+    async def __aexit__(self, exc_type: object, exc_value: object, traceback: object) -> bool: ...
     async def connect(self) -> None: ...
+    async def reconnect(self) -> None: ...
+    async def disconnect(self) -> None: ...
+    @property
+    def connection_state(self) -> ConnectionState: ...
+    @property
+    def max_bars(self) -> int | None: ...
+    def set_http_client(self, http_client: Mt5HttpClient) -> None: ...
+    def set_instrument_provider(self, provider: Mt5InstrumentProvider) -> None: ...
     async def get_symbols(self) -> str: ...
     async def fetch_bars(
         self, symbol: str, timeframe: int, start_time: int, count: int
     ) -> list[dict]: ...
+    async def copy_rates_range(
+        self, symbol: str, timeframe: int, start: datetime, end: datetime
+    ) -> list[dict]: ...
+    async def request_bars(
+        self,
+        instrument_id: object,
+        bar_type: object,
+        instrument: object,
+        start: datetime | None = None,
+        end: datetime | None = None,
+        count: int | None = None,
+    ) -> list[object]: ...
+    async def request_bars_arrays(
+        self,
+        instrument_id: object,
+        bar_type: object,
+        instrument: object,
+        start: datetime | None = None,
+        end: datetime | None = None,
+        count: int | None = None,
+    ) -> dict[str, list[int] | list[float]]: ...
+    async def request_quote_ticks(
+        self,
+        instrument_id: object,
+        instrument: object,
+        start: datetime,
+        end: datetime,
+        kind: Optional[TickKind] = None,
+    ) -> list[object]: ...
+    async def request_trade_ticks(
+        self,
+        instrument_id: object,
+        instrument: object,
+        start: datetime,
+        end: datetime,
+        kind: Optional[TickKind] = None,
+    ) -> list[object]: ...
 
 class Mt5ExecutionClient:
     """MT5 execution client for NautilusTrader."""
 
-    ...
+    def __init__(self, config: Mt5ExecutionClientConfig) -> None: ...
+    async def __aenter__(self) -> Mt5ExecutionClient: ...
+    async def __aexit__(self, exc_type: object, exc_value: object, traceback: object) -> bool: ...
+    async def connect(self) -> None: ...
+    async def reconnect(self) -> list[object]: ...
+    async def disconnect(self) -> None: ...
+    @property
+    def connection_state(self) -> ConnectionState: ...
+    def set_http_client(self, http_client: Mt5HttpClient) -> None: ...
+    def set_instrument_provider(self, provider: Mt5InstrumentProvider) -> None: ...
+    def is_connected(self) -> bool: ...
 
 class Mt5InstrumentProvider:
     """MT5 instrument provider for NautilusTrader."""
 
-    ...
+    def __init__(self, config: Mt5InstrumentProviderConfig) -> None: ...
+    async def load_all(self) -> list[object]: ...
+    async def load_ids(self, instrument_ids: list[object]) -> list[object]: ...
+    async def get(self, instrument_id: object) -> object | None: ...
@@ -22,6 +22,9 @@ class Mt5HttpClient:
 
     # Basic Information
     async def version(self) -> str: ...
+    async def ping(self) -> float: ...
+    @property
+    def average_latency(self) -> float | None: ...
     async def terminal_info(self) -> str: ...
     async def account_info(self) -> str: ...
 
@@ -37,6 +40,7 @@ class Mt5HttpClient:
     async def copy_ticks_range(self, body: str) -> str: ...
     async def copy_rates_from(self, body: str) -> str: ...
     async def copy_rates_range(self, body: str) -> str: ...
+    async def copy_rates_from_pos(self, body: str) -> str: ...
 
     # Orders
     async def orders_total(self) -> str: ...
@@ -83,7 +87,7 @@ class Mt5Symbol:
     """MT5 symbol information."""
 
     name: str
-    digits: int
+    digits: int | None
     point: float
     volume_min: float
     volume_max: float
@@ -62,6 +62,37 @@ class Mt5OrderState(IntEnum):
     REQUEST_CANCEL = 9  # Order is being deleted
 
 
+class Mt5OrderTime(IntEnum):
+    """MT5 pending order lifetime (`type_time`)."""
+
+    GTC = 0  # Good till cancelled
+    DAY = 1  # Good till the end of the trading day
+    SPECIFIED = 2  # Good till `expiration`
+    SPECIFIED_DAY = 3  # Good till 23:59:59 of the `expiration` day
+
+
+class Mt5DealType(IntEnum):
+    """MT5 deal types (only BUY/SELL are trades)."""
+
+    BUY = 0  # Buy
+    SELL = 1  # Sell
+    BALANCE = 2  # Balance operation (deposit/withdrawal)
+    CREDIT = 3  # Credit
+    CHARGE = 4  # Additional charge
+    CORRECTION = 5  # Correction
+    BONUS = 6  # Bonus
+    COMMISSION = 7  # Additional commission
+
+
+class Mt5DealEntry(IntEnum):
+    """MT5 deal entry direction relative to its position."""
+
+    IN = 0  # Entry into the market (opens/increases a position)
+    OUT = 1  # Exit from the market (closes/reduces a position)
+    INOUT = 2  # Reversal (closes and opens the opposite side)
+    OUT_BY = 3  # Closed by an opposite position
+
+
 class Mt5RetCode(IntEnum):
     """MT5 trade server return codes."""
 
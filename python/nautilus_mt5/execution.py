@@ -26,7 +26,10 @@ from typing import TYPE_CHECKING
 
 from nautilus_mt5.common import MT5_VENUE
 from nautilus_mt5.constants import (
+    Mt5DealEntry,
+    Mt5DealType,
     Mt5OrderState,
+    Mt5OrderTime,
     Mt5OrderType,
     Mt5RetCode,
     Mt5TradeAction,
@@ -55,6 +58,7 @@ from nautilus_trader.model.identifiers import AccountId
 from nautilus_trader.model.identifiers import ClientId
 from nautilus_trader.model.identifiers import ClientOrderId
 from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import PositionId
 from nautilus_trader.model.identifiers import Symbol
 from nautilus_trader.model.identifiers import TradeId
 from nautilus_trader.model.identifiers import VenueOrderId
@@ -206,6 +210,19 @@ class Mt5ExecutionClient(LiveExecutionClient):
                 if order.trigger_price:
                     request["stoplimit"] = float(order.trigger_price)
 
+            # Pending order lifetime
+            if order.order_type != OrderType.MARKET:
+                if order.time_in_force == TimeInForce.GTD:
+                    if not order.expire_time_ns:
+                        self._generate_order_rejected(order, "GTD orders require an expire_time")
+                        return
+                    request["type_time"] = Mt5OrderTime.SPECIFIED
+                    request["expiration"] = order.expire_time_ns // 10**9
+                elif order.time_in_force == TimeInForce.DAY:
+                    request["type_time"] = Mt5OrderTime.DAY
+                else:
+                    request["type_time"] = Mt5OrderTime.GTC
+
             # Add SL/TP if configured via tags (Nautilus standard pattern for ad-hoc fields)
             tags = order.tags
             if tags:
@@ -432,6 +449,9 @@ class Mt5ExecutionClient(LiveExecutionClient):
             deals = response.get("result", []) if response else []
             if deals:
                 for deal in deals:
+                    # Balance/credit operations are not fills
+                    if deal.get("type") not in (Mt5DealType.BUY, Mt5DealType.SELL):
+                        continue
                     report = self._parse_fill_report(deal)
                     reports.append(report)
 
@@ -554,12 +574,19 @@ class Mt5ExecutionClient(LiveExecutionClient):
 
         order_side = OrderSide.BUY if mt5_deal.get("type", 0) == 0 else OrderSide.SELL
 
+        # IN and OUT deals of a round-trip share the position, which lets
+        # netting consumers pair them instead of counting both as exposure
+        entry = Mt5DealEntry(mt5_deal.get("entry", Mt5DealEntry.IN))
+        position_id = mt5_deal.get("position_id")
+        self._log.debug(f"Deal {ticket} entry={entry.name} position={position_id}")
+
         return FillReport(
             account_id=self._account_id or AccountId("MT5-UNKNOWN"),
             instrument_id=InstrumentId(Symbol(symbol), MT5_VENUE),
             client_order_id=ClientOrderId(str(order_id)),
             venue_order_id=VenueOrderId(str(order_id)),
             trade_id=TradeId(str(ticket)),
+            venue_position_id=PositionId(str(position_id)) if position_id else None,
             order_side=order_side,
             last_qty=Quantity.from_str(str(mt5_deal.get("volume", 0))),
             last_px=Price.from_str(str(mt5_deal.get("price", 0))),
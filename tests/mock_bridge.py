@@ -0,0 +1,57 @@
+"""
+Minimal in-process stand-in for the MT5 REST middleware.
+
+Serves canned `{"result": ...}` payloads per endpoint and records every request
+path and body, so the smoke tests can run without a live terminal.
+"""
+
+import json
+import threading
+from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
+
+
+class MockBridge:
+    def __init__(self, responses: dict | None = None):
+        self.responses = {
+            "/api/initialize": {"result": True},
+            "/api/login": {"result": True},
+            "/api/symbol_select": {"result": True},
+        }
+        self.responses.update(responses or {})
+        self.requests: list[tuple[str, object]] = []
+        bridge = self
+
+        class Handler(BaseHTTPRequestHandler):
+            def do_POST(self):
+                length = int(self.headers.get("Content-Length", 0))
+                raw = self.rfile.read(length) if length else b""
+                bridge.requests.append((self.path, json.loads(raw) if raw else None))
+                payload = bridge.responses.get(self.path, {"error": f"no mock for {self.path}"})
+                data = json.dumps(payload).encode()
+                self.send_response(200)
+                self.send_header("Content-Type", "application/json")
+                self.send_header("Content-Length", str(len(data)))
+                self.end_headers()
+                self.wfile.write(data)
+
+            def log_message(self, *args):
+                pass
+
+        self._server = ThreadingHTTPServer(("127.0.0.1", 0), Handler)
+        self._thread = threading.Thread(target=self._server.serve_forever, daemon=True)
+
+    @property
+    def base_url(self) -> str:
+        host, port = self._server.server_address[:2]
+        return f"http://{host}:{port}"
+
+    def paths(self) -> list[str]:
+        return [path for path, _ in self.requests]
+
+    def __enter__(self) -> "MockBridge":
+        self._thread.start()
+        return self
+
+    def __exit__(self, *exc) -> None:
+        self._server.shutdown()
+        self._server.server_close()
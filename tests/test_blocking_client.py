@@ -0,0 +1,76 @@
+#!/usr/bin/env python3
+"""
+MT5 Adapter - Blocking HTTP Client Smoke Test
+=============================================
+
+Requires the extension built with `--features python-bindings`.
+Verifies that `Mt5HttpClientSync` can be driven from plain synchronous code,
+without an event loop, against a local mock bridge.
+
+Usage:
+    python test_blocking_client.py
+"""
+
+import json
+import sys
+from pathlib import Path
+
+sys.path.insert(0, str(Path(__file__).parent.parent))
+sys.path.insert(0, str(Path(__file__).parent))
+
+from mock_bridge import MockBridge
+from nautilus_mt5.bindings import _nautilus_mt5 as ext
+
+ACCOUNT_INFO = {
+    "result": {
+        "login": 51234567,
+        "trade_mode": 0,
+        "leverage": 100,
+        "margin_mode": 2,
+        "balance": 10000.0,
+        "profit": 0.0,
+        "equity": 10000.0,
+        "margin": 0.0,
+        "margin_free": 10000.0,
+        "margin_level": 0.0,
+        "name": "Demo Account",
+        "server": "MetaQuotes-Demo",
+        "currency": "USD",
+    }
+}
+
+
+def main():
+    responses = {
+        "/api/account_info": ACCOUNT_INFO,
+        "/api/version": {"result": [500, 4755, "13 Feb 2025"]},
+        "/api/orders_get": {"result": None},
+    }
+    with MockBridge(responses) as bridge:
+        config = ext.Mt5Config(base_url=bridge.base_url)
+        client = ext.Mt5HttpClientSync(config, bridge.base_url)
+
+        print("\n[1/3] account_info...")
+        account = json.loads(client.account_info())
+        assert account["currency"] == "USD", account
+        assert account["login"] == 51234567, account
+
+        print("[2/3] terminal_version and orders_get...")
+        assert json.loads(client.terminal_version())["build"] == 4755
+        assert json.loads(client.orders_get()) == []
+
+        print("[3/3] symbol_select...")
+        client.symbol_select("EURUSD")
+        assert bridge.requests[-1] == ("/api/symbol_select", ["EURUSD", True]), bridge.requests[-1]
+
+        try:
+            client.positions_get()
+        except RuntimeError:
+            print("   ✅ Bridge errors raise instead of returning")
+        else:
+            raise AssertionError("a missing mock must surface as an error")
+    print("\nTEST COMPLETE")
+
+
+if __name__ == "__main__":
+    main()
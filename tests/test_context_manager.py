@@ -0,0 +1,94 @@
+#!/usr/bin/env python3
+"""
+MT5 Adapter - Async Context Manager Smoke Test
+==============================================
+
+Requires the extension built with `--features python-bindings`.
+Verifies that `async with` connects on enter and disconnects on exit
+for both the data and execution clients, against a local mock bridge.
+
+Usage:
+    python test_context_manager.py
+"""
+
+import asyncio
+import sys
+from pathlib import Path
+
+sys.path.insert(0, str(Path(__file__).parent.parent))
+sys.path.insert(0, str(Path(__file__).parent))
+
+from mock_bridge import MockBridge
+from nautilus_mt5.bindings import _nautilus_mt5 as ext
+
+ACCOUNT_INFO = {
+    "result": {
+        "login": 51234567,
+        "trade_mode": 0,
+        "leverage": 100,
+        "margin_mode": 2,
+        "balance": 10000.0,
+        "profit": 0.0,
+        "equity": 10000.0,
+        "margin": 0.0,
+        "margin_free": 10000.0,
+        "margin_level": 0.0,
+        "name": "Demo Account",
+        "server": "MetaQuotes-Demo",
+        "currency": "USD",
+    }
+}
+
+
+async def test_data_client(bridge: MockBridge):
+    print("\n[1/2] Testing Mt5DataClient context manager...")
+    config = ext.Mt5DataClientConfig(mt5_base_url=bridge.base_url)
+    client = ext.Mt5DataClient(config)
+
+    async with client as entered:
+        assert entered is client, "__aenter__ must return the client"
+        assert "/api/login" in bridge.paths(), "__aenter__ must call connect"
+        assert client.connection_state == ext.ConnectionState.Connected
+    assert client.connection_state == ext.ConnectionState.Disconnected, "__aexit__ must disconnect"
+
+    try:
+        async with client:
+            raise ValueError("boom")
+    except ValueError:
+        print("   ✅ Exceptions propagate out of the block")
+    else:
+        raise AssertionError("__aexit__ must not swallow exceptions")
+    assert (
+        client.connection_state == ext.ConnectionState.Disconnected
+    ), "__aexit__ must disconnect when the block raises"
+
+
+async def test_execution_client(bridge: MockBridge):
+    print("\n[2/2] Testing Mt5ExecutionClient context manager...")
+    bridge.requests.clear()
+    bridge.responses["/api/account_info"] = ACCOUNT_INFO
+    config = ext.Mt5ExecutionClientConfig(mt5_base_url=bridge.base_url)
+    client = ext.Mt5ExecutionClient(config)
+
+    async with client as entered:
+        assert entered is client, "__aenter__ must return the client"
+        assert bridge.paths() == [
+            "/api/initialize",
+            "/api/login",
+            "/api/account_info",
+        ], "__aenter__ must call connect, which checks the account environment"
+        assert client.connection_state == ext.ConnectionState.Connected
+    assert client.connection_state == ext.ConnectionState.Disconnected, "__aexit__ must disconnect"
+    assert not client.is_connected()
+    print("   ✅ Connected on enter, disconnected on exit")
+
+
+async def main():
+    with MockBridge() as bridge:
+        await test_data_client(bridge)
+        await test_execution_client(bridge)
+    print("\nTEST COMPLETE")
+
+
+if __name__ == "__main__":
+    asyncio.run(main())
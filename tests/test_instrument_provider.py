@@ -0,0 +1,64 @@
+#!/usr/bin/env python3
+"""
+MT5 Adapter - Instrument Provider Smoke Test
+============================================
+
+Requires the extension built with `--features python-bindings`.
+Loads instruments from a local mock bridge through the Rust provider and
+checks the returned Nautilus instruments.
+
+Usage:
+    python test_instrument_provider.py
+"""
+
+import asyncio
+import sys
+from pathlib import Path
+
+sys.path.insert(0, str(Path(__file__).parent.parent))
+sys.path.insert(0, str(Path(__file__).parent))
+
+from mock_bridge import MockBridge
+from nautilus_mt5.bindings import _nautilus_mt5 as ext
+from nautilus_trader.core.nautilus_pyo3 import InstrumentId
+
+SYMBOLS = [
+    {
+        "symbol": "EURUSD",
+        "digits": 5,
+        "point_size": 0.00001,
+        "volume_min": 0.01,
+        "volume_max": 100.0,
+        "volume_step": 0.01,
+        "contract_size": 100000.0,
+        "margin_initial": None,
+        "margin_maintenance": None,
+        "type": "forex",
+    },
+]
+
+
+async def main():
+    instrument_id = InstrumentId.from_str("EURUSD.MT5")
+
+    with MockBridge({"/api/symbols_get": SYMBOLS}) as bridge:
+        config = ext.Mt5InstrumentProviderConfig(base_url=bridge.base_url)
+        provider = ext.Mt5InstrumentProvider(config)
+
+        print("\n[1/2] Testing load_all...")
+        instruments = await provider.load_all()
+        assert len(instruments) == 1
+        print(f"   ✅ Loaded {instruments[0].id}")
+
+        print("\n[2/2] Testing get...")
+        instrument = await provider.get(instrument_id)
+        assert instrument is not None
+        assert instrument.price_precision == 5, instrument.price_precision
+        assert instrument.size_precision == 2, instrument.size_precision
+        print(f"   ✅ price_precision={instrument.price_precision}")
+
+    print("\nTEST COMPLETE")
+
+
+if __name__ == "__main__":
+    asyncio.run(main())
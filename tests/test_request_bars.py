@@ -0,0 +1,62 @@
+#!/usr/bin/env python3
+"""
+MT5 Adapter - Rust request_bars Smoke Test
+==========================================
+
+Requires the extension built with `--features python-bindings`.
+Verifies the Rust `request_bars` path returns Nautilus `Bar` objects and
+rejects inverted time ranges, against a local mock bridge.
+
+Usage:
+    python test_request_bars.py
+"""
+
+import asyncio
+import sys
+from datetime import datetime, timedelta, timezone
+from pathlib import Path
+from types import SimpleNamespace
+
+sys.path.insert(0, str(Path(__file__).parent.parent))
+sys.path.insert(0, str(Path(__file__).parent))
+
+from mock_bridge import MockBridge
+from nautilus_mt5.bindings import _nautilus_mt5 as ext
+from nautilus_trader.core.nautilus_pyo3 import BarType, InstrumentId
+
+# [time, open, high, low, close, tick_volume, spread, real_volume]
+RATES = [
+    [1704067200, 1.10001, 1.10010, 1.09990, 1.10005, 120, 1, 0],
+    [1704067260, 1.10005, 1.10020, 1.10000, 1.10015, 98, 1, 0],
+]
+
+
+async def main():
+    instrument_id = InstrumentId.from_str("EURUSD.MT5")
+    bar_type = BarType.from_str("EURUSD.MT5-1-MINUTE-LAST-EXTERNAL")
+    instrument = SimpleNamespace(price_precision=5, size_precision=0)
+    start = datetime(2024, 1, 1, tzinfo=timezone.utc)
+    end = start + timedelta(hours=1)
+
+    with MockBridge({"/api/copy_rates_range": {"result": RATES}}) as bridge:
+        client = ext.Mt5DataClient(ext.Mt5DataClientConfig(mt5_base_url=bridge.base_url))
+
+        print("\n[1/2] Testing range request...")
+        bars = await client.request_bars(instrument_id, bar_type, instrument, start, end)
+        assert len(bars) == 2
+        assert hasattr(bars[0], "close")
+        print(f"   ✅ {bars[0]}")
+
+        print("\n[2/2] Testing inverted range...")
+        try:
+            await client.request_bars(instrument_id, bar_type, instrument, end, start)
+        except RuntimeError as e:
+            print(f"   ✅ Rejected: {e}")
+        else:
+            raise AssertionError("start >= end must raise")
+
+    print("\nTEST COMPLETE")
+
+
+if __name__ == "__main__":
+    asyncio.run(main())
@@ -0,0 +1,69 @@
+#!/usr/bin/env python3
+"""
+MT5 Adapter - Columnar request_bars Smoke Test
+==============================================
+
+Requires the extension built with `--features python-bindings`.
+Verifies `request_bars_arrays` returns equal-length columns whose values
+match the `Bar` objects from `request_bars`, against a local mock bridge.
+
+Usage:
+    python test_request_bars_arrays.py
+"""
+
+import asyncio
+import sys
+from datetime import datetime, timedelta, timezone
+from pathlib import Path
+from types import SimpleNamespace
+
+sys.path.insert(0, str(Path(__file__).parent.parent))
+sys.path.insert(0, str(Path(__file__).parent))
+
+from mock_bridge import MockBridge
+from nautilus_mt5.bindings import _nautilus_mt5 as ext
+from nautilus_trader.core.nautilus_pyo3 import BarType, InstrumentId
+
+# [time, open, high, low, close, tick_volume, spread, real_volume]
+RATES = [
+    [1704067200, 1.10001, 1.10010, 1.09990, 1.10005, 120, 1, 0],
+    [1704067260, 1.10005, 1.10020, 1.10000, 1.10015, 98, 1, 0],
+    [1704067320, 1.10015, 1.10025, 1.10002, 1.10008, 75, 1, 0],
+]
+COLUMNS = ["ts_event", "open", "high", "low", "close", "volume"]
+
+
+async def main():
+    instrument_id = InstrumentId.from_str("EURUSD.MT5")
+    bar_type = BarType.from_str("EURUSD.MT5-1-MINUTE-LAST-EXTERNAL")
+    instrument = SimpleNamespace(price_precision=5, size_precision=0)
+    start = datetime(2024, 1, 1, tzinfo=timezone.utc)
+    end = start + timedelta(hours=1)
+
+    with MockBridge({"/api/copy_rates_range": {"result": RATES}}) as bridge:
+        client = ext.Mt5DataClient(ext.Mt5DataClientConfig(mt5_base_url=bridge.base_url))
+
+        print("\n[1/2] Testing column lengths...")
+        arrays = await client.request_bars_arrays(instrument_id, bar_type, instrument, start, end)
+        assert sorted(arrays) == sorted(COLUMNS), sorted(arrays)
+        assert all(len(arrays[column]) == len(RATES) for column in COLUMNS)
+        print(f"   ✅ {len(RATES)} rows in {len(COLUMNS)} columns")
+
+        print("\n[2/2] Testing values round-trip...")
+        bars = await client.request_bars(instrument_id, bar_type, instrument, start, end)
+        for i, bar in enumerate(bars):
+            assert arrays["ts_event"][i] == bar.ts_event
+            assert arrays["open"][i] == float(bar.open)
+            assert arrays["high"][i] == float(bar.high)
+            assert arrays["low"][i] == float(bar.low)
+            assert arrays["close"][i] == float(bar.close)
+            assert arrays["volume"][i] == float(bar.volume)
+        assert arrays["close"] == [rate[4] for rate in RATES]
+        assert arrays["ts_event"][0] == (RATES[0][0] + 60) * 1_000_000_000
+        print("   ✅ Columns match Bar objects")
+
+    print("\nTEST COMPLETE")
+
+
+if __name__ == "__main__":
+    asyncio.run(main())
@@ -0,0 +1,90 @@
+#!/usr/bin/env python3
+"""
+MT5 Adapter - Server Time Range Smoke Test
+==========================================
+
+Requires the extension built with `--features python-bindings`.
+Verifies `copy_rates_range`/`request_bars` convert timezone-aware datetimes
+to server-time epoch seconds and reject naive ones, against a local mock bridge.
+
+Usage:
+    python test_server_time_ranges.py
+"""
+
+import asyncio
+import sys
+from datetime import datetime, timedelta, timezone
+from pathlib import Path
+from types import SimpleNamespace
+
+sys.path.insert(0, str(Path(__file__).parent.parent))
+sys.path.insert(0, str(Path(__file__).parent))
+
+from mock_bridge import MockBridge
+from nautilus_mt5.bindings import _nautilus_mt5 as ext
+from nautilus_trader.core.nautilus_pyo3 import BarType, InstrumentId
+
+# 2024-01-01 00:00 UTC
+UTC_EPOCH = 1704067200
+# UTC+2, a common broker server zone
+SERVER_OFFSET = 7200
+
+# [time, open, high, low, close, tick_volume, spread, real_volume]
+RATES = [
+    [UTC_EPOCH + SERVER_OFFSET, 1.10001, 1.10010, 1.09990, 1.10005, 120, 1, 0],
+]
+
+
+def range_requests(bridge):
+    return [body for path, body in bridge.requests if path == "/api/copy_rates_range"]
+
+
+async def main():
+    instrument_id = InstrumentId.from_str("EURUSD.MT5")
+    bar_type = BarType.from_str("EURUSD.MT5-1-MINUTE-LAST-EXTERNAL")
+    instrument = SimpleNamespace(price_precision=5, size_precision=0)
+    # The same instant in UTC and in New York (UTC-5 in January)
+    start = datetime(2024, 1, 1, tzinfo=timezone.utc)
+    start_ny = datetime(2023, 12, 31, 19, tzinfo=timezone(timedelta(hours=-5)))
+    end = start + timedelta(hours=1)
+
+    with MockBridge({"/api/copy_rates_range": {"result": RATES}}) as bridge:
+        config = ext.Mt5DataClientConfig(mt5_base_url=bridge.base_url)
+        config.server_utc_offset_secs = SERVER_OFFSET
+        client = ext.Mt5DataClient(config)
+
+        print("\n[1/3] Testing copy_rates_range with aware datetimes...")
+        rates = await client.copy_rates_range("EURUSD", 1, start_ny, end)
+        assert len(rates) == 1
+        expected = ["EURUSD", 1, UTC_EPOCH + SERVER_OFFSET, UTC_EPOCH + 3600 + SERVER_OFFSET]
+        assert range_requests(bridge)[-1] == expected, range_requests(bridge)[-1]
+        print(f"   ✅ {range_requests(bridge)[-1]}")
+
+        print("\n[2/3] Testing request_bars with aware datetimes...")
+        bars = await client.request_bars(instrument_id, bar_type, instrument, start_ny, end)
+        assert len(bars) == 1
+        _, _, sent_start, sent_end = range_requests(bridge)[-1]
+        assert (sent_start, sent_end) == (UTC_EPOCH + SERVER_OFFSET, UTC_EPOCH + 3600 + SERVER_OFFSET)
+        print(f"   ✅ {range_requests(bridge)[-1]}")
+
+        print("\n[3/3] Testing naive datetimes are rejected...")
+        sent = len(range_requests(bridge))
+        naive = datetime(2024, 1, 1)
+        for call in (
+            lambda: client.copy_rates_range("EURUSD", 1, naive, end),
+            lambda: client.request_bars(instrument_id, bar_type, instrument, naive, end),
+        ):
+            try:
+                await call()
+            except ValueError as e:
+                assert "timezone-aware" in str(e)
+                print(f"   ✅ Rejected: {e}")
+            else:
+                raise AssertionError("naive datetimes must raise")
+        assert len(range_requests(bridge)) == sent
+
+    print("\nTEST COMPLETE")
+
+
+if __name__ == "__main__":
+    asyncio.run(main())
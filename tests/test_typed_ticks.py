@@ -0,0 +1,59 @@
+#!/usr/bin/env python3
+"""
+MT5 Adapter - Typed Ticks Smoke Test
+====================================
+
+Requires the extension built with `--features python-bindings`.
+Verifies `request_quote_ticks`/`request_trade_ticks` return Nautilus tick
+objects rather than JSON, against a local mock bridge.
+
+Usage:
+    python test_typed_ticks.py
+"""
+
+import asyncio
+import sys
+from datetime import datetime, timedelta, timezone
+from pathlib import Path
+from types import SimpleNamespace
+
+sys.path.insert(0, str(Path(__file__).parent.parent))
+sys.path.insert(0, str(Path(__file__).parent))
+
+from mock_bridge import MockBridge
+from nautilus_mt5.bindings import _nautilus_mt5 as ext
+from nautilus_trader.core.nautilus_pyo3 import InstrumentId
+
+# [time, bid, ask, last, volume, time_msc, flags, volume_real]
+TICKS = [
+    [1704067200, 1.10001, 1.10003, 0.0, 0, 1704067200123, 6, 0.0],
+    [1704067201, 1.10002, 1.10004, 1.10003, 2, 1704067201456, 56, 2.0],
+]
+
+
+async def main():
+    instrument_id = InstrumentId.from_str("EURUSD.MT5")
+    instrument = SimpleNamespace(price_precision=5, size_precision=2)
+    end = datetime(2024, 1, 1, 1, tzinfo=timezone.utc)
+    start = end - timedelta(hours=1)
+
+    with MockBridge({"/api/copy_ticks_range": {"result": TICKS}}) as bridge:
+        client = ext.Mt5DataClient(ext.Mt5DataClientConfig(mt5_base_url=bridge.base_url))
+
+        print("\n[1/2] Testing request_quote_ticks...")
+        quotes = await client.request_quote_ticks(instrument_id, instrument, start, end)
+        assert len(quotes) == 2
+        assert hasattr(quotes[0], "bid_price") and hasattr(quotes[0], "ask_price")
+        print(f"   ✅ {quotes[0]}")
+
+        print("\n[2/2] Testing request_trade_ticks...")
+        trades = await client.request_trade_ticks(instrument_id, instrument, start, end)
+        assert len(trades) == 2
+        assert hasattr(trades[0], "price") and hasattr(trades[0], "size")
+        print(f"   ✅ {trades[1]}")
+
+    print("\nTEST COMPLETE")
+
+
+if __name__ == "__main__":
+    asyncio.run(main())